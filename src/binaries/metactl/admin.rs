@@ -0,0 +1,120 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use anyhow::anyhow;
+use common_grpc::ConnectionFactory;
+use common_meta_api::reply::reply_to_api_result;
+use common_meta_types::protobuf::raft_service_client::RaftServiceClient;
+use common_meta_types::Endpoint;
+use common_meta_types::NodeId;
+use databend_meta::message::ForwardRequest;
+use databend_meta::message::ForwardRequestBody;
+use databend_meta::message::ForwardResponse;
+use databend_meta::message::JoinRequest;
+use databend_meta::message::LeaveRequest;
+
+/// Print the nodes in the cluster, in JSON.
+pub async fn list_members(admin_addr: &str) -> anyhow::Result<()> {
+    let body = http_get(admin_addr, "/v1/cluster/nodes").await?;
+    println!("{}", body);
+    Ok(())
+}
+
+/// Add a node into the cluster, through the raft api of a node in the cluster.
+pub async fn add_member(
+    via: &str,
+    node_id: NodeId,
+    raft_api_address: &str,
+    grpc_api_advertise_address: Option<String>,
+    learner: bool,
+) -> anyhow::Result<()> {
+    let (host, port) = raft_api_address
+        .rsplit_once(':')
+        .ok_or_else(|| anyhow!("invalid raft api address: {}", raft_api_address))?;
+    let endpoint = Endpoint::new(host, port.parse()?);
+
+    let body =
+        JoinRequest::new(node_id, endpoint, grpc_api_advertise_address).with_learner(learner);
+    forward(via, ForwardRequestBody::Join(body)).await?;
+
+    println!("Added node {} via {}", node_id, via);
+    Ok(())
+}
+
+/// Remove a node from the cluster, through the raft api of a node in the cluster.
+pub async fn remove_member(via: &str, node_id: NodeId) -> anyhow::Result<()> {
+    forward(via, ForwardRequestBody::Leave(LeaveRequest { node_id })).await?;
+
+    println!("Removed node {} via {}", node_id, via);
+    Ok(())
+}
+
+/// Promote a learner to a voter, through the admin api of a node in the cluster.
+pub async fn promote_member(admin_addr: &str, node_id: NodeId) -> anyhow::Result<()> {
+    let path = format!("/v1/ctrl/promote_learner?node_id={}", node_id);
+    http_get(admin_addr, &path).await?;
+    println!("Promoted node {} to voter", node_id);
+    Ok(())
+}
+
+/// Demote a voter to a learner, through the admin api of a node in the cluster.
+pub async fn demote_member(admin_addr: &str, node_id: NodeId) -> anyhow::Result<()> {
+    let path = format!("/v1/ctrl/demote_voter?node_id={}", node_id);
+    http_get(admin_addr, &path).await?;
+    println!("Demoted node {} to learner", node_id);
+    Ok(())
+}
+
+/// Let a node start an election, through its admin api.
+///
+/// The election succeeds only if the current leader is gone, thus it does not report the result:
+/// check it with `status`.
+pub async fn trigger_elect(admin_addr: &str) -> anyhow::Result<()> {
+    http_get(admin_addr, "/v1/ctrl/trigger_elect").await?;
+    println!("Asked {} to start an election", admin_addr);
+    Ok(())
+}
+
+/// Let a node build a snapshot, through its admin api.
+pub async fn trigger_snapshot(admin_addr: &str) -> anyhow::Result<()> {
+    http_get(admin_addr, "/v1/ctrl/trigger_snapshot").await?;
+    println!("Triggered snapshot on {}", admin_addr);
+    Ok(())
+}
+
+/// Send a request to a raft node, which forwards it to the leader.
+async fn forward(via: &str, body: ForwardRequestBody) -> anyhow::Result<ForwardResponse> {
+    let chan =
+        ConnectionFactory::create_rpc_channel(via, Some(Duration::from_secs(10)), None).await?;
+    let mut raft_client = RaftServiceClient::new(chan);
+
+    let req = ForwardRequest {
+        forward_to_leader: 1,
+        body,
+    };
+
+    let reply = raft_client.forward(req).await?.into_inner();
+    let resp: ForwardResponse = reply_to_api_result(reply)?;
+    Ok(resp)
+}
+
+async fn http_get(admin_addr: &str, path: &str) -> anyhow::Result<String> {
+    let url = format!("http://{}{}", admin_addr, path);
+
+    let resp = reqwest::get(&url).await?.error_for_status()?;
+    let body = resp.text().await?;
+    Ok(body)
+}
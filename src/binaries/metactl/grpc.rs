@@ -14,14 +14,19 @@
 
 use std::fs::File;
 use std::io::Write;
+use std::sync::Arc;
 use std::time::Duration;
 
+use common_meta_client::ClientHandle;
 use common_meta_client::MetaGrpcClient;
 use common_meta_raft_store::key_spaces::RaftStoreEntry;
 use common_meta_types::protobuf::Empty;
 use tokio_stream::StreamExt;
 
-pub async fn export_meta(addr: &str, save: String) -> anyhow::Result<()> {
+use crate::snapshot::is_excluded_by_prefix;
+
+/// Create a client to the meta-service gRPC api.
+pub fn new_client(addr: &str) -> anyhow::Result<Arc<ClientHandle>> {
     let client = MetaGrpcClient::try_create(
         vec![addr.to_string()],
         "root",
@@ -31,6 +36,11 @@ pub async fn export_meta(addr: &str, save: String) -> anyhow::Result<()> {
         Duration::from_secs(10),
         None,
     )?;
+    Ok(client)
+}
+
+pub async fn export_meta(addr: &str, save: String, prefix: &str) -> anyhow::Result<()> {
+    let client = new_client(addr)?;
 
     let (mut grpc_client, _server_version) = client.make_client().await?;
 
@@ -53,7 +63,11 @@ pub async fn export_meta(addr: &str, save: String) -> anyhow::Result<()> {
             // Check if the received line is a valid json string.
             let de_res: Result<(String, RaftStoreEntry), _> = serde_json::from_str(line);
             match de_res {
-                Ok(_) => {}
+                Ok((_tree_name, entry)) => {
+                    if is_excluded_by_prefix(prefix, &entry) {
+                        continue;
+                    }
+                }
                 Err(e) => {
                     eprintln!("Invalid json string: {:?}", line);
                     eprintln!("              Error: {}", e);
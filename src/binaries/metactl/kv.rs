@@ -0,0 +1,163 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs;
+use std::io;
+use std::io::Read;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use common_meta_kvapi::kvapi::KVApi;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_types::protobuf::watch_request::FilterType;
+use common_meta_types::protobuf::WatchRequest;
+use common_meta_types::SeqV;
+use common_meta_types::TxnRequest;
+use serde_json::json;
+use serde_json::Value;
+
+use crate::grpc::new_client;
+
+pub async fn get(addr: &str, key: &str) -> anyhow::Result<()> {
+    let client = new_client(addr)?;
+
+    let res = client.get_kv(key).await?;
+    println!("{}", kv_to_json(key, res.as_ref()));
+    Ok(())
+}
+
+pub async fn put(
+    addr: &str,
+    key: &str,
+    value: &str,
+    expire_after: Option<u64>,
+) -> anyhow::Result<()> {
+    let client = new_client(addr)?;
+
+    let mut req = UpsertKVReq::update(key, value.as_bytes());
+    if let Some(sec) = expire_after {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+        req = req.with_expire_sec(now + sec);
+    }
+
+    let res = client.upsert_kv(req).await?;
+    println!("{}", kv_to_json(key, res.result.as_ref()));
+    Ok(())
+}
+
+pub async fn del(addr: &str, key: &str) -> anyhow::Result<()> {
+    let client = new_client(addr)?;
+
+    let res = client.upsert_kv(UpsertKVReq::delete(key)).await?;
+    println!("{}", kv_to_json(key, res.prev.as_ref()));
+    Ok(())
+}
+
+pub async fn list(addr: &str, prefix: &str) -> anyhow::Result<()> {
+    let client = new_client(addr)?;
+
+    let res = client.prefix_list_kv(prefix).await?;
+    for (key, seq_v) in res.iter() {
+        println!("{}", kv_to_json(key, Some(seq_v)));
+    }
+    Ok(())
+}
+
+/// Watch a key or keys with a prefix, until the stream is closed by the server.
+pub async fn watch(
+    addr: &str,
+    key: &str,
+    prefix: bool,
+    start_revision: Option<u64>,
+) -> anyhow::Result<()> {
+    let client = new_client(addr)?;
+
+    let key_end = if prefix { Some(prefix_end(key)) } else { None };
+
+    let req = WatchRequest {
+        key: key.to_string(),
+        key_end,
+        filter_type: FilterType::All.into(),
+        start_revision,
+    };
+
+    let mut strm = client.request(req).await?;
+
+    while let Some(resp) = strm.message().await? {
+        let Some(event) = resp.event else {
+            continue;
+        };
+
+        let prev = event.prev.map(SeqV::from);
+        let current = event.current.map(SeqV::from);
+
+        let line = json!({
+            "revision": resp.revision,
+            "key": event.key,
+            "prev": prev.as_ref().map(seq_v_to_json),
+            "current": current.as_ref().map(seq_v_to_json),
+        });
+        println!("{}", line);
+    }
+    Ok(())
+}
+
+/// Run a transaction read from a JSON file, or from stdin if `file` is `-`.
+pub async fn txn(addr: &str, file: &str) -> anyhow::Result<()> {
+    let client = new_client(addr)?;
+
+    let buf = if file == "-" {
+        let mut buf = String::new();
+        io::stdin().read_to_string(&mut buf)?;
+        buf
+    } else {
+        fs::read_to_string(file)?
+    };
+
+    let req: TxnRequest = serde_json::from_str(&buf)?;
+    let reply = client.transaction(req).await?;
+
+    println!("{}", serde_json::to_string(&reply)?);
+    Ok(())
+}
+
+/// Returns the end of the range of keys that start with `prefix`, exclusive.
+fn prefix_end(prefix: &str) -> String {
+    let mut chars = prefix.chars().collect::<Vec<_>>();
+
+    while let Some(c) = chars.pop() {
+        if let Some(next) = char::from_u32(c as u32 + 1) {
+            chars.push(next);
+            return chars.into_iter().collect();
+        }
+    }
+
+    // An empty prefix matches every key.
+    char::MAX.to_string()
+}
+
+fn kv_to_json(key: &str, seq_v: Option<&SeqV>) -> Value {
+    json!({
+        "key": key,
+        "value": seq_v.map(seq_v_to_json),
+    })
+}
+
+fn seq_v_to_json(seq_v: &SeqV) -> Value {
+    json!({
+        "seq": seq_v.seq,
+        "expire_at": seq_v.meta.as_ref().and_then(|m| m.expire_at),
+        "data": String::from_utf8_lossy(&seq_v.data),
+    })
+}
@@ -14,10 +14,14 @@
 
 #![allow(clippy::uninlined_format_args)]
 
+mod admin;
 mod grpc;
+mod kv;
+mod subcommands;
 use common_tracing::QueryLogConfig;
 use common_tracing::TracingConfig;
 use grpc::export_meta;
+use subcommands::CtlCommand;
 
 mod snapshot;
 
@@ -42,6 +46,10 @@ use serde::Serialize;
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Parser)]
 #[clap(about, version = &**METASRV_COMMIT_VERSION, author)]
 pub struct Config {
+    #[clap(subcommand)]
+    #[serde(skip)]
+    pub subcommand: Option<CtlCommand>,
+
     /// Run a command
     #[clap(long, default_value = "")]
     pub cmd: String,
@@ -65,6 +73,14 @@ pub struct Config {
     )]
     pub grpc_api_address: String,
 
+    /// The HTTP admin api address of a meta-service node, for cluster management commands.
+    #[clap(
+        long,
+        env = "METASRV_ADMIN_API_ADDRESS",
+        default_value = "127.0.0.1:28002"
+    )]
+    pub admin_api_address: String,
+
     /// The dir to store persisted meta state, including raft logs, state machine etc.
     #[clap(long)]
     #[serde(alias = "kvsrv_raft_dir")]
@@ -77,6 +93,11 @@ pub struct Config {
     #[clap(long, default_value = "")]
     pub db: String,
 
+    /// When export or import, only the kv records whose key starts with `prefix` are included.
+    /// Other records such as raft logs and cluster nodes are not affected.
+    #[clap(long, default_value = "")]
+    pub prefix: String,
+
     /// initial_cluster format: node_id=endpoint,grpc_api_addr
     #[clap(long)]
     pub initial_cluster: Vec<String>,
@@ -128,6 +149,10 @@ async fn main() -> anyhow::Result<()> {
 
     let _guards = init_logging("metactl", &log_config);
 
+    if let Some(subcommand) = &config.subcommand {
+        return subcommand.run(&config).await;
+    }
+
     if config.status {
         return show_status(&config).await;
     }
@@ -228,17 +253,7 @@ async fn bench_client_num_conn(conf: &Config) -> anyhow::Result<()> {
 }
 
 async fn show_status(conf: &Config) -> anyhow::Result<()> {
-    let addr = &conf.grpc_api_address;
-
-    let client = MetaGrpcClient::try_create(
-        vec![addr.to_string()],
-        "root",
-        "xxx",
-        None,
-        None,
-        Duration::from_secs(10),
-        None,
-    )?;
+    let client = grpc::new_client(&conf.grpc_api_address)?;
 
     let res = client.get_cluster_status().await?;
     println!("BinaryVersion: {}", res.binary_version);
@@ -158,7 +158,7 @@ fn read_version(first_line: &str) -> anyhow::Result<DataVersion> {
 ///
 /// While importing, the max log id is also returned.
 fn import_v0_or_v001(
-    _config: &Config,
+    config: &Config,
     lines: impl IntoIterator<Item = Result<String, io::Error>>,
 ) -> anyhow::Result<Option<LogId>> {
     let db = get_sled_db();
@@ -171,6 +171,10 @@ fn import_v0_or_v001(
         let (tree_name, kv_entry): (String, RaftStoreEntryCompat) = serde_json::from_str(&l)?;
         let kv_entry = kv_entry.upgrade();
 
+        if is_excluded_by_prefix(&config.prefix, &kv_entry) {
+            continue;
+        }
+
         if !trees.contains_key(&tree_name) {
             let tree = db.open_tree(&tree_name)?;
             trees.insert(tree_name.clone(), tree);
@@ -221,6 +225,10 @@ async fn import_v002(
         let (tree_name, kv_entry): (String, RaftStoreEntryCompat) = serde_json::from_str(&l)?;
         let kv_entry = kv_entry.upgrade();
 
+        if is_excluded_by_prefix(&config.prefix, &kv_entry) {
+            continue;
+        }
+
         if tree_name.starts_with("state_machine/") {
             // Write to snapshot
             writer
@@ -437,6 +445,17 @@ async fn init_new_cluster(
     Ok(())
 }
 
+/// Returns true if `entry` is a kv record, or the expiration index of it, whose key does not start with `prefix`.
+///
+/// An empty `prefix` excludes nothing.
+pub(crate) fn is_excluded_by_prefix(prefix: &str, entry: &RaftStoreEntry) -> bool {
+    match entry {
+        RaftStoreEntry::GenericKV { key, .. } => !key.starts_with(prefix),
+        RaftStoreEntry::Expire { value, .. } => !value.key.starts_with(prefix),
+        _ => false,
+    }
+}
+
 fn clear(config: &Config) -> anyhow::Result<()> {
     let db = get_sled_db();
 
@@ -483,6 +502,13 @@ async fn export_from_dir(config: &Config) -> anyhow::Result<()> {
     let mut cnt = 0;
 
     while let Some(line) = lines.try_next().await? {
+        if !config.prefix.is_empty() {
+            let (_tree_name, entry): (String, RaftStoreEntry) = serde_json::from_str(&line)?;
+            if is_excluded_by_prefix(&config.prefix, &entry) {
+                continue;
+            }
+        }
+
         cnt += 1;
 
         if file.as_ref().is_none() {
@@ -509,7 +535,12 @@ async fn export_from_running_node(config: &Config) -> Result<(), anyhow::Error>
 
     let grpc_api_addr = get_available_socket_addr(&config.grpc_api_address).await?;
 
-    export_meta(grpc_api_addr.to_string().as_str(), config.db.clone()).await?;
+    export_meta(
+        grpc_api_addr.to_string().as_str(),
+        config.db.clone(),
+        &config.prefix,
+    )
+    .await?;
     Ok(())
 }
 
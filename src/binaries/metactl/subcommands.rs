@@ -0,0 +1,197 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use clap::Subcommand;
+
+use crate::admin;
+use crate::kv;
+use crate::snapshot;
+use crate::Config;
+
+/// The subcommands of metactl.
+///
+/// KV commands and `status` talk to the gRPC api at `--grpc-api-address`,
+/// cluster management commands talk to the HTTP admin api at `--admin-api-address`.
+#[derive(Clone, Debug, PartialEq, Eq, Subcommand)]
+pub enum CtlCommand {
+    /// Show the status of a meta-service node.
+    Status,
+
+    /// Get the value of a key.
+    Get { key: String },
+
+    /// Update or insert the value of a key.
+    Put {
+        key: String,
+        value: String,
+
+        /// Remove the key after this many seconds.
+        #[clap(long)]
+        expire_after: Option<u64>,
+    },
+
+    /// Delete a key.
+    Del { key: String },
+
+    /// List the keys that start with a prefix.
+    List {
+        #[clap(long, default_value = "")]
+        prefix: String,
+    },
+
+    /// Watch the changes of a key, and print every event as a line of JSON.
+    Watch {
+        key: String,
+
+        /// Watch all keys that start with `key`.
+        #[clap(long)]
+        prefix: bool,
+
+        /// Replay the change events since this revision before new ones.
+        #[clap(long)]
+        start_revision: Option<u64>,
+    },
+
+    /// Run a transaction read from a JSON encoded `TxnRequest`.
+    Txn {
+        /// The file to read the transaction from, `-` for stdin.
+        file: String,
+    },
+
+    /// Manage the members of a cluster.
+    #[clap(subcommand)]
+    Member(MemberCommand),
+
+    /// Ask the node at `--admin-api-address` to start an election.
+    ///
+    /// It does not move the leadership away from a healthy leader: the other nodes reject
+    /// the election while the current leader is active. It only helps when the leader is
+    /// already gone, e.g., stopped or partitioned, to elect a new one without waiting for
+    /// the election timeout.
+    TriggerElect,
+
+    /// Let the node at `--admin-api-address` build a snapshot.
+    TriggerSnapshot,
+
+    /// Export data to JSON lines, see `--raft-dir`, `--db` and `--prefix`.
+    Export,
+
+    /// Import data from JSON lines, see `--raft-dir`, `--db`, `--prefix` and `--initial-cluster`.
+    Import,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Subcommand)]
+pub enum MemberCommand {
+    /// List the nodes in the cluster.
+    List,
+
+    /// Add a node into the cluster.
+    Add {
+        #[clap(long)]
+        node_id: u64,
+
+        /// The raft api address of the node to add, in form of `<host>:<port>`.
+        #[clap(long)]
+        raft_api_address: String,
+
+        /// The gRPC api address for clients to connect to the node to add.
+        #[clap(long)]
+        grpc_api_advertise_address: Option<String>,
+
+        /// The raft api address of a node in the cluster, through which to add the node.
+        #[clap(long)]
+        via: String,
+
+        /// Add the node as a learner, which replicates logs but does not vote.
+        #[clap(long)]
+        learner: bool,
+    },
+
+    /// Remove a node from the cluster.
+    Remove {
+        #[clap(long)]
+        node_id: u64,
+
+        /// The raft api address of a node in the cluster, through which to remove the node.
+        #[clap(long)]
+        via: String,
+    },
+
+    /// Promote a learner to a voter, through the node at `--admin-api-address`.
+    Promote {
+        #[clap(long)]
+        node_id: u64,
+    },
+
+    /// Demote a voter to a learner, through the node at `--admin-api-address`.
+    Demote {
+        #[clap(long)]
+        node_id: u64,
+    },
+}
+
+impl CtlCommand {
+    pub async fn run(&self, config: &Config) -> anyhow::Result<()> {
+        let grpc_addr = &config.grpc_api_address;
+        let admin_addr = &config.admin_api_address;
+
+        match self {
+            CtlCommand::Status => crate::show_status(config).await,
+            CtlCommand::Get { key } => kv::get(grpc_addr, key).await,
+            CtlCommand::Put {
+                key,
+                value,
+                expire_after,
+            } => kv::put(grpc_addr, key, value, *expire_after).await,
+            CtlCommand::Del { key } => kv::del(grpc_addr, key).await,
+            CtlCommand::List { prefix } => kv::list(grpc_addr, prefix).await,
+            CtlCommand::Watch {
+                key,
+                prefix,
+                start_revision,
+            } => kv::watch(grpc_addr, key, *prefix, *start_revision).await,
+            CtlCommand::Txn { file } => kv::txn(grpc_addr, file).await,
+            CtlCommand::Member(member) => match member {
+                MemberCommand::List => admin::list_members(admin_addr).await,
+                MemberCommand::Add {
+                    node_id,
+                    raft_api_address,
+                    grpc_api_advertise_address,
+                    via,
+                    learner,
+                } => {
+                    admin::add_member(
+                        via,
+                        *node_id,
+                        raft_api_address,
+                        grpc_api_advertise_address.clone(),
+                        *learner,
+                    )
+                    .await
+                }
+                MemberCommand::Remove { node_id, via } => admin::remove_member(via, *node_id).await,
+                MemberCommand::Promote { node_id } => {
+                    admin::promote_member(admin_addr, *node_id).await
+                }
+                MemberCommand::Demote { node_id } => {
+                    admin::demote_member(admin_addr, *node_id).await
+                }
+            },
+            CtlCommand::TriggerElect => admin::trigger_elect(admin_addr).await,
+            CtlCommand::TriggerSnapshot => admin::trigger_snapshot(admin_addr).await,
+            CtlCommand::Export => snapshot::export_data(config).await,
+            CtlCommand::Import => snapshot::import_data(config).await,
+        }
+    }
+}
@@ -149,6 +149,10 @@ build_exceptions! {
     UnknownCatalog(1119),
     UnknownCatalogType(1120),
     UnmatchMaskPolicyReturnType(1121),
+    /// The current transaction has failed, only `COMMIT` or `ROLLBACK` can end it.
+    CurrentTransactionIsAborted(1122),
+    /// The statement can not be executed inside an explicit transaction.
+    StatementNotAllowedInTransaction(1123),
 
     // Data Related Errors
 
@@ -214,6 +218,8 @@ build_exceptions! {
     DuplicatedUpsertFiles(2014),
     TableAlreadyLocked(2015),
     TableLockExpired(2016),
+    SnapshotRefAlreadyExists(2017),
+    UnknownSnapshotRef(2018),
 
     // User api error codes.
     UnknownUser(2201),
@@ -254,6 +260,10 @@ build_exceptions! {
     CatalogNotFound(2320),
     /// data mask error codes
     DatamaskAlreadyExists(2321),
+    /// sequence error codes
+    UnknownSequence(2322),
+    SequenceAlreadyExists(2323),
+    SequenceOutOfRange(2324),
 
 
     // Cluster error codes.
@@ -31,6 +31,9 @@ mod schema_api;
 mod schema_api_impl;
 mod schema_api_keys;
 mod schema_api_test_suite;
+mod sequence_api;
+mod sequence_api_impl;
+mod sequence_api_test_suite;
 mod share_api;
 mod share_api_impl;
 mod share_api_keys;
@@ -46,6 +49,8 @@ pub(crate) use id_generator::IdGenerator;
 pub use schema_api::SchemaApi;
 pub(crate) use schema_api_impl::get_db_or_err;
 pub use schema_api_test_suite::SchemaApiTestSuite;
+pub use sequence_api::SequenceApi;
+pub use sequence_api_test_suite::SequenceApiTestSuite;
 pub use share_api::ShareApi;
 pub use share_api_test_suite::ShareApiTestSuite;
 pub use util::assert_table_exist;
@@ -41,6 +41,7 @@ use common_meta_app::app_error::UndropTableAlreadyExists;
 use common_meta_app::app_error::UndropTableHasNoHistory;
 use common_meta_app::app_error::UndropTableWithNoDropTime;
 use common_meta_app::app_error::UnknownCatalog;
+use common_meta_app::app_error::UnknownDatabaseId;
 use common_meta_app::app_error::UnknownIndex;
 use common_meta_app::app_error::UnknownTable;
 use common_meta_app::app_error::UnknownTableId;
@@ -148,6 +149,8 @@ use common_meta_app::schema::UndropTableReply;
 use common_meta_app::schema::UndropTableReq;
 use common_meta_app::schema::UpdateIndexReply;
 use common_meta_app::schema::UpdateIndexReq;
+use common_meta_app::schema::UpdateMultiTableMetaReply;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
 use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpdateVirtualColumnReply;
@@ -2287,6 +2290,61 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
         ))
     }
 
+    #[logcall::logcall("debug")]
+    #[minitrace::trace]
+    async fn get_table_info_by_id(&self, table_id: MetaId) -> Result<Arc<TableInfo>, KVAppError> {
+        debug!(req = as_debug!(&table_id); "SchemaApi: {}", func_name!());
+
+        let tbid = TableId { table_id };
+        let (tb_meta_seq, tb_meta) =
+            get_table_by_id_or_err(self, &tbid, "get_table_info_by_id").await?;
+
+        let table_id_to_name = TableIdToName { table_id };
+        let (_, dbid_tbname): (_, Option<DBIdTableName>) =
+            get_pb_value(self, &table_id_to_name).await?;
+        let dbid_tbname = dbid_tbname.ok_or_else(|| {
+            KVAppError::AppError(AppError::UnknownTableId(UnknownTableId::new(
+                table_id,
+                "get_table_info_by_id failed to find table name",
+            )))
+        })?;
+
+        let db_id_to_name = DatabaseIdToName {
+            db_id: dbid_tbname.db_id,
+        };
+        let (_, db_name_ident): (_, Option<DatabaseNameIdent>) =
+            get_pb_value(self, &db_id_to_name).await?;
+        let db_name_ident = db_name_ident.ok_or_else(|| {
+            KVAppError::AppError(AppError::UnknownDatabaseId(UnknownDatabaseId::new(
+                dbid_tbname.db_id,
+                "get_table_info_by_id failed to find database name",
+            )))
+        })?;
+
+        let tenant_dbname_tbname = TableNameIdent {
+            tenant: db_name_ident.tenant,
+            db_name: db_name_ident.db_name,
+            table_name: dbid_tbname.table_name,
+        };
+
+        debug!(
+            ident = as_display!(&tbid),
+            name = as_display!(&tenant_dbname_tbname);
+            "get_table_info_by_id"
+        );
+
+        let tb_info = TableInfo {
+            ident: TableIdent::new(table_id, tb_meta_seq),
+            desc: tenant_dbname_tbname.to_string(),
+            name: tenant_dbname_tbname.table_name.clone(),
+            meta: tb_meta,
+            tenant: tenant_dbname_tbname.tenant,
+            db_type: DatabaseType::NormalDB,
+        };
+
+        Ok(Arc::new(tb_info))
+    }
+
     #[logcall::logcall("debug")]
     #[minitrace::trace]
     async fn drop_table_by_id(&self, req: DropTableByIdReq) -> Result<DropTableReply, KVAppError> {
@@ -2846,6 +2904,131 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
         }
     }
 
+    #[logcall::logcall("debug")]
+    #[minitrace::trace]
+    async fn update_multi_table_meta(
+        &self,
+        req: UpdateMultiTableMetaReq,
+    ) -> Result<UpdateMultiTableMetaReply, KVAppError> {
+        debug!(req = as_debug!(&req); "SchemaApi: {}", func_name!());
+
+        let fail_if_duplicated = req.update_table_metas.iter().find_map(|update| {
+            update
+                .copied_files
+                .as_ref()
+                .filter(|v| v.fail_if_duplicated)
+                .map(|_| update.table_id)
+        });
+
+        loop {
+            let mut txn_req = TxnRequest {
+                condition: vec![],
+                if_then: vec![],
+                else_then: vec![],
+            };
+            let mut table_metas = Vec::with_capacity(req.update_table_metas.len());
+
+            for update in req.update_table_metas.iter() {
+                let tbid = TableId {
+                    table_id: update.table_id,
+                };
+                let (tb_meta_seq, table_meta): (_, Option<TableMeta>) =
+                    get_pb_value(self, &tbid).await?;
+
+                let Some(table_meta) = table_meta.filter(|_| tb_meta_seq != 0) else {
+                    return Err(KVAppError::AppError(AppError::UnknownTableId(
+                        UnknownTableId::new(update.table_id, "update_multi_table_meta"),
+                    )));
+                };
+                if update.seq.match_seq(tb_meta_seq).is_err() {
+                    return Err(KVAppError::AppError(AppError::from(
+                        TableVersionMismatched::new(
+                            update.table_id,
+                            update.seq,
+                            tb_meta_seq,
+                            "update_multi_table_meta",
+                        ),
+                    )));
+                }
+
+                // table is not changed
+                txn_req.condition.push(txn_cond_seq(&tbid, Eq, tb_meta_seq));
+                txn_req
+                    .if_then
+                    .push(txn_op_put(&tbid, serialize_struct(&update.new_table_meta)?)); // tb_id -> tb_meta
+
+                if let Some(req) = &update.copied_files {
+                    let (conditions, match_operations) =
+                        build_upsert_table_copied_file_info_conditions(
+                            &tbid,
+                            req,
+                            tb_meta_seq,
+                            req.fail_if_duplicated,
+                        )?;
+                    txn_req.condition.extend(conditions);
+                    txn_req.if_then.extend(match_operations)
+                }
+
+                if let Some(deduplicated_label) = update.deduplicated_label.clone() {
+                    txn_req
+                        .if_then
+                        .push(build_upsert_table_deduplicated_label(deduplicated_label))
+                }
+
+                table_metas.push(table_meta);
+            }
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(
+                tables = as_debug!(&req.update_table_metas.iter().map(|v| v.table_id).collect::<Vec<_>>()),
+                succ = succ;
+                "update_multi_table_meta"
+            );
+
+            if succ {
+                let mut share_table_info = vec![];
+                for table_meta in table_metas.iter() {
+                    if let Some(infos) = get_share_table_info_map(self, table_meta).await? {
+                        share_table_info.extend(infos);
+                    }
+                }
+                return Ok(UpdateMultiTableMetaReply {
+                    share_table_info: if share_table_info.is_empty() {
+                        None
+                    } else {
+                        Some(share_table_info)
+                    },
+                });
+            }
+
+            // The txn failed, either some table has been changed, which will be reported
+            // by the version check of the next round, or some copied files are duplicated.
+            if let Some(table_id) = fail_if_duplicated {
+                for update in req.update_table_metas.iter() {
+                    let tbid = TableId {
+                        table_id: update.table_id,
+                    };
+                    let (tb_meta_seq, _): (_, Option<TableMeta>) =
+                        get_pb_value(self, &tbid).await?;
+                    if update.seq.match_seq(tb_meta_seq).is_err() {
+                        return Err(KVAppError::AppError(AppError::from(
+                            TableVersionMismatched::new(
+                                update.table_id,
+                                update.seq,
+                                tb_meta_seq,
+                                "update_multi_table_meta",
+                            ),
+                        )));
+                    }
+                }
+                return Err(KVAppError::AppError(AppError::from(
+                    DuplicatedUpsertFiles::new(table_id, "update_multi_table_meta"),
+                )));
+            }
+        }
+    }
+
     #[logcall::logcall("debug")]
     #[minitrace::trace]
     async fn set_table_column_mask_policy(
@@ -3977,6 +4160,7 @@ fn build_upsert_table_deduplicated_label(deduplicated_label: String) -> TxnOp {
             value: 1_i8.to_le_bytes().to_vec(),
             prev_value: false,
             expire_at,
+            lease_id: None,
         })),
     }
 }
@@ -99,6 +99,7 @@ use common_meta_app::schema::TableStatistics;
 use common_meta_app::schema::TruncateTableReq;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpdateVirtualColumnReq;
 use common_meta_app::schema::UpsertTableCopiedFileReq;
@@ -230,6 +231,7 @@ async fn upsert_test_data(
             seq: MatchSeq::GE(0),
             value: Operation::Update(value),
             value_meta: None,
+            lease_id: None,
         })
         .await?;
 
@@ -247,6 +249,7 @@ async fn delete_test_data(
             seq: MatchSeq::GE(0),
             value: Operation::Delete,
             value_meta: None,
+            lease_id: None,
         })
         .await?;
 
@@ -286,6 +289,7 @@ impl SchemaApiTestSuite {
             .await?;
         suite.table_rename(&b.build().await).await?;
         suite.table_update_meta(&b.build().await).await?;
+        suite.table_update_multi_meta(&b.build().await).await?;
         suite.table_update_mask_policy(&b.build().await).await?;
         suite.table_upsert_option(&b.build().await).await?;
         suite.table_list(&b.build().await).await?;
@@ -2310,6 +2314,109 @@ impl SchemaApiTestSuite {
         Ok(())
     }
 
+    #[minitrace::trace]
+    async fn table_update_multi_meta<MT: SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let db_name = "db1";
+        let tbl_names = ["tb1", "tb2"];
+
+        let schema = || {
+            Arc::new(TableSchema::new(vec![TableField::new(
+                "number",
+                TableDataType::Number(NumberDataType::UInt64),
+            )]))
+        };
+
+        info!("--- prepare db and tables");
+        {
+            let plan = CreateDatabaseReq {
+                if_not_exists: false,
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                },
+                meta: DatabaseMeta {
+                    engine: "".to_string(),
+                    ..DatabaseMeta::default()
+                },
+            };
+            mt.create_database(plan).await?;
+
+            for tbl_name in tbl_names {
+                let req = CreateTableReq {
+                    if_not_exists: false,
+                    name_ident: TableNameIdent {
+                        tenant: tenant.to_string(),
+                        db_name: db_name.to_string(),
+                        table_name: tbl_name.to_string(),
+                    },
+                    table_meta: TableMeta {
+                        schema: schema(),
+                        engine: "JSON".to_string(),
+                        created_on: Utc::now(),
+                        ..TableMeta::default()
+                    },
+                };
+                mt.create_table(req).await?;
+            }
+        }
+
+        let update_req = |table: &TableInfo, seq: u64, data_bytes: u64| {
+            let mut new_table_meta = table.meta.clone();
+            new_table_meta.statistics = TableStatistics {
+                data_bytes,
+                ..Default::default()
+            };
+            UpdateTableMetaReq {
+                table_id: table.ident.table_id,
+                seq: MatchSeq::Exact(seq),
+                new_table_meta,
+                copied_files: None,
+                deduplicated_label: None,
+            }
+        };
+
+        info!("--- update multi table meta, normal case");
+        {
+            let mut update_table_metas = vec![];
+            for tbl_name in tbl_names {
+                let table = mt.get_table((tenant, db_name, tbl_name).into()).await?;
+                update_table_metas.push(update_req(&table, table.ident.seq, 10));
+            }
+            mt.update_multi_table_meta(UpdateMultiTableMetaReq { update_table_metas })
+                .await?;
+
+            for tbl_name in tbl_names {
+                let table = mt.get_table((tenant, db_name, tbl_name).into()).await?;
+                assert_eq!(10, table.meta.statistics.data_bytes);
+            }
+        }
+
+        info!("--- update multi table meta: version mismatch of one table updates nothing");
+        {
+            let tb1 = mt.get_table((tenant, db_name, tbl_names[0]).into()).await?;
+            let tb2 = mt.get_table((tenant, db_name, tbl_names[1]).into()).await?;
+            let res = mt
+                .update_multi_table_meta(UpdateMultiTableMetaReq {
+                    update_table_metas: vec![
+                        update_req(&tb1, tb1.ident.seq, 20),
+                        update_req(&tb2, tb2.ident.seq + 1, 20),
+                    ],
+                })
+                .await;
+
+            let err = ErrorCode::from(res.unwrap_err());
+            assert_eq!(ErrorCode::TABLE_VERSION_MISMATCHED, err.code());
+
+            for tbl_name in tbl_names {
+                let table = mt.get_table((tenant, db_name, tbl_name).into()).await?;
+                assert_eq!(10, table.meta.statistics.data_bytes);
+            }
+        }
+
+        Ok(())
+    }
+
     #[minitrace::trace]
     async fn table_update_mask_policy<
         MT: SchemaApi + DatamaskApi + kvapi::AsKVApi<Error = MetaError>,
@@ -4326,6 +4433,18 @@ impl SchemaApiTestSuite {
                 assert_eq!(ErrorCode::UnknownTableId("").code(), err.code());
             }
         }
+
+        info!("--- get_table_info_by_id ");
+        {
+            let table = mt.get_table((tenant, db_name, tbl_name).into()).await?;
+
+            let got = mt.get_table_info_by_id(table.ident.table_id).await?;
+            assert_eq!(table.as_ref(), got.as_ref());
+
+            let got = mt.get_table_info_by_id(1024).await;
+            let err = ErrorCode::from(got.unwrap_err());
+            assert_eq!(ErrorCode::UnknownTableId("").code(), err.code());
+        }
         Ok(())
     }
 
@@ -0,0 +1,51 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_meta_app::schema::CreateSequenceReply;
+use common_meta_app::schema::CreateSequenceReq;
+use common_meta_app::schema::DropSequenceReply;
+use common_meta_app::schema::DropSequenceReq;
+use common_meta_app::schema::GetSequenceNextValuesReply;
+use common_meta_app::schema::GetSequenceNextValuesReq;
+use common_meta_app::schema::GetSequenceReply;
+use common_meta_app::schema::GetSequenceReq;
+use common_meta_app::schema::ListSequencesReq;
+use common_meta_app::schema::SequenceMeta;
+
+use crate::kv_app_error::KVAppError;
+
+#[async_trait::async_trait]
+pub trait SequenceApi: Send + Sync {
+    async fn create_sequence(
+        &self,
+        req: CreateSequenceReq,
+    ) -> Result<CreateSequenceReply, KVAppError>;
+
+    async fn drop_sequence(&self, req: DropSequenceReq) -> Result<DropSequenceReply, KVAppError>;
+
+    async fn get_sequence(&self, req: GetSequenceReq) -> Result<GetSequenceReply, KVAppError>;
+
+    /// Return a list of sequences of a tenant: `(sequence_name, SequenceMeta)`.
+    async fn list_sequences(
+        &self,
+        req: ListSequencesReq,
+    ) -> Result<Vec<(String, SequenceMeta)>, KVAppError>;
+
+    /// Allocate a batch of `count` values from a sequence.
+    ///
+    /// The batch is reserved atomically, concurrent callers always get disjoint batches.
+    async fn get_sequence_next_values(
+        &self,
+        req: GetSequenceNextValuesReq,
+    ) -> Result<GetSequenceNextValuesReply, KVAppError>;
+}
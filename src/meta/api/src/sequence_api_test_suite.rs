@@ -0,0 +1,240 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use chrono::Utc;
+use common_meta_app::app_error::AppError;
+use common_meta_app::schema::CreateSequenceReq;
+use common_meta_app::schema::DropSequenceReq;
+use common_meta_app::schema::GetSequenceNextValuesReq;
+use common_meta_app::schema::GetSequenceReq;
+use common_meta_app::schema::ListSequencesReq;
+use common_meta_app::schema::SequenceNameIdent;
+use common_meta_kvapi::kvapi;
+use common_meta_types::MetaError;
+use log::info;
+
+use crate::kv_app_error::KVAppError;
+use crate::SequenceApi;
+
+/// Test suite of `SequenceApi`.
+///
+/// It is not used by this crate, but is used by other crate that impl `SequenceApi`,
+/// to ensure an impl works as expected,
+/// such as `meta/embedded` and `metasrv`.
+#[derive(Copy, Clone)]
+pub struct SequenceApiTestSuite {}
+
+impl SequenceApiTestSuite {
+    /// Test SequenceApi on a single node
+    pub async fn test_single_node<B, MT>(b: B) -> anyhow::Result<()>
+    where
+        B: kvapi::ApiBuilder<MT>,
+        MT: SequenceApi + kvapi::AsKVApi<Error = MetaError>,
+    {
+        let suite = SequenceApiTestSuite {};
+
+        suite.sequence_create_get_drop(&b.build().await).await?;
+        suite.sequence_next_values(&b.build().await).await?;
+        Ok(())
+    }
+
+    #[minitrace::trace]
+    async fn sequence_create_get_drop<MT: SequenceApi + kvapi::AsKVApi<Error = MetaError>>(
+        &self,
+        mt: &MT,
+    ) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let name_ident = SequenceNameIdent::new(tenant, "seq1");
+
+        info!("--- list sequences when there is no sequence");
+        {
+            let res = mt
+                .list_sequences(ListSequencesReq {
+                    tenant: tenant.to_string(),
+                })
+                .await?;
+            assert!(res.is_empty());
+        }
+
+        info!("--- create a sequence");
+        let create_on = Utc::now();
+        let create_req = CreateSequenceReq {
+            if_not_exists: false,
+            name_ident: name_ident.clone(),
+            start: 10,
+            step: 5,
+            comment: Some("seq1".to_string()),
+            create_on,
+        };
+        {
+            mt.create_sequence(create_req.clone()).await?;
+
+            let reply = mt
+                .get_sequence(GetSequenceReq {
+                    name_ident: name_ident.clone(),
+                })
+                .await?;
+            assert_eq!(10, reply.meta.start);
+            assert_eq!(5, reply.meta.step);
+            assert_eq!(10, reply.meta.current);
+            assert_eq!(Some("seq1".to_string()), reply.meta.comment);
+            assert_eq!(create_on, reply.meta.create_on);
+        }
+
+        info!("--- create a sequence that already exists");
+        {
+            let res = mt.create_sequence(create_req.clone()).await;
+            assert!(matches!(
+                res,
+                Err(KVAppError::AppError(AppError::SequenceAlreadyExists(_)))
+            ));
+
+            let mut req = create_req.clone();
+            req.if_not_exists = true;
+            mt.create_sequence(req).await?;
+        }
+
+        info!("--- list sequences");
+        {
+            let mut req = create_req.clone();
+            req.name_ident = SequenceNameIdent::new(tenant, "seq2");
+            mt.create_sequence(req).await?;
+
+            let mut req = create_req.clone();
+            req.name_ident = SequenceNameIdent::new("tenant2", "seq3");
+            mt.create_sequence(req).await?;
+
+            let res = mt
+                .list_sequences(ListSequencesReq {
+                    tenant: tenant.to_string(),
+                })
+                .await?;
+            let names = res.into_iter().map(|(name, _)| name).collect::<Vec<_>>();
+            assert_eq!(vec!["seq1".to_string(), "seq2".to_string()], names);
+        }
+
+        info!("--- drop a sequence");
+        {
+            mt.drop_sequence(DropSequenceReq {
+                if_exists: false,
+                name_ident: name_ident.clone(),
+            })
+            .await?;
+
+            let res = mt
+                .get_sequence(GetSequenceReq {
+                    name_ident: name_ident.clone(),
+                })
+                .await;
+            assert!(matches!(
+                res,
+                Err(KVAppError::AppError(AppError::UnknownSequence(_)))
+            ));
+
+            let res = mt
+                .drop_sequence(DropSequenceReq {
+                    if_exists: false,
+                    name_ident: name_ident.clone(),
+                })
+                .await;
+            assert!(matches!(
+                res,
+                Err(KVAppError::AppError(AppError::UnknownSequence(_)))
+            ));
+
+            mt.drop_sequence(DropSequenceReq {
+                if_exists: true,
+                name_ident: name_ident.clone(),
+            })
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    #[minitrace::trace]
+    async fn sequence_next_values<MT: SequenceApi + kvapi::AsKVApi<Error = MetaError>>(
+        &self,
+        mt: &MT,
+    ) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let name_ident = SequenceNameIdent::new(tenant, "seq1");
+
+        info!("--- next values of an unknown sequence");
+        {
+            let res = mt
+                .get_sequence_next_values(GetSequenceNextValuesReq {
+                    name_ident: name_ident.clone(),
+                    count: 1,
+                })
+                .await;
+            assert!(matches!(
+                res,
+                Err(KVAppError::AppError(AppError::UnknownSequence(_)))
+            ));
+        }
+
+        mt.create_sequence(CreateSequenceReq {
+            if_not_exists: false,
+            name_ident: name_ident.clone(),
+            start: 1,
+            step: 2,
+            comment: None,
+            create_on: Utc::now(),
+        })
+        .await?;
+
+        info!("--- allocate batches of values");
+        {
+            let reply = mt
+                .get_sequence_next_values(GetSequenceNextValuesReq {
+                    name_ident: name_ident.clone(),
+                    count: 3,
+                })
+                .await?;
+            assert_eq!((1, 2, 3), (reply.start, reply.step, reply.count));
+
+            let reply = mt
+                .get_sequence_next_values(GetSequenceNextValuesReq {
+                    name_ident: name_ident.clone(),
+                    count: 10,
+                })
+                .await?;
+            assert_eq!((7, 2, 10), (reply.start, reply.step, reply.count));
+
+            let reply = mt
+                .get_sequence(GetSequenceReq {
+                    name_ident: name_ident.clone(),
+                })
+                .await?;
+            assert_eq!(27, reply.meta.current);
+            assert!(reply.meta.update_on.is_some());
+        }
+
+        info!("--- allocate more values than the sequence can hold");
+        {
+            let res = mt
+                .get_sequence_next_values(GetSequenceNextValuesReq {
+                    name_ident: name_ident.clone(),
+                    count: u64::MAX,
+                })
+                .await;
+            assert!(matches!(
+                res,
+                Err(KVAppError::AppError(AppError::SequenceOutOfRange(_)))
+            ));
+        }
+
+        Ok(())
+    }
+}
@@ -234,6 +234,7 @@ pub async fn fetch_id<T: kvapi::Key>(
             seq: MatchSeq::GE(0),
             value: Operation::Update(b"".to_vec()),
             value_meta: None,
+            lease_id: None,
         })
         .await?;
 
@@ -332,6 +333,7 @@ pub fn txn_op_put(key: &impl kvapi::Key, value: Vec<u8>) -> TxnOp {
             value,
             prev_value: true,
             expire_at: None,
+            lease_id: None,
         })),
     }
 }
@@ -344,6 +346,7 @@ pub fn txn_op_put_with_expire(key: &impl kvapi::Key, value: Vec<u8>, expire_at:
             value,
             prev_value: true,
             expire_at: Some(expire_at),
+            lease_id: None,
         })),
     }
 }
@@ -20,6 +20,7 @@ mod index;
 mod least_visible_time;
 mod lock;
 mod ownership;
+mod sequence;
 mod table;
 mod virtual_column;
 pub use catalog::*;
@@ -59,6 +60,17 @@ pub use lock::LockMeta;
 pub use lock::LockType;
 pub use lock::TableLockKey;
 pub use ownership::Ownership;
+pub use sequence::CreateSequenceReply;
+pub use sequence::CreateSequenceReq;
+pub use sequence::DropSequenceReply;
+pub use sequence::DropSequenceReq;
+pub use sequence::GetSequenceNextValuesReply;
+pub use sequence::GetSequenceNextValuesReq;
+pub use sequence::GetSequenceReply;
+pub use sequence::GetSequenceReq;
+pub use sequence::ListSequencesReq;
+pub use sequence::SequenceMeta;
+pub use sequence::SequenceNameIdent;
 pub use table::CountTablesKey;
 pub use table::CountTablesReply;
 pub use table::CountTablesReq;
@@ -100,6 +112,8 @@ pub use table::TruncateTableReply;
 pub use table::TruncateTableReq;
 pub use table::UndropTableReply;
 pub use table::UndropTableReq;
+pub use table::UpdateMultiTableMetaReply;
+pub use table::UpdateMultiTableMetaReq;
 pub use table::UpdateTableMetaReply;
 pub use table::UpdateTableMetaReq;
 pub use table::UpsertTableCopiedFileReply;
@@ -138,3 +152,4 @@ const PREFIX_INDEX_BY_ID: &str = "__fd_index_by_id";
 const PREFIX_TABLE_LOCK: &str = "__fd_table_lock";
 const PREFIX_TABLE_LVT: &str = "__fd_table_lvt";
 const PREFIX_VIRTUAL_COLUMN: &str = "__fd_virtual_column";
+const PREFIX_SEQUENCE: &str = "__fd_sequence";
@@ -0,0 +1,172 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use chrono::DateTime;
+use chrono::Utc;
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Hash, Default)]
+pub struct SequenceNameIdent {
+    pub tenant: String,
+    pub sequence_name: String,
+}
+
+impl SequenceNameIdent {
+    pub fn new(tenant: impl Into<String>, sequence_name: impl Into<String>) -> SequenceNameIdent {
+        SequenceNameIdent {
+            tenant: tenant.into(),
+            sequence_name: sequence_name.into(),
+        }
+    }
+}
+
+impl Display for SequenceNameIdent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "'{}'/'{}'", self.tenant, self.sequence_name)
+    }
+}
+
+/// The persisted state of a sequence.
+///
+/// `current` is the next value that has not been handed out yet,
+/// values are allocated by advancing it by a multiple of `step`.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct SequenceMeta {
+    pub start: u64,
+    pub step: u64,
+    pub current: u64,
+    pub comment: Option<String>,
+    pub create_on: DateTime<Utc>,
+    pub update_on: Option<DateTime<Utc>>,
+}
+
+impl From<CreateSequenceReq> for SequenceMeta {
+    fn from(p: CreateSequenceReq) -> Self {
+        SequenceMeta {
+            start: p.start,
+            step: p.step,
+            current: p.start,
+            comment: p.comment,
+            create_on: p.create_on,
+            update_on: None,
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CreateSequenceReq {
+    pub if_not_exists: bool,
+    pub name_ident: SequenceNameIdent,
+    pub start: u64,
+    pub step: u64,
+    pub comment: Option<String>,
+    pub create_on: DateTime<Utc>,
+}
+
+impl Display for CreateSequenceReq {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "create_sequence(if_not_exists={}):{} start={} step={}",
+            self.if_not_exists, self.name_ident, self.start, self.step
+        )
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CreateSequenceReply {}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DropSequenceReq {
+    pub if_exists: bool,
+    pub name_ident: SequenceNameIdent,
+}
+
+impl Display for DropSequenceReq {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "drop_sequence(if_exists={}):{}",
+            self.if_exists, self.name_ident
+        )
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DropSequenceReply {}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct GetSequenceReq {
+    pub name_ident: SequenceNameIdent,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct GetSequenceReply {
+    pub meta: SequenceMeta,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ListSequencesReq {
+    pub tenant: String,
+}
+
+/// Allocate `count` consecutive values of a sequence in one round-trip.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct GetSequenceNextValuesReq {
+    pub name_ident: SequenceNameIdent,
+    pub count: u64,
+}
+
+/// The allocated values are `start, start + step, ..., start + (count - 1) * step`.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct GetSequenceNextValuesReply {
+    pub start: u64,
+    pub step: u64,
+    pub count: u64,
+}
+
+mod kvapi_key_impl {
+    use common_meta_kvapi::kvapi;
+
+    use crate::schema::SequenceNameIdent;
+    use crate::schema::PREFIX_SEQUENCE;
+
+    /// __fd_sequence/<tenant>/<sequence_name> -> SequenceMeta
+    impl kvapi::Key for SequenceNameIdent {
+        const PREFIX: &'static str = PREFIX_SEQUENCE;
+
+        fn to_string_key(&self) -> String {
+            kvapi::KeyBuilder::new_prefixed(Self::PREFIX)
+                .push_str(&self.tenant)
+                .push_str(&self.sequence_name)
+                .done()
+        }
+
+        fn from_str_key(s: &str) -> Result<Self, kvapi::KeyError> {
+            let mut p = kvapi::KeyParser::new_prefixed(s, Self::PREFIX)?;
+
+            let tenant = p.next_str()?;
+            let sequence_name = p.next_str()?;
+            p.done()?;
+
+            Ok(SequenceNameIdent {
+                tenant,
+                sequence_name,
+            })
+        }
+    }
+}
@@ -30,7 +30,10 @@ use common_meta_types::protobuf::RaftRequest;
 use common_meta_types::protobuf::StreamItem;
 use common_meta_types::protobuf::WatchRequest;
 use common_meta_types::protobuf::WatchResponse;
+use common_meta_types::GrantLeaseReq;
 use common_meta_types::InvalidArgument;
+use common_meta_types::Lease;
+use common_meta_types::RevokeLeaseReq;
 use common_meta_types::TxnReply;
 use common_meta_types::TxnRequest;
 use log::as_debug;
@@ -55,6 +58,9 @@ pub trait RequestFor: Clone + fmt::Debug {
 pub enum MetaGrpcReq {
     UpsertKV(UpsertKVReq),
 
+    GrantLease(GrantLeaseReq),
+    RevokeLease(RevokeLeaseReq),
+
     GetKV(GetKVReq),
     MGetKV(MGetKVReq),
     ListKV(ListKVReq),
@@ -196,6 +202,14 @@ impl RequestFor for UpsertKVReq {
     type Reply = UpsertKVReply;
 }
 
+impl RequestFor for GrantLeaseReq {
+    type Reply = Lease;
+}
+
+impl RequestFor for RevokeLeaseReq {
+    type Reply = Option<Lease>;
+}
+
 impl RequestFor for WatchRequest {
     type Reply = tonic::codec::Streaming<WatchResponse>;
 }
@@ -52,20 +52,26 @@ use common_meta_types::protobuf::ClusterStatus;
 use common_meta_types::protobuf::Empty;
 use common_meta_types::protobuf::ExportedChunk;
 use common_meta_types::protobuf::HandshakeRequest;
+use common_meta_types::protobuf::LeaseKeepAliveRequest;
+use common_meta_types::protobuf::LeaseKeepAliveResponse;
 use common_meta_types::protobuf::MemberListReply;
 use common_meta_types::protobuf::MemberListRequest;
 use common_meta_types::protobuf::RaftRequest;
 use common_meta_types::protobuf::WatchRequest;
 use common_meta_types::protobuf::WatchResponse;
 use common_meta_types::ConnectionError;
+use common_meta_types::GrantLeaseReq;
 use common_meta_types::GrpcConfig;
+use common_meta_types::Lease;
 use common_meta_types::MetaClientError;
 use common_meta_types::MetaError;
 use common_meta_types::MetaHandshakeError;
 use common_meta_types::MetaNetworkError;
+use common_meta_types::RevokeLeaseReq;
 use common_meta_types::TxnReply;
 use common_meta_types::TxnRequest;
 use futures::stream::StreamExt;
+use futures::Stream;
 use log::as_debug;
 use log::as_display;
 use log::debug;
@@ -258,6 +264,28 @@ impl ClientHandle {
         self.request(message::MakeClient {}).await
     }
 
+    /// Grant a lease that expires in `ttl_sec` seconds unless it is kept alive.
+    pub async fn grant_lease(&self, ttl_sec: u64) -> Result<Lease, MetaError> {
+        self.request(GrantLeaseReq { ttl_sec }).await
+    }
+
+    /// Revoke a lease and remove all keys attached to it.
+    pub async fn revoke_lease(&self, lease_id: u64) -> Result<Option<Lease>, MetaError> {
+        self.request(RevokeLeaseReq { lease_id }).await
+    }
+
+    /// Keep leases alive by sending a request for every lease to refresh, through a single stream.
+    ///
+    /// A response with `ttl_sec == 0` is returned for a lease that is not found.
+    pub async fn keep_alive_lease(
+        &self,
+        requests: impl Stream<Item = LeaseKeepAliveRequest> + Send + 'static,
+    ) -> Result<tonic::codec::Streaming<LeaseKeepAliveResponse>, MetaError> {
+        let (mut client, _server_version) = self.make_client().await?;
+        let res = client.keep_alive(requests).await?;
+        Ok(res.into_inner())
+    }
+
     /// Return the endpoints list cached on this client.
     pub async fn get_cached_endpoints(&self) -> Result<Vec<String>, MetaError> {
         self.request(message::GetEndpoints {}).await
@@ -493,6 +521,20 @@ impl MetaGrpcClient {
                             .await;
                         message::Response::Txn(resp)
                     }
+                    message::Request::GrantLease(r) => {
+                        let resp = self
+                            .kv_api(r)
+                            .timed_ge(threshold(), info_spent("MetaGrpcClient::kv_api"))
+                            .await;
+                        message::Response::GrantLease(resp)
+                    }
+                    message::Request::RevokeLease(r) => {
+                        let resp = self
+                            .kv_api(r)
+                            .timed_ge(threshold(), info_spent("MetaGrpcClient::kv_api"))
+                            .await;
+                        message::Response::RevokeLease(resp)
+                    }
                     message::Request::Watch(r) => {
                         let resp = self.watch(r).await;
                         message::Response::Watch(resp)
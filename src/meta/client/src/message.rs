@@ -30,8 +30,11 @@ use common_meta_types::protobuf::ExportedChunk;
 use common_meta_types::protobuf::StreamItem;
 use common_meta_types::protobuf::WatchRequest;
 use common_meta_types::protobuf::WatchResponse;
+use common_meta_types::GrantLeaseReq;
+use common_meta_types::Lease;
 use common_meta_types::MetaClientError;
 use common_meta_types::MetaError;
+use common_meta_types::RevokeLeaseReq;
 use common_meta_types::TxnReply;
 use common_meta_types::TxnRequest;
 use minitrace::Span;
@@ -99,6 +102,12 @@ pub enum Request {
     /// Run a transaction on remote
     Txn(TxnRequest),
 
+    /// Grant a lease that keys can be attached to
+    GrantLease(GrantLeaseReq),
+
+    /// Revoke a lease and remove the keys attached to it
+    RevokeLease(RevokeLeaseReq),
+
     /// Watch KV changes, expecting a Stream that reports KV change events
     Watch(WatchRequest),
 
@@ -129,6 +138,8 @@ impl Request {
             Request::StreamList(_) => "StreamPrefixList",
             Request::Upsert(_) => "Upsert",
             Request::Txn(_) => "Txn",
+            Request::GrantLease(_) => "GrantLease",
+            Request::RevokeLease(_) => "RevokeLease",
             Request::Watch(_) => "Watch",
             Request::Export(_) => "Export",
             Request::MakeClient(_) => "MakeClient",
@@ -150,6 +161,8 @@ pub enum Response {
     StreamList(Result<BoxStream<StreamItem>, MetaError>),
     Upsert(Result<UpsertKVReply, MetaError>),
     Txn(Result<TxnReply, MetaError>),
+    GrantLease(Result<Lease, MetaError>),
+    RevokeLease(Result<Option<Lease>, MetaError>),
     Watch(Result<tonic::codec::Streaming<WatchResponse>, MetaError>),
     Export(Result<tonic::codec::Streaming<ExportedChunk>, MetaError>),
     MakeClient(Result<(RealClient, u64), MetaClientError>),
@@ -185,6 +198,12 @@ impl fmt::Debug for Response {
             Response::Txn(x) => {
                 write!(f, "Txn({:?})", x)
             }
+            Response::GrantLease(x) => {
+                write!(f, "GrantLease({:?})", x)
+            }
+            Response::RevokeLease(x) => {
+                write!(f, "RevokeLease({:?})", x)
+            }
             Response::Watch(x) => {
                 write!(f, "Watch({:?})", x)
             }
@@ -242,6 +261,14 @@ impl Response {
                 .as_ref()
                 .err()
                 .map(|x| x as &(dyn std::error::Error + 'static)),
+            Response::GrantLease(res) => res
+                .as_ref()
+                .err()
+                .map(|x| x as &(dyn std::error::Error + 'static)),
+            Response::RevokeLease(res) => res
+                .as_ref()
+                .err()
+                .map(|x| x as &(dyn std::error::Error + 'static)),
             Response::Watch(res) => res
                 .as_ref()
                 .err()
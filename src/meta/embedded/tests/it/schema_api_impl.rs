@@ -16,6 +16,7 @@ use async_trait::async_trait;
 use common_base::base::tokio;
 use common_meta_api::BackgroundApiTestSuite;
 use common_meta_api::SchemaApiTestSuite;
+use common_meta_api::SequenceApiTestSuite;
 use common_meta_api::ShareApiTestSuite;
 use common_meta_embedded::MetaEmbedded;
 use common_meta_kvapi::kvapi;
@@ -37,5 +38,6 @@ impl kvapi::ApiBuilder<MetaEmbedded> for MetaEmbeddedBuilder {
 async fn test_meta_embedded() -> anyhow::Result<()> {
     SchemaApiTestSuite::test_single_node(MetaEmbeddedBuilder {}).await?;
     ShareApiTestSuite::test_single_node_share(MetaEmbeddedBuilder {}).await?;
-    BackgroundApiTestSuite::test_single_node(MetaEmbeddedBuilder {}).await
+    BackgroundApiTestSuite::test_single_node(MetaEmbeddedBuilder {}).await?;
+    SequenceApiTestSuite::test_single_node(MetaEmbeddedBuilder {}).await
 }
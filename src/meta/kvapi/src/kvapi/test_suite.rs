@@ -506,6 +506,7 @@ impl kvapi::TestSuite {
                 value: b"new_v1".to_vec(),
                 prev_value: true,
                 expire_at: None,
+                lease_id: None,
             })),
         }];
 
@@ -667,6 +668,7 @@ impl kvapi::TestSuite {
                     value: b"new_v1".to_vec(),
                     prev_value: true,
                     expire_at: None,
+                    lease_id: None,
                 })),
             }];
 
@@ -719,6 +721,7 @@ impl kvapi::TestSuite {
                     value: b"new_v1".to_vec(),
                     prev_value: true,
                     expire_at: None,
+                    lease_id: None,
                 })),
             }];
 
@@ -774,6 +777,7 @@ impl kvapi::TestSuite {
                         value: val1_new.to_vec(),
                         prev_value: true,
                         expire_at: None,
+                        lease_id: None,
                     })),
                 },
                 // change k2
@@ -783,6 +787,7 @@ impl kvapi::TestSuite {
                         value: b"new_v2".to_vec(),
                         prev_value: true,
                         expire_at: None,
+                        lease_id: None,
                     })),
                 },
                 // get k1
@@ -892,6 +897,7 @@ impl kvapi::TestSuite {
                         value: val1_new.to_vec(),
                         prev_value: true,
                         expire_at: None,
+                        lease_id: None,
                     })),
                 },
                 // get k1
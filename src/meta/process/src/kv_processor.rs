@@ -127,6 +127,9 @@ where F: Fn(&str, Vec<u8>) -> Result<Vec<u8>, anyhow::Error>
         match log_entry.cmd {
             Cmd::AddNode { .. } => Ok(None),
             Cmd::RemoveNode { .. } => Ok(None),
+            Cmd::GrantLease { .. } => Ok(None),
+            Cmd::KeepAliveLease { .. } => Ok(None),
+            Cmd::RevokeLease { .. } => Ok(None),
             Cmd::UpsertKV(ups) => {
                 let x = LogEntry {
                     txid: log_entry.txid,
@@ -174,6 +177,7 @@ where F: Fn(&str, Vec<u8>) -> Result<Vec<u8>, anyhow::Error>
                     seq: ups.seq,
                     value: Operation::Update(buf),
                     value_meta: ups.value_meta,
+                    lease_id: ups.lease_id,
                 }))
             }
             Operation::Delete => Ok(None),
@@ -218,6 +222,7 @@ where F: Fn(&str, Vec<u8>) -> Result<Vec<u8>, anyhow::Error>
             value,
             prev_value: p.prev_value,
             expire_at: p.expire_at,
+            lease_id: p.lease_id,
         };
 
         Ok(pr)
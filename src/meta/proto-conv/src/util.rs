@@ -93,6 +93,9 @@ const META_CHANGE_LOG: &[(u64, &str)] = &[
     (61, "2023-10-19: Add: config.proto/OssStorageConfig add SSE options", ),
     (62, "2023-10-30: Add: lock.proto"),
     (63, "2023-10-30: Add: connection.proto"),
+    (64, "2023-11-06: Add: file_format.proto/FileFormatParams add OrcFileFormatParams"),
+    (65, "2023-11-08: Add: file_format.proto/FileFormatParams add AvroFileFormatParams"),
+    (66, "2023-11-09: Add: sequence.proto/SequenceMeta"),
     // Dear developer:
     //      If you're gonna add a new metadata version, you'll have to add a test for it.
     //      You could just copy an existing test file(e.g., `../tests/it/v024_table_meta.rs`)
@@ -68,3 +68,6 @@ mod v060_copy_options;
 mod v061_oss_sse_options;
 mod v062_table_lock_meta;
 mod v063_connection;
+mod v064_orc_format_params;
+mod v065_avro_format_params;
+mod v066_sequence_meta;
@@ -0,0 +1,48 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::TimeZone;
+use chrono::Utc;
+use common_meta_app::schema::SequenceMeta;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+#[test]
+fn test_decode_v66_sequence_meta() -> anyhow::Result<()> {
+    let sequence_meta_v66 = vec![
+        8, 1, 16, 2, 24, 11, 34, 3, 115, 101, 113, 42, 23, 50, 48, 50, 51, 45, 49, 49, 45, 48, 57,
+        32, 48, 51, 58, 49, 52, 58, 49, 53, 32, 85, 84, 67, 160, 6, 66, 168, 6, 24,
+    ];
+    let want = || SequenceMeta {
+        start: 1,
+        step: 2,
+        current: 11,
+        comment: Some("seq".to_string()),
+        create_on: Utc.with_ymd_and_hms(2023, 11, 9, 3, 14, 15).unwrap(),
+        update_on: None,
+    };
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), sequence_meta_v66.as_slice(), 66, want())?;
+    Ok(())
+}
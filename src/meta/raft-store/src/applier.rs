@@ -27,7 +27,10 @@ use common_meta_types::ConditionResult;
 use common_meta_types::Entry;
 use common_meta_types::EntryPayload;
 use common_meta_types::KVMeta;
+use common_meta_types::Lease;
 use common_meta_types::MatchSeq;
+use common_meta_types::MetaAPIError;
+use common_meta_types::MetaDataError;
 use common_meta_types::Node;
 use common_meta_types::SeqV;
 use common_meta_types::SeqValue;
@@ -55,12 +58,16 @@ use log::error;
 use log::info;
 use num::FromPrimitive;
 
+use crate::sm_v002::leveled_store::sys_data_api::SysDataApiRO;
 use crate::sm_v002::SMV002;
 
 /// A helper that applies raft log `Entry` to the state machine.
 pub struct Applier<'a> {
     sm: &'a mut SMV002,
 
+    /// The time of the log being applied, leases are granted and kept alive since it.
+    log_time_ms: u64,
+
     /// The changes has been made by the applying one log entry
     changes: Vec<Change<Vec<u8>, String>>,
 }
@@ -69,6 +76,7 @@ impl<'a> Applier<'a> {
     pub fn new(sm: &'a mut SMV002) -> Self {
         Self {
             sm,
+            log_time_ms: 0,
             changes: Vec::new(),
         }
     }
@@ -82,6 +90,7 @@ impl<'a> Applier<'a> {
 
         let log_id = &entry.log_id;
         let log_time_ms = Self::get_log_time(entry);
+        self.log_time_ms = log_time_ms;
 
         self.clean_expired_kvs(log_time_ms).await?;
 
@@ -108,10 +117,13 @@ impl<'a> Applier<'a> {
             }
         };
 
-        // Send queued change events to subscriber
-        if let Some(subscriber) = &self.sm.subscriber {
-            for event in self.changes.drain(..) {
-                subscriber.kv_changed(event);
+        // Keep the changes for watchers to replay, and send them to subscriber
+        let revision = log_id.index;
+        for event in self.changes.drain(..) {
+            self.sm.change_log.append(revision, event.clone());
+
+            if let Some(subscriber) = &self.sm.subscriber {
+                subscriber.kv_changed(revision, event);
             }
         }
 
@@ -139,6 +151,15 @@ impl<'a> Applier<'a> {
             Cmd::UpsertKV(ref upsert_kv) => self.apply_upsert_kv(upsert_kv).await?,
 
             Cmd::Transaction(txn) => self.apply_txn(txn).await?,
+
+            Cmd::GrantLease { ttl_sec } => self.apply_grant_lease(*ttl_sec).await?,
+
+            Cmd::KeepAliveLease { lease_id } => self.apply_keep_alive_lease(*lease_id).await?,
+
+            Cmd::RevokeLease { lease_id } => {
+                let lease = self.revoke_lease(*lease_id).await?;
+                AppliedState::Lease(lease)
+            }
         };
 
         info!("apply_result: cmd: {}; res: {}", cmd, res);
@@ -192,18 +213,57 @@ impl<'a> Applier<'a> {
     async fn apply_upsert_kv(&mut self, upsert_kv: &UpsertKV) -> Result<AppliedState, io::Error> {
         debug!(upsert_kv = as_debug!(upsert_kv); "apply_update_kv_cmd");
 
+        // The lease records are only maintained by the state machine.
+        if Lease::is_reserved_key(&upsert_kv.key) {
+            info!("upsert_kv: reserved key is rejected: {}", upsert_kv);
+            return Ok(AppliedState::None);
+        }
+
+        if let Some(lease_id) = self.find_missing_lease([upsert_kv.lease_id]).await? {
+            info!("upsert_kv: lease {} not found: {}", lease_id, upsert_kv);
+            return Ok(AppliedState::Lease(None));
+        }
+
         let (prev, result) = self.upsert_kv(upsert_kv).await?;
 
         let st = Change::new(prev, result).into();
         Ok(st)
     }
 
+    /// Update or insert a kv entry, and attach it to the lease if there is one.
+    ///
+    /// The caller has to make sure the lease exists, see: [`Self::find_missing_lease`].
+    #[minitrace::trace]
+    pub(crate) async fn upsert_kv(
+        &mut self,
+        upsert_kv: &UpsertKV,
+    ) -> Result<(Option<SeqV>, Option<SeqV>), io::Error> {
+        let Some(lease_id) = upsert_kv.lease_id else {
+            return self.upsert_kv_record(upsert_kv).await;
+        };
+
+        let (prev, result) = self.upsert_kv_record(upsert_kv).await?;
+
+        // Index the key by the lease, with the seq it is attached at.
+        if let Some(seq_v) = &result {
+            if prev.as_ref() != Some(seq_v) {
+                let attached = UpsertKV::update(
+                    Lease::attached_key(lease_id, &upsert_kv.key),
+                    seq_v.seq.to_string().as_bytes(),
+                );
+                self.upsert_kv_record(&attached).await?;
+            }
+        }
+
+        Ok((prev, result))
+    }
+
     // TODO(1): when get an applier, pass in a now_ms to ensure all expired are cleaned.
     /// Update or insert a kv entry.
     ///
     /// If the input entry has expired, it performs a delete operation.
     #[minitrace::trace]
-    pub(crate) async fn upsert_kv(
+    async fn upsert_kv_record(
         &mut self,
         upsert_kv: &UpsertKV,
     ) -> Result<(Option<SeqV>, Option<SeqV>), io::Error> {
@@ -228,6 +288,111 @@ impl<'a> Applier<'a> {
         Ok((prev, result))
     }
 
+    /// Grant a lease, whose id is the seq of the record storing it.
+    #[minitrace::trace]
+    async fn apply_grant_lease(&mut self, ttl_sec: u64) -> Result<AppliedState, io::Error> {
+        let lease_id = self.sm.sys_data_ref().curr_seq() + 1;
+        let lease = self.put_lease(lease_id, ttl_sec).await?;
+
+        info!("applied GrantLease: {}", lease);
+        Ok(AppliedState::Lease(Some(lease)))
+    }
+
+    #[minitrace::trace]
+    async fn apply_keep_alive_lease(&mut self, lease_id: u64) -> Result<AppliedState, io::Error> {
+        let Some(lease) = self.get_lease(lease_id).await? else {
+            info!("keep alive lease {}: not found", lease_id);
+            return Ok(AppliedState::Lease(None));
+        };
+
+        let lease = self.put_lease(lease_id, lease.ttl_sec).await?;
+        Ok(AppliedState::Lease(Some(lease)))
+    }
+
+    /// Store a lease that expires in `ttl_sec` since the log time.
+    ///
+    /// The record of the lease expires along with it, and the keys attached to it are removed then,
+    /// see: `clean_expired_kvs()`.
+    async fn put_lease(&mut self, lease_id: u64, ttl_sec: u64) -> Result<Lease, io::Error> {
+        let lease = Lease {
+            id: lease_id,
+            ttl_sec,
+            expire_at: self.log_time_ms / 1000 + ttl_sec,
+        };
+        let value = serde_json::to_vec(&lease)?;
+
+        let upsert =
+            UpsertKV::update(Lease::key(lease_id), &value).with_expire_sec(lease.expire_at);
+        self.upsert_kv_record(&upsert).await?;
+
+        Ok(lease)
+    }
+
+    async fn get_lease(&self, lease_id: u64) -> Result<Option<Lease>, io::Error> {
+        // Expired leases are revoked before applying a log, see: `clean_expired_kvs()`.
+        let seq_v = self.sm.get_maybe_expired_kv(&Lease::key(lease_id)).await?;
+        let Some(seq_v) = seq_v else {
+            return Ok(None);
+        };
+
+        let lease = serde_json::from_slice(&seq_v.data)?;
+        Ok(Some(lease))
+    }
+
+    /// Returns the first of the leases to attach keys to, that is not found.
+    ///
+    /// Like etcd, a write attaching a key to an unknown or expired lease is rejected as a whole.
+    async fn find_missing_lease(
+        &self,
+        lease_ids: impl IntoIterator<Item = Option<u64>>,
+    ) -> Result<Option<u64>, io::Error> {
+        for lease_id in lease_ids.into_iter().flatten() {
+            if self.get_lease(lease_id).await?.is_none() {
+                return Ok(Some(lease_id));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Remove a lease and the keys attached to it.
+    ///
+    /// A key that is updated again after being attached to the lease, is no longer attached to it.
+    #[minitrace::trace]
+    async fn revoke_lease(&mut self, lease_id: u64) -> Result<Option<Lease>, io::Error> {
+        let Some(lease) = self.get_lease(lease_id).await? else {
+            info!("revoke lease {}: not found", lease_id);
+            return Ok(None);
+        };
+
+        let prefix = Lease::attached_prefix(lease_id);
+        let attached = self
+            .sm
+            .list_kv(&prefix)
+            .await?
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        for (attached_key, seq_v) in attached {
+            let key = &attached_key[prefix.len()..];
+            let attached_seq = String::from_utf8_lossy(&seq_v.data).parse::<u64>().ok();
+
+            let curr = self.sm.get_maybe_expired_kv(key).await?;
+            if curr.is_some() && Some(curr.seq()) == attached_seq {
+                info!("revoke lease {}: remove attached key: {}", lease_id, key);
+                self.upsert_kv_record(&UpsertKV::delete(key)).await?;
+            }
+
+            self.upsert_kv_record(&UpsertKV::delete(&attached_key))
+                .await?;
+        }
+
+        self.upsert_kv_record(&UpsertKV::delete(Lease::key(lease_id)))
+            .await?;
+
+        info!("applied RevokeLease: {}", lease);
+        Ok(Some(lease))
+    }
+
     #[minitrace::trace]
     async fn apply_txn(&mut self, req: &TxnRequest) -> Result<AppliedState, io::Error> {
         debug!(txn = as_display!(req); "apply txn cmd");
@@ -246,6 +411,28 @@ impl<'a> Applier<'a> {
             responses: vec![],
         };
 
+        if let Some(key) = req.reserved_key() {
+            info!("apply txn: reserved key {} is rejected: {}", key, req);
+
+            let err = MetaAPIError::DataError(MetaDataError::ReservedKey(key.to_string()));
+            resp.success = false;
+            resp.error = serde_json::to_string(&err)?;
+            return Ok(AppliedState::TxnReply(resp));
+        }
+
+        let lease_ids = ops.iter().map(|op| match &op.request {
+            Some(txn_op::Request::Put(put)) => put.lease_id,
+            _ => None,
+        });
+        if let Some(lease_id) = self.find_missing_lease(lease_ids).await? {
+            info!("apply txn: lease {} not found: {}", lease_id, req);
+
+            let err = MetaAPIError::DataError(MetaDataError::LeaseNotFound(lease_id));
+            resp.success = false;
+            resp.error = serde_json::to_string(&err)?;
+            return Ok(AppliedState::TxnReply(resp));
+        }
+
         for op in ops {
             self.txn_execute_operation(op, &mut resp).await?;
         }
@@ -382,9 +569,10 @@ impl<'a> Applier<'a> {
         put: &TxnPutRequest,
         resp: &mut TxnReply,
     ) -> Result<(), io::Error> {
-        let upsert = UpsertKV::update(&put.key, &put.value).with(KVMeta {
+        let mut upsert = UpsertKV::update(&put.key, &put.value).with(KVMeta {
             expire_at: put.expire_at,
         });
+        upsert.lease_id = put.lease_id;
 
         let (prev, _result) = self.upsert_kv(&upsert).await?;
 
@@ -446,6 +634,11 @@ impl<'a> Applier<'a> {
         let mut count = 0;
 
         while let Some((key, _seq_v)) = strm.try_next().await? {
+            // e.g., deleting by an empty prefix does not remove the lease records.
+            if Lease::is_reserved_key(&key) {
+                continue;
+            }
+
             let (prev, res) = self.upsert_kv(&UpsertKV::delete(&key)).await?;
             self.push_change(key, prev, res);
             count += 1;
@@ -475,6 +668,7 @@ impl<'a> Applier<'a> {
         info!("to clean expired kvs, log_time_ts: {}", log_time_ms);
 
         let mut to_clean = vec![];
+        let mut expired_leases = vec![];
         let mut strm = self.sm.list_expire_index().await?;
 
         {
@@ -484,6 +678,11 @@ impl<'a> Applier<'a> {
                     break;
                 }
 
+                if let Some(lease_id) = Lease::parse_key(&key) {
+                    expired_leases.push(lease_id);
+                    continue;
+                }
+
                 to_clean.push((expire_key, key));
             }
         }
@@ -503,6 +702,12 @@ impl<'a> Applier<'a> {
             }
         }
 
+        // The keys attached to a lease are removed along with it.
+        for lease_id in expired_leases {
+            info!("clean expired lease: {}", lease_id);
+            self.revoke_lease(lease_id).await?;
+        }
+
         self.sm.update_expire_cursor(log_time_ms);
 
         Ok(())
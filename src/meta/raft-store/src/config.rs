@@ -92,6 +92,20 @@ pub struct RaftConfig {
     /// The value is one or more addresses of a node in the cluster, to which this node sends a `join` request.
     pub join: Vec<String>,
 
+    /// Join the cluster as a learner, which replicates the raft log but does not vote.
+    ///
+    /// It will be ignored if `--join` is absent.
+    pub learner: bool,
+
+    /// The max time in milli seconds since the last contact from the leader,
+    /// within which a learner serves reads with its local state machine.
+    ///
+    /// If the leader has not contacted a learner for longer than this,
+    /// the learner fetches the last applied log index from the leader
+    /// and waits for its local state machine to catch up before serving a read.
+    /// `0` means always reading this way.
+    pub learner_max_staleness: u64,
+
     /// Do not run databend-meta, but just remove a node from its cluster.
     ///
     /// The value is one or more addresses of a node in the cluster, to which this node sends a `leave` request.
@@ -144,6 +158,8 @@ impl Default for RaftConfig {
             snapshot_chunk_size: 4194304, // 4MB
             single: false,
             join: vec![],
+            learner: false,
+            learner_max_staleness: 3000,
             leave_via: vec![],
             leave_id: None,
             id: 0,
@@ -0,0 +1,120 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+
+use common_meta_types::Change;
+
+/// The default number of recent changes a [`ChangeLog`] keeps.
+pub const DEFAULT_CHANGE_LOG_CAPACITY: usize = 8192;
+
+/// The changes a watcher asks for are no longer kept by the state machine.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error(
+    "ChangeLogCompacted: revision {start_revision} is compacted, the oldest available is {first_revision}"
+)]
+pub struct ChangeLogCompacted {
+    /// The revision requested.
+    pub start_revision: u64,
+
+    /// The smallest revision whose changes are all kept.
+    pub first_revision: u64,
+}
+
+/// A bounded log of the recent kv changes, for a watcher to replay the events it missed.
+///
+/// The revision of a change is the index of the raft log that makes it.
+/// A log may make more than one change, thus several changes may share the same revision.
+///
+/// The change log is not persisted:
+/// changes before the state machine is built or installed from a snapshot are considered compacted.
+#[derive(Debug)]
+pub struct ChangeLog {
+    capacity: usize,
+
+    /// The smallest revision whose changes are all kept.
+    /// Changes before it are compacted.
+    first_revision: u64,
+
+    changes: VecDeque<(u64, Change<Vec<u8>, String>)>,
+}
+
+impl Default for ChangeLog {
+    fn default() -> Self {
+        Self::new(DEFAULT_CHANGE_LOG_CAPACITY)
+    }
+}
+
+impl ChangeLog {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            first_revision: 0,
+            changes: VecDeque::new(),
+        }
+    }
+
+    /// Returns the smallest revision whose changes are all kept.
+    pub fn first_revision(&self) -> u64 {
+        self.first_revision
+    }
+
+    /// Append a change made by the log at `revision`, and remove the oldest one if it is full.
+    ///
+    /// When a change is removed, its revision is compacted,
+    /// even if there are other changes of the same revision still in the log.
+    pub fn append(&mut self, revision: u64, change: Change<Vec<u8>, String>) {
+        debug_assert!(
+            self.changes.back().map_or(true, |(r, _)| *r <= revision),
+            "revision must not decrease"
+        );
+
+        self.changes.push_back((revision, change));
+
+        while self.changes.len() > self.capacity {
+            let (r, _) = self.changes.pop_front().unwrap();
+            self.first_revision = std::cmp::max(self.first_revision, r + 1);
+        }
+    }
+
+    /// Remove all changes and mark every revision up to `last_applied`(inclusive) as compacted.
+    ///
+    /// It is called when the state machine data is replaced with a snapshot.
+    pub fn reset(&mut self, last_applied: u64) {
+        self.changes.clear();
+        self.first_revision = std::cmp::max(self.first_revision, last_applied + 1);
+    }
+
+    /// Returns changes with revision `>= start_revision`, in the order they are made.
+    pub fn changes_since(
+        &self,
+        start_revision: u64,
+    ) -> Result<Vec<(u64, Change<Vec<u8>, String>)>, ChangeLogCompacted> {
+        if start_revision < self.first_revision {
+            return Err(ChangeLogCompacted {
+                start_revision,
+                first_revision: self.first_revision,
+            });
+        }
+
+        let changes = self
+            .changes
+            .iter()
+            .filter(|(r, _)| *r >= start_revision)
+            .cloned()
+            .collect();
+
+        Ok(changes)
+    }
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod change_log;
 pub mod leveled_store;
 pub(in crate::sm_v002) mod marked;
 #[allow(clippy::module_inception)]
@@ -27,6 +28,9 @@ mod sm_v002_test;
 #[cfg(test)]
 mod snapshot_view_v002_test;
 
+pub use change_log::ChangeLog;
+pub use change_log::ChangeLogCompacted;
+pub use change_log::DEFAULT_CHANGE_LOG_CAPACITY;
 pub use importer::Importer;
 pub use sm_v002::SMV002;
 pub use snapshot_store::SnapshotStoreError;
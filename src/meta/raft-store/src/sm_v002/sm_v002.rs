@@ -26,7 +26,9 @@ use common_meta_kvapi::kvapi::UpsertKVReq;
 use common_meta_stoerr::MetaBytesError;
 use common_meta_types::protobuf::StreamItem;
 use common_meta_types::AppliedState;
+use common_meta_types::Change;
 use common_meta_types::Entry;
+use common_meta_types::Lease;
 use common_meta_types::MatchSeqExt;
 use common_meta_types::Operation;
 use common_meta_types::SeqV;
@@ -60,6 +62,8 @@ use crate::sm_v002::leveled_store::sys_data::SysData;
 use crate::sm_v002::leveled_store::sys_data_api::SysDataApiRO;
 use crate::sm_v002::marked::Marked;
 use crate::sm_v002::sm_v002;
+use crate::sm_v002::ChangeLog;
+use crate::sm_v002::ChangeLogCompacted;
 use crate::sm_v002::Importer;
 use crate::sm_v002::SnapshotViewV002;
 use crate::state_machine::sm::BlockingConfig;
@@ -67,6 +71,8 @@ use crate::state_machine::ExpireKey;
 use crate::state_machine::StateMachineSubscriber;
 
 /// A wrapper that implements KVApi **readonly** methods for the state machine.
+///
+/// The records reserved for the state machine, such as leases, are invisible through it.
 pub struct SMV002KVApi<'a> {
     sm: &'a SMV002,
 }
@@ -80,6 +86,10 @@ impl<'a> kvapi::KVApi for SMV002KVApi<'a> {
     }
 
     async fn get_kv(&self, key: &str) -> Result<GetKVReply, Self::Error> {
+        if Lease::is_reserved_key(key) {
+            return Ok(None);
+        }
+
         let got = self.sm.get_maybe_expired_kv(key).await?;
 
         let local_now_ms = SeqV::<()>::now_ms();
@@ -93,6 +103,11 @@ impl<'a> kvapi::KVApi for SMV002KVApi<'a> {
         let mut values = Vec::with_capacity(keys.len());
 
         for k in keys {
+            if Lease::is_reserved_key(k) {
+                values.push(None);
+                continue;
+            }
+
             let got = self.sm.get_maybe_expired_kv(k.as_str()).await?;
             let v = Self::non_expired(got, local_now_ms);
             values.push(v);
@@ -108,7 +123,9 @@ impl<'a> kvapi::KVApi for SMV002KVApi<'a> {
             .sm
             .list_kv(prefix)
             .await?
-            .try_filter(move |(_k, v)| future::ready(!v.is_expired(local_now_ms)))
+            .try_filter(move |(k, v)| {
+                future::ready(!v.is_expired(local_now_ms) && !Lease::is_reserved_key(k))
+            })
             .map_ok(StreamItem::from);
 
         Ok(strm.boxed())
@@ -138,6 +155,9 @@ pub struct SMV002 {
     /// The expiration key since which for next clean.
     pub(in crate::sm_v002) expire_cursor: ExpireKey,
 
+    /// The recent kv changes, for a watcher to replay events since a revision.
+    pub(crate) change_log: ChangeLog,
+
     /// subscriber of state machine data
     pub(crate) subscriber: Option<Box<dyn StateMachineSubscriber>>,
 }
@@ -334,6 +354,20 @@ impl SMV002 {
         self.subscriber = Some(subscriber);
     }
 
+    pub fn change_log(&self) -> &ChangeLog {
+        &self.change_log
+    }
+
+    /// Returns the recent kv changes with revision `>= start_revision`.
+    ///
+    /// The revision of a change is the index of the log that makes it.
+    pub fn changes_since(
+        &self,
+        start_revision: u64,
+    ) -> Result<Vec<(u64, Change<Vec<u8>, String>)>, ChangeLogCompacted> {
+        self.change_log.changes_since(start_revision)
+    }
+
     /// Creates a snapshot view that contains the latest state.
     ///
     /// Internally, the state machine creates a new empty writable level and makes all current states immutable.
@@ -358,6 +392,10 @@ impl SMV002 {
             new_applied
         );
 
+        // Changes before the installed data can not be replayed.
+        let new_applied_index = new_applied.as_ref().map(|log_id| log_id.index);
+        self.change_log.reset(new_applied_index.unwrap_or_default());
+
         self.levels = level;
 
         // The installed data may not cleaned up all expired keys, if it is built with an older state machine.
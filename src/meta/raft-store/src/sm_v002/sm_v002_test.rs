@@ -12,15 +12,33 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_meta_kvapi::kvapi::KVApi;
+use common_meta_types::new_log_id;
+use common_meta_types::txn_op;
+use common_meta_types::AppliedState;
+use common_meta_types::Change;
+use common_meta_types::Cmd;
+use common_meta_types::Entry;
+use common_meta_types::EntryPayload;
+use common_meta_types::Lease;
+use common_meta_types::LogEntry;
+use common_meta_types::MetaAPIError;
+use common_meta_types::MetaDataError;
 use common_meta_types::SeqV;
 use common_meta_types::SeqValue;
+use common_meta_types::TxnDeleteByPrefixRequest;
+use common_meta_types::TxnOp;
+use common_meta_types::TxnRequest;
 use common_meta_types::UpsertKV;
 use futures_util::TryStreamExt;
 use pretty_assertions::assert_eq;
 
+use crate::sm_v002::leveled_store::leveled_map::LeveledMap;
 use crate::sm_v002::leveled_store::map_api::AsMap;
 use crate::sm_v002::leveled_store::map_api::MapApiRO;
 use crate::sm_v002::marked::Marked;
+use crate::sm_v002::ChangeLog;
+use crate::sm_v002::ChangeLogCompacted;
 use crate::sm_v002::SMV002;
 use crate::state_machine::ExpireKey;
 
@@ -295,6 +313,325 @@ async fn test_inserting_expired_becomes_deleting() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_lease_expire_removes_attached_keys() -> anyhow::Result<()> {
+    let mut sm = SMV002::default();
+
+    let lease = Lease {
+        id: 1,
+        ttl_sec: 10,
+        expire_at: 1_010,
+    };
+
+    let res = sm
+        .apply_entries(&[
+            ent(1, 1_000_000, Cmd::GrantLease { ttl_sec: 10 }),
+            ent(
+                2,
+                1_000_000,
+                Cmd::UpsertKV(UpsertKV::update("a", b"a0").with_lease(1)),
+            ),
+            ent(
+                3,
+                1_000_000,
+                Cmd::UpsertKV(UpsertKV::update("b", b"b0").with_lease(1)),
+            ),
+            ent(4, 1_000_000, Cmd::UpsertKV(UpsertKV::update("c", b"c0"))),
+            // Attaching to an absent lease is rejected
+            ent(
+                5,
+                1_000_000,
+                Cmd::UpsertKV(UpsertKV::update("d", b"d0").with_lease(100)),
+            ),
+            // Updating without lease detaches `b`
+            ent(6, 1_000_000, Cmd::UpsertKV(UpsertKV::update("b", b"b1"))),
+        ])
+        .await?;
+    assert_eq!(res[0], AppliedState::Lease(Some(lease.clone())));
+    assert_eq!(res[4], AppliedState::Lease(None));
+    assert_eq!(sm.get_maybe_expired_kv("d").await?, None);
+
+    // Keep alive extends the lease
+    let res = sm
+        .apply_entries(&[ent(7, 1_005_000, Cmd::KeepAliveLease { lease_id: 1 })])
+        .await?;
+    assert_eq!(
+        res[0],
+        AppliedState::Lease(Some(Lease {
+            expire_at: 1_015,
+            ..lease.clone()
+        }))
+    );
+
+    // Not expired yet
+    sm.apply_entries(&[ent(
+        8,
+        1_011_000,
+        Cmd::UpsertKV(UpsertKV::update("e", b"e0")),
+    )])
+    .await?;
+    assert!(sm.get_maybe_expired_kv("a").await?.is_some());
+
+    // Expired, `a` is removed along with the lease
+    sm.apply_entries(&[ent(
+        9,
+        1_016_000,
+        Cmd::UpsertKV(UpsertKV::update("f", b"f0")),
+    )])
+    .await?;
+
+    let got = sm.list_kv("").await?.try_collect::<Vec<_>>().await?;
+    let keys = got.into_iter().map(|(k, _)| k).collect::<Vec<_>>();
+    assert_eq!(keys, vec![s("b"), s("c"), s("e"), s("f")]);
+
+    // Revoke an absent lease
+    let res = sm
+        .apply_entries(&[ent(10, 1_016_000, Cmd::RevokeLease { lease_id: 1 })])
+        .await?;
+    assert_eq!(res[0], AppliedState::Lease(None));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_lease_revoke() -> anyhow::Result<()> {
+    let mut sm = SMV002::default();
+
+    sm.apply_entries(&[
+        ent(1, 1_000_000, Cmd::GrantLease { ttl_sec: 10 }),
+        ent(
+            2,
+            1_000_000,
+            Cmd::UpsertKV(UpsertKV::update("a", b"a0").with_lease(1)),
+        ),
+        ent(
+            3,
+            1_000_000,
+            Cmd::UpsertKV(UpsertKV::update("b", b"b0").with_lease(1)),
+        ),
+    ])
+    .await?;
+
+    let res = sm
+        .apply_entries(&[ent(4, 1_001_000, Cmd::RevokeLease { lease_id: 1 })])
+        .await?;
+    assert_eq!(
+        res[0],
+        AppliedState::Lease(Some(Lease {
+            id: 1,
+            ttl_sec: 10,
+            expire_at: 1_010,
+        }))
+    );
+
+    let got = sm.list_kv("").await?.try_collect::<Vec<_>>().await?;
+    assert_eq!(got, vec![]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_lease_not_found_rejects_write() -> anyhow::Result<()> {
+    let mut sm = SMV002::default();
+
+    sm.apply_entries(&[ent(1, 1_000_000, Cmd::GrantLease { ttl_sec: 10 })])
+        .await?;
+
+    // The lease expires at 1_010 sec, it is cleaned before applying the log.
+    let res = sm
+        .apply_entries(&[ent(
+            2,
+            1_011_000,
+            Cmd::UpsertKV(UpsertKV::update("a", b"a0").with_lease(1)),
+        )])
+        .await?;
+    assert_eq!(res[0], AppliedState::Lease(None));
+    assert_eq!(sm.get_maybe_expired_kv("a").await?, None);
+
+    // A txn putting a key with an absent lease is rejected as a whole.
+    let txn = TxnRequest {
+        condition: vec![],
+        if_then: vec![
+            TxnOp::put("b", b("b0")),
+            TxnOp::put_with_lease("c", b("c0"), 100),
+        ],
+        else_then: vec![],
+    };
+    let res = sm
+        .apply_entries(&[ent(3, 1_011_000, Cmd::Transaction(txn))])
+        .await?;
+
+    let AppliedState::TxnReply(reply) = &res[0] else {
+        panic!("expect AppliedState::TxnReply");
+    };
+    assert!(!reply.success);
+    assert!(reply.responses.is_empty());
+    let err: MetaAPIError = serde_json::from_str(&reply.error)?;
+    assert_eq!(
+        err,
+        MetaAPIError::DataError(MetaDataError::LeaseNotFound(100))
+    );
+
+    let got = sm.list_kv("").await?.try_collect::<Vec<_>>().await?;
+    assert_eq!(got, vec![]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_lease_records_are_reserved() -> anyhow::Result<()> {
+    let mut sm = SMV002::default();
+
+    let res = sm
+        .apply_entries(&[
+            ent(1, 1_000_000, Cmd::GrantLease { ttl_sec: 10 }),
+            ent(
+                2,
+                1_000_000,
+                Cmd::UpsertKV(UpsertKV::update("a", b"a0").with_lease(1)),
+            ),
+            // A user can not overwrite a lease record
+            ent(
+                3,
+                1_000_000,
+                Cmd::UpsertKV(UpsertKV::update(Lease::key(1), b"x")),
+            ),
+        ])
+        .await?;
+    assert_eq!(res[2], AppliedState::None);
+
+    // A txn accessing a lease record is rejected as a whole.
+    let txn = TxnRequest {
+        condition: vec![],
+        if_then: vec![
+            TxnOp::put("b", b("b0")),
+            TxnOp::delete(Lease::attached_key(1, "a")),
+        ],
+        else_then: vec![],
+    };
+    // Deleting by an empty prefix does not remove the lease records.
+    let delete_all = TxnRequest {
+        condition: vec![],
+        if_then: vec![TxnOp {
+            request: Some(txn_op::Request::DeleteByPrefix(TxnDeleteByPrefixRequest {
+                prefix: s(""),
+            })),
+        }],
+        else_then: vec![],
+    };
+    let res = sm
+        .apply_entries(&[
+            ent(4, 1_000_000, Cmd::Transaction(txn)),
+            ent(5, 1_000_000, Cmd::Transaction(delete_all)),
+        ])
+        .await?;
+
+    let AppliedState::TxnReply(reply) = &res[0] else {
+        panic!("expect AppliedState::TxnReply");
+    };
+    assert!(!reply.success);
+    let err: MetaAPIError = serde_json::from_str(&reply.error)?;
+    assert_eq!(
+        err,
+        MetaAPIError::DataError(MetaDataError::ReservedKey(Lease::attached_key(1, "a")))
+    );
+
+    let got = sm.list_kv("").await?.try_collect::<Vec<_>>().await?;
+    let keys = got.into_iter().map(|(k, _)| k).collect::<Vec<_>>();
+    assert_eq!(keys, vec![Lease::attached_key(1, "a"), Lease::key(1)]);
+
+    // The lease records are invisible to a user.
+    let kv_api = sm.kv_api();
+    assert_eq!(kv_api.get_kv(&Lease::key(1)).await?, None);
+    assert_eq!(kv_api.mget_kv(&[Lease::key(1)]).await?, vec![None]);
+    let listed = kv_api.prefix_list_kv("").await?;
+    assert_eq!(listed, vec![]);
+
+    // The lease records are still removed by the state machine.
+    sm.apply_entries(&[ent(6, 1_000_000, Cmd::RevokeLease { lease_id: 1 })])
+        .await?;
+    let got = sm.list_kv("").await?.try_collect::<Vec<_>>().await?;
+    assert_eq!(got, vec![]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_change_log_revision() -> anyhow::Result<()> {
+    let mut sm = SMV002::default();
+
+    sm.apply_entries(&[
+        ent(3, 1_000_000, Cmd::UpsertKV(UpsertKV::update("a", b"a0"))),
+        ent(4, 1_000_000, Cmd::UpsertKV(UpsertKV::update("b", b"b0"))),
+        ent(5, 1_000_000, Cmd::UpsertKV(UpsertKV::delete("a"))),
+    ])
+    .await?;
+
+    let got = sm.changes_since(4)?;
+    assert_eq!(got, vec![
+        (
+            4,
+            Change::new(None, Some(SeqV::new(2, b("b0")))).with_id(s("b"))
+        ),
+        (
+            5,
+            Change::new(Some(SeqV::new(1, b("a0"))), None).with_id(s("a"))
+        ),
+    ]);
+
+    // Changes before the installed data are compacted.
+    let mut installed = LeveledMap::default();
+    *installed.writable_mut().sys_data_mut().last_applied_mut() = Some(new_log_id(1, 0, 5));
+    sm.replace(installed);
+    let got = sm.changes_since(5);
+    assert_eq!(
+        got,
+        Err(ChangeLogCompacted {
+            start_revision: 5,
+            first_revision: 6,
+        })
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_change_log_capacity() -> anyhow::Result<()> {
+    let mut log = ChangeLog::new(2);
+    let change = |k: &str| Change::new(None, Some(SeqV::new(1, b(k)))).with_id(s(k));
+
+    log.append(1, change("a"));
+    log.append(2, change("b"));
+    log.append(2, change("c"));
+    assert_eq!(log.first_revision(), 2);
+
+    log.append(3, change("d"));
+    assert_eq!(log.first_revision(), 3, "revision 2 is partially removed");
+
+    assert_eq!(log.changes_since(3)?, vec![(3, change("d"))]);
+    assert_eq!(
+        log.changes_since(2),
+        Err(ChangeLogCompacted {
+            start_revision: 2,
+            first_revision: 3,
+        })
+    );
+
+    Ok(())
+}
+
+/// Build a raft log entry applied at `time_ms`.
+fn ent(index: u64, time_ms: u64, cmd: Cmd) -> Entry {
+    Entry {
+        log_id: new_log_id(1, 0, index),
+        payload: EntryPayload::Normal(LogEntry {
+            txid: None,
+            time_ms: Some(time_ms),
+            cmd,
+        }),
+    }
+}
+
 fn s(x: impl ToString) -> String {
     x.to_string()
 }
@@ -96,7 +96,8 @@ const TREE_STATE_MACHINE: &str = "state_machine";
 
 /// StateMachine subscriber trait
 pub trait StateMachineSubscriber: Debug + Sync + Send {
-    fn kv_changed(&self, change: Change<Vec<u8>, String>);
+    /// Called when a kv is changed by the log at index `revision`.
+    fn kv_changed(&self, revision: u64, change: Change<Vec<u8>, String>);
 }
 
 /// The state machine of the `MemStore`.
@@ -365,7 +366,7 @@ impl StateMachine {
         // Send queued change events to subscriber
         if let Some(subscriber) = &self.subscriber {
             for event in changes {
-                subscriber.kv_changed(event);
+                subscriber.kv_changed(entry.log_id.index, event);
             }
         }
 
@@ -800,6 +801,12 @@ impl StateMachine {
             }
 
             Cmd::Transaction(txn) => self.apply_txn_cmd(txn, txn_tree, kv_pairs, log_time_ms),
+
+            // Leases are only supported by the state machine v002, see `Applier`.
+            Cmd::GrantLease { .. } | Cmd::KeepAliveLease { .. } | Cmd::RevokeLease { .. } => {
+                warn!("apply_cmd: lease is not supported: {}", cmd);
+                Ok(AppliedState::Lease(None))
+            }
         };
 
         let elapsed = now.elapsed().as_micros();
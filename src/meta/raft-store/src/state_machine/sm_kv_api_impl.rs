@@ -41,6 +41,7 @@ impl kvapi::KVApi for StateMachine {
             seq: act.seq,
             value: act.value,
             value_meta: act.value_meta,
+            lease_id: act.lease_id,
         });
 
         let res = self.sm_tree.txn(true, |mut txn_sled_tree| {
@@ -216,6 +216,7 @@ async fn test_state_machine_apply_non_dup_generic_kv_upsert_get() -> anyhow::Res
                         seq: c.seq,
                         value: Operation::Update(c.value.clone()),
                         value_meta: c.value_meta.clone(),
+                        lease_id: None,
                     }),
                     &mut t,
                     None,
@@ -280,6 +281,7 @@ async fn test_state_machine_apply_non_dup_generic_kv_value_meta() -> anyhow::Res
                     value_meta: Some(KVMeta {
                         expire_at: Some(now + 10),
                     }),
+                    lease_id: None,
                 }),
                 &mut t,
                 None,
@@ -307,6 +309,7 @@ async fn test_state_machine_apply_non_dup_generic_kv_value_meta() -> anyhow::Res
                     value_meta: Some(KVMeta {
                         expire_at: Some(now + 10),
                     }),
+                    lease_id: None,
                 }),
                 &mut t,
                 None,
@@ -326,6 +329,7 @@ async fn test_state_machine_apply_non_dup_generic_kv_value_meta() -> anyhow::Res
                     value_meta: Some(KVMeta {
                         expire_at: Some(now + 20),
                     }),
+                    lease_id: None,
                 }),
                 &mut t,
                 None,
@@ -18,6 +18,7 @@ use std::sync::Mutex;
 use async_trait::async_trait;
 use common_meta_api::BackgroundApiTestSuite;
 use common_meta_api::SchemaApiTestSuite;
+use common_meta_api::SequenceApiTestSuite;
 use common_meta_api::ShareApiTestSuite;
 use common_meta_kvapi::kvapi;
 use common_meta_raft_store::state_machine::StateMachine;
@@ -59,7 +60,8 @@ async fn test_meta_embedded_single() -> anyhow::Result<()> {
 
     SchemaApiTestSuite::test_single_node(builder.clone()).await?;
     ShareApiTestSuite::test_single_node_share(builder.clone()).await?;
-    BackgroundApiTestSuite::test_single_node(builder).await?;
+    BackgroundApiTestSuite::test_single_node(builder.clone()).await?;
+    SequenceApiTestSuite::test_single_node(builder).await?;
 
     Ok(())
 }
@@ -31,6 +31,8 @@ use common_meta_types::protobuf::Empty;
 use common_meta_types::protobuf::ExportedChunk;
 use common_meta_types::protobuf::HandshakeRequest;
 use common_meta_types::protobuf::HandshakeResponse;
+use common_meta_types::protobuf::LeaseKeepAliveRequest;
+use common_meta_types::protobuf::LeaseKeepAliveResponse;
 use common_meta_types::protobuf::MemberListReply;
 use common_meta_types::protobuf::MemberListRequest;
 use common_meta_types::protobuf::RaftReply;
@@ -110,6 +112,14 @@ impl MetaServiceImpl {
                 let res = m.upsert_kv(a.clone()).await;
                 RaftReply::from(res)
             }
+            MetaGrpcReq::GrantLease(a) => {
+                let res = m.grant_lease(a.ttl_sec).await;
+                RaftReply::from(res)
+            }
+            MetaGrpcReq::RevokeLease(a) => {
+                let res = m.revoke_lease(a.lease_id).await;
+                RaftReply::from(res)
+            }
             MetaGrpcReq::GetKV(a) => {
                 let res = m.get_kv(&a.key).await;
                 RaftReply::from(res)
@@ -169,11 +179,8 @@ impl MetaServiceImpl {
         let ret = self.meta_node.transaction(request).await;
 
         let body = match ret {
-            Ok(resp) => TxnReply {
-                success: resp.success,
-                error: "".to_string(),
-                responses: resp.responses,
-            },
+            // The error is not empty if the txn is rejected when applying, e.g., a lease is not found.
+            Ok(resp) => resp,
             Err(err) => TxnReply {
                 success: false,
                 error: serde_json::to_string(&err).expect("fail to serialize"),
@@ -300,6 +307,35 @@ impl MetaService for MetaServiceImpl {
         Ok(Response::new(reply))
     }
 
+    type KeepAliveStream = BoxStream<LeaseKeepAliveResponse>;
+
+    /// Refresh leases through a single stream: a response is sent back for every request.
+    async fn keep_alive(
+        &self,
+        request: Request<Streaming<LeaseKeepAliveRequest>>,
+    ) -> Result<Response<Self::KeepAliveStream>, Status> {
+        self.check_token(request.metadata())?;
+
+        let mn = self.meta_node.clone();
+
+        let strm = request.into_inner().then(move |req| {
+            let mn = mn.clone();
+            async move {
+                let req = req?;
+                debug!("keep alive lease: {}", req.lease_id);
+
+                let lease = mn
+                    .keep_alive_lease(req.lease_id)
+                    .await
+                    .map_err(GrpcHelper::internal_err)?;
+
+                Ok(LeaseKeepAliveResponse::new(req.lease_id, lease))
+            }
+        });
+
+        Ok(Response::new(Box::pin(strm)))
+    }
+
     type ExportStream = Pin<Box<dyn Stream<Item = Result<ExportedChunk, Status>> + Send + 'static>>;
 
     /// Export all meta data.
@@ -338,11 +374,29 @@ impl MetaService for MetaServiceImpl {
 
         let mn = &self.meta_node;
 
+        let start_revision = request.get_ref().start_revision;
         let add_res = mn.add_watcher(request.into_inner(), tx).await;
 
         match add_res {
             Ok(watcher) => {
-                let stream = WatchStream::new(rx, watcher, mn.dispatcher_handle.clone());
+                let stream = WatchStream::new(rx, watcher.clone(), mn.dispatcher_handle.clone());
+
+                let Some(start_revision) = start_revision else {
+                    return Ok(Response::new(Box::pin(stream) as Self::WatchStream));
+                };
+
+                // The watcher is added before reading the history,
+                // thus an event is either replayed or dispatched to the watcher after `last_revision`.
+                let (replay, last_revision) = mn
+                    .replay_watch_events(&watcher, start_revision)
+                    .await
+                    .map_err(|e| Status::out_of_range(e.to_string()))?;
+
+                let stream =
+                    futures::stream::iter(replay.into_iter().map(Ok)).chain(stream.try_filter(
+                        move |resp| futures::future::ready(resp.revision > last_revision),
+                    ));
+
                 Ok(Response::new(Box::pin(stream) as Self::WatchStream))
             }
             Err(e) => {
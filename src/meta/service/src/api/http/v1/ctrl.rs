@@ -15,13 +15,20 @@
 use std::sync::Arc;
 use std::time::Duration;
 
+use common_meta_types::NodeId;
 use poem::http::StatusCode;
 use poem::web::Data;
 use poem::web::IntoResponse;
 use poem::web::Json;
+use poem::web::Query;
 
 use crate::meta_service::MetaNode;
 
+#[derive(serde::Serialize, serde::Deserialize, Debug)]
+pub struct NodeIdQuery {
+    node_id: NodeId,
+}
+
 /// Let raft leader send snapshot to followers/learners.
 ///
 /// If this node is not a leader this request will be just ignored.
@@ -36,6 +43,52 @@ pub async fn trigger_snapshot(meta_node: Data<&Arc<MetaNode>>) -> poem::Result<i
     Ok(Json(()))
 }
 
+/// Let this node start an election to become the leader.
+///
+/// It is not a leadership transfer: the other nodes refuse to vote while the current leader is
+/// active, so it only takes effect when the leader is already gone.
+/// The response does not tell if the election succeeded.
+#[poem::handler]
+pub async fn trigger_elect(meta_node: Data<&Arc<MetaNode>>) -> poem::Result<impl IntoResponse> {
+    meta_node
+        .raft
+        .trigger()
+        .elect()
+        .await
+        .map_err(|e| poem::Error::from_string(e.to_string(), StatusCode::INTERNAL_SERVER_ERROR))?;
+    Ok(Json(()))
+}
+
+/// Promote a learner to a voter.
+///
+/// The request is forwarded to the leader if this node is not the leader.
+#[poem::handler]
+pub async fn promote_learner(
+    meta_node: Data<&Arc<MetaNode>>,
+    query: Query<NodeIdQuery>,
+) -> poem::Result<impl IntoResponse> {
+    meta_node
+        .promote_learner(query.node_id)
+        .await
+        .map_err(|e| poem::Error::from_string(e.to_string(), StatusCode::INTERNAL_SERVER_ERROR))?;
+    Ok(Json(()))
+}
+
+/// Demote a voter to a learner, which keeps replicating logs and serving reads but does not vote.
+///
+/// The request is forwarded to the leader if this node is not the leader.
+#[poem::handler]
+pub async fn demote_voter(
+    meta_node: Data<&Arc<MetaNode>>,
+    query: Query<NodeIdQuery>,
+) -> poem::Result<impl IntoResponse> {
+    meta_node
+        .demote_voter(query.node_id)
+        .await
+        .map_err(|e| poem::Error::from_string(e.to_string(), StatusCode::INTERNAL_SERVER_ERROR))?;
+    Ok(Json(()))
+}
+
 #[poem::handler]
 pub async fn block_write_snapshot(
     meta_node: Data<&Arc<MetaNode>>,
@@ -62,6 +62,18 @@ impl HttpService {
                 "/v1/ctrl/trigger_snapshot",
                 get(super::http::v1::ctrl::trigger_snapshot),
             )
+            .at(
+                "/v1/ctrl/trigger_elect",
+                get(super::http::v1::ctrl::trigger_elect),
+            )
+            .at(
+                "/v1/ctrl/promote_learner",
+                get(super::http::v1::ctrl::promote_learner),
+            )
+            .at(
+                "/v1/ctrl/demote_voter",
+                get(super::http::v1::ctrl::demote_voter),
+            )
             .at(
                 "/v1/ctrl/block_write_snapshot",
                 get(super::http::v1::ctrl::block_write_snapshot),
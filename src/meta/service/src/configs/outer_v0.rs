@@ -289,6 +289,8 @@ pub struct ConfigViaEnv {
     pub raft_snapshot_chunk_size: u64,
     pub kvsrv_single: bool,
     pub metasrv_join: Vec<String>,
+    pub metasrv_learner: bool,
+    pub metasrv_learner_max_staleness: u64,
     pub kvsrv_id: u64,
     pub sled_tree_prefix: String,
     pub cluster_name: String,
@@ -334,6 +336,8 @@ impl From<Config> for ConfigViaEnv {
             raft_snapshot_chunk_size: cfg.raft_config.snapshot_chunk_size,
             kvsrv_single: cfg.raft_config.single,
             metasrv_join: cfg.raft_config.join,
+            metasrv_learner: cfg.raft_config.learner,
+            metasrv_learner_max_staleness: cfg.raft_config.learner_max_staleness,
             kvsrv_id: cfg.raft_config.id,
             sled_tree_prefix: cfg.raft_config.sled_tree_prefix,
             cluster_name: cfg.raft_config.cluster_name,
@@ -360,6 +364,8 @@ impl Into<Config> for ConfigViaEnv {
             snapshot_chunk_size: self.raft_snapshot_chunk_size,
             single: self.kvsrv_single,
             join: self.metasrv_join,
+            learner: self.metasrv_learner,
+            learner_max_staleness: self.metasrv_learner_max_staleness,
             // Do not allow to leave via environment variable
             leave_via: vec![],
             // Do not allow to leave via environment variable
@@ -479,6 +485,20 @@ pub struct RaftConfig {
     #[clap(long)]
     pub join: Vec<String>,
 
+    /// Join the cluster as a learner, which replicates the raft log but does not vote.
+    ///
+    /// It will take effect only when `--join` is specified.
+    #[clap(long)]
+    pub learner: bool,
+
+    /// The max time in milli seconds since the last contact from the leader,
+    /// within which a learner serves reads with its local state machine.
+    ///
+    /// Beyond it, a learner waits for its state machine to catch up with the leader before serving a read.
+    /// `0` means a learner always waits for catching up.
+    #[clap(long, default_value = "3000")]
+    pub learner_max_staleness: u64,
+
     /// Do not run databend-meta, but just remove a node from its cluster via the provided endpoints.
     ///
     /// This node will be removed by `id`.
@@ -531,6 +551,8 @@ impl From<RaftConfig> for InnerRaftConfig {
             snapshot_chunk_size: x.snapshot_chunk_size,
             single: x.single,
             join: x.join,
+            learner: x.learner,
+            learner_max_staleness: x.learner_max_staleness,
             leave_via: x.leave_via,
             leave_id: x.leave_id,
             id: x.id,
@@ -557,6 +579,8 @@ impl From<InnerRaftConfig> for RaftConfig {
             snapshot_chunk_size: inner.snapshot_chunk_size,
             single: inner.single,
             join: inner.join,
+            learner: inner.learner,
+            learner_max_staleness: inner.learner_max_staleness,
             leave_via: inner.leave_via,
             leave_id: inner.leave_id,
             id: inner.id,
@@ -37,6 +37,10 @@ pub struct JoinRequest {
     pub grpc_api_addr: String,
 
     pub grpc_api_advertise_address: Option<String>,
+
+    /// Join as a learner, which replicates the raft log but does not vote.
+    #[serde(default)]
+    pub learner: bool,
 }
 
 impl JoinRequest {
@@ -52,6 +56,12 @@ impl JoinRequest {
             ..Default::default()
         }
     }
+
+    #[must_use]
+    pub fn with_learner(mut self, learner: bool) -> Self {
+        self.learner = learner;
+        self
+    }
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq)]
@@ -59,6 +69,18 @@ pub struct LeaveRequest {
     pub node_id: NodeId,
 }
 
+/// Promote a learner to a voter.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct PromoteRequest {
+    pub node_id: NodeId,
+}
+
+/// Demote a voter to a learner.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct DemoteRequest {
+    pub node_id: NodeId,
+}
+
 #[derive(
     serde::Serialize,
     serde::Deserialize,
@@ -74,9 +96,17 @@ pub enum ForwardRequestBody {
 
     Join(JoinRequest),
     Leave(LeaveRequest),
+    Promote(PromoteRequest),
+    Demote(DemoteRequest),
 
     Write(LogEntry),
 
+    /// Get the index of the last log applied by the leader.
+    ///
+    /// A learner waits for its local state machine to reach this index before serving a read.
+    #[from(ignore)]
+    ReadIndex,
+
     GetKV(GetKVReq),
     MGetKV(MGetKVReq),
     ListKV(ListKVReq),
@@ -134,6 +164,9 @@ pub enum ForwardResponse {
 
     Join(()),
     Leave(()),
+    Promote(()),
+    Demote(()),
+    ReadIndex(u64),
     AppliedState(AppliedState),
 
     GetKV(GetKVReply),
@@ -19,6 +19,7 @@ use common_meta_client::MetaGrpcReadReq;
 use common_meta_kvapi::kvapi::KVApi;
 use common_meta_raft_store::sm_v002::leveled_store::sys_data_api::SysDataApiRO;
 use common_meta_raft_store::sm_v002::SMV002;
+use common_meta_sled_store::openraft::error::CheckIsLeaderError;
 use common_meta_sled_store::openraft::ChangeMembers;
 use common_meta_stoerr::MetaStorageError;
 use common_meta_types::protobuf::StreamItem;
@@ -43,11 +44,13 @@ use maplit::btreemap;
 use maplit::btreeset;
 use tonic::codegen::BoxStream;
 
+use crate::message::DemoteRequest;
 use crate::message::ForwardRequest;
 use crate::message::ForwardRequestBody;
 use crate::message::ForwardResponse;
 use crate::message::JoinRequest;
 use crate::message::LeaveRequest;
+use crate::message::PromoteRequest;
 use crate::meta_service::meta_node::MetaRaft;
 use crate::meta_service::MetaNode;
 use crate::metrics::server_metrics;
@@ -84,10 +87,22 @@ impl<'a> Handler<ForwardRequestBody> for MetaLeader<'a> {
                 self.leave(leave_req).await?;
                 Ok(ForwardResponse::Leave(()))
             }
+            ForwardRequestBody::Promote(promote_req) => {
+                self.promote(promote_req).await?;
+                Ok(ForwardResponse::Promote(()))
+            }
+            ForwardRequestBody::Demote(demote_req) => {
+                self.demote(demote_req).await?;
+                Ok(ForwardResponse::Demote(()))
+            }
             ForwardRequestBody::Write(entry) => {
                 let res = self.write(entry.clone()).await?;
                 Ok(ForwardResponse::AppliedState(res))
             }
+            ForwardRequestBody::ReadIndex => {
+                let index = self.read_index().await?;
+                Ok(ForwardResponse::ReadIndex(index))
+            }
 
             ForwardRequestBody::GetKV(req) => {
                 let sm = self.get_state_machine().await;
@@ -172,7 +187,8 @@ impl<'a> MetaLeader<'a> {
     /// Join a new node to the cluster.
     ///
     /// - Adds the node to cluster as a non-voter persistently and starts replication.
-    /// - Adds the node to membership to let it become a voter.
+    /// - Adds the node to membership to let it become a voter,
+    ///   or adds it as a learner if `req.learner` is set.
     ///
     /// If the node is already in cluster membership, it still returns Ok.
     #[minitrace::trace]
@@ -188,6 +204,10 @@ impl<'a> MetaLeader<'a> {
             return Ok(());
         }
 
+        if req.learner && membership.get_node(&node_id).is_some() {
+            return Ok(());
+        }
+
         let ent = LogEntry {
             txid: None,
             time_ms: None,
@@ -200,11 +220,68 @@ impl<'a> MetaLeader<'a> {
         };
         self.write(ent).await?;
 
+        let nodes = btreemap! {node_id=>MembershipNode{}};
+        let changes = if req.learner {
+            ChangeMembers::AddNodes(nodes)
+        } else {
+            ChangeMembers::AddVoters(nodes)
+        };
+
+        self.raft.change_membership(changes, false).await?;
+        Ok(())
+    }
+
+    /// Promote a learner to a voter.
+    ///
+    /// If the node is already a voter, it still returns Ok.
+    #[minitrace::trace]
+    pub async fn promote(&self, req: PromoteRequest) -> Result<(), RaftError<ClientWriteError>> {
+        let node_id = req.node_id;
+        let metrics = self.raft.metrics().borrow().clone();
+        let membership = metrics.membership_config.membership();
+
+        if membership.voter_ids().any(|id| id == node_id) {
+            return Ok(());
+        }
+
+        info!("promote learner {} to voter", node_id);
+
         self.raft
-            .change_membership(
-                ChangeMembers::AddVoters(btreemap! {node_id=>MembershipNode{}}),
-                false,
-            )
+            .change_membership(ChangeMembers::AddVoterIds(btreeset! {node_id}), false)
+            .await?;
+        Ok(())
+    }
+
+    /// Demote a voter to a learner, which keeps replicating logs but does not vote any more.
+    ///
+    /// If the node is not a voter, or it is the last voter, it still returns Ok.
+    #[minitrace::trace]
+    pub async fn demote(&self, req: DemoteRequest) -> Result<(), MetaOperationError> {
+        let node_id = req.node_id;
+
+        let can_res = self
+            .can_leave(node_id)
+            .await
+            .map_err(|e| MetaDataError::ReadError(MetaDataReadError::new("can_leave()", "", &e)))?;
+
+        if let Err(e) = can_res {
+            info!("no need to demote: {}", e);
+            return Ok(());
+        }
+
+        let metrics = self.raft.metrics().borrow().clone();
+        let membership = metrics.membership_config.membership();
+
+        if !membership.voter_ids().any(|id| id == node_id) {
+            info!("no need to demote: node {} is not a voter", node_id);
+            return Ok(());
+        }
+
+        info!("demote voter {} to learner", node_id);
+
+        // Keep the removed voter as a learner.
+        self.raft
+            .change_membership(ChangeMembers::RemoveVoters(btreeset! {node_id}), true)
             .await?;
         Ok(())
     }
@@ -279,6 +356,25 @@ impl<'a> MetaLeader<'a> {
         }
     }
 
+    /// Returns the log index a learner has to apply up to, to serve a read that sees every
+    /// write committed so far.
+    ///
+    /// A node that believes it is the leader may have been deposed without perceiving it,
+    /// so the leadership is confirmed with a quorum first.
+    /// The last log index of a leader is not less than the committed index.
+    #[minitrace::trace]
+    pub async fn read_index(&self) -> Result<u64, MetaOperationError> {
+        self.raft.is_leader().await.map_err(|e| match e {
+            RaftError::APIError(CheckIsLeaderError::ForwardToLeader(to_leader)) => {
+                MetaOperationError::ForwardToLeader(to_leader)
+            }
+            e => MetaDataError::ReadError(MetaDataReadError::new("is_leader()", "", &e)).into(),
+        })?;
+
+        let metrics = self.raft.metrics().borrow().clone();
+        Ok(metrics.last_log_index.unwrap_or_default())
+    }
+
     /// Check if a node is allowed to leave the cluster.
     ///
     /// A cluster must have at least one node in it.
@@ -36,6 +36,7 @@ use common_meta_raft_store::config::RaftConfig;
 use common_meta_raft_store::ondisk::DataVersion;
 use common_meta_raft_store::ondisk::DATA_VERSION;
 use common_meta_raft_store::sm_v002::leveled_store::sys_data_api::SysDataApiRO;
+use common_meta_raft_store::sm_v002::ChangeLogCompacted;
 use common_meta_sled_store::openraft;
 use common_meta_sled_store::openraft::storage::Adaptor;
 use common_meta_sled_store::openraft::ChangeMembers;
@@ -43,6 +44,7 @@ use common_meta_stoerr::MetaStorageError;
 use common_meta_types::protobuf::raft_service_client::RaftServiceClient;
 use common_meta_types::protobuf::raft_service_server::RaftServiceServer;
 use common_meta_types::protobuf::WatchRequest;
+use common_meta_types::protobuf::WatchResponse;
 use common_meta_types::AppliedState;
 use common_meta_types::Cmd;
 use common_meta_types::CommittedLeaderId;
@@ -51,6 +53,7 @@ use common_meta_types::ForwardRPCError;
 use common_meta_types::ForwardToLeader;
 use common_meta_types::GrpcConfig;
 use common_meta_types::InvalidReply;
+use common_meta_types::Lease;
 use common_meta_types::LogEntry;
 use common_meta_types::LogId;
 use common_meta_types::MembershipNode;
@@ -81,11 +84,13 @@ use openraft::ServerState;
 use openraft::SnapshotPolicy;
 
 use crate::configs::Config as MetaConfig;
+use crate::message::DemoteRequest;
 use crate::message::ForwardRequest;
 use crate::message::ForwardRequestBody;
 use crate::message::ForwardResponse;
 use crate::message::JoinRequest;
 use crate::message::LeaveRequest;
+use crate::message::PromoteRequest;
 use crate::meta_service::errors::grpc_error_to_network_err;
 use crate::meta_service::forwarder::MetaForwarder;
 use crate::meta_service::meta_leader::MetaLeader;
@@ -94,6 +99,7 @@ use crate::metrics::server_metrics;
 use crate::network::Network;
 use crate::request_handling::Forwarder;
 use crate::request_handling::Handler;
+use crate::request_handling::ReadOnly;
 use crate::store::RaftStore;
 use crate::version::METASRV_COMMIT_VERSION;
 use crate::watcher::DispatcherSender;
@@ -175,6 +181,14 @@ pub struct MetaNode {
     pub running_rx: watch::Receiver<()>,
     pub join_handles: Mutex<Vec<JoinHandle<Result<(), AnyError>>>>,
     pub joined_tasks: AtomicI32,
+
+    /// Within this duration since the last contact from the leader,
+    /// a learner serves reads with its local state machine.
+    pub learner_max_staleness: Duration,
+
+    /// The time when this node received the last successful append-entries from the leader,
+    /// and the committed log index the leader sent along with it.
+    pub last_leader_contact: std::sync::Mutex<Option<(Instant, u64)>>,
 }
 
 impl Opened for MetaNode {
@@ -189,6 +203,7 @@ pub struct MetaNodeBuilder {
     sto: Option<RaftStore>,
     monitor_metrics: bool,
     endpoint: Option<Endpoint>,
+    learner_max_staleness: Duration,
 }
 
 impl MetaNodeBuilder {
@@ -232,6 +247,8 @@ impl MetaNodeBuilder {
             running_rx: rx,
             join_handles: Mutex::new(Vec::new()),
             joined_tasks: AtomicI32::new(1),
+            learner_max_staleness: self.learner_max_staleness,
+            last_leader_contact: std::sync::Mutex::new(None),
         });
 
         if self.monitor_metrics {
@@ -292,6 +309,7 @@ impl MetaNode {
             sto: None,
             monitor_metrics: true,
             endpoint: None,
+            learner_max_staleness: Duration::from_millis(config.learner_max_staleness),
         }
     }
 
@@ -641,7 +659,7 @@ impl MetaNode {
         // Try to join a cluster only when this node has no log.
         // Joining a node with log has risk messing up the data in this node and in the target cluster.
         let in_cluster = self
-            .is_in_cluster()
+            .is_in_cluster(conf.learner)
             .await
             .map_err(|e| MetaManagementError::Join(AnyError::new(&e)))?;
 
@@ -728,11 +746,14 @@ impl MetaNode {
 
         let req = ForwardRequest {
             forward_to_leader: 1,
-            body: ForwardRequestBody::Join(JoinRequest::new(
-                conf.id,
-                advertise_endpoint.clone(),
-                grpc_api_advertise_address.clone(),
-            )),
+            body: ForwardRequestBody::Join(
+                JoinRequest::new(
+                    conf.id,
+                    advertise_endpoint.clone(),
+                    grpc_api_advertise_address.clone(),
+                )
+                .with_learner(conf.learner),
+            ),
         };
 
         let join_res = raft_client.forward(req.clone()).await;
@@ -785,7 +806,12 @@ impl MetaNode {
     ///   Then the next leader does not know about this new node.
     ///
     ///   Only when the membership is committed, this node can be sure it is in a cluster.
-    async fn is_in_cluster(&self) -> Result<Result<String, String>, MetaStorageError> {
+    ///
+    /// A node joining as a learner is in a cluster if it is a voter or a learner in the membership.
+    async fn is_in_cluster(
+        &self,
+        learner: bool,
+    ) -> Result<Result<String, String>, MetaStorageError> {
         let membership = {
             let sm = self.sto.get_state_machine().await;
             sm.sys_data_ref().last_membership_ref().membership().clone()
@@ -798,6 +824,13 @@ impl MetaNode {
             return Ok(Ok(format!("node {} already in cluster", self.sto.id)));
         }
 
+        if learner && membership.get_node(&self.sto.id).is_some() {
+            return Ok(Ok(format!(
+                "node {} already in cluster as a learner",
+                self.sto.id
+            )));
+        }
+
         Ok(Err(format!(
             "node {} has membership but not in it",
             self.sto.id
@@ -1013,7 +1046,7 @@ impl MetaNode {
         req: ForwardRequest<Req>,
     ) -> Result<Req::Reply, MetaAPIError>
     where
-        Req: RequestFor,
+        Req: RequestFor + ReadOnly,
         for<'a> MetaLeader<'a>: Handler<Req>,
         for<'a> MetaForwarder<'a>: Forwarder<Req>,
     {
@@ -1021,6 +1054,28 @@ impl MetaNode {
                req = as_debug!(&req);
                "handle_forwardable_request");
 
+        if req.body.is_read_only() && self.is_learner() {
+            match self.ensure_learner_read().await {
+                Ok(()) => {
+                    // Reading does not require leadership:
+                    // MetaLeader only reads the local state machine to serve a read-only request.
+                    let res = MetaLeader::new(self).handle(req.clone()).await;
+                    match res {
+                        Ok(x) => return Ok(x),
+                        Err(e) => {
+                            warn!("learner failed to read locally: {}, forward to leader", e);
+                        }
+                    }
+                }
+                Err(e) => {
+                    warn!(
+                        "learner is too stale to read locally: {}, forward to leader",
+                        e
+                    );
+                }
+            }
+        }
+
         let mut n_retry = 20;
         let mut slp = Duration::from_millis(200);
 
@@ -1088,6 +1143,106 @@ impl MetaNode {
         }
     }
 
+    /// Returns true if this node is a learner in the current membership.
+    pub fn is_learner(&self) -> bool {
+        let metrics = self.raft.metrics().borrow().clone();
+        let membership = metrics.membership_config.membership();
+        membership.learner_ids().any(|id| id == self.sto.id)
+    }
+
+    /// Record that the leader has just contacted this node with an accepted append-entries,
+    /// in which the leader's committed log index is `leader_committed`.
+    pub(crate) fn record_leader_contact(&self, leader_committed: Option<LogId>) {
+        let committed = leader_committed.map(|x| x.index).unwrap_or_default();
+        let mut last = self.last_leader_contact.lock().unwrap();
+        *last = Some((Instant::now(), committed));
+    }
+
+    /// Make sure the local state machine of this learner is fresh enough to serve a read.
+    ///
+    /// If the leader has contacted this node within `learner_max_staleness`,
+    /// it waits for the local state machine to apply up to the committed index the leader sent.
+    /// Receiving the logs does not mean they are applied.
+    /// Otherwise, it gets a read-index from the leader, see [`MetaNode::learner_sync_with_leader`].
+    #[minitrace::trace]
+    async fn ensure_learner_read(&self) -> Result<(), AnyError> {
+        let last_contact = *self.last_leader_contact.lock().unwrap();
+
+        if let Some((t, committed)) = last_contact {
+            if t.elapsed() <= self.learner_max_staleness {
+                return self.wait_applied(committed).await;
+            }
+        }
+
+        self.learner_sync_with_leader().await
+    }
+
+    /// Get the read-index from the leader and wait for the local state machine to apply up to it.
+    ///
+    /// The leader confirms its leadership with a quorum before returning the read-index,
+    /// thus a read after this returns sees every write committed before it is called.
+    #[minitrace::trace]
+    pub async fn learner_sync_with_leader(&self) -> Result<(), AnyError> {
+        let leader_id = self
+            .get_leader()
+            .await
+            .map_err(|e| AnyError::new(&e))?
+            .ok_or_else(|| AnyError::error("no known leader to get read-index from"))?;
+
+        let req = ForwardRequest {
+            forward_to_leader: 1,
+            body: ForwardRequestBody::ReadIndex,
+        };
+
+        let resp = MetaForwarder::new(self)
+            .forward(leader_id, req)
+            .await
+            .map_err(|e| AnyError::new(&e))?;
+
+        let read_index: u64 = resp
+            .try_into()
+            .map_err(|e| AnyError::error(format!("expect reply type to be ReadIndex: {}", e)))?;
+
+        debug!("learner waits for read-index: {}", read_index);
+
+        self.wait_applied(read_index).await
+    }
+
+    /// Wait for the local state machine to apply up to log `index`.
+    async fn wait_applied(&self, index: u64) -> Result<(), AnyError> {
+        let timeout = Some(Duration::from_millis(2_000));
+        self.raft
+            .wait(timeout)
+            .metrics(
+                |m| m.last_applied.map(|x| x.index).unwrap_or_default() >= index,
+                format!("apply up to index {}", index),
+            )
+            .await
+            .map_err(|e| AnyError::new(&e))?;
+
+        Ok(())
+    }
+
+    /// Promote a learner to a voter, through the leader.
+    pub async fn promote_learner(&self, node_id: NodeId) -> Result<(), MetaAPIError> {
+        self.handle_forwardable_request(ForwardRequest {
+            forward_to_leader: 1,
+            body: ForwardRequestBody::Promote(PromoteRequest { node_id }),
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Demote a voter to a learner, through the leader.
+    pub async fn demote_voter(&self, node_id: NodeId) -> Result<(), MetaAPIError> {
+        self.handle_forwardable_request(ForwardRequest {
+            forward_to_leader: 1,
+            body: ForwardRequestBody::Demote(DemoteRequest { node_id }),
+        })
+        .await?;
+        Ok(())
+    }
+
     /// Return a MetaLeader if `self` believes it is the leader.
     ///
     /// Otherwise it returns the leader in a ForwardToLeader error.
@@ -1138,6 +1293,49 @@ impl MetaNode {
         Ok(resp)
     }
 
+    /// Grant a lease that expires in `ttl_sec` seconds unless it is kept alive.
+    pub async fn grant_lease(&self, ttl_sec: u64) -> Result<Lease, MetaAPIError> {
+        let res = self
+            .write(LogEntry::new(Cmd::GrantLease { ttl_sec }))
+            .await?;
+
+        match res {
+            AppliedState::Lease(Some(lease)) => Ok(lease),
+            _ => {
+                unreachable!("expect type {}", "AppliedState::Lease(Some(_))")
+            }
+        }
+    }
+
+    /// Refresh the ttl of a lease. It returns `None` if the lease is not found.
+    pub async fn keep_alive_lease(&self, lease_id: u64) -> Result<Option<Lease>, MetaAPIError> {
+        let res = self
+            .write(LogEntry::new(Cmd::KeepAliveLease { lease_id }))
+            .await?;
+
+        match res {
+            AppliedState::Lease(lease) => Ok(lease),
+            _ => {
+                unreachable!("expect type {}", "AppliedState::Lease")
+            }
+        }
+    }
+
+    /// Revoke a lease and remove all keys attached to it.
+    /// It returns `None` if the lease is not found.
+    pub async fn revoke_lease(&self, lease_id: u64) -> Result<Option<Lease>, MetaAPIError> {
+        let res = self
+            .write(LogEntry::new(Cmd::RevokeLease { lease_id }))
+            .await?;
+
+        match res {
+            AppliedState::Lease(lease) => Ok(lease),
+            _ => {
+                unreachable!("expect type {}", "AppliedState::Lease")
+            }
+        }
+    }
+
     /// Submit a write request to the known leader. Returns the response after applying the request.
     #[minitrace::trace]
     pub async fn write(&self, req: LogEntry) -> Result<AppliedState, MetaAPIError> {
@@ -1202,4 +1400,45 @@ impl MetaNode {
             Err(_e) => Err("dispatcher closed"),
         }
     }
+
+    /// Returns the historical events since `start_revision` a watcher is interested in,
+    /// and the last applied log index, up to which the events are replayed.
+    ///
+    /// It should be called after the watcher is added:
+    /// an event with revision greater than the returned one is dispatched to the watcher.
+    pub(crate) async fn replay_watch_events(
+        &self,
+        watcher: &Watcher,
+        start_revision: u64,
+    ) -> Result<(Vec<WatchResponse>, u64), ChangeLogCompacted> {
+        let inner = self.sto.inner();
+        let sm = inner.state_machine.read().await;
+
+        let last_applied = sm
+            .sys_data_ref()
+            .last_applied_ref()
+            .as_ref()
+            .map(|x| x.index);
+        let changes = sm.changes_since(start_revision)?;
+
+        let events = changes
+            .iter()
+            .filter(|(_, change)| {
+                let key = change.ident.as_deref().unwrap_or_default();
+                watcher.is_interested(key)
+            })
+            .filter_map(|(revision, change)| {
+                EventDispatcher::build_response(watcher.filter_type, *revision, change)
+            })
+            .collect::<Vec<_>>();
+
+        info!(
+            "replay {} events since revision {} for watcher {}",
+            events.len(),
+            start_revision,
+            watcher.id
+        );
+
+        Ok((events, last_applied.unwrap_or_default()))
+    }
 }
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use anyerror::AnyError;
 use async_trait::async_trait;
 use common_meta_client::MetaGrpcReadReq;
 use common_meta_kvapi::kvapi;
@@ -25,8 +26,11 @@ use common_meta_kvapi::kvapi::UpsertKVReply;
 use common_meta_kvapi::kvapi::UpsertKVReq;
 use common_meta_types::AppliedState;
 use common_meta_types::Cmd;
+use common_meta_types::InvalidReply;
+use common_meta_types::Lease;
 use common_meta_types::LogEntry;
 use common_meta_types::MetaAPIError;
+use common_meta_types::MetaDataError;
 use common_meta_types::MetaNetworkError;
 use common_meta_types::TxnReply;
 use common_meta_types::TxnRequest;
@@ -48,18 +52,28 @@ impl kvapi::KVApi for MetaNode {
     type Error = MetaAPIError;
 
     async fn upsert_kv(&self, act: UpsertKVReq) -> Result<UpsertKVReply, Self::Error> {
+        if Lease::is_reserved_key(&act.key) {
+            return Err(MetaDataError::ReservedKey(act.key).into());
+        }
+
+        let lease_id = act.lease_id;
         let ent = LogEntry::new(Cmd::UpsertKV(UpsertKV {
             key: act.key,
             seq: act.seq,
             value: act.value,
             value_meta: act.value_meta,
+            lease_id: act.lease_id,
         }));
         let rst = self.write(ent).await?;
 
-        match rst {
-            AppliedState::KV(x) => Ok(x),
-            _ => {
-                unreachable!("expect type {}", "AppliedState::KV")
+        match (rst, lease_id) {
+            (AppliedState::KV(x), _) => Ok(x),
+            (AppliedState::Lease(None), Some(lease_id)) => {
+                Err(MetaDataError::LeaseNotFound(lease_id).into())
+            }
+            (st, _) => {
+                let err = AnyError::error(format!("expect AppliedState::KV, got: {}", st));
+                Err(MetaNetworkError::from(InvalidReply::new("upsert_kv", &err)).into())
             }
         }
     }
@@ -107,6 +121,11 @@ impl kvapi::KVApi for MetaNode {
     #[minitrace::trace]
     async fn transaction(&self, txn: TxnRequest) -> Result<TxnReply, Self::Error> {
         info!("MetaNode::transaction(): {}", txn);
+
+        if let Some(key) = txn.reserved_key() {
+            return Err(MetaDataError::ReservedKey(key.to_string()).into());
+        }
+
         let ent = LogEntry::new(Cmd::Transaction(txn));
         let rst = self.write(ent).await?;
 
@@ -110,11 +110,16 @@ impl RaftService for RaftServiceImpl {
             let ae_req = GrpcHelper::parse_req(request)?;
             let raft = &self.meta_node.raft;
 
+            let leader_committed = ae_req.leader_commit;
             let resp = raft
                 .append_entries(ae_req)
                 .await
                 .map_err(GrpcHelper::internal_err)?;
 
+            if resp.is_success() {
+                self.meta_node.record_leader_contact(leader_committed);
+            }
+
             GrpcHelper::ok_response(resp)
         }
         .in_span(root)
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_meta_client::MetaGrpcReadReq;
 use common_meta_client::RequestFor;
 use common_meta_types::ForwardRPCError;
 use common_meta_types::MetaOperationError;
@@ -37,6 +38,31 @@ pub trait Forwarder<Req: RequestFor> {
     ) -> Result<Req::Reply, ForwardRPCError>;
 }
 
+/// Tells if a request only reads the state machine.
+///
+/// A read-only request can be served by a learner with its local state machine,
+/// instead of being forwarded to the leader.
+pub trait ReadOnly {
+    fn is_read_only(&self) -> bool;
+}
+
 impl RequestFor for ForwardRequestBody {
     type Reply = ForwardResponse;
 }
+
+impl ReadOnly for ForwardRequestBody {
+    fn is_read_only(&self) -> bool {
+        matches!(
+            self,
+            ForwardRequestBody::GetKV(_)
+                | ForwardRequestBody::MGetKV(_)
+                | ForwardRequestBody::ListKV(_)
+        )
+    }
+}
+
+impl ReadOnly for MetaGrpcReadReq {
+    fn is_read_only(&self) -> bool {
+        true
+    }
+}
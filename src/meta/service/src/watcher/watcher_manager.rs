@@ -47,8 +47,11 @@ pub(crate) struct DispatcherSender(pub(crate) mpsc::UnboundedSender<WatchEvent>)
 
 /// An event sent to EventDispatcher.
 pub(crate) enum WatchEvent {
-    /// Submit a kv change event to dispatcher
-    KVChange(Change<Vec<u8>, String>),
+    /// Submit a kv change event to dispatcher, made by the log at index `revision`.
+    KVChange {
+        revision: u64,
+        change: Change<Vec<u8>, String>,
+    },
 
     /// Send a request to EventDispatcher.
     ///
@@ -120,8 +123,8 @@ impl EventDispatcher {
         loop {
             if let Some(event) = self.event_rx.recv().await {
                 match event {
-                    WatchEvent::KVChange(kv_change) => {
-                        self.dispatch_event(kv_change).await;
+                    WatchEvent::KVChange { revision, change } => {
+                        self.dispatch_event(revision, change).await;
                     }
                     WatchEvent::Request { req } => req(&mut self),
                 }
@@ -133,39 +136,26 @@ impl EventDispatcher {
     }
 
     /// Dispatch a kv change event to interested watchers.
-    async fn dispatch_event(&mut self, change: Change<Vec<u8>, String>) {
+    async fn dispatch_event(&mut self, revision: u64, change: Change<Vec<u8>, String>) {
         let k = change.ident.as_ref().unwrap();
         let set = self.watcher_range_map.get_by_point(k);
         if set.is_empty() {
             return;
         }
 
-        let current = change.result;
-        let prev = change.prev;
-
-        let is_delete_event = current.is_none();
         let mut remove_range_keys: Vec<RangeMapKey<String, WatcherId>> = vec![];
 
         for range_key_stream in set.iter() {
             let filter = range_key_stream.1.watcher.filter_type;
 
             // filter out event
-            if (filter == FilterType::Delete && !is_delete_event)
-                || (filter == FilterType::Update && is_delete_event)
-            {
+            let Some(resp) = Self::build_response(filter, revision, &change) else {
                 continue;
-            }
+            };
 
             let watcher_id = range_key_stream.0.key;
             let stream = range_key_stream.1;
             assert_eq!(stream.watcher.id, watcher_id);
-            let resp = WatchResponse {
-                event: Some(Event {
-                    key: k.to_string(),
-                    current: current.clone().map(pb::SeqV::from),
-                    prev: prev.clone().map(pb::SeqV::from),
-                }),
-            };
 
             network_metrics::incr_sent_bytes(resp.encoded_len() as u64);
 
@@ -187,6 +177,33 @@ impl EventDispatcher {
         }
     }
 
+    /// Build a response of a kv change event for a watcher with `filter`.
+    ///
+    /// It returns `None` if the event is filtered out.
+    pub(crate) fn build_response(
+        filter: FilterType,
+        revision: u64,
+        change: &Change<Vec<u8>, String>,
+    ) -> Option<WatchResponse> {
+        let is_delete_event = change.result.is_none();
+
+        if (filter == FilterType::Delete && !is_delete_event)
+            || (filter == FilterType::Update && is_delete_event)
+        {
+            return None;
+        }
+
+        let resp = WatchResponse {
+            event: Some(Event {
+                key: change.ident.clone().unwrap_or_default(),
+                current: change.result.clone().map(pb::SeqV::from),
+                prev: change.prev.clone().map(pb::SeqV::from),
+            }),
+            revision,
+        };
+        Some(resp)
+    }
+
     #[minitrace::trace]
     pub fn add_watcher(
         &mut self,
@@ -246,7 +263,7 @@ impl EventDispatcher {
 }
 
 impl StateMachineSubscriber for DispatcherSender {
-    fn kv_changed(&self, change: Change<Vec<u8>, String>) {
-        let _ = self.0.send(WatchEvent::KVChange(change));
+    fn kv_changed(&self, revision: u64, change: Change<Vec<u8>, String>) {
+        let _ = self.0.send(WatchEvent::KVChange { revision, change });
     }
 }
@@ -49,6 +49,19 @@ impl Watcher {
             key_range,
         }
     }
+
+    /// Returns true if the key is in the range this watcher is interested in.
+    ///
+    /// A range with the same start and end is a single key to watch.
+    pub fn is_interested(&self, key: &str) -> bool {
+        let r = &self.key_range;
+
+        if r.start == r.end {
+            r.start == key
+        } else {
+            r.start.as_str() <= key && key < r.end.as_str()
+        }
+    }
 }
 
 /// A handle of a watching stream, for feeding messages to the stream.
@@ -19,6 +19,7 @@ use std::sync::Mutex;
 
 use common_meta_api::BackgroundApiTestSuite;
 use common_meta_api::SchemaApiTestSuite;
+use common_meta_api::SequenceApiTestSuite;
 use common_meta_api::ShareApiTestSuite;
 use test_harness::test;
 
@@ -34,7 +35,8 @@ async fn test_meta_grpc_client_single() -> anyhow::Result<()> {
 
     SchemaApiTestSuite::test_single_node(builder.clone()).await?;
     ShareApiTestSuite::test_single_node_share(builder.clone()).await?;
-    BackgroundApiTestSuite::test_single_node(builder).await?;
+    BackgroundApiTestSuite::test_single_node(builder.clone()).await?;
+    SequenceApiTestSuite::test_single_node(builder).await?;
 
     Ok(())
 }
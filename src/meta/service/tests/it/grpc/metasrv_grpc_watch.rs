@@ -133,6 +133,7 @@ async fn test_watch() -> anyhow::Result<()> {
             key: "a".to_string(),
             key_end: Some("z".to_string()),
             filter_type: FilterType::All.into(),
+            start_revision: None,
         };
 
         let key_a = s("a");
@@ -191,6 +192,7 @@ async fn test_watch() -> anyhow::Result<()> {
             key_end: None,
             // filter only delete events
             filter_type: FilterType::Delete.into(),
+            start_revision: None,
         };
 
         let key = s(key_str);
@@ -254,6 +256,7 @@ async fn test_watch() -> anyhow::Result<()> {
             key: start,
             key_end: Some(end),
             filter_type: FilterType::All.into(),
+            start_revision: None,
         };
 
         let conditions = vec![TxnCondition {
@@ -269,6 +272,7 @@ async fn test_watch() -> anyhow::Result<()> {
                     value: txn_val.clone(),
                     prev_value: true,
                     expire_at: None,
+                    lease_id: None,
                 })),
             },
             TxnOp {
@@ -378,6 +382,7 @@ async fn test_watch_expired_events() -> anyhow::Result<()> {
             key: start,
             key_end: Some(end),
             filter_type: FilterType::All.into(),
+            start_revision: None,
         };
         watch_client.request(watch).await?
     };
@@ -452,6 +457,62 @@ async fn test_watch_expired_events() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test(harness = meta_service_test_harness)]
+#[minitrace::trace]
+async fn test_watch_start_revision() -> anyhow::Result<()> {
+    // - Watch and write some data, record the revision of every event.
+    // - Watch again from a revision, the events since it are replayed.
+
+    let (_tc, addr) = crate::tests::start_metasrv().await?;
+
+    let watch_req = |start_revision: Option<u64>| WatchRequest {
+        key: "r".to_string(),
+        key_end: Some("s".to_string()),
+        filter_type: FilterType::All.into(),
+        start_revision,
+    };
+
+    let client = make_client(&addr)?;
+    let mut watch_stream = client.request(watch_req(None)).await?;
+
+    for key in ["ra", "rb", "rc", "z"] {
+        client.upsert_kv(UpsertKVReq::update(key, &b(key))).await?;
+    }
+
+    let mut revisions = vec![];
+    for key in ["ra", "rb", "rc"] {
+        let resp = watch_stream.message().await?.unwrap();
+        assert_eq!(key, resp.event.unwrap().key);
+        revisions.push(resp.revision);
+    }
+    assert!(revisions[0] < revisions[1]);
+    assert!(revisions[1] < revisions[2]);
+
+    info!("replay since the second event");
+    {
+        let client = make_client(&addr)?;
+        let mut watch_stream = client.request(watch_req(Some(revisions[1]))).await?;
+
+        for (key, revision) in [("rb", revisions[1]), ("rc", revisions[2])] {
+            let resp = watch_stream.message().await?.unwrap();
+            assert_eq!(key, resp.event.unwrap().key);
+            assert_eq!(revision, resp.revision);
+        }
+
+        info!("new events are delivered after replay");
+
+        client
+            .upsert_kv(UpsertKVReq::update("rd", &b("rd")))
+            .await?;
+
+        let resp = watch_stream.message().await?.unwrap();
+        assert_eq!("rd", resp.event.unwrap().key);
+        assert!(resp.revision > revisions[2]);
+    }
+
+    Ok(())
+}
+
 #[test(harness = meta_service_test_harness)]
 #[minitrace::trace]
 async fn test_watch_stream_count() -> anyhow::Result<()> {
@@ -463,6 +524,7 @@ async fn test_watch_stream_count() -> anyhow::Result<()> {
         key: "a".to_string(),
         key_end: Some("z".to_string()),
         filter_type: FilterType::All.into(),
+        start_revision: None,
     };
 
     let client1 = make_client(&addr)?;
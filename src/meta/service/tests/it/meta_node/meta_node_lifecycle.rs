@@ -41,6 +41,7 @@ use crate::testing::meta_service_test_harness;
 use crate::tests::meta_node::start_meta_node_cluster;
 use crate::tests::meta_node::start_meta_node_leader;
 use crate::tests::meta_node::start_meta_node_non_voter;
+use crate::tests::meta_node::start_meta_node_non_voter_with_context;
 use crate::tests::meta_node::timeout;
 use crate::tests::service::MetaSrvTestContext;
 
@@ -540,6 +541,140 @@ async fn test_meta_node_leave_last_not_allowed() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test(harness = meta_service_test_harness)]
+#[minitrace::trace]
+async fn test_meta_node_learner_promote_demote() -> anyhow::Result<()> {
+    // - Bring up a cluster with a voter and a learner.
+    // - Read from the learner.
+    // - Promote the learner to voter, then demote it back.
+
+    let (_log_index, tcs) = start_meta_node_cluster(btreeset![0], btreeset![1]).await?;
+    let all = test_context_nodes(&tcs);
+
+    let leader = all[0].clone();
+    let learner = all[1].clone();
+
+    assert!(!leader.is_learner());
+    assert!(learner.is_learner());
+
+    info!("--- read from learner");
+    {
+        leader
+            .write(LogEntry {
+                txid: None,
+                time_ms: None,
+                cmd: Cmd::UpsertKV(UpsertKV::update("foo", b"bar")),
+            })
+            .await?;
+
+        // A learner serves reads with bounded staleness.
+        let last_applied = leader.raft.metrics().borrow().last_applied;
+        learner
+            .raft
+            .wait(timeout())
+            .log(last_applied.index(), "learner applied the write")
+            .await?;
+
+        let got = learner.get_kv("foo").await?;
+        assert_eq!(b"bar".to_vec(), got.unwrap().data);
+    }
+
+    info!("--- promote learner node-1 via node-1");
+    {
+        learner.promote_learner(1).await?;
+
+        for mn in all.iter() {
+            mn.raft
+                .wait(timeout())
+                .members(
+                    btreeset! {0,1},
+                    format!("node-1 is promoted: {}", mn.sto.id),
+                )
+                .await?;
+        }
+        assert!(!learner.is_learner());
+    }
+
+    info!("--- demote voter node-1 via node-0");
+    {
+        leader.demote_voter(1).await?;
+
+        for mn in all.iter() {
+            mn.raft
+                .wait(timeout())
+                .members(btreeset! {0}, format!("node-1 is demoted: {}", mn.sto.id))
+                .await?;
+        }
+        assert!(learner.is_learner());
+    }
+
+    info!("--- demoting the last voter is not allowed");
+    {
+        leader.demote_voter(0).await?;
+
+        sleep(Duration::from_millis(1_000)).await;
+
+        leader
+            .raft
+            .wait(timeout())
+            .members(btreeset! {0}, "node-0 is still a voter")
+            .await?;
+    }
+
+    Ok(())
+}
+
+#[test(harness = meta_service_test_harness)]
+#[minitrace::trace]
+async fn test_meta_node_learner_read_stale() -> anyhow::Result<()> {
+    // - Bring up a leader and a learner that never trusts the last leader contact.
+    // - A read on the learner right after a write on the leader goes through read-index,
+    //   and sees the write.
+
+    let (_id, tc0) = start_meta_node_leader().await?;
+    let leader = tc0.meta_node();
+
+    let mut tc1 = MetaSrvTestContext::new(1);
+    tc1.config.raft_config.learner_max_staleness = 0;
+    let (_id, tc1) = start_meta_node_non_voter_with_context(leader.clone(), tc1).await?;
+    let learner = tc1.meta_node();
+
+    learner
+        .raft
+        .wait(timeout())
+        .state(ServerState::Learner, "learner started")
+        .await?;
+    assert!(learner.is_learner());
+
+    for i in 0..3 {
+        let value = format!("bar-{}", i).into_bytes();
+
+        info!("--- write on leader, read on learner at once: {}", i);
+        leader
+            .write(LogEntry {
+                txid: None,
+                time_ms: None,
+                cmd: Cmd::UpsertKV(UpsertKV::update("foo", &value)),
+            })
+            .await?;
+
+        let got = learner.get_kv("foo").await?;
+        assert_eq!(value, got.unwrap().data);
+    }
+
+    info!("--- sync with leader applies up to the leader's logs");
+    {
+        let leader_last_log = leader.raft.metrics().borrow().last_log_index;
+
+        learner.learner_sync_with_leader().await?;
+
+        let learner_applied = learner.raft.metrics().borrow().last_applied.index();
+        assert!(learner_applied >= leader_last_log);
+    }
+
+    Ok(())
+}
+
 #[test(harness = meta_service_test_harness)]
 #[minitrace::trace]
 async fn test_meta_node_restart() -> anyhow::Result<()> {
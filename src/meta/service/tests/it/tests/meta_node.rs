@@ -188,7 +188,15 @@ pub(crate) async fn start_meta_node_non_voter(
     leader: Arc<MetaNode>,
     id: NodeId,
 ) -> anyhow::Result<(NodeId, MetaSrvTestContext)> {
-    let mut tc = MetaSrvTestContext::new(id);
+    start_meta_node_non_voter_with_context(leader, MetaSrvTestContext::new(id)).await
+}
+
+/// Same as [`start_meta_node_non_voter`], with a prepared test context, e.g., with modified config.
+pub(crate) async fn start_meta_node_non_voter_with_context(
+    leader: Arc<MetaNode>,
+    mut tc: MetaSrvTestContext,
+) -> anyhow::Result<(NodeId, MetaSrvTestContext)> {
+    let id = tc.config.raft_config.id;
     let addr = tc.config.raft_config.raft_api_addr().await?;
 
     let raft_conf = &tc.config.raft_config;
@@ -55,6 +55,15 @@ fn build_proto() {
             "TxnPutRequest",
             "#[derive(Eq, serde::Serialize, serde::Deserialize)]",
         )
+        .field_attribute(
+            "TxnPutRequest.lease_id",
+            r#"#[serde(default, skip_serializing_if = "Option::is_none")]"#,
+        )
+        .field_attribute(
+            "WatchRequest.start_revision",
+            r#"#[serde(default, skip_serializing_if = "Option::is_none")]"#,
+        )
+        .field_attribute("WatchResponse.revision", r#"#[serde(default)]"#)
         .type_attribute(
             "TxnDeleteRequest",
             "#[derive(Eq, serde::Serialize, serde::Deserialize)]",
@@ -17,6 +17,7 @@ use std::fmt::Formatter;
 
 use crate::protobuf::RaftReply;
 use crate::Change;
+use crate::Lease;
 use crate::Node;
 use crate::TxnReply;
 
@@ -42,6 +43,12 @@ pub enum AppliedState {
 
     TxnReply(TxnReply),
 
+    /// The lease granted or kept alive, or the revoked one.
+    /// `None` if the lease to keep alive or revoke is not found,
+    /// or the lease an upsert attaches the key to is not found.
+    Lease(Option<Lease>),
+
+    /// Nothing is applied, e.g., an upsert to a key reserved for the state machine is rejected.
     #[try_into(ignore)]
     None,
 }
@@ -59,6 +66,10 @@ impl fmt::Display for AppliedState {
             AppliedState::TxnReply(txnreply) => {
                 write!(f, "Txn: {}", txnreply)
             }
+            AppliedState::Lease(lease) => match lease {
+                Some(lease) => write!(f, "Lease: {}", lease),
+                None => write!(f, "Lease: None"),
+            },
             AppliedState::None => {
                 write!(f, "None")
             }
@@ -77,6 +88,7 @@ impl AppliedState {
             AppliedState::KV(ref ch) => ch.is_changed(),
             AppliedState::None => false,
             AppliedState::TxnReply(txn) => txn.success,
+            AppliedState::Lease(lease) => lease.is_some(),
         }
     }
 
@@ -102,6 +114,7 @@ impl AppliedState {
             AppliedState::KV(Change { ref prev, .. }) => prev.is_none(),
             AppliedState::None => true,
             AppliedState::TxnReply(_txn) => true,
+            AppliedState::Lease(_lease) => true,
         }
     }
 
@@ -111,6 +124,7 @@ impl AppliedState {
             AppliedState::KV(Change { ref result, .. }) => result.is_none(),
             AppliedState::None => true,
             AppliedState::TxnReply(txn) => !txn.success,
+            AppliedState::Lease(lease) => lease.is_none(),
         }
     }
 }
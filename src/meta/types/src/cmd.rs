@@ -46,6 +46,18 @@ pub enum Cmd {
 
     /// Update one or more kv with a transaction.
     Transaction(TxnRequest),
+
+    // The lease variants are not known to the versions before leases are supported:
+    // upgrade all the nodes in a cluster before using leases, see: `src/meta/README.md`.
+    /// Grant a lease that expires in `ttl_sec` seconds since the time of the log,
+    /// if it is not kept alive.
+    GrantLease { ttl_sec: u64 },
+
+    /// Refresh the expiration time of a lease to `ttl_sec` seconds since the time of the log.
+    KeepAliveLease { lease_id: u64 },
+
+    /// Revoke a lease and remove all of the keys attached to it.
+    RevokeLease { lease_id: u64 },
 }
 
 /// Update or insert a general purpose kv store
@@ -64,6 +76,14 @@ pub struct UpsertKV {
 
     /// Meta data of a value.
     pub value_meta: Option<KVMeta>,
+
+    /// The lease the key is attached to.
+    ///
+    /// The key is removed when the lease expires or is revoked,
+    /// unless it is updated again without the lease before that.
+    /// The upsert does not take effect if the lease does not exist.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lease_id: Option<u64>,
 }
 
 impl fmt::Display for Cmd {
@@ -89,6 +109,15 @@ impl fmt::Display for Cmd {
             Cmd::Transaction(txn) => {
                 write!(f, "txn:{}", txn)
             }
+            Cmd::GrantLease { ttl_sec } => {
+                write!(f, "grant_lease:ttl={}s", ttl_sec)
+            }
+            Cmd::KeepAliveLease { lease_id } => {
+                write!(f, "keep_alive_lease:{}", lease_id)
+            }
+            Cmd::RevokeLease { lease_id } => {
+                write!(f, "revoke_lease:{}", lease_id)
+            }
         }
     }
 }
@@ -99,7 +128,11 @@ impl fmt::Display for UpsertKV {
             f,
             "{}({:?}) = {:?} ({:?})",
             self.key, self.seq, self.value, self.value_meta
-        )
+        )?;
+        if let Some(lease_id) = self.lease_id {
+            write!(f, " lease: {}", lease_id)?;
+        }
+        Ok(())
     }
 }
 
@@ -115,6 +148,7 @@ impl UpsertKV {
             seq,
             value,
             value_meta,
+            lease_id: None,
         }
     }
 
@@ -124,6 +158,7 @@ impl UpsertKV {
             seq: MatchSeq::Exact(0),
             value: Operation::Update(value.to_vec()),
             value_meta: None,
+            lease_id: None,
         }
     }
 
@@ -133,6 +168,7 @@ impl UpsertKV {
             seq: MatchSeq::GE(0),
             value: Operation::Update(value.to_vec()),
             value_meta: None,
+            lease_id: None,
         }
     }
 
@@ -142,6 +178,7 @@ impl UpsertKV {
             seq: MatchSeq::GE(0),
             value: Operation::Delete,
             value_meta: None,
+            lease_id: None,
         }
     }
 
@@ -151,6 +188,12 @@ impl UpsertKV {
         })
     }
 
+    /// Attach the key to a lease.
+    pub fn with_lease(mut self, lease_id: u64) -> Self {
+        self.lease_id = Some(lease_id);
+        self
+    }
+
     pub fn get_expire_at_ms(&self) -> Option<u64> {
         if let Some(meta) = &self.value_meta {
             meta.expire_at.map(|x| x * 1000)
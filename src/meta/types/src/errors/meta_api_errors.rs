@@ -68,6 +68,8 @@ impl MetaAPIError {
                 },
                 MetaDataError::WriteError(_) => false,
                 MetaDataError::ReadError(_) => false,
+                MetaDataError::LeaseNotFound(_) => false,
+                MetaDataError::ReservedKey(_) => false,
             },
             MetaAPIError::ForwardToLeader(_) => {
                 // Leader is changing, wait a while and retry
@@ -81,6 +83,8 @@ impl MetaAPIError {
                 MetaDataError::WriteError(_) => false,
                 MetaDataError::ChangeMembershipError(_) => true,
                 MetaDataError::ReadError(_) => false,
+                MetaDataError::LeaseNotFound(_) => false,
+                MetaDataError::ReservedKey(_) => false,
             },
         }
     }
@@ -130,6 +134,14 @@ pub enum MetaDataError {
     /// Error occurred when reading.
     #[error(transparent)]
     ReadError(#[from] MetaDataReadError),
+
+    /// The lease to attach a key to is not found, or has expired.
+    #[error("lease not found: {0}")]
+    LeaseNotFound(u64),
+
+    /// The key is reserved for the records maintained by the state machine, e.g., leases.
+    #[error("key is reserved: {0}")]
+    ReservedKey(String),
 }
 
 /// Error occurred when a meta-node reads data.
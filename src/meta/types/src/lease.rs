@@ -0,0 +1,116 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::protobuf::LeaseKeepAliveResponse;
+
+/// The prefix of the records that store leases: `__fd_leases/<lease_id>`.
+pub const LEASE_KEY_PREFIX: &str = "__fd_leases";
+
+/// The prefix of the records that index the keys attached to a lease:
+/// `__fd_lease_keys/<lease_id>/<key> -> <seq of key when it is attached>`.
+pub const LEASE_ATTACHED_KEY_PREFIX: &str = "__fd_lease_keys";
+
+/// A lease that a group of keys can be attached to.
+///
+/// A lease expires if it is not kept alive within `ttl_sec` seconds,
+/// and all of the keys attached to it are removed altogether when it expires or is revoked.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Lease {
+    /// The id of the lease, it is the seq of the record created when granting it.
+    pub id: u64,
+
+    /// Time to live in seconds since the lease is granted or kept alive.
+    pub ttl_sec: u64,
+
+    /// Expiration time in seconds since 1970.
+    pub expire_at: u64,
+}
+
+impl fmt::Display for Lease {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "lease({}, ttl: {}s, expire_at: {})",
+            self.id, self.ttl_sec, self.expire_at
+        )
+    }
+}
+
+impl Lease {
+    /// The key of the record that stores the lease.
+    pub fn key(lease_id: u64) -> String {
+        format!("{}/{}", LEASE_KEY_PREFIX, lease_id)
+    }
+
+    /// Parse the lease id from the key of a lease record.
+    pub fn parse_key(key: &str) -> Option<u64> {
+        key.strip_prefix(LEASE_KEY_PREFIX)?
+            .strip_prefix('/')?
+            .parse()
+            .ok()
+    }
+
+    /// The prefix of the index records of the keys attached to the lease.
+    pub fn attached_prefix(lease_id: u64) -> String {
+        format!("{}/{}/", LEASE_ATTACHED_KEY_PREFIX, lease_id)
+    }
+
+    /// The key of the index record of `key` attached to the lease.
+    pub fn attached_key(lease_id: u64, key: &str) -> String {
+        format!("{}{}", Self::attached_prefix(lease_id), key)
+    }
+
+    /// Whether `key` belongs to the lease bookkeeping records,
+    /// which are maintained only by the state machine and are not accessible to a user.
+    pub fn is_reserved_key(key: &str) -> bool {
+        [LEASE_KEY_PREFIX, LEASE_ATTACHED_KEY_PREFIX]
+            .iter()
+            .any(|prefix| key.strip_prefix(prefix).is_some_and(|k| k.starts_with('/')))
+    }
+}
+
+/// Grant a lease that expires in `ttl_sec` seconds if it is not kept alive.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct GrantLeaseReq {
+    pub ttl_sec: u64,
+}
+
+/// Revoke a lease and remove all of the keys attached to it.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct RevokeLeaseReq {
+    pub lease_id: u64,
+}
+
+impl LeaseKeepAliveResponse {
+    /// Build a response of keeping alive `lease_id`, a `ttl_sec` of 0 means the lease is not found.
+    pub fn new(lease_id: u64, lease: Option<Lease>) -> Self {
+        match lease {
+            Some(lease) => Self {
+                lease_id,
+                ttl_sec: lease.ttl_sec,
+                expire_at: lease.expire_at,
+            },
+            None => Self {
+                lease_id,
+                ttl_sec: 0,
+                expire_at: 0,
+            },
+        }
+    }
+}
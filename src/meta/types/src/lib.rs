@@ -26,6 +26,7 @@ pub mod config;
 mod endpoint;
 pub mod errors;
 mod grpc_config;
+mod lease;
 mod log_entry;
 mod match_seq;
 mod message;
@@ -75,6 +76,11 @@ pub use errors::meta_network_errors::MetaNetworkResult;
 pub use errors::meta_startup_errors::MetaStartupError;
 pub use errors::rpc_errors::ForwardRPCError;
 pub use grpc_config::GrpcConfig;
+pub use lease::GrantLeaseReq;
+pub use lease::Lease;
+pub use lease::RevokeLeaseReq;
+pub use lease::LEASE_ATTACHED_KEY_PREFIX;
+pub use lease::LEASE_KEY_PREFIX;
 pub use log_entry::LogEntry;
 pub use match_seq::MatchSeq;
 pub use match_seq::MatchSeqExt;
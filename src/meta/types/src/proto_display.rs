@@ -152,6 +152,9 @@ impl Display for TxnPutRequest {
         if let Some(expire_at) = self.expire_at {
             write!(f, " expire at: {}", expire_at)?;
         }
+        if let Some(lease_id) = self.lease_id {
+            write!(f, " lease: {}", lease_id)?;
+        }
         Ok(())
     }
 }
@@ -13,6 +13,27 @@
 // limitations under the License.
 
 use crate::protobuf as pb;
+use crate::Lease;
+
+impl pb::TxnRequest {
+    /// Returns the first key or prefix the txn accesses, that is reserved for the state machine,
+    /// see: [`Lease::is_reserved_key`].
+    pub fn reserved_key(&self) -> Option<&str> {
+        let cond_keys = self.condition.iter().map(|c| c.key.as_str());
+        let op_keys = self
+            .if_then
+            .iter()
+            .chain(self.else_then.iter())
+            .filter_map(|op| match op.request.as_ref()? {
+                pb::txn_op::Request::Get(get) => Some(get.key.as_str()),
+                pb::txn_op::Request::Put(put) => Some(put.key.as_str()),
+                pb::txn_op::Request::Delete(delete) => Some(delete.key.as_str()),
+                pb::txn_op::Request::DeleteByPrefix(delete) => Some(delete.prefix.as_str()),
+            });
+
+        cond_keys.chain(op_keys).find(|k| Lease::is_reserved_key(k))
+    }
+}
 
 impl pb::TxnCondition {
     /// Create a txn condition that checks if the `seq` matches.
@@ -43,6 +64,20 @@ impl pb::TxnOp {
                 value,
                 prev_value: true,
                 expire_at,
+                lease_id: None,
+            })),
+        }
+    }
+
+    /// Create a txn operation that puts a record attached to a lease.
+    pub fn put_with_lease(key: impl ToString, value: Vec<u8>, lease_id: u64) -> pb::TxnOp {
+        pb::TxnOp {
+            request: Some(pb::txn_op::Request::Put(pb::TxnPutRequest {
+                key: key.to_string(),
+                value,
+                prev_value: true,
+                expire_at: None,
+                lease_id: Some(lease_id),
             })),
         }
     }
@@ -132,6 +132,38 @@ fn pretty_table_source(source: CreateTableSource) -> RcDoc<'static> {
                 RcDoc::nil()
             })
             .append(RcDoc::text(table.to_string())),
+        CreateTableSource::Clone {
+            catalog,
+            database,
+            table,
+            travel_point,
+        } => RcDoc::space()
+            .append(RcDoc::text("CLONE"))
+            .append(RcDoc::space())
+            .append(if let Some(catalog) = catalog {
+                RcDoc::text(catalog.to_string()).append(RcDoc::text("."))
+            } else {
+                RcDoc::nil()
+            })
+            .append(if let Some(database) = database {
+                RcDoc::text(database.to_string()).append(RcDoc::text("."))
+            } else {
+                RcDoc::nil()
+            })
+            .append(RcDoc::text(table.to_string()))
+            .append(match travel_point {
+                Some(TimeTravelPoint::Snapshot(sid)) => {
+                    RcDoc::text(format!(" AT (SNAPSHOT => {sid})"))
+                }
+                Some(TimeTravelPoint::Timestamp(ts)) => {
+                    RcDoc::text(format!(" AT (TIMESTAMP => {ts})"))
+                }
+                Some(TimeTravelPoint::Tag(name)) => RcDoc::text(format!(" AT (TAG => {name})")),
+                Some(TimeTravelPoint::Branch(name)) => {
+                    RcDoc::text(format!(" AT (BRANCH => {name})"))
+                }
+                None => RcDoc::nil(),
+            }),
     }
 }
 
@@ -216,6 +248,8 @@ pub(crate) fn pretty_alter_table_action(action: AlterTableAction) -> RcDoc<'stat
         AlterTableAction::RevertTo { point } => match point {
             TimeTravelPoint::Snapshot(sid) => RcDoc::text(format!(" AT (SNAPSHOT => {sid})")),
             TimeTravelPoint::Timestamp(ts) => RcDoc::text(format!(" AT (TIMESTAMP => {ts})")),
+            TimeTravelPoint::Tag(name) => RcDoc::text(format!(" AT (TAG => {name})")),
+            TimeTravelPoint::Branch(name) => RcDoc::text(format!(" AT (BRANCH => {name})")),
         },
         AlterTableAction::SetOptions { set_options } => {
             let mut doc = RcDoc::line();
@@ -225,6 +259,10 @@ pub(crate) fn pretty_alter_table_action(action: AlterTableAction) -> RcDoc<'stat
             }
             doc
         }
+        action @ AlterTableAction::CreateSnapshotRef { .. }
+        | action @ AlterTableAction::DropSnapshotRef { .. } => {
+            RcDoc::line().append(RcDoc::text(action.to_string()))
+        }
     }
 }
 
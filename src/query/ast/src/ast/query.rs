@@ -92,6 +92,8 @@ pub struct SelectStmt {
     pub having: Option<Expr>,
     // `WINDOW` clause
     pub window_list: Option<Vec<WindowDefinition>>,
+    // `QUALIFY` clause
+    pub qualify: Option<Expr>,
 }
 
 /// Group by Clause.
@@ -215,6 +217,8 @@ pub enum Indirection {
 pub enum TimeTravelPoint {
     Snapshot(String),
     Timestamp(Box<Expr>),
+    Tag(String),
+    Branch(String),
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -431,6 +435,14 @@ impl Display for TableReference {
                     write!(f, " AT (TIMESTAMP => {ts})")?;
                 }
 
+                if let Some(TimeTravelPoint::Tag(name)) = travel_point {
+                    write!(f, " AT (TAG => {name})")?;
+                }
+
+                if let Some(TimeTravelPoint::Branch(name)) = travel_point {
+                    write!(f, " AT (BRANCH => {name})")?;
+                }
+
                 if let Some(alias) = alias {
                     write!(f, " AS {alias}")?;
                 }
@@ -643,6 +655,11 @@ impl Display for SelectStmt {
             write!(f, " HAVING {having}")?;
         }
 
+        // QUALIFY clause
+        if let Some(qualify) = &self.qualify {
+            write!(f, " QUALIFY {qualify}")?;
+        }
+
         Ok(())
     }
 }
@@ -752,6 +769,12 @@ impl Display for TimeTravelPoint {
             TimeTravelPoint::Timestamp(ts) => {
                 write!(f, " (TIMESTAMP => {ts})")?;
             }
+            TimeTravelPoint::Tag(name) => {
+                write!(f, " (TAG => {name})")?;
+            }
+            TimeTravelPoint::Branch(name) => {
+                write!(f, " (BRANCH => {name})")?;
+            }
         }
 
         Ok(())
@@ -29,10 +29,12 @@ mod merge_into;
 mod network_policy;
 mod presign;
 mod replace;
+mod sequence;
 mod share;
 mod show;
 mod stage;
 mod statement;
+mod stream;
 mod table;
 mod task;
 mod udf;
@@ -59,10 +61,12 @@ pub use merge_into::*;
 pub use network_policy::*;
 pub use presign::*;
 pub use replace::*;
+pub use sequence::*;
 pub use share::*;
 pub use show::*;
 pub use stage::*;
 pub use statement::*;
+pub use stream::*;
 pub use table::*;
 pub use task::*;
 pub use udf::*;
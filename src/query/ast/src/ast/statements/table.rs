@@ -132,11 +132,15 @@ pub struct CreateTableStmt {
     pub table_options: BTreeMap<String, String>,
     pub as_query: Option<Box<Query>>,
     pub transient: bool,
+    pub temporary: bool,
 }
 
 impl Display for CreateTableStmt {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         write!(f, "CREATE ")?;
+        if self.temporary {
+            write!(f, "TEMPORARY ")?;
+        }
         if self.transient {
             write!(f, "TRANSIENT ")?;
         }
@@ -218,6 +222,12 @@ pub enum CreateTableSource {
         database: Option<Identifier>,
         table: Identifier,
     },
+    Clone {
+        catalog: Option<Identifier>,
+        database: Option<Identifier>,
+        table: Identifier,
+        travel_point: Option<TimeTravelPoint>,
+    },
 }
 
 impl Display for CreateTableSource {
@@ -236,6 +246,22 @@ impl Display for CreateTableSource {
                 write!(f, "LIKE ")?;
                 write_dot_separated_list(f, catalog.iter().chain(database).chain(Some(table)))
             }
+            CreateTableSource::Clone {
+                catalog,
+                database,
+                table,
+                travel_point,
+            } => {
+                write!(f, "CLONE ")?;
+                write_dot_separated_list(f, catalog.iter().chain(database).chain(Some(table)))?;
+                match travel_point {
+                    Some(TimeTravelPoint::Snapshot(sid)) => write!(f, " AT (SNAPSHOT => {sid})"),
+                    Some(TimeTravelPoint::Timestamp(ts)) => write!(f, " AT (TIMESTAMP => {ts})"),
+                    Some(TimeTravelPoint::Tag(name)) => write!(f, " AT (TAG => {name})"),
+                    Some(TimeTravelPoint::Branch(name)) => write!(f, " AT (BRANCH => {name})"),
+                    None => Ok(()),
+                }
+            }
         }
     }
 }
@@ -361,6 +387,16 @@ pub enum AlterTableAction {
     SetOptions {
         set_options: BTreeMap<String, String>,
     },
+    CreateSnapshotRef {
+        ref_type: SnapshotRefType,
+        name: String,
+        travel_point: Option<TimeTravelPoint>,
+        retain_days: Option<u64>,
+    },
+    DropSnapshotRef {
+        ref_type: SnapshotRefType,
+        name: String,
+    },
 }
 
 impl Display for AlterTableAction {
@@ -415,11 +451,46 @@ impl Display for AlterTableAction {
             AlterTableAction::RevertTo { point } => {
                 write!(f, "REVERT TO {}", point)?;
             }
+            AlterTableAction::CreateSnapshotRef {
+                ref_type,
+                name,
+                travel_point,
+                retain_days,
+            } => {
+                write!(f, "CREATE {ref_type} '{name}'")?;
+                if let Some(point) = travel_point {
+                    write!(f, " AT{point}")?;
+                }
+                if let Some(days) = retain_days {
+                    write!(f, " RETAIN {days} DAYS")?;
+                }
+            }
+            AlterTableAction::DropSnapshotRef { ref_type, name } => {
+                write!(f, "DROP {ref_type} '{name}'")?;
+            }
         };
         Ok(())
     }
 }
 
+/// A named reference to a table snapshot, pinned against garbage collection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnapshotRefType {
+    /// A read-only reference to a snapshot.
+    Tag,
+    /// A writable reference that has its own snapshot chain.
+    Branch,
+}
+
+impl Display for SnapshotRefType {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            SnapshotRefType::Tag => write!(f, "TAG"),
+            SnapshotRefType::Branch => write!(f, "BRANCH"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum AddColumnOption {
     End,
@@ -194,15 +194,26 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         rule! {
             INSERT ~ #hint? ~ ( INTO | OVERWRITE ) ~ TABLE?
             ~ #dot_separated_idents_1_to_3
+            ~ ( AT ~ "(" ~ BRANCH ~ "=>" ~ #literal_string ~ ")" )?
             ~ ( "(" ~ #comma_separated_list1(ident) ~ ")" )?
             ~ #insert_source
         },
-        |(_, opt_hints, overwrite, _, (catalog, database, table), opt_columns, source)| {
+        |(
+            _,
+            opt_hints,
+            overwrite,
+            _,
+            (catalog, database, table),
+            opt_branch,
+            opt_columns,
+            source,
+        )| {
             Statement::Insert(InsertStmt {
                 hints: opt_hints,
                 catalog,
                 database,
                 table,
+                branch: opt_branch.map(|(_, _, _, _, branch, _)| branch),
                 columns: opt_columns
                     .map(|(_, columns, _)| columns)
                     .unwrap_or_default(),
@@ -649,7 +660,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
     );
     let create_table = map(
         rule! {
-            CREATE ~ TRANSIENT? ~ TABLE ~ ( IF ~ ^NOT ~ ^EXISTS )?
+            CREATE ~ ( TEMPORARY | TEMP )? ~ TRANSIENT? ~ TABLE ~ ( IF ~ ^NOT ~ ^EXISTS )?
             ~ #dot_separated_idents_1_to_3
             ~ #create_table_source?
             ~ ( #engine )?
@@ -660,6 +671,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
         |(
             _,
+            opt_temporary,
             opt_transient,
             _,
             opt_if_not_exists,
@@ -685,6 +697,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
                 table_options: opt_table_options.unwrap_or_default(),
                 as_query: opt_as_query.map(|(_, query)| Box::new(query)),
                 transient: opt_transient.is_some(),
+                temporary: opt_temporary.is_some(),
             })
         },
     );
@@ -910,6 +923,55 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
     );
 
+    let create_inverted_index = map(
+        rule! {
+            CREATE ~ INVERTED ~ INDEX ~ ( IF ~ ^NOT ~ ^EXISTS )?
+            ~ #ident
+            ~ ON ~ #dot_separated_idents_1_to_3
+            ~ "(" ~ ^#ident ~ ^")"
+            ~ ( TOKENIZER ~ ^"=" ~ ^#literal_string )?
+        },
+        |(
+            _,
+            _,
+            _,
+            opt_if_not_exists,
+            index_name,
+            _,
+            (catalog, database, table),
+            _,
+            column,
+            _,
+            opt_tokenizer,
+        )| {
+            Statement::CreateInvertedIndex(CreateInvertedIndexStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                index_name,
+                catalog,
+                database,
+                table,
+                column,
+                tokenizer: opt_tokenizer.map(|(_, _, tokenizer)| tokenizer),
+            })
+        },
+    );
+
+    let drop_inverted_index = map(
+        rule! {
+            DROP ~ INVERTED ~ INDEX ~ ( IF ~ ^EXISTS )? ~ #ident
+            ~ ON ~ #dot_separated_idents_1_to_3
+        },
+        |(_, _, _, opt_if_exists, index_name, _, (catalog, database, table))| {
+            Statement::DropInvertedIndex(DropInvertedIndexStmt {
+                if_exists: opt_if_exists.is_some(),
+                index_name,
+                catalog,
+                database,
+                table,
+            })
+        },
+    );
+
     let refresh_index = map(
         rule! {
             REFRESH ~ AGGREGATING ~ INDEX ~ #ident ~ ( LIMIT ~ #literal_u64 )?
@@ -1246,6 +1308,93 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         |(_, _)| Statement::ShowConnections(ShowConnectionsStmt {}),
     );
 
+    // sequences
+    let create_sequence = map(
+        rule! {
+            CREATE ~ SEQUENCE ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ #ident
+            ~ ( START ~ ( WITH | "=" )? ~ ^#literal_u64 )?
+            ~ ( INCREMENT ~ ( BY | "=" )? ~ ^#literal_u64 )?
+            ~ ( COMMENT ~ "=" ~ ^#literal_string )?
+        },
+        |(_, _, opt_if_not_exists, name, opt_start, opt_increment, opt_comment)| {
+            Statement::CreateSequence(CreateSequenceStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name,
+                start: opt_start.map(|(_, _, start)| start),
+                increment: opt_increment.map(|(_, _, increment)| increment),
+                comment: opt_comment.map(|(_, _, comment)| comment),
+            })
+        },
+    );
+
+    let drop_sequence = map(
+        rule! {
+            DROP ~ SEQUENCE ~ ( IF ~ ^EXISTS )? ~ #ident
+        },
+        |(_, _, opt_if_exists, name)| {
+            Statement::DropSequence(DropSequenceStmt {
+                if_exists: opt_if_exists.is_some(),
+                name,
+            })
+        },
+    );
+
+    let show_sequences = map(
+        rule! {
+            SHOW ~ SEQUENCES
+        },
+        |(_, _)| Statement::ShowSequences(ShowSequencesStmt {}),
+    );
+
+    // streams
+    let create_stream = map(
+        rule! {
+            CREATE ~ STREAM ~ ( IF ~ ^NOT ~ ^EXISTS )?
+            ~ #dot_separated_idents_1_to_3
+            ~ ON ~ TABLE ~ #dot_separated_idents_1_to_2
+            ~ ( COMMENT ~ "=" ~ ^#literal_string )?
+        },
+        |(
+            _,
+            _,
+            opt_if_not_exists,
+            (catalog, database, stream),
+            _,
+            _,
+            (table_database, table),
+            opt_comment,
+        )| {
+            Statement::CreateStream(CreateStreamStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                catalog,
+                database,
+                stream,
+                table_database,
+                table,
+                comment: opt_comment.map(|(_, _, comment)| comment),
+            })
+        },
+    );
+
+    let drop_stream = map(
+        rule! {
+            DROP ~ STREAM ~ ( IF ~ ^EXISTS )? ~ #dot_separated_idents_1_to_3
+        },
+        |(_, _, opt_if_exists, (catalog, database, stream))| {
+            Statement::DropStream(DropStreamStmt {
+                if_exists: opt_if_exists.is_some(),
+                catalog,
+                database,
+                stream,
+            })
+        },
+    );
+
+    // transaction
+    let begin = value(Statement::Begin, rule! { BEGIN ~ TRANSACTION? });
+    let commit = value(Statement::Commit, rule! { COMMIT ~ TRANSACTION? });
+    let rollback = value(Statement::Rollback, rule! { ROLLBACK ~ TRANSACTION? });
+
     let call = map(
         rule! {
             CALL ~ #ident ~ "(" ~ #comma_separated_list0(parameter_to_string) ~ ")"
@@ -1635,6 +1784,8 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             #create_index: "`CREATE AGGREGATING INDEX [IF NOT EXISTS] <index> AS SELECT ...`"
             | #drop_index: "`DROP AGGREGATING INDEX [IF EXISTS] <index>`"
             | #refresh_index: "`REFRESH AGGREGATING INDEX <index> [LIMIT <limit>]`"
+            | #create_inverted_index: "`CREATE INVERTED INDEX [IF NOT EXISTS] <index> ON [<database>.]<table>(<column>) [TOKENIZER = '<tokenizer>']`"
+            | #drop_inverted_index: "`DROP INVERTED INDEX [IF EXISTS] <index> ON [<database>.]<table>`"
         ),
         rule!(
             #create_virtual_column: "`CREATE VIRTUAL COLUMN (expr, ...) FOR [<database>.]<table>`"
@@ -1727,6 +1878,16 @@ AS
         | #desc_connection: "`DESC | DESCRIBE CONNECTION  <connection_name>`"
         | #show_connections: "`SHOW CONNECTIONS`"
         ),
+        rule!(
+        #create_sequence: "`CREATE SEQUENCE [IF NOT EXISTS] <sequence_name> [START [WITH | =] <n>] [INCREMENT [BY | =] <n>] [COMMENT = '<string_literal>']`"
+        | #drop_sequence: "`DROP SEQUENCE [IF EXISTS] <sequence_name>`"
+        | #show_sequences: "`SHOW SEQUENCES`"
+        | #begin: "`BEGIN [TRANSACTION]`"
+        | #commit: "`COMMIT [TRANSACTION]`"
+        | #rollback: "`ROLLBACK [TRANSACTION]`"
+        | #create_stream: "`CREATE STREAM [IF NOT EXISTS] [<database>.]<stream> ON TABLE [<database>.]<table> [COMMENT = '<string_literal>']`"
+        | #drop_stream: "`DROP STREAM [IF EXISTS] [<database>.]<stream>`"
+        ),
     ));
 
     map(
@@ -2246,10 +2407,22 @@ pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
             table,
         },
     );
+    let clone = map(
+        rule! {
+            CLONE ~ #dot_separated_idents_1_to_3 ~ ( AT ~ ^#travel_point )?
+        },
+        |(_, (catalog, database, table), opt_travel_point)| CreateTableSource::Clone {
+            catalog,
+            database,
+            table,
+            travel_point: opt_travel_point.map(|(_, p)| p),
+        },
+    );
 
     rule!(
         #columns
         | #like
+        | #clone
     )(i)
 }
 
@@ -2450,6 +2623,26 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         |(_, _, _, set_options, _)| AlterTableAction::SetOptions { set_options },
     );
 
+    let create_snapshot_ref = map(
+        rule! {
+            CREATE ~ #snapshot_ref_type ~ #literal_string
+            ~ ( AT ~ ^#travel_point )?
+            ~ ( RETAIN ~ ^#literal_u64 ~ ^DAYS )?
+        },
+        |(_, ref_type, name, opt_point, opt_retain)| AlterTableAction::CreateSnapshotRef {
+            ref_type,
+            name,
+            travel_point: opt_point.map(|(_, point)| point),
+            retain_days: opt_retain.map(|(_, days, _)| days),
+        },
+    );
+    let drop_snapshot_ref = map(
+        rule! {
+            DROP ~ #snapshot_ref_type ~ #literal_string
+        },
+        |(_, ref_type, name)| AlterTableAction::DropSnapshotRef { ref_type, name },
+    );
+
     rule!(
         #rename_table
         | #rename_column
@@ -2461,9 +2654,18 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         | #recluster_table
         | #revert_table
         | #set_table_options
+        | #create_snapshot_ref
+        | #drop_snapshot_ref
     )(i)
 }
 
+pub fn snapshot_ref_type(i: Input) -> IResult<SnapshotRefType> {
+    alt((
+        value(SnapshotRefType::Tag, rule! { TAG }),
+        value(SnapshotRefType::Branch, rule! { BRANCH }),
+    ))(i)
+}
+
 pub fn match_clause(i: Input) -> IResult<MergeOption> {
     map(
         rule! {
@@ -2741,33 +2943,52 @@ pub fn show_options(i: Input) -> IResult<ShowOptions> {
 pub fn table_option(i: Input) -> IResult<BTreeMap<String, String>> {
     map(
         rule! {
-           ( #ident ~ "=" ~ #parameter_to_string )*
-        },
-        |opts| {
-            BTreeMap::from_iter(
-                opts.iter()
-                    .map(|(k, _, v)| (k.name.to_lowercase(), v.clone())),
-            )
+           ( #table_option_item )*
         },
+        BTreeMap::from_iter,
     )(i)
 }
 
 pub fn set_table_option(i: Input) -> IResult<BTreeMap<String, String>> {
     map(
         rule! {
-           ( #ident ~ "=" ~ #parameter_to_string ) ~ ("," ~ #ident ~ "=" ~ #parameter_to_string )*
+           #table_option_item ~ ("," ~ #table_option_item )*
         },
-        |(key, _, value, opts)| {
-            let mut options = BTreeMap::from_iter(
-                opts.iter()
-                    .map(|(_, k, _, v)| (k.name.to_lowercase(), v.clone())),
-            );
-            options.insert(key.name.to_lowercase(), value);
+        |((key, value), opts)| {
+            let mut options = BTreeMap::from_iter(opts.into_iter().map(|(_, opt)| opt));
+            options.insert(key, value);
             options
         },
     )(i)
 }
 
+/// `<key> = <value>`, or `TTL = <expr>` whose value is the text of the expression.
+fn table_option_item(i: Input) -> IResult<(String, String)> {
+    let ttl = map(
+        rule! {
+            TTL ~ "=" ~ #expr
+        },
+        |(_, _, expr)| match expr {
+            Expr::Literal {
+                lit: Literal::String(value),
+                ..
+            } => ("ttl".to_string(), value),
+            expr => ("ttl".to_string(), expr.to_string()),
+        },
+    );
+    let option = map(
+        rule! {
+            #ident ~ "=" ~ #parameter_to_string
+        },
+        |(key, _, value)| (key.name.to_lowercase(), value),
+    );
+
+    rule!(
+        #ttl
+        | #option
+    )(i)
+}
+
 pub fn engine(i: Input) -> IResult<Engine> {
     let engine = alt((
         value(Engine::Null, rule! { NULL }),
@@ -94,6 +94,8 @@ fn test_statement() {
         r#"create table if not exists a.b (a int, b int, c int generated always as (a + b) virtual );"#,
         r#"create table a.b like c.d;"#,
         r#"create table t like t2 engine = memory;"#,
+        r#"create table t2 clone t1;"#,
+        r#"create table a.t2 clone a.t1 at (snapshot => '9828b23f74664ff3806f44bbc1925ea5');"#,
         r#"create table if not exists a.b (a int) 's3://testbucket/admin/data/' connection=(aws_key_id='minioadmin' aws_secret_key='minioadmin' endpoint_url='http://127.0.0.1:9900');"#,
         r#"create table if not exists a.b (a int) 's3://testbucket/admin/data/'
              connection=(aws_key_id='minioadmin' aws_secret_key='minioadmin' endpoint_url='http://127.0.0.1:9900')
@@ -125,6 +127,7 @@ fn test_statement() {
         r#"CREATE TABLE t(c1 int not null, c2 bigint not null, c3 varchar not null);"#,
         r#"CREATE TABLE t(c1 varbinary);"#,
         r#"CREATE TABLE t(c1 int default 1);"#,
+        r#"create temporary table t(c1 int);"#,
         r#"create table abc as (select * from xyz limit 10)"#,
         r#"ALTER USER u1 IDENTIFIED BY '123456';"#,
         r#"ALTER USER u1 WITH DEFAULT_ROLE = role1;"#,
@@ -168,6 +171,8 @@ fn test_statement() {
         r#"insert into t (c1, c2) values (1, 2);   "#,
         r#"insert into table t format json;"#,
         r#"insert into table t select * from t2;"#,
+        r#"insert into t at (branch => 'dq') select * from t2;"#,
+        r#"select * from t at (tag => 'release_2026_10');"#,
         r#"select parse_json('{"k1": [0, 1, 2]}').k1[0];"#,
         r#"CREATE STAGE ~"#,
         r#"CREATE STAGE IF NOT EXISTS test_stage 's3://load/files/' credentials=(aws_key_id='1a2b3c', aws_secret_key='4x5y6z') file_format=(type = CSV, compression = GZIP record_delimiter=',')"#,
@@ -203,6 +208,9 @@ fn test_statement() {
         r#"ALTER TABLE t MODIFY COLUMN a int;"#,
         r#"ALTER TABLE t MODIFY COLUMN a DROP STORED;"#,
         r#"ALTER TABLE t SET OPTIONS(SNAPSHOT_LOCATION='1/7/_ss/101fd790dbbe4238a31a8f2e2f856179_v4.mpk',block_per_segment = 500);"#,
+        r#"ALTER TABLE t CREATE TAG 'release_2026_10' RETAIN 7 DAYS;"#,
+        r#"ALTER TABLE t CREATE BRANCH 'dq' AT (TAG => 'release_2026_10');"#,
+        r#"ALTER TABLE t DROP BRANCH 'dq';"#,
         r#"ALTER DATABASE IF EXISTS ctl.c RENAME TO a;"#,
         r#"ALTER DATABASE c RENAME TO a;"#,
         r#"ALTER DATABASE ctl.c RENAME TO a;"#,
@@ -499,6 +507,17 @@ fn test_statement() {
         r#"DROP CONNECTION IF EXISTS my_conn;"#,
         r#"DESC CONNECTION my_conn;"#,
         r#"SHOW CONNECTIONS;"#,
+        r#"CREATE SEQUENCE IF NOT EXISTS seq START WITH 10 INCREMENT BY 2 COMMENT = 'ids'"#,
+        r#"DROP SEQUENCE IF EXISTS seq"#,
+        r#"SHOW SEQUENCES"#,
+        r#"BEGIN"#,
+        r#"BEGIN TRANSACTION;"#,
+        r#"COMMIT"#,
+        r#"ROLLBACK TRANSACTION"#,
+        r#"CREATE STREAM IF NOT EXISTS db.s ON TABLE db.t COMMENT = 'changes'"#,
+        r#"DROP STREAM IF EXISTS s"#,
+        r#"CREATE INVERTED INDEX IF NOT EXISTS idx ON db.t(content) TOKENIZER = 'english'"#,
+        r#"DROP INVERTED INDEX IF EXISTS idx ON t"#,
         "--各环节转各环节转各环节转各环节转各\n  select 34343",
         "-- 96477300355	31379974136	3.074486292973661\nselect 34343",
         "-- xxxxx\n  select 34343;",
@@ -656,6 +675,7 @@ fn test_query() {
         r#"SELECT * FROM ((SELECT * FROM xyu ORDER BY x, y)) AS xyu"#,
         r#"SELECT * FROM (VALUES(1,1),(2,null),(null,5)) AS t(a,b)"#,
         r#"VALUES(1,'a'),(2,'b'),(null,'c') order by col0 limit 2"#,
+        r#"select a from t qualify row_number() over (partition by a order by b) = 1"#,
     ];
 
     for case in cases {
@@ -37,6 +37,8 @@ use common_pipeline_core::Pipeline;
 use common_storage::StorageMetrics;
 use storages_common_table_meta::meta::SnapshotId;
 use storages_common_table_meta::meta::TableSnapshot;
+use storages_common_table_meta::table::SnapshotRefType;
+use storages_common_table_meta::table::OPT_KEY_BRANCH;
 
 use crate::plan::DataSourceInfo;
 use crate::plan::DataSourcePlan;
@@ -364,6 +366,35 @@ pub trait Table: Sync + Send {
         )))
     }
 
+    #[async_backtrace::framed]
+    async fn create_snapshot_ref(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        descriptor: SnapshotRefDescriptor,
+    ) -> Result<()> {
+        let (_, _) = (ctx, descriptor);
+        Err(ErrorCode::Unimplemented(format!(
+            "table {},  of engine type {}, does not support tags and branches",
+            self.name(),
+            self.get_table_info().engine(),
+        )))
+    }
+
+    #[async_backtrace::framed]
+    async fn drop_snapshot_ref(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        ref_type: SnapshotRefType,
+        name: &str,
+    ) -> Result<()> {
+        let (_, _, _) = (ctx, ref_type, name);
+        Err(ErrorCode::Unimplemented(format!(
+            "table {},  of engine type {}, does not support tags and branches",
+            self.name(),
+            self.get_table_info().engine(),
+        )))
+    }
+
     fn is_stage_table(&self) -> bool {
         false
     }
@@ -398,7 +429,16 @@ pub trait TableExt: Table {
             tenant: "".to_owned(),
             db_type: DatabaseType::NormalDB,
         };
-        catalog.get_table_by_info(&table_info)
+        let table = catalog.get_table_by_info(&table_info)?;
+        // a table navigated to a branch keeps working on the head of the branch
+        match self.get_table_info().options().get(OPT_KEY_BRANCH) {
+            Some(branch) => {
+                table
+                    .navigate_to(&NavigationPoint::Branch(branch.clone()))
+                    .await
+            }
+            None => Ok(table),
+        }
     }
 
     fn check_mutable(&self) -> Result<()> {
@@ -419,6 +459,8 @@ impl<T: ?Sized> TableExt for T where T: Table {}
 pub enum NavigationPoint {
     SnapshotID(String),
     TimePoint(DateTime<Utc>),
+    Tag(String),
+    Branch(String),
 }
 
 #[derive(Debug, Copy, Clone, Default)]
@@ -481,6 +523,15 @@ pub struct NavigationDescriptor {
     pub point: NavigationPoint,
 }
 
+#[derive(Debug, Clone)]
+pub struct SnapshotRefDescriptor {
+    pub ref_type: SnapshotRefType,
+    pub name: String,
+    /// The snapshot to be referenced, the current snapshot of the table if not specified.
+    pub point: Option<NavigationPoint>,
+    pub retain_days: Option<u64>,
+}
+
 use std::collections::HashMap;
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default)]
@@ -32,6 +32,7 @@ use common_meta_app::principal::FileFormatParams;
 use common_meta_app::principal::OnErrorMode;
 use common_meta_app::principal::RoleInfo;
 use common_meta_app::principal::UserInfo;
+use common_meta_app::schema::UpdateTableMetaReq;
 use common_pipeline_core::InputError;
 use common_settings::ChangeValue;
 use common_settings::Settings;
@@ -116,10 +117,14 @@ pub trait TableContext: Send + Sync {
     fn get_join_spill_progress(&self) -> Arc<Progress>;
     fn get_group_by_spill_progress(&self) -> Arc<Progress>;
     fn get_aggregate_spill_progress(&self) -> Arc<Progress>;
+    fn get_sort_spill_progress(&self) -> Arc<Progress>;
+    fn get_window_spill_progress(&self) -> Arc<Progress>;
     fn get_write_progress_value(&self) -> ProgressValues;
     fn get_join_spill_progress_value(&self) -> ProgressValues;
     fn get_group_by_spill_progress_value(&self) -> ProgressValues;
     fn get_aggregate_spill_progress_value(&self) -> ProgressValues;
+    fn get_sort_spill_progress_value(&self) -> ProgressValues;
+    fn get_window_spill_progress_value(&self) -> ProgressValues;
     fn get_result_progress(&self) -> Arc<Progress>;
     fn get_result_progress_value(&self) -> ProgressValues;
     fn get_status_info(&self) -> String;
@@ -215,6 +220,22 @@ pub trait TableContext: Send + Sync {
 
     fn get_segment_locations(&self) -> Result<Vec<Location>>;
 
+    /// Register the new offset of a stream consumed by the query, the offset is advanced
+    /// in the same meta transaction that commits the table written by the query.
+    fn add_stream_offset(&self, req: UpdateTableMetaReq) -> Result<()>;
+
+    fn get_stream_offsets(&self) -> Vec<UpdateTableMetaReq>;
+
+    fn clear_stream_offsets(&self);
+
+    /// Whether the query is inside an explicit transaction, in which the table meta updates
+    /// are only buffered until `COMMIT`.
+    fn is_txn_active(&self) -> bool;
+
+    /// Mark the explicit transaction as failed, if any, e.g., when a statement fails to plan.
+    /// A failed transaction can only be rolled back.
+    fn set_txn_fail(&self);
+
     fn add_file_status(&self, file_path: &str, file_status: FileStatus) -> Result<()>;
 
     fn get_copy_status(&self) -> Arc<CopyStatus>;
@@ -32,6 +32,9 @@ pub struct BackgroundConfig {
     // Fs compaction related background config.
     #[clap(flatten)]
     pub compaction: BackgroundCompactionConfig,
+    // Compaction triggered by the commits of write queries.
+    #[clap(flatten)]
+    pub auto_compaction: BackgroundAutoCompactionConfig,
 }
 
 #[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Args)]
@@ -101,11 +104,50 @@ impl BackgroundScheduledConfig {
     }
 }
 
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Args)]
+#[serde(default)]
+pub struct BackgroundAutoCompactionConfig {
+    // Check the snapshot statistics of a table after each commit of
+    // insert/copy/replace/merge, and compact or recluster it in background.
+    // It does not depend on `enable-background-service`.
+    #[clap(long, value_name = "VALUE")]
+    pub enable_auto_compaction: bool,
+
+    // Compact blocks if the number of blocks that are not perfect reaches this
+    // threshold. The table with cluster keys is reclustered once the average
+    // depth of its blocks exceeds the `row_avg_depth_threshold` of the table.
+    #[clap(long, value_name = "VALUE", default_value = "100")]
+    pub auto_compaction_block_threshold: u64,
+
+    // Compact segments if the number of segments exceeds the number that the
+    // blocks need (`block_count / block_per_segment`) by this threshold.
+    #[clap(long, value_name = "VALUE", default_value = "20")]
+    pub auto_compaction_segment_threshold: u64,
+
+    // The maximum number of tables that are compacted at the same time.
+    #[clap(long, value_name = "VALUE", default_value = "1")]
+    pub auto_compaction_max_concurrency: u64,
+
+    // The `max_threads` setting of each compaction.
+    #[clap(long, value_name = "VALUE", default_value = "2")]
+    pub auto_compaction_max_threads: u64,
+
+    // The maximum number of blocks that would be compacted in one run.
+    // None represent their is no limit
+    #[clap(long, value_name = "VALUE")]
+    pub auto_compaction_block_limit: Option<u64>,
+
+    // The minimal interval between two compactions of the same table.
+    #[clap(long, value_name = "VALUE", default_value = "60")]
+    pub auto_compaction_cooldown_secs: u64,
+}
+
 /// Config for background config
 #[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct InnerBackgroundConfig {
     pub enable: bool,
     pub compaction: InnerBackgroundCompactionConfig,
+    pub auto_compaction: InnerBackgroundAutoCompactionConfig,
 }
 
 #[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -117,6 +159,17 @@ pub struct InnerBackgroundCompactionConfig {
     pub params: BackgroundJobParams,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InnerBackgroundAutoCompactionConfig {
+    pub enable: bool,
+    pub block_threshold: u64,
+    pub segment_threshold: u64,
+    pub max_concurrency: u64,
+    pub max_threads: u64,
+    pub block_limit: Option<u64>,
+    pub cooldown_secs: u64,
+}
+
 impl InnerBackgroundCompactionConfig {
     pub fn has_target_tables(&self) -> bool {
         self.target_tables.is_some() && !self.target_tables.as_ref().unwrap().is_empty()
@@ -130,6 +183,7 @@ impl TryInto<InnerBackgroundConfig> for BackgroundConfig {
         Ok(InnerBackgroundConfig {
             enable: self.enable,
             compaction: self.compaction.try_into()?,
+            auto_compaction: self.auto_compaction.try_into()?,
         })
     }
 }
@@ -139,6 +193,42 @@ impl From<InnerBackgroundConfig> for BackgroundConfig {
         Self {
             enable: inner.enable,
             compaction: BackgroundCompactionConfig::from(inner.compaction),
+            auto_compaction: BackgroundAutoCompactionConfig::from(inner.auto_compaction),
+        }
+    }
+}
+
+impl TryInto<InnerBackgroundAutoCompactionConfig> for BackgroundAutoCompactionConfig {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<InnerBackgroundAutoCompactionConfig> {
+        if self.auto_compaction_max_concurrency == 0 {
+            return Err(ErrorCode::InvalidArgument(
+                "auto_compaction_max_concurrency must be greater than 0".to_string(),
+            ));
+        }
+        Ok(InnerBackgroundAutoCompactionConfig {
+            enable: self.enable_auto_compaction,
+            block_threshold: self.auto_compaction_block_threshold,
+            segment_threshold: self.auto_compaction_segment_threshold,
+            max_concurrency: self.auto_compaction_max_concurrency,
+            max_threads: self.auto_compaction_max_threads,
+            block_limit: self.auto_compaction_block_limit,
+            cooldown_secs: self.auto_compaction_cooldown_secs,
+        })
+    }
+}
+
+impl From<InnerBackgroundAutoCompactionConfig> for BackgroundAutoCompactionConfig {
+    fn from(inner: InnerBackgroundAutoCompactionConfig) -> Self {
+        Self {
+            enable_auto_compaction: inner.enable,
+            auto_compaction_block_threshold: inner.block_threshold,
+            auto_compaction_segment_threshold: inner.segment_threshold,
+            auto_compaction_max_concurrency: inner.max_concurrency,
+            auto_compaction_max_threads: inner.max_threads,
+            auto_compaction_block_limit: inner.block_limit,
+            auto_compaction_cooldown_secs: inner.cooldown_secs,
         }
     }
 }
@@ -264,6 +354,26 @@ impl Default for BackgroundScheduledConfig {
     }
 }
 
+impl Default for BackgroundAutoCompactionConfig {
+    fn default() -> Self {
+        InnerBackgroundAutoCompactionConfig::default().into()
+    }
+}
+
+impl Debug for BackgroundAutoCompactionConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BackgroundAutoCompactionConfig")
+            .field("enable", &self.enable_auto_compaction)
+            .field("block_threshold", &self.auto_compaction_block_threshold)
+            .field("segment_threshold", &self.auto_compaction_segment_threshold)
+            .field("max_concurrency", &self.auto_compaction_max_concurrency)
+            .field("max_threads", &self.auto_compaction_max_threads)
+            .field("block_limit", &self.auto_compaction_block_limit)
+            .field("cooldown_secs", &self.auto_compaction_cooldown_secs)
+            .finish()
+    }
+}
+
 impl Debug for BackgroundScheduledConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("BackgroundCompactionFixedConfig")
@@ -283,6 +393,21 @@ impl Default for InnerBackgroundConfig {
                 block_limit: None,
                 params: Default::default(),
             },
+            auto_compaction: Default::default(),
+        }
+    }
+}
+
+impl Default for InnerBackgroundAutoCompactionConfig {
+    fn default() -> Self {
+        Self {
+            enable: false,
+            block_threshold: 100,
+            segment_threshold: 20,
+            max_concurrency: 1,
+            max_threads: 2,
+            block_limit: None,
+            cooldown_secs: 60,
         }
     }
 }
@@ -291,6 +416,7 @@ impl Debug for InnerBackgroundConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("InnerBackgroundConfig")
             .field("compaction", &self.compaction)
+            .field("auto_compaction", &self.auto_compaction)
             .finish()
     }
 }
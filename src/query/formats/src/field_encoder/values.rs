@@ -24,12 +24,14 @@ use common_expression::types::timestamp::timestamp_to_string;
 use common_expression::types::NumberColumn;
 use common_expression::types::ValueType;
 use common_expression::Column;
+use common_io::constants::FALSE_BYTES_LOWER;
 use common_io::constants::FALSE_BYTES_NUM;
 use common_io::constants::INF_BYTES_LONG;
 use common_io::constants::INF_BYTES_LOWER;
 use common_io::constants::NAN_BYTES_LOWER;
 use common_io::constants::NAN_BYTES_SNAKE;
 use common_io::constants::NULL_BYTES_UPPER;
+use common_io::constants::TRUE_BYTES_LOWER;
 use common_io::constants::TRUE_BYTES_NUM;
 use lexical_core::ToLexical;
 use micromarshal::Marshal;
@@ -93,6 +95,21 @@ impl FieldEncoderValues {
         }
     }
 
+    // PostgreSQL clients parse booleans from "t"/"true" and floats from "NaN"/"Infinity".
+    pub fn create_for_postgres_handler(timezone: Tz) -> Self {
+        FieldEncoderValues {
+            common_settings: OutputCommonSettings {
+                true_bytes: TRUE_BYTES_LOWER.as_bytes().to_vec(),
+                false_bytes: FALSE_BYTES_LOWER.as_bytes().to_vec(),
+                null_bytes: NULL_BYTES_UPPER.as_bytes().to_vec(),
+                nan_bytes: NAN_BYTES_SNAKE.as_bytes().to_vec(),
+                inf_bytes: INF_BYTES_LONG.as_bytes().to_vec(),
+                timezone,
+            },
+            quote_char: b'\'',
+        }
+    }
+
     pub fn write_field(
         &self,
         column: &Column,
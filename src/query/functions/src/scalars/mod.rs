@@ -29,6 +29,7 @@ mod hash;
 mod map;
 mod math;
 mod other;
+mod search;
 mod string;
 mod string_multi_args;
 mod tuple;
@@ -39,6 +40,7 @@ pub use comparison::check_pattern_type;
 pub use comparison::is_like_pattern_escape;
 pub use comparison::PatternType;
 pub use comparison::ALL_COMP_FUNC_NAMES;
+pub use search::Tokenizer;
 
 pub fn register(registry: &mut FunctionRegistry) {
     variant::register(registry);
@@ -60,4 +62,5 @@ pub fn register(registry: &mut FunctionRegistry) {
     decimal::register(registry);
     vector::register(registry);
     bitmap::register(registry);
+    search::register(registry);
 }
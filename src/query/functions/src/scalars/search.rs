@@ -0,0 +1,135 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+use common_exception::ErrorCode;
+use common_expression::types::number::F64;
+use common_expression::types::BooleanType;
+use common_expression::types::NumberType;
+use common_expression::types::StringType;
+use common_expression::vectorize_with_builder_2_arg;
+use common_expression::FunctionDomain;
+use common_expression::FunctionRegistry;
+
+/// Saturation of the term frequency in `match_score`, as the `k1` of BM25.
+const SCORE_TERM_SATURATION: f64 = 1.2;
+
+/// Common english words that are not worth indexing.
+const ENGLISH_STOP_WORDS: [&str; 33] = [
+    "a", "an", "and", "are", "as", "at", "be", "but", "by", "for", "if", "in", "into", "is", "it",
+    "no", "not", "of", "on", "or", "such", "that", "the", "their", "then", "there", "these",
+    "they", "this", "to", "was", "will", "with",
+];
+
+/// Tokenizer of the full-text search, used by the inverted index and the `match` functions.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Tokenizer {
+    /// Splits the text into the lowercase words of alphanumeric characters.
+    #[default]
+    Default,
+    /// Same as `Default`, but the english stop words are skipped.
+    English,
+}
+
+impl Tokenizer {
+    /// Splits the text into terms, the terms of a text are always a superset of
+    /// the terms of the same text split by `English` tokenizer.
+    pub fn tokenize(&self, text: &[u8]) -> Vec<String> {
+        let skip_stop_words = matches!(self, Tokenizer::English);
+        String::from_utf8_lossy(text)
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .map(|word| word.to_lowercase())
+            .filter(|word| !(skip_stop_words && ENGLISH_STOP_WORDS.contains(&word.as_str())))
+            .collect()
+    }
+}
+
+impl FromStr for Tokenizer {
+    type Err = ErrorCode;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "default" => Ok(Tokenizer::Default),
+            "english" => Ok(Tokenizer::English),
+            _ => Err(ErrorCode::BadArguments(format!(
+                "unknown tokenizer '{s}', expecting 'default' or 'english'"
+            ))),
+        }
+    }
+}
+
+impl Display for Tokenizer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Tokenizer::Default => write!(f, "default"),
+            Tokenizer::English => write!(f, "english"),
+        }
+    }
+}
+
+pub fn register(registry: &mut FunctionRegistry) {
+    // `match(text, query)` is true if the text contains all the terms of the query.
+    registry.register_passthrough_nullable_2_arg::<StringType, StringType, BooleanType, _, _>(
+        "match",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_with_builder_2_arg::<StringType, StringType, BooleanType>(
+            |text, query, output, _| {
+                let terms = Tokenizer::Default
+                    .tokenize(query)
+                    .into_iter()
+                    .collect::<HashSet<_>>();
+                let words = Tokenizer::Default
+                    .tokenize(text)
+                    .into_iter()
+                    .collect::<HashSet<_>>();
+                output.push(!terms.is_empty() && terms.is_subset(&words));
+            },
+        ),
+    );
+
+    // `match_score(text, query)` is the relevance of the text to the query, it grows
+    // with the frequencies of the query terms in the text, and saturates like BM25.
+    registry.register_passthrough_nullable_2_arg::<StringType, StringType, NumberType<F64>, _, _>(
+        "match_score",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_with_builder_2_arg::<StringType, StringType, NumberType<F64>>(
+            |text, query, output, _| {
+                let terms = Tokenizer::Default
+                    .tokenize(query)
+                    .into_iter()
+                    .collect::<HashSet<_>>();
+                let mut frequencies = HashMap::new();
+                for word in Tokenizer::Default.tokenize(text) {
+                    if terms.contains(&word) {
+                        *frequencies.entry(word).or_insert(0usize) += 1;
+                    }
+                }
+                let score = frequencies
+                    .values()
+                    .map(|tf| {
+                        let tf = *tf as f64;
+                        tf * (SCORE_TERM_SATURATION + 1.0) / (tf + SCORE_TERM_SATURATION)
+                    })
+                    .sum::<f64>();
+                output.push(F64::from(score));
+            },
+        ),
+    );
+}
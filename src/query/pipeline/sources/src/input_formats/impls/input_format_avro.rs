@@ -0,0 +1,655 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::io::Cursor;
+use std::mem;
+use std::sync::Arc;
+
+use apache_avro::schema::RecordField;
+use apache_avro::types::Value as AvroValue;
+use apache_avro::Reader as AvroReader;
+use apache_avro::Schema as AvroSchema;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::decimal::Decimal;
+use common_expression::types::decimal::DecimalDataType;
+use common_expression::types::decimal::DecimalSize;
+use common_expression::types::number::F32;
+use common_expression::types::number::F64;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::NumberScalar;
+use common_expression::BlockMetaInfo;
+use common_expression::ColumnBuilder;
+use common_expression::DataBlock;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_meta_app::principal::StageInfo;
+use common_pipeline_core::Pipeline;
+use common_settings::Settings;
+use common_storage::StageFileInfo;
+use ethnum::i256;
+use log::debug;
+use opendal::Operator;
+use serde::Deserializer;
+use serde::Serializer;
+
+use crate::input_formats::input_pipeline::AligningStateTrait;
+use crate::input_formats::input_pipeline::BlockBuilderTrait;
+use crate::input_formats::input_pipeline::InputFormatPipe;
+use crate::input_formats::input_pipeline::RowBatchTrait;
+use crate::input_formats::input_split::FileInfo;
+use crate::input_formats::InputContext;
+use crate::input_formats::InputFormat;
+use crate::input_formats::SplitInfo;
+
+pub struct InputFormatAvro;
+
+#[async_trait::async_trait]
+impl InputFormat for InputFormatAvro {
+    #[async_backtrace::framed]
+    async fn get_splits(
+        &self,
+        file_infos: Vec<StageFileInfo>,
+        _stage_info: &StageInfo,
+        _op: &Operator,
+        _settings: &Arc<Settings>,
+    ) -> Result<Vec<Arc<SplitInfo>>> {
+        // The data blocks of a avro container file can only be decoded with the schema in
+        // the file header, so each file is a split.
+        let mut infos = Vec::with_capacity(file_infos.len());
+        for info in file_infos {
+            let size = info.size as usize;
+            let file = Arc::new(FileInfo {
+                path: info.path,
+                size,
+                num_splits: 1,
+                compress_alg: None,
+            });
+            infos.push(Arc::new(SplitInfo {
+                file,
+                seq_in_file: 0,
+                offset: 0,
+                size,
+                num_file_splits: 1,
+                format_info: None,
+            }));
+        }
+        Ok(infos)
+    }
+
+    fn exec_copy(&self, ctx: Arc<InputContext>, pipeline: &mut Pipeline) -> Result<()> {
+        AvroFormatPipe::execute_copy_with_aligner(ctx, pipeline)
+    }
+
+    fn exec_stream(&self, ctx: Arc<InputContext>, pipeline: &mut Pipeline) -> Result<()> {
+        AvroFormatPipe::execute_stream(ctx, pipeline)
+    }
+}
+
+pub struct AvroFormatPipe;
+
+#[async_trait::async_trait]
+impl InputFormatPipe for AvroFormatPipe {
+    type SplitMeta = ();
+    type ReadBatch = Vec<u8>;
+    type RowBatch = AvroRowBatch;
+    type AligningState = AvroAligningState;
+    type BlockBuilder = AvroBlockBuilder;
+
+    fn try_create_align_state(
+        ctx: &Arc<InputContext>,
+        split_info: &Arc<SplitInfo>,
+    ) -> Result<AvroAligningState> {
+        Ok(AvroAligningState {
+            ctx: ctx.clone(),
+            split_info: split_info.clone(),
+            buffers: vec![],
+        })
+    }
+
+    fn try_create_block_builder(ctx: &Arc<InputContext>) -> Result<AvroBlockBuilder> {
+        Ok(AvroBlockBuilder { ctx: ctx.clone() })
+    }
+}
+
+/// Records decoded from a avro file.
+pub struct AvroRowBatch {
+    pub path: String,
+    pub size: usize,
+    pub writer_schema: Arc<AvroSchema>,
+    // for each field of the schema, the position of the field in the records,
+    // `None` if the field is absent in the file or not used in query.
+    pub field_positions: Arc<Vec<Option<usize>>>,
+    pub records: Vec<AvroValue>,
+}
+
+impl Debug for AvroRowBatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "AvroRowBatch")
+    }
+}
+
+impl serde::Serialize for AvroRowBatch {
+    fn serialize<S>(&self, _serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where S: Serializer {
+        unimplemented!()
+    }
+}
+
+impl<'a> serde::Deserialize<'a> for AvroRowBatch {
+    fn deserialize<D: Deserializer<'a>>(_deserializer: D) -> std::result::Result<Self, D::Error> {
+        unimplemented!()
+    }
+}
+
+impl RowBatchTrait for AvroRowBatch {
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    fn rows(&self) -> usize {
+        self.records.len()
+    }
+}
+
+#[typetag::serde(name = "row_batch_avro")]
+impl BlockMetaInfo for AvroRowBatch {
+    fn equals(&self, _info: &Box<dyn BlockMetaInfo>) -> bool {
+        unreachable!("AvroRowBatch as BlockMetaInfo is not expected to be compared.")
+    }
+
+    fn clone_self(&self) -> Box<dyn BlockMetaInfo> {
+        unreachable!("AvroRowBatch as BlockMetaInfo is not expected to be cloned.")
+    }
+}
+
+pub struct AvroAligningState {
+    ctx: Arc<InputContext>,
+    split_info: Arc<SplitInfo>,
+    buffers: Vec<Vec<u8>>,
+}
+
+impl AligningStateTrait for AvroAligningState {
+    type Pipe = AvroFormatPipe;
+
+    fn align(&mut self, read_batch: Option<Vec<u8>>) -> Result<Vec<AvroRowBatch>> {
+        if let Some(rb) = read_batch {
+            self.buffers.push(rb);
+            return Ok(vec![]);
+        }
+
+        let path = self.split_info.file.path.clone();
+        let file_in_memory = self.buffers.concat();
+        self.buffers.clear();
+        let size = file_in_memory.len();
+        debug!("aligning avro file {} of {} bytes", path, size);
+
+        let reader =
+            AvroReader::new(Cursor::new(file_in_memory)).map_err(|e| avro_error(&path, e))?;
+        let writer_schema = Arc::new(reader.writer_schema().clone());
+        let field_positions = Arc::new(get_field_positions(
+            &writer_schema,
+            &self.ctx.schema.fields,
+            &self.ctx.projection,
+        )?);
+
+        let max_rows = self.ctx.block_compact_thresholds.max_rows_per_block;
+        let mut row_batches = vec![];
+        let mut records = vec![];
+        for value in reader {
+            records.push(value.map_err(|e| avro_error(&path, e))?);
+            if records.len() >= max_rows {
+                row_batches.push(AvroRowBatch {
+                    path: path.clone(),
+                    size: 0,
+                    writer_schema: writer_schema.clone(),
+                    field_positions: field_positions.clone(),
+                    records: mem::take(&mut records),
+                });
+            }
+        }
+        if !records.is_empty() {
+            row_batches.push(AvroRowBatch {
+                path: path.clone(),
+                size: 0,
+                writer_schema: writer_schema.clone(),
+                field_positions: field_positions.clone(),
+                records,
+            });
+        }
+        // the file is decoded as a whole, attribute the bytes to the first batch.
+        if let Some(first) = row_batches.first_mut() {
+            first.size = size;
+        }
+        debug!(
+            "align avro file {} of {} bytes to {} row batches",
+            path,
+            size,
+            row_batches.len()
+        );
+        Ok(row_batches)
+    }
+}
+
+pub struct AvroBlockBuilder {
+    ctx: Arc<InputContext>,
+}
+
+impl BlockBuilderTrait for AvroBlockBuilder {
+    type Pipe = AvroFormatPipe;
+
+    fn deserialize(&mut self, batch: Option<AvroRowBatch>) -> Result<Vec<DataBlock>> {
+        let batch = match batch {
+            Some(batch) => batch,
+            None => return Ok(vec![]),
+        };
+
+        let record_fields = match batch.writer_schema.as_ref() {
+            AvroSchema::Record(record) => &record.fields,
+            _ => unreachable!("the schema of avro file is checked in align()"),
+        };
+        let fields = &self.ctx.schema.fields;
+        let mut columns = fields
+            .iter()
+            .map(|f| ColumnBuilder::with_capacity(&f.data_type().into(), batch.records.len()))
+            .collect::<Vec<_>>();
+
+        for record in &batch.records {
+            let values = match record {
+                AvroValue::Record(values) => values,
+                _ => {
+                    return Err(ErrorCode::BadBytes(format!(
+                        "fail to read avro file {}: expect record, got {:?}",
+                        batch.path, record
+                    )));
+                }
+            };
+            for (i, column) in columns.iter_mut().enumerate() {
+                match batch.field_positions[i] {
+                    Some(pos) => {
+                        let scalar = avro_to_scalar(
+                            &values[pos].1,
+                            Some(&record_fields[pos].schema),
+                            &column.data_type(),
+                        )
+                        .map_err(|e| {
+                            e.add_message(format!(
+                                "fail to read column {} of avro file {}",
+                                fields[i].name(),
+                                batch.path
+                            ))
+                        })?;
+                        column.push(scalar.as_ref());
+                    }
+                    None => match &self.ctx.default_values {
+                        Some(values) => column.push(values[i].as_ref()),
+                        None => column.push_default(),
+                    },
+                }
+            }
+        }
+
+        let columns = columns.into_iter().map(|c| c.build()).collect::<Vec<_>>();
+        Ok(vec![DataBlock::new_from_columns(columns)])
+    }
+}
+
+fn avro_error(path: &str, e: impl std::fmt::Display) -> ErrorCode {
+    ErrorCode::BadBytes(format!("fail to read avro file {}: {}", path, e))
+}
+
+fn record_field_matches(field: &RecordField, name: &str) -> bool {
+    field.name.eq_ignore_ascii_case(name)
+        || field
+            .aliases
+            .as_ref()
+            .map(|aliases| aliases.iter().any(|a| a.eq_ignore_ascii_case(name)))
+            .unwrap_or(false)
+}
+
+/// Match the fields of the schema with the fields of avro records by name (or alias),
+/// so that files written with older schemas can be loaded, the absent fields are filled
+/// with default values.
+fn get_field_positions(
+    writer_schema: &AvroSchema,
+    fields: &[TableField],
+    projection: &Option<Vec<usize>>,
+) -> Result<Vec<Option<usize>>> {
+    let record_fields = match writer_schema {
+        AvroSchema::Record(record) => &record.fields,
+        _ => {
+            return Err(ErrorCode::BadBytes(format!(
+                "the schema of avro file must be a record, got {:?}",
+                writer_schema
+            )));
+        }
+    };
+    Ok(fields
+        .iter()
+        .enumerate()
+        .map(|(i, f)| {
+            if let Some(projection) = projection {
+                if !projection.contains(&i) {
+                    return None;
+                }
+            }
+            record_fields
+                .iter()
+                .position(|r| record_field_matches(r, f.name()))
+        })
+        .collect())
+}
+
+fn type_mismatch(value: &AvroValue, data_type: &DataType) -> ErrorCode {
+    ErrorCode::BadBytes(format!(
+        "can not convert avro value {:?} to {}",
+        value, data_type
+    ))
+}
+
+fn avro_to_scalar(
+    value: &AvroValue,
+    schema: Option<&AvroSchema>,
+    data_type: &DataType,
+) -> Result<Scalar> {
+    // Resolve the union to the branch of the value.
+    if let AvroValue::Union(index, inner) = value {
+        let schema = match schema {
+            Some(AvroSchema::Union(union)) => union.variants().get(*index as usize),
+            _ => None,
+        };
+        return avro_to_scalar(inner, schema, data_type);
+    }
+
+    match data_type {
+        DataType::Nullable(inner) => match value {
+            AvroValue::Null => Ok(Scalar::Null),
+            _ => avro_to_scalar(value, schema, inner),
+        },
+        DataType::Null => match value {
+            AvroValue::Null => Ok(Scalar::Null),
+            _ => Err(type_mismatch(value, data_type)),
+        },
+        DataType::Boolean => match value {
+            AvroValue::Boolean(v) => Ok(Scalar::Boolean(*v)),
+            _ => Err(type_mismatch(value, data_type)),
+        },
+        DataType::Number(number_type) => avro_to_number(value, number_type)
+            .map(Scalar::Number)
+            .ok_or_else(|| type_mismatch(value, data_type)),
+        DataType::Decimal(decimal_type) => avro_to_decimal(value, schema, decimal_type)
+            .ok_or_else(|| type_mismatch(value, data_type)),
+        DataType::String => match value {
+            AvroValue::String(v) => Ok(Scalar::String(v.as_bytes().to_vec())),
+            AvroValue::Enum(_, symbol) => Ok(Scalar::String(symbol.as_bytes().to_vec())),
+            AvroValue::Bytes(v) | AvroValue::Fixed(_, v) => Ok(Scalar::String(v.clone())),
+            AvroValue::Uuid(v) => Ok(Scalar::String(v.to_string().into_bytes())),
+            _ => Err(type_mismatch(value, data_type)),
+        },
+        DataType::Date => match value {
+            AvroValue::Date(v) => Ok(Scalar::Date(*v)),
+            _ => Err(type_mismatch(value, data_type)),
+        },
+        DataType::Timestamp => match value {
+            AvroValue::TimestampMicros(v) | AvroValue::LocalTimestampMicros(v) => {
+                Ok(Scalar::Timestamp(*v))
+            }
+            AvroValue::TimestampMillis(v) | AvroValue::LocalTimestampMillis(v) => {
+                Ok(Scalar::Timestamp(*v * 1000))
+            }
+            AvroValue::Date(v) => Ok(Scalar::Timestamp(*v as i64 * 24 * 3600 * 1_000_000)),
+            _ => Err(type_mismatch(value, data_type)),
+        },
+        DataType::Array(inner) => match value {
+            AvroValue::Array(values) => {
+                let item_schema = match schema {
+                    Some(AvroSchema::Array(item_schema)) => Some(item_schema.as_ref()),
+                    _ => None,
+                };
+                let mut builder = ColumnBuilder::with_capacity(inner, values.len());
+                for v in values {
+                    builder.push(avro_to_scalar(v, item_schema, inner)?.as_ref());
+                }
+                Ok(Scalar::Array(builder.build()))
+            }
+            _ => Err(type_mismatch(value, data_type)),
+        },
+        DataType::Map(inner) => match (value, inner.as_ref()) {
+            (AvroValue::Map(values), DataType::Tuple(kv_types)) if kv_types.len() == 2 => {
+                let value_schema = match schema {
+                    Some(AvroSchema::Map(value_schema)) => Some(value_schema.as_ref()),
+                    _ => None,
+                };
+                let mut entries = values.iter().collect::<Vec<_>>();
+                entries.sort_by(|a, b| a.0.cmp(b.0));
+                let mut builder = ColumnBuilder::with_capacity(inner, entries.len());
+                for (k, v) in entries {
+                    let key = avro_to_scalar(&AvroValue::String(k.clone()), None, &kv_types[0])?;
+                    let value = avro_to_scalar(v, value_schema, &kv_types[1])?;
+                    builder.push(Scalar::Tuple(vec![key, value]).as_ref());
+                }
+                Ok(Scalar::Map(builder.build()))
+            }
+            _ => Err(type_mismatch(value, data_type)),
+        },
+        DataType::Tuple(types) => match value {
+            AvroValue::Record(values) if values.len() == types.len() => {
+                let record_fields = match schema {
+                    Some(AvroSchema::Record(record)) => Some(&record.fields),
+                    _ => None,
+                };
+                let scalars = values
+                    .iter()
+                    .zip(types.iter())
+                    .enumerate()
+                    .map(|(i, ((_, v), ty))| {
+                        let schema = record_fields.map(|fields| &fields[i].schema);
+                        avro_to_scalar(v, schema, ty)
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(Scalar::Tuple(scalars))
+            }
+            _ => Err(type_mismatch(value, data_type)),
+        },
+        DataType::Variant => {
+            let json = serde_json::Value::try_from(value.clone())
+                .map_err(|e| ErrorCode::BadBytes(format!("{}", e)))?;
+            let mut buf = vec![];
+            jsonb::Value::from(&json).write_to_vec(&mut buf);
+            Ok(Scalar::Variant(buf))
+        }
+        _ => Err(type_mismatch(value, data_type)),
+    }
+}
+
+fn avro_to_number(value: &AvroValue, number_type: &NumberDataType) -> Option<NumberScalar> {
+    let v = match value {
+        AvroValue::Int(v) => *v as i64,
+        AvroValue::Long(v) => *v,
+        AvroValue::Float(v) => {
+            return match number_type {
+                NumberDataType::Float32 => Some(NumberScalar::Float32(F32::from(*v))),
+                NumberDataType::Float64 => Some(NumberScalar::Float64(F64::from(*v as f64))),
+                _ => None,
+            };
+        }
+        AvroValue::Double(v) => {
+            return match number_type {
+                NumberDataType::Float32 => Some(NumberScalar::Float32(F32::from(*v as f32))),
+                NumberDataType::Float64 => Some(NumberScalar::Float64(F64::from(*v))),
+                _ => None,
+            };
+        }
+        _ => return None,
+    };
+    match number_type {
+        NumberDataType::UInt8 => u8::try_from(v).ok().map(NumberScalar::UInt8),
+        NumberDataType::UInt16 => u16::try_from(v).ok().map(NumberScalar::UInt16),
+        NumberDataType::UInt32 => u32::try_from(v).ok().map(NumberScalar::UInt32),
+        NumberDataType::UInt64 => u64::try_from(v).ok().map(NumberScalar::UInt64),
+        NumberDataType::Int8 => i8::try_from(v).ok().map(NumberScalar::Int8),
+        NumberDataType::Int16 => i16::try_from(v).ok().map(NumberScalar::Int16),
+        NumberDataType::Int32 => i32::try_from(v).ok().map(NumberScalar::Int32),
+        NumberDataType::Int64 => Some(NumberScalar::Int64(v)),
+        NumberDataType::Float32 => Some(NumberScalar::Float32(F32::from(v as f32))),
+        NumberDataType::Float64 => Some(NumberScalar::Float64(F64::from(v as f64))),
+    }
+}
+
+/// Decode the big-endian two's-complement unscaled value of a avro decimal,
+/// and rescale it to the scale of the target decimal type.
+fn avro_to_decimal(
+    value: &AvroValue,
+    schema: Option<&AvroSchema>,
+    decimal_type: &DecimalDataType,
+) -> Option<Scalar> {
+    let (bytes, scale) = match (value, schema) {
+        (AvroValue::Decimal(v), Some(AvroSchema::Decimal(decimal_schema))) => {
+            (Vec::<u8>::try_from(v).ok()?, decimal_schema.scale as u8)
+        }
+        (AvroValue::Int(v), _) => (v.to_be_bytes().to_vec(), 0),
+        (AvroValue::Long(v), _) => (v.to_be_bytes().to_vec(), 0),
+        _ => return None,
+    };
+    if bytes.len() > 32 {
+        return None;
+    }
+    let fill = if bytes.first().map(|b| b & 0x80 != 0).unwrap_or(false) {
+        0xFF
+    } else {
+        0
+    };
+    let mut buf = [fill; 32];
+    buf[32 - bytes.len()..].copy_from_slice(&bytes);
+    let unscaled = i256::from_be_bytes(buf);
+
+    match decimal_type {
+        DecimalDataType::Decimal128(size) => {
+            let v = rescale_decimal(unscaled, scale, size)?;
+            if v < i256::from(i128::MIN) || v > i256::from(i128::MAX) {
+                return None;
+            }
+            Some(i128::upcast_scalar(v.as_i128(), *size))
+        }
+        DecimalDataType::Decimal256(size) => {
+            let v = rescale_decimal(unscaled, scale, size)?;
+            Some(i256::upcast_scalar(v, *size))
+        }
+    }
+}
+
+fn rescale_decimal(unscaled: i256, scale: u8, size: &DecimalSize) -> Option<i256> {
+    let v = if size.scale >= scale {
+        unscaled.checked_mul(i256::e((size.scale - scale) as u32))?
+    } else {
+        unscaled.checked_div(i256::e((scale - size.scale) as u32))?
+    };
+    if v < i256::min_for_precision(size.precision) || v > i256::max_for_precision(size.precision) {
+        return None;
+    }
+    Some(v)
+}
+
+/// Infer the schema of a avro file from the writer schema in its header.
+#[async_backtrace::framed]
+pub async fn read_avro_schema_async(operator: &Operator, path: &str) -> Result<TableSchema> {
+    let data = operator.read(path).await?;
+    let reader = AvroReader::new(Cursor::new(data)).map_err(|e| avro_error(path, e))?;
+    match reader.writer_schema() {
+        AvroSchema::Record(record) => {
+            let fields = record
+                .fields
+                .iter()
+                .map(|f| TableField::new(&f.name, avro_schema_to_table_type(&f.schema)))
+                .collect::<Vec<_>>();
+            Ok(TableSchema::new(fields))
+        }
+        schema => Err(ErrorCode::BadBytes(format!(
+            "the schema of avro file {} must be a record, got {:?}",
+            path, schema
+        ))),
+    }
+}
+
+fn avro_schema_to_table_type(schema: &AvroSchema) -> TableDataType {
+    match schema {
+        AvroSchema::Null => TableDataType::Null,
+        AvroSchema::Boolean => TableDataType::Boolean,
+        AvroSchema::Int | AvroSchema::TimeMillis => TableDataType::Number(NumberDataType::Int32),
+        AvroSchema::Long | AvroSchema::TimeMicros => TableDataType::Number(NumberDataType::Int64),
+        AvroSchema::Float => TableDataType::Number(NumberDataType::Float32),
+        AvroSchema::Double => TableDataType::Number(NumberDataType::Float64),
+        AvroSchema::Bytes
+        | AvroSchema::Fixed(_)
+        | AvroSchema::String
+        | AvroSchema::Enum(_)
+        | AvroSchema::Uuid => TableDataType::String,
+        AvroSchema::Date => TableDataType::Date,
+        AvroSchema::TimestampMillis
+        | AvroSchema::TimestampMicros
+        | AvroSchema::LocalTimestampMillis
+        | AvroSchema::LocalTimestampMicros => TableDataType::Timestamp,
+        AvroSchema::Decimal(decimal) => {
+            let size = DecimalSize {
+                precision: decimal.precision as u8,
+                scale: decimal.scale as u8,
+            };
+            if decimal.precision <= i128::default_decimal_size().precision as usize {
+                TableDataType::Decimal(DecimalDataType::Decimal128(size))
+            } else {
+                TableDataType::Decimal(DecimalDataType::Decimal256(size))
+            }
+        }
+        AvroSchema::Array(item) => TableDataType::Array(Box::new(avro_schema_to_table_type(item))),
+        AvroSchema::Map(value) => TableDataType::Map(Box::new(TableDataType::Tuple {
+            fields_name: vec!["key".to_string(), "value".to_string()],
+            fields_type: vec![TableDataType::String, avro_schema_to_table_type(value)],
+        })),
+        AvroSchema::Record(record) => TableDataType::Tuple {
+            fields_name: record.fields.iter().map(|f| f.name.clone()).collect(),
+            fields_type: record
+                .fields
+                .iter()
+                .map(|f| avro_schema_to_table_type(&f.schema))
+                .collect(),
+        },
+        AvroSchema::Union(union) => {
+            // `["null", T]` is the way to declare a nullable field in avro,
+            // other unions have no corresponding type.
+            let variants = union
+                .variants()
+                .iter()
+                .filter(|v| !matches!(v, AvroSchema::Null))
+                .collect::<Vec<_>>();
+            match variants.as_slice() {
+                [] => TableDataType::Null,
+                [variant] => {
+                    let data_type = avro_schema_to_table_type(variant);
+                    if union.is_nullable() {
+                        data_type.wrap_nullable()
+                    } else {
+                        data_type
+                    }
+                }
+                _ => TableDataType::Variant.wrap_nullable(),
+            }
+        }
+        _ => TableDataType::Variant,
+    }
+}
@@ -0,0 +1,449 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::ops::Range;
+use std::sync::Arc;
+
+use bytes::Bytes;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::number::F32;
+use common_expression::types::number::F64;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::NumberScalar;
+use common_expression::BlockEntry;
+use common_expression::BlockMetaInfo;
+use common_expression::DataBlock;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRef;
+use common_expression::Value;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_meta_app::principal::StageInfo;
+use common_pipeline_core::Pipeline;
+use common_settings::Settings;
+use common_storage::StageFileInfo;
+use log::debug;
+use log::info;
+use opendal::Operator;
+use orc_rust::projection::ProjectionMask;
+use orc_rust::reader::metadata::read_metadata;
+use orc_rust::reader::metadata::FileMetadata;
+use orc_rust::statistics::ColumnStatistics as OrcColumnStatistics;
+use orc_rust::statistics::TypeStatistics;
+use orc_rust::ArrowReaderBuilder;
+use serde::Deserializer;
+use serde::Serializer;
+use storages_common_pruner::RangePruner;
+use storages_common_pruner::RangePrunerCreator;
+use storages_common_table_meta::meta::ColumnStatistics;
+use storages_common_table_meta::meta::StatisticsOfColumns;
+
+use crate::input_formats::input_pipeline::AligningStateTrait;
+use crate::input_formats::input_pipeline::BlockBuilderTrait;
+use crate::input_formats::input_pipeline::InputFormatPipe;
+use crate::input_formats::input_pipeline::RowBatchTrait;
+use crate::input_formats::input_split::FileInfo;
+use crate::input_formats::InputContext;
+use crate::input_formats::InputFormat;
+use crate::input_formats::SplitInfo;
+
+pub struct InputFormatORC;
+
+#[async_trait::async_trait]
+impl InputFormat for InputFormatORC {
+    #[async_backtrace::framed]
+    async fn get_splits(
+        &self,
+        file_infos: Vec<StageFileInfo>,
+        _stage_info: &StageInfo,
+        _op: &Operator,
+        _settings: &Arc<Settings>,
+    ) -> Result<Vec<Arc<SplitInfo>>> {
+        // The stripes of a ORC file can only be located by the file tail,
+        // so each file is a split and the stripes are split in `align()`.
+        let mut infos = Vec::with_capacity(file_infos.len());
+        for info in file_infos {
+            let size = info.size as usize;
+            let file = Arc::new(FileInfo {
+                path: info.path,
+                size,
+                num_splits: 1,
+                compress_alg: None,
+            });
+            infos.push(Arc::new(SplitInfo {
+                file,
+                seq_in_file: 0,
+                offset: 0,
+                size,
+                num_file_splits: 1,
+                format_info: None,
+            }));
+        }
+        Ok(infos)
+    }
+
+    fn exec_copy(&self, ctx: Arc<InputContext>, pipeline: &mut Pipeline) -> Result<()> {
+        OrcFormatPipe::execute_copy_with_aligner(ctx, pipeline)
+    }
+
+    fn exec_stream(&self, ctx: Arc<InputContext>, pipeline: &mut Pipeline) -> Result<()> {
+        OrcFormatPipe::execute_stream(ctx, pipeline)
+    }
+}
+
+pub struct OrcFormatPipe;
+
+#[async_trait::async_trait]
+impl InputFormatPipe for OrcFormatPipe {
+    type SplitMeta = ();
+    type ReadBatch = Vec<u8>;
+    type RowBatch = StripeInMemory;
+    type AligningState = OrcAligningState;
+    type BlockBuilder = OrcBlockBuilder;
+
+    fn try_create_align_state(
+        ctx: &Arc<InputContext>,
+        split_info: &Arc<SplitInfo>,
+    ) -> Result<OrcAligningState> {
+        Ok(OrcAligningState {
+            ctx: ctx.clone(),
+            split_info: split_info.clone(),
+            buffers: vec![],
+        })
+    }
+
+    fn try_create_block_builder(ctx: &Arc<InputContext>) -> Result<OrcBlockBuilder> {
+        Ok(OrcBlockBuilder { ctx: ctx.clone() })
+    }
+}
+
+/// A stripe of a ORC file which is not pruned by statistics.
+pub struct StripeInMemory {
+    pub path: String,
+    // the whole file, shared by all stripes of it.
+    pub file: Bytes,
+    pub byte_range: Range<usize>,
+    pub num_rows: usize,
+    // names of the columns in the file, in the order of the schema,
+    // `None` if the column is not used in query.
+    pub columns_to_read: Arc<Vec<Option<String>>>,
+}
+
+impl Debug for StripeInMemory {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "StripeInMemory")
+    }
+}
+
+impl serde::Serialize for StripeInMemory {
+    fn serialize<S>(&self, _serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where S: Serializer {
+        unimplemented!()
+    }
+}
+
+impl<'a> serde::Deserialize<'a> for StripeInMemory {
+    fn deserialize<D: Deserializer<'a>>(_deserializer: D) -> std::result::Result<Self, D::Error> {
+        unimplemented!()
+    }
+}
+
+impl RowBatchTrait for StripeInMemory {
+    fn size(&self) -> usize {
+        self.byte_range.len()
+    }
+
+    fn rows(&self) -> usize {
+        self.num_rows
+    }
+}
+
+#[typetag::serde(name = "row_batch_orc")]
+impl BlockMetaInfo for StripeInMemory {
+    fn equals(&self, _info: &Box<dyn BlockMetaInfo>) -> bool {
+        unreachable!("StripeInMemory as BlockMetaInfo is not expected to be compared.")
+    }
+
+    fn clone_self(&self) -> Box<dyn BlockMetaInfo> {
+        unreachable!("StripeInMemory as BlockMetaInfo is not expected to be cloned.")
+    }
+}
+
+pub struct OrcAligningState {
+    ctx: Arc<InputContext>,
+    split_info: Arc<SplitInfo>,
+    buffers: Vec<Vec<u8>>,
+}
+
+impl OrcAligningState {
+    fn create_pruner(&self) -> Result<Option<Arc<dyn RangePruner + Send + Sync>>> {
+        match &self.ctx.filter {
+            Some(filter) => {
+                let func_ctx = self.ctx.table_context.get_function_context()?;
+                let filter_expr = filter.as_expr(&BUILTIN_FUNCTIONS);
+                Ok(Some(RangePrunerCreator::try_create(
+                    func_ctx,
+                    &self.ctx.schema,
+                    Some(&filter_expr),
+                )?))
+            }
+            None => Ok(None),
+        }
+    }
+}
+
+impl AligningStateTrait for OrcAligningState {
+    type Pipe = OrcFormatPipe;
+
+    fn align(&mut self, read_batch: Option<Vec<u8>>) -> Result<Vec<StripeInMemory>> {
+        if let Some(rb) = read_batch {
+            self.buffers.push(rb);
+            return Ok(vec![]);
+        }
+
+        let path = self.split_info.file.path.clone();
+        let file = Bytes::from(self.buffers.concat());
+        self.buffers.clear();
+        let size = file.len();
+        debug!("aligning orc file {} of {} bytes", path, size);
+
+        let file_meta = read_orc_metadata(&path, file.clone())?;
+        let columns = get_used_columns(&file_meta, &self.ctx.schema, &self.ctx.projection)?;
+        let pruner = self.create_pruner()?;
+
+        let stripes = file_meta.stripe_metadatas();
+        let mut row_batches = Vec::with_capacity(stripes.len());
+        for stripe in stripes {
+            if let Some(pruner) = &pruner {
+                let stats = collect_stripe_stats(
+                    stripe.column_statistics(),
+                    stripe.number_of_rows(),
+                    &file_meta,
+                    &self.ctx.schema,
+                );
+                if !pruner.should_keep(&stats, None) {
+                    continue;
+                }
+            }
+            let start = stripe.offset() as usize;
+            let end = start
+                + (stripe.index_length() + stripe.data_length() + stripe.footer_length()) as usize;
+            row_batches.push(StripeInMemory {
+                path: path.clone(),
+                file: file.clone(),
+                byte_range: start..end,
+                num_rows: stripe.number_of_rows() as usize,
+                columns_to_read: columns.clone(),
+            });
+        }
+        info!(
+            "align orc file {} of {} bytes to {} stripes, {} stripes pruned",
+            path,
+            size,
+            row_batches.len(),
+            stripes.len() - row_batches.len()
+        );
+        Ok(row_batches)
+    }
+}
+
+pub struct OrcBlockBuilder {
+    ctx: Arc<InputContext>,
+}
+
+impl BlockBuilderTrait for OrcBlockBuilder {
+    type Pipe = OrcFormatPipe;
+
+    fn deserialize(&mut self, batch: Option<StripeInMemory>) -> Result<Vec<DataBlock>> {
+        let stripe = match batch {
+            Some(stripe) => stripe,
+            None => return Ok(vec![]),
+        };
+
+        let builder = ArrowReaderBuilder::try_new(stripe.file.clone())
+            .map_err(|e| orc_error(&stripe.path, e))?;
+        let names = stripe
+            .columns_to_read
+            .iter()
+            .flatten()
+            .map(|name| name.as_str())
+            .collect::<Vec<_>>();
+        let projection =
+            ProjectionMask::named_roots(builder.file_metadata().root_data_type(), &names);
+        let reader = builder
+            .with_projection(projection)
+            .with_file_byte_range(stripe.byte_range.clone())
+            .build();
+
+        let mut blocks = vec![];
+        for record_batch in reader {
+            let (block, schema) = DataBlock::from_record_batch(&record_batch?)?;
+            let num_rows = block.num_rows();
+            let mut columns = Vec::with_capacity(self.ctx.schema.num_fields());
+            for (field, name) in self
+                .ctx
+                .schema
+                .fields()
+                .iter()
+                .zip(stripe.columns_to_read.iter())
+            {
+                match name {
+                    Some(name) => {
+                        let index = schema.index_of(name)?;
+                        columns.push(block.get_by_offset(index).clone());
+                    }
+                    None => {
+                        // The column is not used in query, fill it with default value.
+                        let data_type: DataType = field.data_type().into();
+                        let value = Value::Scalar(Scalar::default_value(&data_type));
+                        columns.push(BlockEntry::new(data_type, value));
+                    }
+                }
+            }
+            blocks.push(DataBlock::new(columns, num_rows));
+        }
+        Ok(blocks)
+    }
+}
+
+fn orc_error(path: &str, e: impl std::fmt::Display) -> ErrorCode {
+    ErrorCode::BadBytes(format!("fail to read orc file {}: {}", path, e))
+}
+
+fn read_orc_metadata(path: &str, file: Bytes) -> Result<FileMetadata> {
+    let mut reader = file;
+    read_metadata(&mut reader).map_err(|e| orc_error(path, e))
+}
+
+/// Infer the schema of a ORC file from its footer.
+#[async_backtrace::framed]
+pub async fn read_orc_schema_async(operator: &Operator, path: &str) -> Result<TableSchema> {
+    let file = Bytes::from(operator.read(path).await?);
+    let file_meta = read_orc_metadata(path, file)?;
+    let arrow_schema = file_meta
+        .root_data_type()
+        .create_arrow_schema(&HashMap::new());
+    Ok(TableSchema::try_from(&arrow_schema)?)
+}
+
+/// Find the columns in the file for each field of the schema, matching the names case-insensitively.
+fn get_used_columns(
+    file_meta: &FileMetadata,
+    schema: &TableSchemaRef,
+    projection: &Option<Vec<usize>>,
+) -> Result<Arc<Vec<Option<String>>>> {
+    let file_columns = file_meta.root_data_type().children();
+    let mut columns = Vec::with_capacity(schema.num_fields());
+    for (i, f) in schema.fields().iter().enumerate() {
+        if let Some(projection) = projection {
+            if !projection.contains(&i) {
+                columns.push(None);
+                continue;
+            }
+        }
+        match file_columns
+            .iter()
+            .filter(|c| c.name().eq_ignore_ascii_case(f.name()))
+            .last()
+        {
+            Some(c) => columns.push(Some(c.name().to_string())),
+            None => {
+                return Err(ErrorCode::TableSchemaMismatch(format!(
+                    "schema field size mismatch, expected to find column: {}",
+                    f.name()
+                )));
+            }
+        }
+    }
+    Ok(Arc::new(columns))
+}
+
+/// Collect the statistics of the top level columns of a stripe, keyed by the column ids of the schema.
+///
+/// Columns whose statistics can not be converted are absent, which will not be pruned.
+fn collect_stripe_stats(
+    stripe_stats: &[OrcColumnStatistics],
+    num_rows: u64,
+    file_meta: &FileMetadata,
+    schema: &TableSchemaRef,
+) -> StatisticsOfColumns {
+    let file_columns = file_meta.root_data_type().children();
+    let mut stats = HashMap::with_capacity(schema.num_fields());
+    for field in schema.fields().iter() {
+        if field.is_nested() {
+            continue;
+        }
+        let data_type = field.data_type().remove_nullable();
+        let file_column = file_columns
+            .iter()
+            .filter(|c| c.name().eq_ignore_ascii_case(field.name()))
+            .last();
+        let column_stats = file_column
+            .and_then(|c| stripe_stats.get(c.data_type().column_index()))
+            .and_then(|s| convert_column_statistics(s, num_rows, &data_type));
+        if let Some(column_stats) = column_stats {
+            stats.insert(field.column_id(), column_stats);
+        }
+    }
+    stats
+}
+
+fn convert_column_statistics(
+    s: &OrcColumnStatistics,
+    num_rows: u64,
+    typ: &TableDataType,
+) -> Option<ColumnStatistics> {
+    let (min, max) = match (s.type_statistics()?, typ) {
+        (TypeStatistics::Integer { min, max, .. }, TableDataType::Number(number_type)) => {
+            let (min, max) = (*min, *max);
+            match number_type {
+                NumberDataType::Int8 => (Scalar::from(min as i8), Scalar::from(max as i8)),
+                NumberDataType::Int16 => (Scalar::from(min as i16), Scalar::from(max as i16)),
+                NumberDataType::Int32 => (Scalar::from(min as i32), Scalar::from(max as i32)),
+                NumberDataType::Int64 => (Scalar::from(min), Scalar::from(max)),
+                _ => return None,
+            }
+        }
+        (TypeStatistics::Double { min, max, .. }, TableDataType::Number(number_type)) => {
+            let (min, max) = (*min, *max);
+            match number_type {
+                NumberDataType::Float32 => (
+                    Scalar::Number(NumberScalar::Float32(F32::from(min as f32))),
+                    Scalar::Number(NumberScalar::Float32(F32::from(max as f32))),
+                ),
+                NumberDataType::Float64 => (
+                    Scalar::Number(NumberScalar::Float64(F64::from(min))),
+                    Scalar::Number(NumberScalar::Float64(F64::from(max))),
+                ),
+                _ => return None,
+            }
+        }
+        (TypeStatistics::String { min, max, .. }, TableDataType::String) => (
+            Scalar::String(min.as_bytes().to_vec()),
+            Scalar::String(max.as_bytes().to_vec()),
+        ),
+        (TypeStatistics::Date { min, max }, TableDataType::Date) => {
+            (Scalar::Date(*min), Scalar::Date(*max))
+        }
+        _ => return None,
+    };
+    let null_count = num_rows.saturating_sub(s.number_of_values());
+    Some(ColumnStatistics::new(min, max, null_count, 0, None))
+}
@@ -30,6 +30,7 @@ use common_exception::Result;
 use common_expression::BlockThresholds;
 use common_expression::ColumnBuilder;
 use common_expression::DataSchema;
+use common_expression::RemoteExpr;
 use common_expression::Scalar;
 use common_expression::TableSchemaRef;
 use common_formats::ClickhouseFormatType;
@@ -45,8 +46,10 @@ use common_storage::FileStatus;
 use dashmap::DashMap;
 use opendal::Operator;
 
+use crate::input_formats::impls::InputFormatAvro;
 use crate::input_formats::impls::InputFormatCSV;
 use crate::input_formats::impls::InputFormatNDJson;
+use crate::input_formats::impls::InputFormatORC;
 use crate::input_formats::impls::InputFormatParquet;
 use crate::input_formats::impls::InputFormatTSV;
 use crate::input_formats::impls::InputFormatXML;
@@ -134,6 +137,8 @@ pub struct InputContext {
     pub on_error_count: AtomicU64,
     pub on_error_map: Option<Arc<DashMap<String, HashMap<u16, InputError>>>>,
     pub projection: Option<Vec<usize>>,
+    // used to prune by statistics for formats like ORC.
+    pub filter: Option<RemoteExpr<String>>,
 }
 
 impl InputContext {}
@@ -157,6 +162,8 @@ impl InputContext {
             FileFormatParams::NdJson(_) => Ok(Arc::new(InputFormatNDJson::create())),
             FileFormatParams::Parquet(_) => Ok(Arc::new(InputFormatParquet {})),
             FileFormatParams::Xml(_) => Ok(Arc::new(InputFormatXML::create())),
+            FileFormatParams::Orc(_) => Ok(Arc::new(InputFormatORC {})),
+            FileFormatParams::Avro(_) => Ok(Arc::new(InputFormatAvro {})),
             format => Err(ErrorCode::Internal(format!(
                 "Unsupported file format: {:?}",
                 format
@@ -177,6 +184,7 @@ impl InputContext {
         on_error_map: Arc<DashMap<String, HashMap<u16, InputError>>>,
         is_select: bool,
         projection: Option<Vec<usize>>,
+        filter: Option<RemoteExpr<String>>,
         default_values: Option<Vec<Scalar>>,
     ) -> Result<Self> {
         let mut file_format_options_ext =
@@ -207,6 +215,7 @@ impl InputContext {
             on_error_count: AtomicU64::new(0),
             on_error_map: Some(on_error_map),
             projection,
+            filter,
             default_values,
         })
     }
@@ -264,6 +273,7 @@ impl InputContext {
             on_error_count: AtomicU64::new(0),
             on_error_map: None,
             projection: None,
+            filter: None,
             default_values: None,
         })
     }
@@ -307,6 +317,7 @@ impl InputContext {
             on_error_count: AtomicU64::new(0),
             on_error_map: None,
             projection: None,
+            filter: None,
             default_values: None,
         })
     }
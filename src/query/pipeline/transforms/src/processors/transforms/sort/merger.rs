@@ -0,0 +1,298 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::collections::VecDeque;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::SortColumnDescription;
+
+use super::Cursor;
+use super::Rows;
+
+/// A stream of sorted blocks, the order column of each block is its last column.
+pub trait SortedStream {
+    /// Returns the next block, and if the stream is pending.
+    ///
+    /// `(None, false)` means the stream is finished, `(None, true)` means the next block
+    /// is not ready yet, the stream should be polled again after it is prepared.
+    fn next(&mut self) -> Result<(Option<DataBlock>, bool)>;
+}
+
+/// Merge multiple sorted streams into one with a min-heap of [`Cursor`].
+pub struct Merger<R: Rows, S: SortedStream> {
+    streams: Vec<S>,
+    sort_desc: Vec<SortColumnDescription>,
+
+    // Parameters
+    block_size: usize,
+    limit: Option<usize>,
+
+    /// For each stream, maintain a dequeue of data blocks.
+    blocks: Vec<VecDeque<DataBlock>>,
+    /// The streams whose cursor is finished, the next block needs to be polled.
+    pending_streams: VecDeque<usize>,
+    /// The accumulated rows for the next output data block.
+    ///
+    /// Data format: (stream_index, block_index, row_index)
+    in_progress_rows: Vec<(usize, usize, usize)>,
+    /// Heap that yields [`Cursor`] in increasing order.
+    heap: BinaryHeap<Reverse<Cursor<R>>>,
+}
+
+impl<R: Rows, S: SortedStream> Merger<R, S> {
+    pub fn create(
+        streams: Vec<S>,
+        sort_desc: Vec<SortColumnDescription>,
+        block_size: usize,
+        limit: Option<usize>,
+    ) -> Self {
+        let size = streams.len();
+        Self {
+            streams,
+            sort_desc,
+            block_size,
+            limit,
+            blocks: vec![VecDeque::with_capacity(2); size],
+            pending_streams: (0..size).collect(),
+            in_progress_rows: vec![],
+            heap: BinaryHeap::with_capacity(size),
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.limit == Some(0)
+            || (self.heap.is_empty()
+                && self.pending_streams.is_empty()
+                && self.in_progress_rows.is_empty())
+    }
+
+    pub fn has_pending_streams(&self) -> bool {
+        !self.pending_streams.is_empty()
+    }
+
+    pub fn pending_streams(&self) -> impl Iterator<Item = usize> + '_ {
+        self.pending_streams.iter().copied()
+    }
+
+    pub fn streams_mut(&mut self) -> &mut [S] {
+        &mut self.streams
+    }
+
+    /// Poll the pending streams, push the cursors of the ready blocks into the heap.
+    pub fn poll_pending_streams(&mut self) -> Result<()> {
+        let mut still_pending = VecDeque::new();
+        while let Some(index) = self.pending_streams.pop_front() {
+            match self.streams[index].next()? {
+                (Some(block), _) if block.is_empty() => self.pending_streams.push_back(index),
+                (Some(block), _) => {
+                    let block = block.convert_to_full();
+                    let order_col = block
+                        .columns()
+                        .last()
+                        .ok_or_else(|| ErrorCode::Internal("Sorted block has no order column"))?
+                        .value
+                        .as_column()
+                        .unwrap()
+                        .clone();
+                    let rows = R::from_column(order_col, &self.sort_desc).ok_or_else(|| {
+                        ErrorCode::BadDataValueType("Order column type mismatched.")
+                    })?;
+                    // Remove the order column
+                    let block = block.pop_columns(1)?;
+                    self.heap.push(Reverse(Cursor::new(index, rows)));
+                    self.blocks[index].push_back(block);
+                }
+                (None, true) => still_pending.push_back(index),
+                (None, false) => {}
+            }
+        }
+        self.pending_streams = still_pending;
+        Ok(())
+    }
+
+    /// Returns the next merged block. `None` means the merger is finished, or some
+    /// streams are pending, see [`Merger::has_pending_streams`].
+    pub fn next_block(&mut self) -> Result<Option<DataBlock>> {
+        loop {
+            if self.limit == Some(0) {
+                return Ok(None);
+            }
+
+            self.poll_pending_streams()?;
+            if self.has_pending_streams() {
+                // The cursors of all the unfinished streams must be in the heap to go on merging.
+                return Ok(None);
+            }
+
+            if self.drain_heap() || (self.heap.is_empty() && !self.in_progress_rows.is_empty()) {
+                let block = self.build_block()?;
+                self.limit = self
+                    .limit
+                    .map(|limit| limit.saturating_sub(block.num_rows()));
+                return Ok(Some(block));
+            }
+
+            if self.heap.is_empty() {
+                return Ok(None);
+            }
+        }
+    }
+
+    // Return if need output
+    #[inline]
+    fn drain_cursor(&mut self, mut cursor: Cursor<R>) -> bool {
+        let stream_index = cursor.input_index;
+        let block_index = self.blocks[stream_index].len() - 1;
+        while !cursor.is_finished() {
+            self.in_progress_rows
+                .push((stream_index, block_index, cursor.advance()));
+            if let Some(limit) = self.limit {
+                if self.in_progress_rows.len() == limit {
+                    // The rest rows are beyond the limit, no need to read them.
+                    return true;
+                }
+            }
+        }
+        // We have read all rows of this block, need to read a new one.
+        self.pending_streams.push_back(stream_index);
+        false
+    }
+
+    // Drain the heap into `in_progress_rows`, returns if need output.
+    fn drain_heap(&mut self) -> bool {
+        while let Some(Reverse(cursor)) = self.heap.peek() {
+            let stream_index = cursor.input_index;
+            let mut need_output = false;
+            if self.heap.len() == 1 {
+                let cursor = self.heap.pop().unwrap().0;
+                // If there is no other block in the heap, we can drain the whole block.
+                need_output = self.drain_cursor(cursor);
+            } else {
+                let next_cursor = &find_bigger_child_of_root(&self.heap).0;
+                if cursor.last().le(&next_cursor.current()) {
+                    // If the last row of current block is smaller than the next cursor,
+                    // we can drain the whole block.
+                    let cursor = self.heap.pop().unwrap().0;
+                    need_output = self.drain_cursor(cursor);
+                } else {
+                    // We copy current cursor for advancing,
+                    // and we will use this copied cursor to update the top of the heap at last
+                    // (let heap adjust itself without popping and pushing any element).
+                    let mut cursor = cursor.clone();
+                    let block_index = self.blocks[stream_index].len() - 1;
+                    while !cursor.is_finished() && cursor.le(next_cursor) {
+                        // If the cursor is smaller than the next cursor, don't need to push the cursor back to the heap.
+                        self.in_progress_rows
+                            .push((stream_index, block_index, cursor.advance()));
+                        if let Some(limit) = self.limit {
+                            if self.in_progress_rows.len() == limit {
+                                need_output = true;
+                                break;
+                            }
+                        }
+                    }
+
+                    if !cursor.is_finished() {
+                        // Update the top of the heap.
+                        // `self.heap.peek_mut` will return a `PeekMut` object which allows us to modify the top element of the heap.
+                        // The heap will adjust itself automatically when the `PeekMut` object is dropped (RAII).
+                        self.heap.peek_mut().unwrap().0 = cursor;
+                    } else {
+                        // Pop the current `cursor`.
+                        self.heap.pop();
+                        // We have read all rows of this block, need to read a new one.
+                        self.pending_streams.push_back(stream_index);
+                    }
+                }
+            }
+
+            // Reach the block size or the limit, need to output.
+            if need_output || self.in_progress_rows.len() >= self.block_size {
+                return true;
+            }
+            if self.has_pending_streams() {
+                // Correctness: the stream may have more data, we need to poll it
+                // before going on merging.
+                return false;
+            }
+        }
+        false
+    }
+
+    /// Drain `self.in_progress_rows` to build a output data block.
+    fn build_block(&mut self) -> Result<DataBlock> {
+        debug_assert!(!self.in_progress_rows.is_empty());
+
+        let mut blocks_num_pre_sum = Vec::with_capacity(self.blocks.len());
+        let mut len = 0;
+        for block in self.blocks.iter() {
+            blocks_num_pre_sum.push(len);
+            len += block.len();
+        }
+
+        // Compute the indices of the output block.
+        let first_row = &self.in_progress_rows[0];
+        let mut index = blocks_num_pre_sum[first_row.0] + first_row.1;
+        let mut start_row_index = first_row.2;
+        let mut end_row_index = start_row_index + 1;
+        let mut indices = Vec::new();
+        for row in self.in_progress_rows.iter().skip(1) {
+            let next_index = blocks_num_pre_sum[row.0] + row.1;
+            if next_index == index {
+                // Within a same block.
+                end_row_index += 1;
+                continue;
+            }
+            // next_index != index
+            // Record a range in the block.
+            indices.push((index, start_row_index, end_row_index - start_row_index));
+            // Start to record a new block.
+            index = next_index;
+            start_row_index = row.2;
+            end_row_index = start_row_index + 1;
+        }
+        indices.push((index, start_row_index, end_row_index - start_row_index));
+
+        let candidate_blocks = self.blocks.iter().flatten().cloned().collect::<Vec<_>>();
+        let output = DataBlock::take_by_slices_limit_from_blocks(&candidate_blocks, &indices, None);
+
+        // Clear no need data.
+        self.in_progress_rows.clear();
+        // A cursor pointing to a new block is created only if the previous block is finished.
+        // This means that all blocks except the last one for each stream are drained into the output block.
+        // Therefore, the previous blocks can be cleared.
+        for blocks in self.blocks.iter_mut() {
+            if blocks.len() > 1 {
+                blocks.drain(0..(blocks.len() - 1));
+            }
+        }
+
+        Ok(output)
+    }
+}
+
+/// Find the bigger child of the root of the heap.
+#[inline(always)]
+fn find_bigger_child_of_root<T: Ord>(heap: &BinaryHeap<T>) -> &T {
+    debug_assert!(heap.len() >= 2);
+    let slice = heap.as_slice();
+    if heap.len() == 2 {
+        &slice[1]
+    } else {
+        (&slice[1]).max(&slice[2])
+    }
+}
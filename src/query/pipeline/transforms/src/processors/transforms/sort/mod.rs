@@ -13,7 +13,9 @@
 // limitations under the License.
 
 mod cursor;
+mod merger;
 mod rows;
 
 pub use cursor::*;
+pub use merger::*;
 pub use rows::*;
@@ -13,9 +13,6 @@
 // limitations under the License.
 
 use std::any::Any;
-use std::cmp::Reverse;
-use std::collections::BinaryHeap;
-use std::collections::VecDeque;
 use std::sync::Arc;
 
 use common_exception::ErrorCode;
@@ -40,9 +37,10 @@ use common_pipeline_core::processors::processor::ProcessorPtr;
 use common_pipeline_core::processors::Processor;
 use common_pipeline_core::Pipeline;
 
-use super::sort::Cursor;
+use super::sort::Merger;
 use super::sort::Rows;
 use super::sort::SimpleRows;
+use super::sort::SortedStream;
 
 pub fn try_add_multi_sort_merge(
     pipeline: &mut Pipeline,
@@ -155,6 +153,32 @@ fn create_processor(
     })
 }
 
+/// An input port as a [`SortedStream`].
+pub struct InputBlockStream {
+    input: Arc<InputPort>,
+}
+
+impl InputBlockStream {
+    pub fn create(input: Arc<InputPort>) -> Self {
+        Self { input }
+    }
+}
+
+impl SortedStream for InputBlockStream {
+    fn next(&mut self) -> Result<(Option<DataBlock>, bool)> {
+        if self.input.has_data() {
+            let block = self.input.pull_data().unwrap()?;
+            self.input.set_need_data();
+            return Ok((Some(block), false));
+        }
+        if self.input.is_finished() {
+            return Ok((None, false));
+        }
+        self.input.set_need_data();
+        Ok((None, true))
+    }
+}
+
 /// TransformMultiSortMerge is a processor with multiple input ports;
 pub struct MultiSortMergeProcessor<R>
 where R: Rows
@@ -162,28 +186,10 @@ where R: Rows
     /// Data from inputs (every input is sorted)
     inputs: Vec<Arc<InputPort>>,
     output: Arc<OutputPort>,
+    merger: Merger<R, InputBlockStream>,
 
-    sort_desc: Vec<SortColumnDescription>,
-
-    // Parameters
-    block_size: usize,
-    limit: Option<usize>,
-
-    /// For each input port, maintain a dequeue of data blocks.
-    blocks: Vec<VecDeque<DataBlock>>,
-    /// Maintain a flag for each input denoting if the current cursor has finished
-    /// and needs to pull data from input.
-    cursor_finished: Vec<bool>,
-    /// The accumulated rows for the next output data block.
-    ///
-    /// Data format: (input_index, block_index, row_index)
-    in_progress_rows: Vec<(usize, usize, usize)>,
-    /// Heap that yields [`Cursor`] in increasing order.
-    heap: BinaryHeap<Reverse<Cursor<R>>>,
-    /// If the input port is finished.
-    input_finished: Vec<bool>,
-
-    state: ProcessorState,
+    /// The next output data block.
+    output_data: Option<DataBlock>,
 }
 
 impl<R> MultiSortMergeProcessor<R>
@@ -196,196 +202,18 @@ where R: Rows
         limit: Option<usize>,
         sort_desc: Vec<SortColumnDescription>,
     ) -> Result<Self> {
-        let input_size = inputs.len();
+        let streams = inputs
+            .iter()
+            .map(|input| InputBlockStream::create(input.clone()))
+            .collect::<Vec<_>>();
+        let merger = Merger::create(streams, sort_desc, block_size, limit);
         Ok(Self {
             inputs,
             output,
-            sort_desc,
-            block_size,
-            limit,
-            blocks: vec![VecDeque::with_capacity(2); input_size],
-            heap: BinaryHeap::with_capacity(input_size),
-            in_progress_rows: vec![],
-            cursor_finished: vec![true; input_size],
-            input_finished: vec![false; input_size],
-            state: ProcessorState::Consume,
+            merger,
+            output_data: None,
         })
     }
-
-    fn get_data_blocks(&mut self) -> Result<Vec<(usize, DataBlock)>> {
-        let mut data = Vec::new();
-        for (i, input) in self.inputs.iter().enumerate() {
-            if input.is_finished() {
-                self.input_finished[i] = true;
-                continue;
-            }
-
-            input.set_need_data();
-            if self.cursor_finished[i] && input.has_data() {
-                data.push((i, input.pull_data().unwrap()?));
-            }
-        }
-        Ok(data)
-    }
-
-    fn nums_active_inputs(&self) -> usize {
-        self.input_finished
-            .iter()
-            .zip(self.cursor_finished.iter())
-            .filter(|(f, c)| !**f || !**c)
-            .count()
-    }
-
-    // Return if need output
-    #[inline]
-    fn drain_cursor(&mut self, mut cursor: Cursor<R>) -> bool {
-        let input_index = cursor.input_index;
-        let block_index = self.blocks[input_index].len() - 1;
-        while !cursor.is_finished() {
-            self.in_progress_rows
-                .push((input_index, block_index, cursor.advance()));
-            if let Some(limit) = self.limit {
-                if self.in_progress_rows.len() == limit {
-                    return true;
-                }
-            }
-        }
-        // We have read all rows of this block, need to read a new one.
-        self.cursor_finished[input_index] = true;
-        false
-    }
-
-    fn drain_heap(&mut self) {
-        let nums_active_inputs = self.nums_active_inputs();
-        let mut need_output = false;
-        // Need to pop data to in_progress_rows.
-        // Use `>=` because some of the input ports may be finished, but the data is still in the heap.
-        while self.heap.len() >= nums_active_inputs && !need_output {
-            match self.heap.peek() {
-                Some(Reverse(cursor)) => {
-                    let input_index = cursor.input_index;
-                    if self.heap.len() == 1 {
-                        let cursor = self.heap.pop().unwrap().0;
-                        // If there is no other block in the heap, we can drain the whole block.
-                        need_output = self.drain_cursor(cursor);
-                    } else {
-                        let next_cursor = &find_bigger_child_of_root(&self.heap).0;
-                        if cursor.last().le(&next_cursor.current()) {
-                            // If the last row of current block is smaller than the next cursor,
-                            // we can drain the whole block.
-                            let cursor = self.heap.pop().unwrap().0;
-                            need_output = self.drain_cursor(cursor);
-                        } else {
-                            // We copy current cursor for advancing,
-                            // and we will use this copied cursor to update the top of the heap at last
-                            // (let heap adjust itself without popping and pushing any element).
-                            let mut cursor = cursor.clone();
-                            let block_index = self.blocks[input_index].len() - 1;
-                            while !cursor.is_finished() && cursor.le(next_cursor) {
-                                // If the cursor is smaller than the next cursor, don't need to push the cursor back to the heap.
-                                self.in_progress_rows.push((
-                                    input_index,
-                                    block_index,
-                                    cursor.advance(),
-                                ));
-                                if let Some(limit) = self.limit {
-                                    if self.in_progress_rows.len() == limit {
-                                        need_output = true;
-                                        break;
-                                    }
-                                }
-                            }
-
-                            if !cursor.is_finished() {
-                                // Update the top of the heap.
-                                // `self.heap.peek_mut` will return a `PeekMut` object which allows us to modify the top element of the heap.
-                                // The heap will adjust itself automatically when the `PeekMut` object is dropped (RAII).
-                                self.heap.peek_mut().unwrap().0 = cursor;
-                            } else {
-                                // Pop the current `cursor`.
-                                self.heap.pop();
-                                // We have read all rows of this block, need to read a new one.
-                                self.cursor_finished[input_index] = true;
-                            }
-                        }
-                    }
-                    // Reach the block size, need to output.
-                    if self.in_progress_rows.len() >= self.block_size {
-                        need_output = true;
-                        break;
-                    }
-                    if self.cursor_finished[input_index] && !self.input_finished[input_index] {
-                        // Correctness: if input is not finished, we need to pull more data,
-                        // or we can continue this loop.
-                        break;
-                    }
-                }
-                None => {
-                    // Special case: self.heap.len() == 0 && nums_active_inputs == 0.
-                    // `self.in_progress_rows` cannot be empty.
-                    // If reach here, it means that all inputs are finished but `self.heap` is not empty before the while loop.
-                    // Therefore, when reach here, data in `self.heap` is all drained into `self.in_progress_rows`.
-                    debug_assert!(!self.in_progress_rows.is_empty());
-                    self.state = ProcessorState::Output;
-                    break;
-                }
-            }
-        }
-        if need_output {
-            self.state = ProcessorState::Output;
-        }
-    }
-
-    /// Drain `self.in_progress_rows` to build a output data block.
-    fn build_block(&mut self) -> Result<DataBlock> {
-        debug_assert!(!self.in_progress_rows.is_empty());
-
-        let mut blocks_num_pre_sum = Vec::with_capacity(self.blocks.len());
-        let mut len = 0;
-        for block in self.blocks.iter() {
-            blocks_num_pre_sum.push(len);
-            len += block.len();
-        }
-
-        // Compute the indices of the output block.
-        let first_row = &self.in_progress_rows[0];
-        let mut index = blocks_num_pre_sum[first_row.0] + first_row.1;
-        let mut start_row_index = first_row.2;
-        let mut end_row_index = start_row_index + 1;
-        let mut indices = Vec::new();
-        for row in self.in_progress_rows.iter().skip(1) {
-            let next_index = blocks_num_pre_sum[row.0] + row.1;
-            if next_index == index {
-                // Within a same block.
-                end_row_index += 1;
-                continue;
-            }
-            // next_index != index
-            // Record a range in the block.
-            indices.push((index, start_row_index, end_row_index - start_row_index));
-            // Start to record a new block.
-            index = next_index;
-            start_row_index = row.2;
-            end_row_index = start_row_index + 1;
-        }
-        indices.push((index, start_row_index, end_row_index - start_row_index));
-
-        let candidate_blocks = self.blocks.iter().flatten().cloned().collect::<Vec<_>>();
-        let output = DataBlock::take_by_slices_limit_from_blocks(&candidate_blocks, &indices, None);
-
-        // Clear no need data.
-        self.in_progress_rows.clear();
-        // A cursor pointing to a new block is created only if the previous block is finished.
-        // This means that all blocks except the last one for each input port are drained into the output block.
-        // Therefore, the previous blocks can be cleared.
-        for blocks in self.blocks.iter_mut() {
-            if blocks.len() > 1 {
-                blocks.drain(0..(blocks.len() - 1));
-            }
-        }
-
-        Ok(output)
-    }
 }
 
 #[async_trait::async_trait]
@@ -412,128 +240,31 @@ where R: Rows + Send + 'static
             return Ok(Event::NeedConsume);
         }
 
-        if let Some(limit) = self.limit {
-            if limit == 0 {
-                for input in self.inputs.iter() {
-                    input.finish();
-                }
-                self.output.finish();
-                return Ok(Event::Finished);
-            }
+        if let Some(data_block) = self.output_data.take() {
+            self.output.push_data(Ok(data_block));
+            return Ok(Event::NeedConsume);
         }
 
-        if matches!(self.state, ProcessorState::Generated(_)) {
-            if let ProcessorState::Generated(data_block) =
-                std::mem::replace(&mut self.state, ProcessorState::Consume)
-            {
-                self.limit = self.limit.map(|limit| {
-                    if data_block.num_rows() > limit {
-                        0
-                    } else {
-                        limit - data_block.num_rows()
-                    }
-                });
-                self.output.push_data(Ok(data_block));
-                return Ok(Event::NeedConsume);
+        if self.merger.is_finished() {
+            for input in self.inputs.iter() {
+                input.finish();
             }
+            self.output.finish();
+            return Ok(Event::Finished);
         }
 
-        match &self.state {
-            ProcessorState::Consume => {
-                let data_blocks = self.get_data_blocks()?;
-                if !data_blocks.is_empty() {
-                    self.state = ProcessorState::Preserve(data_blocks);
-                    return Ok(Event::Sync);
-                }
-
-                let active_inputs = self.nums_active_inputs();
-
-                if active_inputs == 0 {
-                    if !self.heap.is_empty() {
-                        // The heap is not drained yet. Need to drain data into in_progress_rows.
-                        self.state = ProcessorState::Preserve(vec![]);
-                        return Ok(Event::Sync);
-                    }
-                    if !self.in_progress_rows.is_empty() {
-                        // The in_progress_rows is not drained yet. Need to drain data into output.
-                        self.state = ProcessorState::Output;
-                        return Ok(Event::Sync);
-                    }
-                    self.output.finish();
-                    Ok(Event::Finished)
-                } else {
-                    // `data_blocks` is empty
-                    if !self.heap.is_empty() {
-                        // The heap is not drained yet. Need to drain data into in_progress_rows.
-                        self.state = ProcessorState::Preserve(vec![]);
-                        Ok(Event::Sync)
-                    } else {
-                        Ok(Event::NeedData)
-                    }
-                }
-            }
-            ProcessorState::Output => Ok(Event::Sync),
-            _ => Err(ErrorCode::Internal("It's a bug.")),
+        self.merger.poll_pending_streams()?;
+        if self.merger.has_pending_streams() {
+            return Ok(Event::NeedData);
         }
+
+        Ok(Event::Sync)
     }
 
     fn process(&mut self) -> Result<()> {
-        match std::mem::replace(&mut self.state, ProcessorState::Consume) {
-            ProcessorState::Preserve(blocks) => {
-                for (input_index, block) in blocks.into_iter() {
-                    if block.is_empty() {
-                        continue;
-                    }
-                    let block = block.convert_to_full();
-                    let order_col = block
-                        .columns()
-                        .last()
-                        .unwrap()
-                        .value
-                        .as_column()
-                        .unwrap()
-                        .clone();
-                    let rows = R::from_column(order_col, &self.sort_desc).ok_or_else(|| {
-                        ErrorCode::BadDataValueType("Order column type mismatched.")
-                    })?;
-                    // Remove the order column
-                    let block = block.pop_columns(1)?;
-                    let cursor = Cursor::new(input_index, rows);
-                    self.heap.push(Reverse(cursor));
-                    self.cursor_finished[input_index] = false;
-                    self.blocks[input_index].push_back(block);
-                }
-                self.drain_heap();
-                Ok(())
-            }
-            ProcessorState::Output => {
-                let block = self.build_block()?;
-                self.state = ProcessorState::Generated(block);
-                Ok(())
-            }
-            _ => Err(ErrorCode::Internal("It's a bug.")),
+        if let Some(block) = self.merger.next_block()? {
+            self.output_data = Some(block);
         }
-    }
-}
-
-enum ProcessorState {
-    Consume,
-    // Need to consume data from input.
-    Preserve(Vec<(usize, DataBlock)>),
-    // Need to preserve blocks in memory.
-    Output,
-    // Need to generate output block.
-    Generated(DataBlock), // Need to push output block to output port.
-}
-
-/// Find the bigger child of the root of the heap.
-#[inline(always)]
-fn find_bigger_child_of_root<T: Ord>(heap: &BinaryHeap<T>) -> &T {
-    debug_assert!(heap.len() >= 2);
-    let slice = heap.as_slice();
-    if heap.len() == 2 {
-        &slice[1]
-    } else {
-        (&slice[1]).max(&slice[2])
+        Ok(())
     }
 }
@@ -36,7 +36,32 @@ pub fn build_full_sort_pipeline(
     prof_info: Option<(u32, SharedProcessorProfiles)>,
     after_exchange: bool,
 ) -> Result<()> {
-    // Partial sort
+    build_partial_sort_pipeline(
+        pipeline,
+        sort_desc.clone(),
+        limit,
+        prof_info.clone(),
+        after_exchange,
+    )?;
+
+    build_merge_sort_pipeline(
+        pipeline,
+        input_schema,
+        sort_desc,
+        limit,
+        partial_block_size,
+        final_block_size,
+        prof_info,
+    )
+}
+
+pub fn build_partial_sort_pipeline(
+    pipeline: &mut Pipeline,
+    sort_desc: Vec<SortColumnDescription>,
+    limit: Option<usize>,
+    prof_info: Option<(u32, SharedProcessorProfiles)>,
+    after_exchange: bool,
+) -> Result<()> {
     if limit.is_none() || !after_exchange {
         // If the sort plan is after an exchange plan, the blocks are already partially sorted on other nodes.
         pipeline.add_transform(|input, output| {
@@ -53,16 +78,7 @@ pub fn build_full_sort_pipeline(
             }
         })?;
     }
-
-    build_merge_sort_pipeline(
-        pipeline,
-        input_schema,
-        sort_desc,
-        limit,
-        partial_block_size,
-        final_block_size,
-        prof_info,
-    )
+    Ok(())
 }
 
 pub fn build_merge_sort_pipeline(
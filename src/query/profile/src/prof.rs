@@ -63,6 +63,7 @@ pub enum OperatorType {
     ProjectSet,
     EvalScalar,
     Lambda,
+    AsyncFunction,
     Limit,
     TableScan,
     CteScan,
@@ -87,6 +88,7 @@ impl Display for OperatorType {
             OperatorType::ProjectSet => write!(f, "ProjectSet"),
             OperatorType::EvalScalar => write!(f, "EvalScalar"),
             OperatorType::Lambda => write!(f, "Lambda"),
+            OperatorType::AsyncFunction => write!(f, "AsyncFunction"),
             OperatorType::Limit => write!(f, "Limit"),
             OperatorType::TableScan => write!(f, "TableScan"),
             OperatorType::Sort => write!(f, "Sort"),
@@ -0,0 +1,488 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use common_base::base::tokio::sync::mpsc::unbounded_channel;
+use common_base::base::tokio::sync::mpsc::UnboundedReceiver;
+use common_base::base::tokio::sync::mpsc::UnboundedSender;
+use common_base::base::tokio::sync::Semaphore;
+use common_base::base::tokio::time::sleep;
+use common_base::base::GlobalInstance;
+use common_base::runtime::GlobalIORuntime;
+use common_base::runtime::TrySpawn;
+use common_catalog::table_context::TableContext;
+use common_config::InnerBackgroundAutoCompactionConfig;
+use common_config::InnerConfig;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::UserInfo;
+use common_meta_types::MetaId;
+use common_sql::plans::OptimizeTableAction;
+use common_sql::plans::OptimizeTablePlan;
+use common_sql::plans::ReclusterTablePlan;
+use common_sql::Metadata;
+use common_storages_fuse::table_functions::ClusteringInformation;
+use common_storages_fuse::FuseTable;
+use common_storages_fuse::DEFAULT_BLOCK_PER_SEGMENT;
+use common_storages_fuse::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
+use common_storages_system::AutoCompactionHistoryLogElement;
+use common_storages_system::AutoCompactionHistoryQueue;
+use futures::StreamExt;
+use log::info;
+use log::warn;
+use parking_lot::Mutex;
+use parking_lot::RwLock;
+use storages_common_table_meta::meta::Statistics;
+
+use crate::interpreters::CompactTargetTableDescription;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::interpreters::OptimizeTableInterpreter;
+use crate::interpreters::ReclusterTableInterpreter;
+use crate::sessions::QueryContext;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AutoCompactAction {
+    CompactSegments,
+    CompactBlocks,
+    Recluster,
+}
+
+impl AutoCompactAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AutoCompactAction::CompactSegments => "compact_segments",
+            AutoCompactAction::CompactBlocks => "compact_blocks",
+            AutoCompactAction::Recluster => "recluster",
+        }
+    }
+}
+
+/// The clustering depth of a table with cluster keys.
+#[derive(Clone, Copy, Debug)]
+pub struct ClusteringDepth {
+    /// The average depth of the blocks.
+    pub average_depth: f64,
+    /// The average depth above which the table is reclustered, see
+    /// [`FuseTable::recluster_depth_threshold`].
+    pub threshold: f64,
+}
+
+/// Decide the compactions that the table needs by the statistics of its latest snapshot.
+///
+/// Segments are compacted first, since it only rewrites the segment files. The table with
+/// cluster keys is reclustered once the average depth of its blocks exceeds the threshold.
+pub fn check_auto_compaction(
+    config: &InnerBackgroundAutoCompactionConfig,
+    summary: &Statistics,
+    segment_count: u64,
+    block_per_segment: u64,
+    clustering: Option<ClusteringDepth>,
+) -> Vec<AutoCompactAction> {
+    let mut actions = vec![];
+    let block_per_segment = block_per_segment.max(1);
+    let expected_segment_count =
+        ((summary.block_count + block_per_segment - 1) / block_per_segment).max(1);
+    if segment_count >= expected_segment_count + config.segment_threshold.max(1) {
+        actions.push(AutoCompactAction::CompactSegments);
+    }
+
+    let imperfect_block_count = summary
+        .block_count
+        .saturating_sub(summary.perfect_block_count);
+    if imperfect_block_count >= config.block_threshold.max(1) {
+        actions.push(AutoCompactAction::CompactBlocks);
+    }
+
+    if clustering.is_some_and(|clustering| clustering.average_depth > clustering.threshold) {
+        actions.push(AutoCompactAction::Recluster);
+    }
+    actions
+}
+
+/// The result of a notification to [`AutoCompactSchedule`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum Schedule<T> {
+    /// Start the compaction of the table now.
+    Start(T),
+    /// The table is being compacted or cooling down, the request is kept as pending.
+    /// Re-check the table after the delay if there is one, see [`AutoCompactSchedule::take_pending`].
+    Pending(Option<Duration>),
+}
+
+/// Schedules the compactions of the tables.
+///
+/// A table is compacted by one task at a time, and at most once per cool down. The latest
+/// notification arriving during the compaction or the cool down is kept as pending, and
+/// the table is re-checked once the cool down ends.
+pub struct AutoCompactSchedule<T> {
+    cooldown: Duration,
+    // the tables being compacted.
+    running: HashSet<String>,
+    // the start time of the last compaction of each table.
+    last_runs: HashMap<String, Instant>,
+    // the latest notification of each table that is not started yet.
+    pending: HashMap<String, T>,
+}
+
+impl<T> AutoCompactSchedule<T> {
+    pub fn create(cooldown: Duration) -> Self {
+        Self {
+            cooldown,
+            running: HashSet::new(),
+            last_runs: HashMap::new(),
+            pending: HashMap::new(),
+        }
+    }
+
+    pub fn notify(&mut self, key: &str, request: T, now: Instant) -> Schedule<T> {
+        if self.running.contains(key) {
+            // re-checked once the running compaction is finished.
+            self.pending.insert(key.to_string(), request);
+            return Schedule::Pending(None);
+        }
+        let remaining = self.remaining_cooldown(key, now);
+        if !remaining.is_zero() {
+            let is_new = self.pending.insert(key.to_string(), request).is_none();
+            return Schedule::Pending(is_new.then_some(remaining));
+        }
+        self.pending.remove(key);
+        self.last_runs.insert(key.to_string(), now);
+        self.running.insert(key.to_string());
+        Schedule::Start(request)
+    }
+
+    /// The compaction of the table is finished, returns the delay to re-check the table
+    /// if there are notifications during the compaction.
+    pub fn finish(&mut self, key: &str, now: Instant) -> Option<Duration> {
+        self.running.remove(key);
+        self.pending
+            .contains_key(key)
+            .then(|| self.remaining_cooldown(key, now))
+    }
+
+    /// Take the pending notification of the table to re-check it.
+    pub fn take_pending(&mut self, key: &str) -> Option<T> {
+        self.pending.remove(key)
+    }
+
+    fn remaining_cooldown(&self, key: &str, now: Instant) -> Duration {
+        self.last_runs
+            .get(key)
+            .map(|last_run| self.cooldown.saturating_sub(now.duration_since(*last_run)))
+            .unwrap_or_default()
+    }
+}
+
+struct AutoCompactRequest {
+    user: UserInfo,
+    target: CompactTargetTableDescription,
+    // the table is resolved by id, the name is only used to build the compaction plans.
+    table_id: MetaId,
+    trigger: String,
+}
+
+impl AutoCompactRequest {
+    fn key(&self) -> String {
+        format!("{}.{}", self.target.catalog, self.table_id)
+    }
+
+    fn name(&self) -> String {
+        format!(
+            "{}.{}.{}",
+            self.target.catalog, self.target.database, self.target.table
+        )
+    }
+}
+
+/// Compacts the tables in background after they are written.
+///
+/// The write interpreters notify the compactor once their commits succeed, the compactor
+/// checks the latest snapshot statistics of the table, and runs segment compaction, block
+/// compaction or recluster if the thresholds are reached. The compactions are scheduled by
+/// [`AutoCompactSchedule`], the notifications arriving during the compaction or cool down
+/// of a table are re-checked once the cool down ends.
+pub struct AutoCompactor {
+    config: InnerBackgroundAutoCompactionConfig,
+    tx: UnboundedSender<AutoCompactRequest>,
+    schedule: Mutex<AutoCompactSchedule<AutoCompactRequest>>,
+}
+
+impl AutoCompactor {
+    pub fn init(conf: &InnerConfig) -> Result<()> {
+        let config = conf.background.auto_compaction.clone();
+        if !config.enable {
+            return Ok(());
+        }
+
+        let (tx, rx) = unbounded_channel();
+        let cooldown = Duration::from_secs(config.cooldown_secs);
+        let compactor = Arc::new(AutoCompactor {
+            config,
+            tx,
+            schedule: Mutex::new(AutoCompactSchedule::create(cooldown)),
+        });
+        GlobalIORuntime::instance().spawn("auto-compactor", compactor.clone().work(rx));
+        GlobalInstance::set(compactor);
+        Ok(())
+    }
+
+    /// Returns None if the auto compaction is disabled.
+    pub fn try_instance() -> Option<Arc<AutoCompactor>> {
+        GlobalInstance::try_get()
+    }
+
+    pub fn notify(
+        &self,
+        ctx: &QueryContext,
+        target: CompactTargetTableDescription,
+        table_id: MetaId,
+        trigger: &str,
+    ) -> Result<()> {
+        let request = AutoCompactRequest {
+            user: ctx.get_current_user()?,
+            target,
+            table_id,
+            trigger: trigger.to_string(),
+        };
+        // the receiver lives as long as the compactor.
+        let _ = self.tx.send(request);
+        Ok(())
+    }
+
+    async fn work(self: Arc<Self>, mut rx: UnboundedReceiver<AutoCompactRequest>) {
+        let semaphore = Arc::new(Semaphore::new(self.config.max_concurrency as usize));
+        while let Some(request) = rx.recv().await {
+            let key = request.key();
+            let name = request.name();
+            let schedule = self.schedule.lock().notify(&key, request, Instant::now());
+            let request = match schedule {
+                Schedule::Start(request) => request,
+                Schedule::Pending(Some(delay)) => {
+                    self.recheck_after(key, delay);
+                    continue;
+                }
+                Schedule::Pending(None) => continue,
+            };
+
+            let permit = match semaphore.clone().acquire_owned().await {
+                Ok(permit) => permit,
+                Err(_) => break,
+            };
+            let compactor = self.clone();
+            GlobalIORuntime::instance().spawn("auto-compactor", async move {
+                if let Err(e) = compactor.compact(&request).await {
+                    warn!("auto compaction of table {} failed: {}", name, e);
+                }
+                let recheck = compactor.schedule.lock().finish(&key, Instant::now());
+                if let Some(delay) = recheck {
+                    compactor.recheck_after(key, delay);
+                }
+                drop(permit);
+            });
+        }
+    }
+
+    // Send the pending notification of the table again after the delay.
+    fn recheck_after(self: &Arc<Self>, key: String, delay: Duration) {
+        let compactor = self.clone();
+        GlobalIORuntime::instance().spawn("auto-compactor", async move {
+            sleep(delay).await;
+            let pending = compactor.schedule.lock().take_pending(&key);
+            if let Some(request) = pending {
+                // the receiver lives as long as the compactor.
+                let _ = compactor.tx.send(request);
+            }
+        });
+    }
+
+    async fn compact(&self, request: &AutoCompactRequest) -> Result<()> {
+        let session = SessionManager::instance()
+            .create_session(SessionType::Dummy)
+            .await?;
+        session.set_authed_user(request.user.clone(), None).await?;
+
+        let ctx = session.create_query_context().await?;
+        let Some((segment_count, summary, block_per_segment, clustering)) =
+            Self::read_statistics(&ctx, request).await?
+        else {
+            return Ok(());
+        };
+        let actions = check_auto_compaction(
+            &self.config,
+            &summary,
+            segment_count,
+            block_per_segment,
+            clustering,
+        );
+
+        let mut before = (segment_count, summary.block_count);
+        for action in actions {
+            let start = SystemTime::now();
+            info!(
+                "auto compaction ({}) on table {} starts, triggered by {}",
+                action.as_str(),
+                request.name(),
+                request.trigger
+            );
+            let ctx = session.create_query_context().await?;
+            ctx.get_settings()
+                .set_max_threads(self.config.max_threads.max(1))?;
+            let res = self.run_action(ctx.clone(), action, request).await;
+
+            let after = match Self::read_statistics(&ctx, request).await {
+                Ok(Some((segment_count, summary, _, _))) => (segment_count, summary.block_count),
+                _ => before,
+            };
+            AutoCompactionHistoryQueue::instance()?.append_data(
+                AutoCompactionHistoryLogElement {
+                    start_time: start
+                        .duration_since(UNIX_EPOCH)
+                        .expect("Time went backwards")
+                        .as_micros() as i64,
+                    end_time: SystemTime::now()
+                        .duration_since(UNIX_EPOCH)
+                        .expect("Time went backwards")
+                        .as_micros() as i64,
+                    database: request.target.database.clone(),
+                    table: request.target.table.clone(),
+                    trigger: request.trigger.clone(),
+                    action: action.as_str().to_string(),
+                    segment_count_before: before.0,
+                    block_count_before: before.1,
+                    segment_count_after: after.0,
+                    block_count_after: after.1,
+                    status: if res.is_ok() { "success" } else { "failed" }.to_string(),
+                    error_message: res
+                        .as_ref()
+                        .err()
+                        .map(|e| e.to_string())
+                        .unwrap_or_default(),
+                },
+            )?;
+            res?;
+            before = after;
+        }
+        Ok(())
+    }
+
+    // returns the segment count, the summary, the block_per_segment option and the clustering
+    // depth if the table has cluster keys, or None if the table is not a fuse table or has no
+    // snapshot.
+    async fn read_statistics(
+        ctx: &Arc<QueryContext>,
+        request: &AutoCompactRequest,
+    ) -> Result<Option<(u64, Statistics, u64, Option<ClusteringDepth>)>> {
+        // read the table from the catalog directly, the context caches the table.
+        let catalog = ctx.get_catalog(&request.target.catalog).await?;
+        let table_info = catalog.get_table_info_by_id(request.table_id).await?;
+        let table = catalog.get_table_by_info(&table_info)?;
+        let Ok(fuse_table) = FuseTable::try_from_table(table.as_ref()) else {
+            return Ok(None);
+        };
+        let Some(snapshot) = fuse_table.read_table_snapshot().await? else {
+            return Ok(None);
+        };
+        let block_per_segment =
+            fuse_table.get_option(FUSE_OPT_KEY_BLOCK_PER_SEGMENT, DEFAULT_BLOCK_PER_SEGMENT);
+        let clustering = if table.cluster_keys(ctx.clone()).is_empty() {
+            None
+        } else {
+            let stats = ClusteringInformation::new(ctx.clone(), fuse_table)
+                .get_clustering_statistics()
+                .await?;
+            Some(ClusteringDepth {
+                average_depth: stats.average_depth,
+                threshold: fuse_table.recluster_depth_threshold(snapshot.summary.block_count),
+            })
+        };
+        Ok(Some((
+            snapshot.segments.len() as u64,
+            snapshot.summary.clone(),
+            block_per_segment as u64,
+            clustering,
+        )))
+    }
+
+    async fn run_action(
+        &self,
+        ctx: Arc<QueryContext>,
+        action: AutoCompactAction,
+        request: &AutoCompactRequest,
+    ) -> Result<()> {
+        // the plans refer to the table by name, make sure that the name still refers to
+        // the table notified, it may have been renamed or replaced since then.
+        let target = &request.target;
+        let table = ctx
+            .get_table(&target.catalog, &target.database, &target.table)
+            .await?;
+        if table.get_id() != request.table_id {
+            return Err(ErrorCode::UnknownTable(format!(
+                "table {} is no longer the table {} notified",
+                request.name(),
+                request.table_id
+            )));
+        }
+
+        let limit = self.config.block_limit.map(|v| v as usize);
+        let optimize = |action, limit| -> Result<InterpreterPtr> {
+            Ok(Arc::new(OptimizeTableInterpreter::try_create(
+                ctx.clone(),
+                OptimizeTablePlan {
+                    catalog: target.catalog.clone(),
+                    database: target.database.clone(),
+                    table: target.table.clone(),
+                    action,
+                    limit,
+                    need_lock: true,
+                },
+            )?))
+        };
+        let interpreter: InterpreterPtr = match action {
+            AutoCompactAction::CompactSegments => {
+                optimize(OptimizeTableAction::CompactSegments, None)?
+            }
+            AutoCompactAction::CompactBlocks => {
+                optimize(OptimizeTableAction::CompactBlocks, limit)?
+            }
+            AutoCompactAction::Recluster => Arc::new(ReclusterTableInterpreter::try_create(
+                ctx.clone(),
+                ReclusterTablePlan {
+                    tenant: ctx.get_tenant(),
+                    catalog: target.catalog.clone(),
+                    database: target.database.clone(),
+                    table: target.table.clone(),
+                    is_final: false,
+                    metadata: Arc::new(RwLock::new(Metadata::default())),
+                    push_downs: None,
+                    limit,
+                },
+            )?),
+        };
+        let mut stream = interpreter.execute(ctx).await?;
+        while let Some(block) = stream.next().await {
+            block?;
+        }
+        Ok(())
+    }
+}
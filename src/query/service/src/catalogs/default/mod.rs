@@ -16,10 +16,15 @@ mod catalog_context;
 mod database_catalog;
 mod immutable_catalog;
 mod mutable_catalog;
+mod session_catalog;
 pub mod table_id_ranges;
 pub mod table_memory_meta;
+mod temp_tbl_mgr;
 
 pub use database_catalog::DatabaseCatalog;
 // for "unit" test
 pub use immutable_catalog::ImmutableCatalog;
 pub use mutable_catalog::MutableCatalog;
+pub use session_catalog::SessionCatalog;
+pub use temp_tbl_mgr::TempTblMgr;
+pub use temp_tbl_mgr::TempTblMgrRef;
@@ -0,0 +1,611 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_catalog::catalog::Catalog;
+use common_catalog::catalog::StorageDescription;
+use common_catalog::database::Database;
+use common_catalog::table_args::TableArgs;
+use common_catalog::table_function::TableFunction;
+use common_exception::Result;
+use common_meta_app::schema::CatalogInfo;
+use common_meta_app::schema::CountTablesReply;
+use common_meta_app::schema::CountTablesReq;
+use common_meta_app::schema::CreateDatabaseReply;
+use common_meta_app::schema::CreateDatabaseReq;
+use common_meta_app::schema::CreateIndexReply;
+use common_meta_app::schema::CreateIndexReq;
+use common_meta_app::schema::CreateLockRevReply;
+use common_meta_app::schema::CreateLockRevReq;
+use common_meta_app::schema::CreateTableReply;
+use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::CreateVirtualColumnReply;
+use common_meta_app::schema::CreateVirtualColumnReq;
+use common_meta_app::schema::DeleteLockRevReq;
+use common_meta_app::schema::DropDatabaseReply;
+use common_meta_app::schema::DropDatabaseReq;
+use common_meta_app::schema::DropIndexReply;
+use common_meta_app::schema::DropIndexReq;
+use common_meta_app::schema::DropTableByIdReq;
+use common_meta_app::schema::DropTableReply;
+use common_meta_app::schema::DropVirtualColumnReply;
+use common_meta_app::schema::DropVirtualColumnReq;
+use common_meta_app::schema::DroppedId;
+use common_meta_app::schema::ExtendLockRevReq;
+use common_meta_app::schema::GcDroppedTableReq;
+use common_meta_app::schema::GcDroppedTableResp;
+use common_meta_app::schema::GetIndexReply;
+use common_meta_app::schema::GetIndexReq;
+use common_meta_app::schema::GetTableCopiedFileReply;
+use common_meta_app::schema::GetTableCopiedFileReq;
+use common_meta_app::schema::IndexMeta;
+use common_meta_app::schema::ListDroppedTableReq;
+use common_meta_app::schema::ListIndexesByIdReq;
+use common_meta_app::schema::ListIndexesReq;
+use common_meta_app::schema::ListLockRevReq;
+use common_meta_app::schema::ListVirtualColumnsReq;
+use common_meta_app::schema::LockMeta;
+use common_meta_app::schema::LockType;
+use common_meta_app::schema::RenameDatabaseReply;
+use common_meta_app::schema::RenameDatabaseReq;
+use common_meta_app::schema::RenameTableReply;
+use common_meta_app::schema::RenameTableReq;
+use common_meta_app::schema::SetTableColumnMaskPolicyReply;
+use common_meta_app::schema::SetTableColumnMaskPolicyReq;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::TruncateTableReply;
+use common_meta_app::schema::TruncateTableReq;
+use common_meta_app::schema::UndropDatabaseReply;
+use common_meta_app::schema::UndropDatabaseReq;
+use common_meta_app::schema::UndropTableReply;
+use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateIndexReply;
+use common_meta_app::schema::UpdateIndexReq;
+use common_meta_app::schema::UpdateMultiTableMetaReply;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
+use common_meta_app::schema::UpdateTableMetaReply;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_app::schema::UpdateVirtualColumnReply;
+use common_meta_app::schema::UpdateVirtualColumnReq;
+use common_meta_app::schema::UpsertTableOptionReply;
+use common_meta_app::schema::UpsertTableOptionReq;
+use common_meta_app::schema::VirtualColumnMeta;
+use common_meta_types::MetaId;
+use common_storage::DataOperator;
+use common_storages_fuse::FuseTable;
+use log::info;
+use log::warn;
+use storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
+
+use crate::catalogs::default::TempTblMgrRef;
+use crate::sessions::TxnManagerRef;
+use crate::storages::Table;
+
+/// The lock revision handed out for temporary tables.
+const TEMP_TBL_LOCK_REVISION: u64 = 0;
+
+/// The default catalog seen by a session.
+///
+/// Temporary tables created by the session are kept in its [`TempTblMgr`], they shadow the
+/// tables with the same name in the underlying catalog and are never written to meta.
+///
+/// Inside an explicit transaction, the table meta updates are buffered in the session's
+/// [`TxnManager`] instead, and the tables read in the transaction carry the buffered meta.
+/// Everything else is delegated to the underlying catalog.
+///
+/// [`TempTblMgr`]: crate::catalogs::default::TempTblMgr
+/// [`TxnManager`]: crate::sessions::TxnManager
+#[derive(Clone)]
+pub struct SessionCatalog {
+    inner: Arc<dyn Catalog>,
+    temp_tbl_mgr: TempTblMgrRef,
+    txn_mgr: TxnManagerRef,
+}
+
+impl Debug for SessionCatalog {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SessionCatalog").finish_non_exhaustive()
+    }
+}
+
+impl SessionCatalog {
+    pub fn create(
+        inner: Arc<dyn Catalog>,
+        temp_tbl_mgr: TempTblMgrRef,
+        txn_mgr: TxnManagerRef,
+    ) -> Self {
+        Self {
+            inner,
+            temp_tbl_mgr,
+            txn_mgr,
+        }
+    }
+
+    fn is_temp_table(&self, table_id: u64) -> bool {
+        self.temp_tbl_mgr.lock().is_temp_table(table_id)
+    }
+
+    /// Remove the data and snapshots of dropped temporary tables.
+    #[async_backtrace::framed]
+    pub async fn purge_temp_tables(table_infos: Vec<TableInfo>) -> Result<()> {
+        let operator = DataOperator::instance().operator();
+        for table_info in table_infos {
+            let prefix = FuseTable::parse_storage_prefix(&table_info)?;
+            info!(
+                "Purge temporary table {}, storage prefix {}",
+                table_info.desc, prefix
+            );
+            if let Err(e) = operator.remove_all(&format!("{}/", prefix)).await {
+                warn!(
+                    "Failed to purge temporary table {}: {:?}",
+                    table_info.desc, e
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Catalog for SessionCatalog {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> String {
+        self.inner.name()
+    }
+
+    fn info(&self) -> CatalogInfo {
+        self.inner.info()
+    }
+
+    #[async_backtrace::framed]
+    async fn get_database(&self, tenant: &str, db_name: &str) -> Result<Arc<dyn Database>> {
+        self.inner.get_database(tenant, db_name).await
+    }
+
+    #[async_backtrace::framed]
+    async fn list_databases(&self, tenant: &str) -> Result<Vec<Arc<dyn Database>>> {
+        self.inner.list_databases(tenant).await
+    }
+
+    #[async_backtrace::framed]
+    async fn create_database(&self, req: CreateDatabaseReq) -> Result<CreateDatabaseReply> {
+        self.inner.create_database(req).await
+    }
+
+    #[async_backtrace::framed]
+    async fn drop_database(&self, req: DropDatabaseReq) -> Result<DropDatabaseReply> {
+        self.inner.drop_database(req).await
+    }
+
+    #[async_backtrace::framed]
+    async fn undrop_database(&self, req: UndropDatabaseReq) -> Result<UndropDatabaseReply> {
+        self.inner.undrop_database(req).await
+    }
+
+    #[async_backtrace::framed]
+    async fn rename_database(&self, req: RenameDatabaseReq) -> Result<RenameDatabaseReply> {
+        self.inner.rename_database(req).await
+    }
+
+    fn get_table_by_info(&self, table_info: &TableInfo) -> Result<Arc<dyn Table>> {
+        self.inner.get_table_by_info(table_info)
+    }
+
+    #[async_backtrace::framed]
+    async fn get_table_meta_by_id(&self, table_id: MetaId) -> Result<(TableIdent, Arc<TableMeta>)> {
+        if let Some(table_info) = self.txn_mgr.lock().get_table_from_buffer(table_id) {
+            return Ok((table_info.ident, Arc::new(table_info.meta)));
+        }
+        if let Some(table_info) = self.temp_tbl_mgr.lock().get_table_by_id(table_id) {
+            return Ok((table_info.ident, Arc::new(table_info.meta)));
+        }
+        self.inner.get_table_meta_by_id(table_id).await
+    }
+
+    #[async_backtrace::framed]
+    async fn get_table_info_by_id(&self, table_id: MetaId) -> Result<Arc<TableInfo>> {
+        let temp_table_info = self.temp_tbl_mgr.lock().get_table_by_id(table_id);
+        let mut table_info = match temp_table_info {
+            Some(table_info) => table_info,
+            None => self
+                .inner
+                .get_table_info_by_id(table_id)
+                .await?
+                .as_ref()
+                .clone(),
+        };
+
+        // Read the writes of the current transaction.
+        if let Some(buffered) = self.txn_mgr.lock().get_table_from_buffer(table_id) {
+            table_info.ident = buffered.ident;
+            table_info.meta = buffered.meta;
+        }
+        Ok(Arc::new(table_info))
+    }
+
+    #[async_backtrace::framed]
+    async fn get_table(
+        &self,
+        tenant: &str,
+        db_name: &str,
+        table_name: &str,
+    ) -> Result<Arc<dyn Table>> {
+        let table_info = self.temp_tbl_mgr.lock().get_table(db_name, table_name);
+        let table = match table_info {
+            Some(table_info) => self.get_table_by_info(&table_info)?,
+            None => self.inner.get_table(tenant, db_name, table_name).await?,
+        };
+
+        // Read the writes of the current transaction.
+        let table_id = table.get_table_info().ident.table_id;
+        match self.txn_mgr.lock().get_table_from_buffer(table_id) {
+            Some(buffered) => {
+                let mut table_info = table.get_table_info().clone();
+                table_info.ident = buffered.ident;
+                table_info.meta = buffered.meta;
+                self.get_table_by_info(&table_info)
+            }
+            None => Ok(table),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn list_tables(&self, tenant: &str, db_name: &str) -> Result<Vec<Arc<dyn Table>>> {
+        let mut tables = self.inner.list_tables(tenant, db_name).await?;
+        let temp_tables = self.temp_tbl_mgr.lock().list_tables(db_name);
+        for table_info in temp_tables {
+            tables.retain(|table| table.name() != table_info.name);
+            tables.push(self.get_table_by_info(&table_info)?);
+        }
+        Ok(tables)
+    }
+
+    #[async_backtrace::framed]
+    async fn list_tables_history(
+        &self,
+        tenant: &str,
+        db_name: &str,
+    ) -> Result<Vec<Arc<dyn Table>>> {
+        self.inner.list_tables_history(tenant, db_name).await
+    }
+
+    async fn get_drop_table_infos(
+        &self,
+        req: ListDroppedTableReq,
+    ) -> Result<(Vec<Arc<dyn Table>>, Vec<DroppedId>)> {
+        self.inner.get_drop_table_infos(req).await
+    }
+
+    async fn gc_drop_tables(&self, req: GcDroppedTableReq) -> Result<GcDroppedTableResp> {
+        self.inner.gc_drop_tables(req).await
+    }
+
+    #[async_backtrace::framed]
+    async fn create_table(&self, req: CreateTableReq) -> Result<CreateTableReply> {
+        if !req.table_meta.options.contains_key(OPT_KEY_TEMP_PREFIX) {
+            return self.inner.create_table(req).await;
+        }
+
+        // Make sure the database exists, the temporary table lives in its namespace.
+        self.inner.get_database(req.tenant(), req.db_name()).await?;
+        info!("Create temporary table from req:{:?}", req);
+        self.temp_tbl_mgr.lock().create_table(req)
+    }
+
+    #[async_backtrace::framed]
+    async fn drop_table_by_id(&self, req: DropTableByIdReq) -> Result<DropTableReply> {
+        let table_info = self.temp_tbl_mgr.lock().drop_table_by_id(req.tb_id);
+        match table_info {
+            Some(table_info) => {
+                Self::purge_temp_tables(vec![table_info]).await?;
+                Ok(DropTableReply { spec_vec: None })
+            }
+            None => self.inner.drop_table_by_id(req).await,
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn undrop_table(&self, req: UndropTableReq) -> Result<UndropTableReply> {
+        self.inner.undrop_table(req).await
+    }
+
+    #[async_backtrace::framed]
+    async fn rename_table(&self, req: RenameTableReq) -> Result<RenameTableReply> {
+        if let Some(table_id) = self.temp_tbl_mgr.lock().rename_table(&req)? {
+            return Ok(RenameTableReply { table_id });
+        }
+        self.inner.rename_table(req).await
+    }
+
+    #[async_backtrace::framed]
+    async fn count_tables(&self, req: CountTablesReq) -> Result<CountTablesReply> {
+        self.inner.count_tables(req).await
+    }
+
+    #[async_backtrace::framed]
+    async fn get_table_copied_file_info(
+        &self,
+        tenant: &str,
+        db_name: &str,
+        req: GetTableCopiedFileReq,
+    ) -> Result<GetTableCopiedFileReply> {
+        // Copied files of temporary tables are not tracked.
+        if self.is_temp_table(req.table_id) {
+            return Ok(GetTableCopiedFileReply {
+                file_info: Default::default(),
+            });
+        }
+        self.inner
+            .get_table_copied_file_info(tenant, db_name, req)
+            .await
+    }
+
+    #[async_backtrace::framed]
+    async fn truncate_table(
+        &self,
+        table_info: &TableInfo,
+        req: TruncateTableReq,
+    ) -> Result<TruncateTableReply> {
+        if self.is_temp_table(req.table_id) {
+            return Ok(TruncateTableReply {});
+        }
+        self.inner.truncate_table(table_info, req).await
+    }
+
+    #[async_backtrace::framed]
+    async fn upsert_table_option(
+        &self,
+        tenant: &str,
+        db_name: &str,
+        req: UpsertTableOptionReq,
+    ) -> Result<UpsertTableOptionReply> {
+        if self.is_temp_table(req.table_id) {
+            self.temp_tbl_mgr.lock().upsert_table_option(req)?;
+            return Ok(UpsertTableOptionReply {
+                share_table_info: None,
+            });
+        }
+        self.inner.upsert_table_option(tenant, db_name, req).await
+    }
+
+    #[async_backtrace::framed]
+    async fn update_table_meta(
+        &self,
+        table_info: &TableInfo,
+        req: UpdateTableMetaReq,
+    ) -> Result<UpdateTableMetaReply> {
+        {
+            let mut txn_mgr = self.txn_mgr.lock();
+            if txn_mgr.is_active() {
+                txn_mgr.update_table_meta(table_info, req)?;
+                return Ok(UpdateTableMetaReply {
+                    share_table_info: None,
+                });
+            }
+        }
+        if self.is_temp_table(req.table_id) {
+            self.temp_tbl_mgr.lock().update_table_meta(req)?;
+            return Ok(UpdateTableMetaReply {
+                share_table_info: None,
+            });
+        }
+        self.inner.update_table_meta(table_info, req).await
+    }
+
+    #[async_backtrace::framed]
+    async fn update_multi_table_meta(
+        &self,
+        req: UpdateMultiTableMetaReq,
+    ) -> Result<UpdateMultiTableMetaReply> {
+        if self.txn_mgr.lock().is_active() {
+            let mut table_infos = Vec::with_capacity(req.update_table_metas.len());
+            for req in req.update_table_metas.iter() {
+                let table_info = self.get_table_info_by_id(req.table_id).await?;
+                table_infos.push(table_info.as_ref().clone());
+            }
+            let mut txn_mgr = self.txn_mgr.lock();
+            for (table_info, req) in table_infos.iter().zip(req.update_table_metas) {
+                txn_mgr.update_table_meta(table_info, req)?;
+            }
+            return Ok(UpdateMultiTableMetaReply {
+                share_table_info: None,
+            });
+        }
+
+        let (temp_reqs, reqs): (Vec<_>, Vec<_>) = req
+            .update_table_metas
+            .into_iter()
+            .partition(|req| self.is_temp_table(req.table_id));
+
+        let reply = if reqs.is_empty() {
+            UpdateMultiTableMetaReply {
+                share_table_info: None,
+            }
+        } else {
+            self.inner
+                .update_multi_table_meta(UpdateMultiTableMetaReq {
+                    update_table_metas: reqs,
+                })
+                .await?
+        };
+
+        // Temporary tables are only visible to this session, apply them after the
+        // other tables are committed.
+        let mut temp_tbl_mgr = self.temp_tbl_mgr.lock();
+        for req in temp_reqs {
+            temp_tbl_mgr.update_table_meta(req)?;
+        }
+        Ok(reply)
+    }
+
+    #[async_backtrace::framed]
+    async fn set_table_column_mask_policy(
+        &self,
+        req: SetTableColumnMaskPolicyReq,
+    ) -> Result<SetTableColumnMaskPolicyReply> {
+        self.inner.set_table_column_mask_policy(req).await
+    }
+
+    // Table index
+
+    #[async_backtrace::framed]
+    async fn create_index(&self, req: CreateIndexReq) -> Result<CreateIndexReply> {
+        self.inner.create_index(req).await
+    }
+
+    #[async_backtrace::framed]
+    async fn drop_index(&self, req: DropIndexReq) -> Result<DropIndexReply> {
+        self.inner.drop_index(req).await
+    }
+
+    #[async_backtrace::framed]
+    async fn get_index(&self, req: GetIndexReq) -> Result<GetIndexReply> {
+        self.inner.get_index(req).await
+    }
+
+    #[async_backtrace::framed]
+    async fn update_index(&self, req: UpdateIndexReq) -> Result<UpdateIndexReply> {
+        self.inner.update_index(req).await
+    }
+
+    #[async_backtrace::framed]
+    async fn list_indexes(&self, req: ListIndexesReq) -> Result<Vec<(u64, String, IndexMeta)>> {
+        self.inner.list_indexes(req).await
+    }
+
+    #[async_backtrace::framed]
+    async fn list_index_ids_by_table_id(&self, req: ListIndexesByIdReq) -> Result<Vec<u64>> {
+        self.inner.list_index_ids_by_table_id(req).await
+    }
+
+    #[async_backtrace::framed]
+    async fn list_indexes_by_table_id(
+        &self,
+        req: ListIndexesByIdReq,
+    ) -> Result<Vec<(u64, String, IndexMeta)>> {
+        self.inner.list_indexes_by_table_id(req).await
+    }
+
+    // Virtual column
+
+    #[async_backtrace::framed]
+    async fn create_virtual_column(
+        &self,
+        req: CreateVirtualColumnReq,
+    ) -> Result<CreateVirtualColumnReply> {
+        self.inner.create_virtual_column(req).await
+    }
+
+    #[async_backtrace::framed]
+    async fn update_virtual_column(
+        &self,
+        req: UpdateVirtualColumnReq,
+    ) -> Result<UpdateVirtualColumnReply> {
+        self.inner.update_virtual_column(req).await
+    }
+
+    #[async_backtrace::framed]
+    async fn drop_virtual_column(
+        &self,
+        req: DropVirtualColumnReq,
+    ) -> Result<DropVirtualColumnReply> {
+        self.inner.drop_virtual_column(req).await
+    }
+
+    #[async_backtrace::framed]
+    async fn list_virtual_columns(
+        &self,
+        req: ListVirtualColumnsReq,
+    ) -> Result<Vec<VirtualColumnMeta>> {
+        self.inner.list_virtual_columns(req).await
+    }
+
+    fn get_table_function(
+        &self,
+        func_name: &str,
+        tbl_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        self.inner.get_table_function(func_name, tbl_args)
+    }
+
+    fn exists_table_function(&self, func_name: &str) -> bool {
+        self.inner.exists_table_function(func_name)
+    }
+
+    fn list_table_functions(&self) -> Vec<String> {
+        self.inner.list_table_functions()
+    }
+
+    fn get_table_engines(&self) -> Vec<StorageDescription> {
+        self.inner.get_table_engines()
+    }
+
+    // Table lock
+    //
+    // A temporary table is only visible to its own session, so there is nothing
+    // to lock against, the lock is always granted without touching meta.
+
+    #[async_backtrace::framed]
+    async fn list_lock_revisions(&self, req: ListLockRevReq) -> Result<Vec<(u64, LockMeta)>> {
+        if self.is_temp_table(req.lock_key.get_table_id()) {
+            let now = Utc::now();
+            return Ok(vec![(TEMP_TBL_LOCK_REVISION, LockMeta {
+                user: "".to_string(),
+                node: "".to_string(),
+                query_id: "".to_string(),
+                created_on: now,
+                acquired_on: Some(now),
+                lock_type: LockType::TABLE,
+                extra_info: Default::default(),
+            })]);
+        }
+        self.inner.list_lock_revisions(req).await
+    }
+
+    #[async_backtrace::framed]
+    async fn create_lock_revision(&self, req: CreateLockRevReq) -> Result<CreateLockRevReply> {
+        if self.is_temp_table(req.lock_key.get_table_id()) {
+            return Ok(CreateLockRevReply {
+                revision: TEMP_TBL_LOCK_REVISION,
+            });
+        }
+        self.inner.create_lock_revision(req).await
+    }
+
+    #[async_backtrace::framed]
+    async fn extend_lock_revision(&self, req: ExtendLockRevReq) -> Result<()> {
+        if self.is_temp_table(req.lock_key.get_table_id()) {
+            return Ok(());
+        }
+        self.inner.extend_lock_revision(req).await
+    }
+
+    #[async_backtrace::framed]
+    async fn delete_lock_revision(&self, req: DeleteLockRevReq) -> Result<()> {
+        if self.is_temp_table(req.lock_key.get_table_id()) {
+            return Ok(());
+        }
+        self.inner.delete_lock_revision(req).await
+    }
+}
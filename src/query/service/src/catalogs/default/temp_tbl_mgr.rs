@@ -0,0 +1,199 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::CreateTableReply;
+use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::RenameTableReq;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableNameIdent;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_app::schema::UpsertTableOptionReq;
+use common_meta_types::MatchSeq;
+use common_meta_types::MatchSeqExt;
+use parking_lot::Mutex;
+
+use crate::catalogs::default::table_id_ranges::TEMP_TBL_ID_BEGIN;
+
+static NEXT_TEMP_TBL_ID: AtomicU64 = AtomicU64::new(TEMP_TBL_ID_BEGIN);
+
+pub type TempTblMgrRef = Arc<Mutex<TempTblMgr>>;
+
+/// Keeps the temporary tables of a session.
+///
+/// Temporary tables are never registered in meta, their table infos only live
+/// in the session that created them, and are dropped together with the session.
+#[derive(Default)]
+pub struct TempTblMgr {
+    /// `(database name, table name) -> table id`
+    name_to_id: BTreeMap<(String, String), u64>,
+    /// `table id -> (database name, table info)`
+    tables: HashMap<u64, (String, TableInfo)>,
+}
+
+impl TempTblMgr {
+    pub fn init() -> TempTblMgrRef {
+        Arc::new(Mutex::new(TempTblMgr::default()))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tables.is_empty()
+    }
+
+    pub fn is_temp_table(&self, table_id: u64) -> bool {
+        self.tables.contains_key(&table_id)
+    }
+
+    pub fn create_table(&mut self, req: CreateTableReq) -> Result<CreateTableReply> {
+        let key = (req.db_name().to_string(), req.table_name().to_string());
+        if let Some(table_id) = self.name_to_id.get(&key) {
+            return if req.if_not_exists {
+                Ok(CreateTableReply {
+                    table_id: *table_id,
+                    new_table: false,
+                })
+            } else {
+                Err(ErrorCode::TableAlreadyExists(format!(
+                    "Temporary table '{}' already exists",
+                    req.table_name()
+                )))
+            };
+        }
+
+        let table_id = NEXT_TEMP_TBL_ID.fetch_add(1, Ordering::Relaxed);
+        let table_info = TableInfo {
+            ident: TableIdent::new(table_id, 0),
+            desc: req.name_ident.to_string(),
+            name: req.table_name().to_string(),
+            meta: req.table_meta,
+            tenant: req.tenant().to_string(),
+            ..Default::default()
+        };
+        self.name_to_id.insert(key.clone(), table_id);
+        self.tables.insert(table_id, (key.0, table_info));
+        Ok(CreateTableReply {
+            table_id,
+            new_table: true,
+        })
+    }
+
+    pub fn get_table(&self, db_name: &str, table_name: &str) -> Option<TableInfo> {
+        let key = (db_name.to_string(), table_name.to_string());
+        self.name_to_id
+            .get(&key)
+            .and_then(|table_id| self.get_table_by_id(*table_id))
+    }
+
+    pub fn get_table_by_id(&self, table_id: u64) -> Option<TableInfo> {
+        self.tables
+            .get(&table_id)
+            .map(|(_, table_info)| table_info.clone())
+    }
+
+    pub fn list_tables(&self, db_name: &str) -> Vec<TableInfo> {
+        self.tables
+            .values()
+            .filter(|(db, _)| db == db_name)
+            .map(|(_, table_info)| table_info.clone())
+            .collect()
+    }
+
+    /// Remove the table from the session, returns the removed table info.
+    pub fn drop_table_by_id(&mut self, table_id: u64) -> Option<TableInfo> {
+        let (db_name, table_info) = self.tables.remove(&table_id)?;
+        self.name_to_id.remove(&(db_name, table_info.name.clone()));
+        Some(table_info)
+    }
+
+    /// Remove all the tables from the session, returns the removed table infos.
+    pub fn drop_all_tables(&mut self) -> Vec<TableInfo> {
+        self.name_to_id.clear();
+        self.tables
+            .drain()
+            .map(|(_, (_, table_info))| table_info)
+            .collect()
+    }
+
+    pub fn rename_table(&mut self, req: &RenameTableReq) -> Result<Option<u64>> {
+        let key = (req.db_name().to_string(), req.table_name().to_string());
+        let Some(table_id) = self.name_to_id.get(&key).cloned() else {
+            return Ok(None);
+        };
+
+        let new_key = (req.new_db_name.clone(), req.new_table_name.clone());
+        if self.name_to_id.contains_key(&new_key) {
+            return Err(ErrorCode::TableAlreadyExists(format!(
+                "Temporary table '{}' already exists",
+                req.new_table_name
+            )));
+        }
+
+        self.name_to_id.remove(&key);
+        self.name_to_id.insert(new_key.clone(), table_id);
+        let (db_name, table_info) = self.tables.get_mut(&table_id).unwrap();
+        let name_ident = TableNameIdent::new(
+            req.tenant(),
+            req.new_db_name.clone(),
+            req.new_table_name.clone(),
+        );
+        *db_name = new_key.0;
+        table_info.desc = name_ident.to_string();
+        table_info.name = new_key.1;
+        Ok(Some(table_id))
+    }
+
+    pub fn update_table_meta(&mut self, req: UpdateTableMetaReq) -> Result<()> {
+        let table_info = self.get_table_info_with_seq(req.table_id, req.seq)?;
+        table_info.meta = req.new_table_meta;
+        table_info.ident.seq += 1;
+        Ok(())
+    }
+
+    pub fn upsert_table_option(&mut self, req: UpsertTableOptionReq) -> Result<()> {
+        let table_info = self.get_table_info_with_seq(req.table_id, req.seq)?;
+        for (key, value) in req.options {
+            match value {
+                Some(value) => {
+                    table_info.meta.options.insert(key, value);
+                }
+                None => {
+                    table_info.meta.options.remove(&key);
+                }
+            }
+        }
+        table_info.ident.seq += 1;
+        Ok(())
+    }
+
+    fn get_table_info_with_seq(&mut self, table_id: u64, seq: MatchSeq) -> Result<&mut TableInfo> {
+        let (_, table_info) = self.tables.get_mut(&table_id).ok_or_else(|| {
+            ErrorCode::UnknownTableId(format!("Unknown temporary table id {}", table_id))
+        })?;
+        if seq.match_seq(table_info.ident.seq).is_err() {
+            return Err(ErrorCode::TableVersionMismatched(format!(
+                "Temporary table '{}' version mismatched, expect {}, got {}",
+                table_info.name, seq, table_info.ident.seq
+            )));
+        }
+        Ok(table_info)
+    }
+}
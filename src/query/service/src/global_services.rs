@@ -38,8 +38,10 @@ use storages_common_locks::LockManager;
 
 use crate::api::DataExchangeManager;
 use crate::auth::AuthMgr;
+use crate::auto_compaction::AutoCompactor;
 use crate::catalogs::DatabaseCatalog;
 use crate::clusters::ClusterDiscovery;
+use crate::sequences::SequenceCache;
 use crate::servers::http::v1::HttpQueryManager;
 use crate::sessions::SessionManager;
 
@@ -121,6 +123,8 @@ impl GlobalServices {
             config.query.tenant_id.clone(),
         )?;
         CacheManager::init(&config.cache, &config.query.tenant_id)?;
+        AutoCompactor::init(&config)?;
+        SequenceCache::init()?;
 
         if let Some(addr) = config.query.cloud_control_grpc_server_address.clone() {
             CloudControlApiProvider::init(addr).await?;
@@ -305,6 +305,18 @@ impl AccessChecker for PrivilegeAccess {
                     true,
                 )
                     .await?;
+                if let Some(clone_from) = &plan.clone_from {
+                    self.validate_access(
+                        &GrantObject::Table(
+                            clone_from.catalog.clone(),
+                            clone_from.database.clone(),
+                            clone_from.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Select],
+                        true,
+                    )
+                        .await?;
+                }
             }
             Plan::DropTable(plan) => {
                 self.validate_access(
@@ -348,6 +360,30 @@ impl AccessChecker for PrivilegeAccess {
                 )
                     .await?;
             }
+            Plan::CreateInvertedIndex(plan) => {
+                self.validate_access(
+                    &GrantObject::Table(
+                        plan.catalog.clone(),
+                        plan.database.clone(),
+                        plan.table.clone(),
+                    ),
+                    vec![UserPrivilegeType::Alter],
+                    true,
+                )
+                    .await?;
+            }
+            Plan::DropInvertedIndex(plan) => {
+                self.validate_access(
+                    &GrantObject::Table(
+                        plan.catalog.clone(),
+                        plan.database.clone(),
+                        plan.table.clone(),
+                    ),
+                    vec![UserPrivilegeType::Alter],
+                    true,
+                )
+                    .await?;
+            }
             Plan::SetOptions(plan) => {
                 self.validate_access(
                     &GrantObject::Table(
@@ -360,6 +396,30 @@ impl AccessChecker for PrivilegeAccess {
                 )
                     .await?;
             }
+            Plan::CreateTableSnapshotRef(plan) => {
+                self.validate_access(
+                    &GrantObject::Table(
+                        plan.catalog.clone(),
+                        plan.database.clone(),
+                        plan.table.clone(),
+                    ),
+                    vec![UserPrivilegeType::Alter],
+                    true,
+                )
+                    .await?;
+            }
+            Plan::DropTableSnapshotRef(plan) => {
+                self.validate_access(
+                    &GrantObject::Table(
+                        plan.catalog.clone(),
+                        plan.database.clone(),
+                        plan.table.clone(),
+                    ),
+                    vec![UserPrivilegeType::Alter],
+                    true,
+                )
+                    .await?;
+            }
             Plan::AddTableColumn(plan) => {
                 self.validate_access(
                     &GrantObject::Table(
@@ -585,6 +645,32 @@ impl AccessChecker for PrivilegeAccess {
                 )
                     .await?;
             }
+            Plan::CreateStream(plan) => {
+                self.validate_access(
+                    &GrantObject::Database(plan.catalog.clone(), plan.database.clone()),
+                    vec![UserPrivilegeType::Create],
+                    true,
+                )
+                    .await?;
+                self.validate_access(
+                    &GrantObject::Table(
+                        plan.catalog.clone(),
+                        plan.table_database.clone(),
+                        plan.table_name.clone(),
+                    ),
+                    vec![UserPrivilegeType::Select],
+                    true,
+                )
+                    .await?;
+            }
+            Plan::DropStream(plan) => {
+                self.validate_access(
+                    &GrantObject::Database(plan.catalog.clone(), plan.database.clone()),
+                    vec![UserPrivilegeType::Drop],
+                    true,
+                )
+                    .await?;
+            }
             Plan::CreateUser(_) => {
                 self.validate_access(
                     &GrantObject::Global,
@@ -701,6 +787,9 @@ impl AccessChecker for PrivilegeAccess {
             | Plan::ShowConnections(_)
             | Plan::DescConnection(_)
             | Plan::DropConnection(_)
+            | Plan::CreateSequence(_)
+            | Plan::DropSequence(_)
+            | Plan::ShowSequences(_)
             | Plan::CreateTask(_)   // TODO: need to build ownership info for task
             | Plan::ShowTasks(_)    // TODO: need to build ownership info for task
             | Plan::DescribeTask(_) // TODO: need to build ownership info for task
@@ -751,6 +840,8 @@ impl AccessChecker for PrivilegeAccess {
             // just used in clickhouse-sqlalchemy, no need to check
             Plan::ExistsTable(_) => {}
             Plan::DescDatamaskPolicy(_) => {}
+            // The privileges are checked by the statements of the transaction
+            Plan::Begin | Plan::Commit | Plan::Rollback => {}
         }
 
         Ok(())
@@ -22,7 +22,9 @@ use common_pipeline_core::Pipeline;
 use common_sql::plans::OptimizeTableAction;
 use common_sql::plans::OptimizeTablePlan;
 use log::info;
+use storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
 
+use crate::auto_compaction::AutoCompactor;
 use crate::interpreters::common::metrics_inc_compact_hook_compact_time_ms;
 use crate::interpreters::common::metrics_inc_compact_hook_main_operation_time_ms;
 use crate::interpreters::Interpreter;
@@ -35,6 +37,8 @@ pub struct CompactTargetTableDescription {
     pub catalog: String,
     pub database: String,
     pub table: String,
+    /// The branch of the table written to, if any.
+    pub branch: Option<String>,
 }
 
 pub struct CompactHookTraceCtx {
@@ -70,6 +74,12 @@ async fn do_hook_compact(
         return Ok(());
     }
 
+    // the auto compactor (if enabled) takes over the compaction, instead of the write query.
+    if AutoCompactor::try_instance().is_some() {
+        hook_auto_compact(ctx, pipeline, compact_target, &trace_ctx.operation_name).await;
+        return Ok(());
+    }
+
     if !pipeline.is_empty() && ctx.get_settings().get_enable_recluster_after_write()? {
         pipeline.set_on_finished(move |err| {
 
@@ -96,6 +106,68 @@ async fn do_hook_compact(
     Ok(())
 }
 
+// notify the auto compactor (if enabled) once the write is committed.
+//
+// The writes of temporary tables, of branches, and of explicit transactions are not
+// compacted in background.
+//
+// errors (if any) are ignored
+pub async fn hook_auto_compact(
+    ctx: Arc<QueryContext>,
+    pipeline: &mut Pipeline,
+    compact_target: CompactTargetTableDescription,
+    operation_name: &str,
+) {
+    let Some(compactor) = AutoCompactor::try_instance() else {
+        return;
+    };
+    if pipeline.is_empty() || compact_target.branch.is_some() {
+        return;
+    }
+    // the writes are not committed until `COMMIT`.
+    if ctx.is_txn_active() {
+        return;
+    }
+
+    // the compactor resolves the table by id, since the name may refer to another table
+    // in the compactor's session, e.g. a temporary table shadows a table of the same name.
+    let table = match ctx
+        .get_table(
+            &compact_target.catalog,
+            &compact_target.database,
+            &compact_target.table,
+        )
+        .await
+    {
+        Ok(table) => table,
+        Err(e) => {
+            info!(
+                "auto compact hook ({}) with error (ignored): {}",
+                operation_name, e
+            );
+            return;
+        }
+    };
+    // the temporary tables are only visible in the session, and are not compacted.
+    if table.options().contains_key(OPT_KEY_TEMP_PREFIX) {
+        return;
+    }
+    let table_id = table.get_id();
+
+    let op_name = operation_name.to_string();
+    pipeline.set_on_finished(move |err| {
+        if err.is_none() {
+            if let Err(e) = compactor.notify(&ctx, compact_target, table_id, &op_name) {
+                info!(
+                    "notify auto compactor ({}) with error (ignored): {}",
+                    op_name, e
+                );
+            }
+        }
+        Ok(())
+    });
+}
+
 async fn compact_table(
     ctx: Arc<QueryContext>,
     compact_target: CompactTargetTableDescription,
@@ -56,6 +56,7 @@ pub trait Interpreter: Sync + Send {
         let mut build_res = match self.execute2().await {
             Ok(build_res) => build_res,
             Err(build_error) => {
+                ctx.txn_mgr().lock().set_fail();
                 InterpreterMetrics::record_query_error(&ctx);
                 log_query_finished(&ctx, Some(build_error.clone()));
                 return Err(build_error);
@@ -76,7 +77,10 @@ pub trait Interpreter: Sync + Send {
 
             match may_error {
                 None => Ok(()),
-                Some(error) => Err(error.clone()),
+                Some(error) => {
+                    query_ctx.txn_mgr().lock().set_fail();
+                    Err(error.clone())
+                }
             }
         });
 
@@ -344,6 +344,7 @@ impl Interpreter for CopyIntoTableInterpreter {
                 catalog: self.plan.catalog_info.name_ident.catalog_name.clone(),
                 database: self.plan.database_name.clone(),
                 table: self.plan.table_name.clone(),
+                branch: None,
             };
 
             let trace_ctx = CompactHookTraceCtx {
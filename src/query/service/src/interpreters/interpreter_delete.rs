@@ -66,6 +66,8 @@ use storages_common_locks::LockManager;
 use storages_common_table_meta::meta::TableSnapshot;
 
 use crate::interpreters::common::create_push_down_filters;
+use crate::interpreters::common::hook_auto_compact;
+use crate::interpreters::common::CompactTargetTableDescription;
 use crate::interpreters::Interpreter;
 use crate::interpreters::SelectInterpreter;
 use crate::pipelines::executor::ExecutorSettings;
@@ -263,6 +265,24 @@ impl Interpreter for DeleteInterpreter {
                     .await?;
         }
 
+        // compact in background if the auto compaction is enabled.
+        {
+            let compact_target = CompactTargetTableDescription {
+                catalog: catalog_name.to_string(),
+                database: db_name.to_string(),
+                table: tbl_name.to_string(),
+                branch: None,
+            };
+
+            hook_auto_compact(
+                self.ctx.clone(),
+                &mut build_res.main_pipeline,
+                compact_target,
+                "delete",
+            )
+            .await;
+        }
+
         build_res.main_pipeline.add_lock_guard(lock_guard);
 
         Ok(build_res)
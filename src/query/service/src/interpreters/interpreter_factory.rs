@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use common_ast::ast::ExplainKind;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use log::error;
 
@@ -22,6 +23,8 @@ use super::interpreter_catalog_create::CreateCatalogInterpreter;
 use super::interpreter_catalog_show_create::ShowCreateCatalogInterpreter;
 use super::interpreter_index_create::CreateIndexInterpreter;
 use super::interpreter_index_drop::DropIndexInterpreter;
+use super::interpreter_inverted_index_create::CreateInvertedIndexInterpreter;
+use super::interpreter_inverted_index_drop::DropInvertedIndexInterpreter;
 use super::interpreter_merge_into::MergeIntoInterpreter;
 use super::interpreter_share_desc::DescShareInterpreter;
 use super::interpreter_table_set_options::SetOptionsInterpreter;
@@ -40,7 +43,14 @@ use crate::interpreters::interpreter_file_format_drop::DropFileFormatInterpreter
 use crate::interpreters::interpreter_file_format_show::ShowFileFormatsInterpreter;
 use crate::interpreters::interpreter_presign::PresignInterpreter;
 use crate::interpreters::interpreter_role_show::ShowRolesInterpreter;
+use crate::interpreters::interpreter_sequence_create::CreateSequenceInterpreter;
+use crate::interpreters::interpreter_sequence_drop::DropSequenceInterpreter;
+use crate::interpreters::interpreter_sequence_show::ShowSequencesInterpreter;
+use crate::interpreters::interpreter_stream_create::CreateStreamInterpreter;
+use crate::interpreters::interpreter_stream_drop::DropStreamInterpreter;
 use crate::interpreters::interpreter_table_create::CreateTableInterpreter;
+use crate::interpreters::interpreter_table_create_snapshot_ref::CreateTableSnapshotRefInterpreter;
+use crate::interpreters::interpreter_table_drop_snapshot_ref::DropTableSnapshotRefInterpreter;
 use crate::interpreters::interpreter_table_revert::RevertTableInterpreter;
 use crate::interpreters::interpreter_task_alter::AlterTaskInterpreter;
 use crate::interpreters::interpreter_task_create::CreateTaskInterpreter;
@@ -48,6 +58,9 @@ use crate::interpreters::interpreter_task_describe::DescribeTaskInterpreter;
 use crate::interpreters::interpreter_task_drop::DropTaskInterpreter;
 use crate::interpreters::interpreter_task_execute::ExecuteTaskInterpreter;
 use crate::interpreters::interpreter_tasks_show::ShowTasksInterpreter;
+use crate::interpreters::interpreter_txn_begin::BeginInterpreter;
+use crate::interpreters::interpreter_txn_commit::CommitInterpreter;
+use crate::interpreters::interpreter_txn_rollback::RollbackInterpreter;
 use crate::interpreters::AlterUserInterpreter;
 use crate::interpreters::CreateShareEndpointInterpreter;
 use crate::interpreters::CreateShareInterpreter;
@@ -66,13 +79,68 @@ pub struct InterpreterFactory;
 impl InterpreterFactory {
     #[async_backtrace::framed]
     pub async fn get(ctx: Arc<QueryContext>, plan: &Plan) -> Result<InterpreterPtr> {
-        // Check the access permission.
-        let access_checker = Accessor::create(ctx.clone());
-        access_checker.check(plan).await.map_err(|e| {
-            error!("Access.denied(v2): {:?}", e);
-            e
-        })?;
-        Self::get_inner(ctx, plan)
+        let res = async {
+            Self::check_txn_state(&ctx, plan)?;
+
+            // Check the access permission.
+            let access_checker = Accessor::create(ctx.clone());
+            access_checker.check(plan).await.map_err(|e| {
+                error!("Access.denied(v2): {:?}", e);
+                e
+            })?;
+            Self::get_inner(ctx.clone(), plan)
+        }
+        .await;
+
+        if res.is_err() {
+            // The statement is rejected before it executes, it fails the explicit transaction
+            // as a statement that fails to execute does.
+            ctx.txn_mgr().lock().set_fail();
+        }
+        res
+    }
+
+    /// Inside an explicit transaction, only the statements which write table snapshots
+    /// can be executed, because the other statements commit to meta directly.
+    fn check_txn_state(ctx: &QueryContext, plan: &Plan) -> Result<()> {
+        let txn_mgr = ctx.txn_mgr();
+        let txn_mgr = txn_mgr.lock();
+        if txn_mgr.is_fail() && !matches!(plan, Plan::Commit | Plan::Rollback) {
+            return Err(ErrorCode::CurrentTransactionIsAborted(
+                "Current transaction is aborted, commands ignored until end of transaction block",
+            ));
+        }
+
+        if txn_mgr.is_active() {
+            match plan {
+                Plan::Query { .. }
+                | Plan::Explain { .. }
+                | Plan::ExplainAst { .. }
+                | Plan::ExplainSyntax { .. }
+                | Plan::ExplainAnalyze { .. }
+                | Plan::Insert(_)
+                | Plan::Replace(_)
+                | Plan::Delete(_)
+                | Plan::Update(_)
+                | Plan::MergeInto(_)
+                | Plan::CopyIntoTable(_)
+                | Plan::UseDatabase(_)
+                | Plan::ShowCreateTable(_)
+                | Plan::DescribeTable(_)
+                | Plan::SetVariable(_)
+                | Plan::UnSetVariable(_)
+                | Plan::Begin
+                | Plan::Commit
+                | Plan::Rollback => {}
+                _ => {
+                    return Err(ErrorCode::StatementNotAllowedInTransaction(
+                        "Only queries and DML statements are allowed in a transaction, \
+                        please COMMIT or ROLLBACK first",
+                    ));
+                }
+            }
+        }
+        Ok(())
     }
 
     pub fn get_inner(ctx: Arc<QueryContext>, plan: &Plan) -> Result<InterpreterPtr> {
@@ -250,6 +318,12 @@ impl InterpreterFactory {
                 ctx,
                 *index.clone(),
             )?)),
+            Plan::CreateInvertedIndex(index) => Ok(Arc::new(
+                CreateInvertedIndexInterpreter::try_create(ctx, *index.clone())?,
+            )),
+            Plan::DropInvertedIndex(index) => Ok(Arc::new(
+                DropInvertedIndexInterpreter::try_create(ctx, *index.clone())?,
+            )),
             // Virtual columns
             Plan::CreateVirtualColumn(create_virtual_column) => Ok(Arc::new(
                 CreateVirtualColumnInterpreter::try_create(ctx, *create_virtual_column.clone())?,
@@ -424,6 +498,12 @@ impl InterpreterFactory {
                 ctx,
                 *p.clone(),
             )?)),
+            Plan::CreateTableSnapshotRef(p) => Ok(Arc::new(
+                CreateTableSnapshotRefInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::DropTableSnapshotRef(p) => Ok(Arc::new(
+                DropTableSnapshotRefInterpreter::try_create(ctx, *p.clone())?,
+            )),
             Plan::CreateDatamaskPolicy(p) => Ok(Arc::new(CreateDataMaskInterpreter::try_create(
                 ctx,
                 *p.clone(),
@@ -485,6 +565,29 @@ impl InterpreterFactory {
                 *p.clone(),
             )?)),
             Plan::ShowConnections(_) => Ok(Arc::new(ShowConnectionsInterpreter::try_create(ctx)?)),
+            Plan::CreateSequence(p) => Ok(Arc::new(CreateSequenceInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::DropSequence(p) => Ok(Arc::new(DropSequenceInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::ShowSequences(p) => Ok(Arc::new(ShowSequencesInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::CreateStream(p) => Ok(Arc::new(CreateStreamInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::DropStream(p) => Ok(Arc::new(DropStreamInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::Begin => Ok(Arc::new(BeginInterpreter::try_create(ctx)?)),
+            Plan::Commit => Ok(Arc::new(CommitInterpreter::try_create(ctx)?)),
+            Plan::Rollback => Ok(Arc::new(RollbackInterpreter::try_create(ctx)?)),
         }
     }
 }
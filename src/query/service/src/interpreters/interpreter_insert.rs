@@ -16,6 +16,7 @@ use std::str::FromStr;
 use std::sync::Arc;
 
 use common_catalog::table::AppendMode;
+use common_catalog::table::NavigationPoint;
 use common_catalog::table::TableExt;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -31,7 +32,9 @@ use common_sql::plans::Plan;
 use common_sql::NameResolutionContext;
 
 use crate::interpreters::common::check_deduplicate_label;
+use crate::interpreters::common::hook_auto_compact;
 use crate::interpreters::common::hook_refresh_agg_index;
+use crate::interpreters::common::CompactTargetTableDescription;
 use crate::interpreters::common::RefreshAggIndexDesc;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
@@ -53,6 +56,15 @@ impl InsertInterpreter {
         Ok(Arc::new(InsertInterpreter { ctx, plan }))
     }
 
+    fn compact_target(&self) -> CompactTargetTableDescription {
+        CompactTargetTableDescription {
+            catalog: self.plan.catalog.clone(),
+            database: self.plan.database.clone(),
+            table: self.plan.table.clone(),
+            branch: self.plan.branch.clone(),
+        }
+    }
+
     fn check_schema_cast(&self, plan: &Plan) -> Result<bool> {
         let output_schema = &self.plan.schema;
         let select_schema = plan.schema();
@@ -87,6 +99,14 @@ impl Interpreter for InsertInterpreter {
             .ctx
             .get_table(&self.plan.catalog, &self.plan.database, &self.plan.table)
             .await?;
+        let table = match &self.plan.branch {
+            Some(branch) => {
+                table
+                    .navigate_to(&NavigationPoint::Branch(branch.clone()))
+                    .await?
+            }
+            None => table,
+        };
 
         // check mutability
         table.check_mutable()?;
@@ -254,6 +274,14 @@ impl Interpreter for InsertInterpreter {
                 )
                 .await?;
 
+                hook_auto_compact(
+                    self.ctx.clone(),
+                    &mut build_res.main_pipeline,
+                    self.compact_target(),
+                    "insert",
+                )
+                .await;
+
                 return Ok(build_res);
             }
         };
@@ -287,6 +315,14 @@ impl Interpreter for InsertInterpreter {
         )
         .await?;
 
+        hook_auto_compact(
+            self.ctx.clone(),
+            &mut build_res.main_pipeline,
+            self.compact_target(),
+            "insert",
+        )
+        .await;
+
         Ok(build_res)
     }
 }
@@ -0,0 +1,118 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_catalog::table::TableExt;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::UpsertTableOptionReq;
+use common_meta_types::MatchSeq;
+use common_sql::plans::CreateInvertedIndexPlan;
+use common_storages_fuse::FuseTable;
+use storages_common_index::InvertedIndex;
+use storages_common_table_meta::table::write_inverted_indexes;
+use storages_common_table_meta::table::InvertedIndexMeta;
+use storages_common_table_meta::table::OPT_KEY_INVERTED_INDEXES;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct CreateInvertedIndexInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateInvertedIndexPlan,
+}
+
+impl CreateInvertedIndexInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateInvertedIndexPlan) -> Result<Self> {
+        Ok(CreateInvertedIndexInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateInvertedIndexInterpreter {
+    fn name(&self) -> &str {
+        "CreateInvertedIndexInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        let catalog = self.ctx.get_catalog(&plan.catalog).await?;
+        let table = catalog
+            .get_table(&plan.tenant, &plan.database, &plan.table)
+            .await?;
+        // check mutability
+        table.check_mutable()?;
+        if table.engine() != "FUSE" {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "Inverted index can only be created on FUSE table, but table {}.{} is {}",
+                plan.database,
+                plan.table,
+                table.engine()
+            )));
+        }
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+
+        let mut inverted_indexes = fuse_table.inverted_indexes().clone();
+        if inverted_indexes.contains_key(&plan.index_name) {
+            if plan.if_not_exists {
+                return Ok(PipelineBuildResult::create());
+            }
+            return Err(ErrorCode::IndexAlreadyExists(format!(
+                "Inverted index '{}' already exists on table {}.{}",
+                plan.index_name, plan.database, plan.table
+            )));
+        }
+
+        let schema = table.schema();
+        let field = schema.field_with_name(&plan.column)?;
+        if field.computed_expr().is_some() {
+            return Err(ErrorCode::UnsupportedIndex(format!(
+                "Inverted index can't be created on computed column '{}'",
+                plan.column
+            )));
+        }
+        if !InvertedIndex::supported_type(field.data_type()) {
+            return Err(ErrorCode::UnsupportedIndex(format!(
+                "Unsupported data type '{}' for inverted index",
+                field.data_type()
+            )));
+        }
+        inverted_indexes.insert(plan.index_name.clone(), InvertedIndexMeta {
+            column_id: field.column_id(),
+            tokenizer: plan.tokenizer.clone(),
+        });
+
+        let mut options = BTreeMap::new();
+        write_inverted_indexes(&mut options, &inverted_indexes)?;
+        let req = UpsertTableOptionReq {
+            table_id: table.get_id(),
+            seq: MatchSeq::Exact(table.get_table_info().ident.seq),
+            options: HashMap::from([(
+                OPT_KEY_INVERTED_INDEXES.to_string(),
+                options.remove(OPT_KEY_INVERTED_INDEXES),
+            )]),
+        };
+        catalog
+            .upsert_table_option(&plan.tenant, &plan.database, req)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -99,6 +99,7 @@ impl Interpreter for MergeIntoInterpreter {
                 catalog: self.plan.catalog.clone(),
                 database: self.plan.database.clone(),
                 table: self.plan.table.clone(),
+                branch: None,
             };
 
             let compact_hook_trace_ctx = CompactHookTraceCtx {
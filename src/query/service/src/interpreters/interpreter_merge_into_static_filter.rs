@@ -304,6 +304,8 @@ impl MergeIntoInterpreter {
             RelOperator::ConstantTableScan(_) => {}
             RelOperator::Pattern(_) => {}
             RelOperator::AddRowNumber(_) => {}
+            RelOperator::RecursiveCteScan(_) => {}
+            RelOperator::AsyncFunction(_) => {}
         }
         Ok(())
     }
@@ -107,6 +107,7 @@ impl Interpreter for ReplaceInterpreter {
                 catalog: self.plan.catalog.clone(),
                 database: self.plan.database.clone(),
                 table: self.plan.table.clone(),
+                branch: None,
             };
 
             let compact_hook_trace_ctx = CompactHookTraceCtx {
@@ -0,0 +1,74 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_exception::Result;
+use common_meta_api::SequenceApi;
+use common_meta_app::schema::CreateSequenceReq;
+use common_meta_app::schema::SequenceNameIdent;
+use common_sql::plans::CreateSequencePlan;
+use common_users::UserApiProvider;
+use log::debug;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sequences::SequenceCache;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct CreateSequenceInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateSequencePlan,
+}
+
+impl CreateSequenceInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateSequencePlan) -> Result<Self> {
+        Ok(CreateSequenceInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateSequenceInterpreter {
+    fn name(&self) -> &str {
+        "CreateSequenceInterpreter"
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "create_sequence_execute");
+
+        let plan = self.plan.clone();
+        let name_ident = SequenceNameIdent::new(plan.tenant, plan.name);
+        let req = CreateSequenceReq {
+            if_not_exists: plan.if_not_exists,
+            name_ident: name_ident.clone(),
+            start: plan.start,
+            step: plan.increment,
+            comment: plan.comment,
+            create_on: Utc::now(),
+        };
+
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+        meta_api.create_sequence(req).await?;
+
+        // Values cached from a dropped sequence of the same name must not be handed out.
+        SequenceCache::instance().invalidate(&name_ident);
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,92 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::types::UInt64Type;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_meta_api::SequenceApi;
+use common_meta_app::schema::ListSequencesReq;
+use common_sql::plans::ShowSequencesPlan;
+use common_users::UserApiProvider;
+use log::debug;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct ShowSequencesInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: ShowSequencesPlan,
+}
+
+impl ShowSequencesInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: ShowSequencesPlan) -> Result<Self> {
+        Ok(ShowSequencesInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for ShowSequencesInterpreter {
+    fn name(&self) -> &str {
+        "ShowSequencesInterpreter"
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "show_sequences_execute");
+
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+        let mut sequences = meta_api
+            .list_sequences(ListSequencesReq {
+                tenant: self.plan.tenant.clone(),
+            })
+            .await?;
+        sequences.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        let mut names = Vec::with_capacity(sequences.len());
+        let mut starts = Vec::with_capacity(sequences.len());
+        let mut increments = Vec::with_capacity(sequences.len());
+        let mut next_values = Vec::with_capacity(sequences.len());
+        let mut created_on = Vec::with_capacity(sequences.len());
+        let mut updated_on = Vec::with_capacity(sequences.len());
+        let mut comments = Vec::with_capacity(sequences.len());
+        for (name, meta) in sequences {
+            names.push(name.into_bytes());
+            starts.push(meta.start);
+            increments.push(meta.step);
+            next_values.push(meta.current);
+            created_on.push(meta.create_on.timestamp_micros());
+            updated_on.push(meta.update_on.map(|t| t.timestamp_micros()));
+            comments.push(meta.comment.map(|s| s.into_bytes()));
+        }
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            UInt64Type::from_data(starts),
+            UInt64Type::from_data(increments),
+            UInt64Type::from_data(next_values),
+            TimestampType::from_data(created_on),
+            TimestampType::from_opt_data(updated_on),
+            StringType::from_opt_data(comments),
+        ])])
+    }
+}
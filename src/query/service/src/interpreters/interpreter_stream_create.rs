@@ -0,0 +1,112 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::TableNameIdent;
+use common_sql::plans::CreateStreamPlan;
+use common_storages_fuse::FuseTable;
+use common_storages_stream::CHANGE_ACTION_COL_NAME;
+use common_storages_stream::CHANGE_IS_UPDATE_COL_NAME;
+use common_storages_stream::OPT_KEY_TABLE_DATABASE;
+use common_storages_stream::OPT_KEY_TABLE_ID;
+use common_storages_stream::OPT_KEY_TABLE_NAME;
+use common_storages_stream::STREAM_ENGINE;
+use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct CreateStreamInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateStreamPlan,
+}
+
+impl CreateStreamInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateStreamPlan) -> Result<Self> {
+        Ok(CreateStreamInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateStreamInterpreter {
+    fn name(&self) -> &str {
+        "CreateStreamInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        let catalog = self.ctx.get_catalog(&plan.catalog).await?;
+        let table = catalog
+            .get_table(&plan.tenant, &plan.table_database, &plan.table_name)
+            .await?;
+        if table.engine() != "FUSE" {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "Stream can only be created on FUSE table, but table {}.{} is {}",
+                plan.table_database,
+                plan.table_name,
+                table.engine()
+            )));
+        }
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+
+        // the columns of the base table, followed by the change columns.
+        let mut schema = fuse_table.schema().as_ref().clone();
+        schema.add_columns(&[
+            TableField::new(CHANGE_ACTION_COL_NAME, TableDataType::String),
+            TableField::new(CHANGE_IS_UPDATE_COL_NAME, TableDataType::Boolean),
+        ])?;
+
+        let mut options = BTreeMap::new();
+        options.insert(OPT_KEY_TABLE_ID.to_string(), table.get_id().to_string());
+        options.insert(OPT_KEY_TABLE_NAME.to_string(), plan.table_name.clone());
+        options.insert(
+            OPT_KEY_TABLE_DATABASE.to_string(),
+            plan.table_database.clone(),
+        );
+        // the offset is the current snapshot of the base table.
+        if let Some(location) = fuse_table.snapshot_loc().await? {
+            options.insert(OPT_KEY_SNAPSHOT_LOCATION.to_string(), location);
+        }
+
+        let req = CreateTableReq {
+            if_not_exists: plan.if_not_exists,
+            name_ident: TableNameIdent {
+                tenant: plan.tenant.clone(),
+                db_name: plan.database.clone(),
+                table_name: plan.stream_name.clone(),
+            },
+            table_meta: TableMeta {
+                schema: Arc::new(schema),
+                engine: STREAM_ENGINE.to_string(),
+                options,
+                comment: plan.comment.clone().unwrap_or_default(),
+                ..Default::default()
+            },
+        };
+        catalog.create_table(req).await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
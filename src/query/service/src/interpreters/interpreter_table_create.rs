@@ -16,6 +16,7 @@ use std::collections::BTreeMap;
 use std::collections::HashSet;
 use std::sync::Arc;
 
+use common_catalog::table::Table;
 use common_config::GlobalConfig;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -31,19 +32,25 @@ use common_license::license_manager::get_license_manager;
 use common_management::RoleApi;
 use common_meta_app::principal::GrantObjectByID;
 use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::DropTableByIdReq;
 use common_meta_app::schema::Ownership;
 use common_meta_app::schema::TableMeta;
 use common_meta_app::schema::TableNameIdent;
 use common_meta_app::schema::TableStatistics;
 use common_meta_types::MatchSeq;
 use common_sql::field_default_value;
+use common_sql::plans::CloneTableSource;
 use common_sql::plans::CreateTablePlan;
 use common_sql::plans::PREDICATE_COLUMN_NAME;
 use common_sql::BloomIndexColumns;
 use common_storage::DataOperator;
 use common_storages_fuse::io::MetaReaders;
+use common_storages_fuse::operations::TableTtl;
+use common_storages_fuse::FuseTable;
 use common_storages_fuse::FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
 use common_storages_fuse::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
+use common_storages_fuse::FUSE_OPT_KEY_ICEBERG_EXPORT;
+use common_storages_fuse::FUSE_OPT_KEY_MERGE_ON_READ;
 use common_storages_fuse::FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD;
 use common_storages_fuse::FUSE_OPT_KEY_ROW_PER_BLOCK;
 use common_storages_fuse::FUSE_OPT_KEY_ROW_PER_PAGE;
@@ -59,11 +66,14 @@ use storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 use storages_common_table_meta::table::OPT_KEY_COMMENT;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use storages_common_table_meta::table::OPT_KEY_ENGINE;
+use storages_common_table_meta::table::OPT_KEY_NGRAM_INDEX_COLUMNS;
 use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_READ_ONLY;
 use storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
+use storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
+use storages_common_table_meta::table::OPT_KEY_TTL;
 
 use crate::interpreters::InsertInterpreter;
 use crate::interpreters::Interpreter;
@@ -174,8 +184,9 @@ impl CreateTableInterpreter {
             .await?;
 
         // grant the ownership of the table to the current role.
+        // temporary tables are not registered in meta, they are always owned by the session.
         let current_role = self.ctx.get_current_role();
-        if let Some(current_role) = current_role {
+        if let Some(current_role) = current_role.filter(|_| !self.is_temp_table()) {
             let db = catalog
                 .get_database(tenant.as_str(), &self.plan.database)
                 .await?;
@@ -208,6 +219,7 @@ impl CreateTableInterpreter {
             database: self.plan.database.clone(),
             table: self.plan.table.clone(),
             table_id: table.get_id(),
+            branch: None,
             schema: self.plan.schema.clone(),
             overwrite: false,
             source: InsertInputSource::SelectPlan(select_plan),
@@ -255,10 +267,20 @@ impl CreateTableInterpreter {
             req.table_meta.owner = Some(Ownership::new(current_role.name));
         }
 
+        // resolve the source of `CREATE TABLE ... CLONE` before the table is created
+        let clone_source = match &self.plan.clone_from {
+            Some(clone_from) => Some(self.get_clone_source(clone_from).await?),
+            None => None,
+        };
+
         let reply = catalog.create_table(req.clone()).await?;
 
         // grant the ownership of the table to the current role, the above req.table_meta.owner could be removed in future.
-        if let Some(current_role) = self.ctx.get_current_role() {
+        if let Some(current_role) = self
+            .ctx
+            .get_current_role()
+            .filter(|_| !self.is_temp_table())
+        {
             let tenant = self.ctx.get_tenant();
             let db = catalog
                 .get_database(tenant.as_str(), &self.plan.database)
@@ -278,9 +300,52 @@ impl CreateTableInterpreter {
                 .await?;
         }
 
+        if let Some(source) = clone_source.filter(|_| reply.new_table) {
+            if let Err(e) = self.clone_table_files(source.as_ref()).await {
+                // the table is not usable without the cloned files, drop it
+                catalog
+                    .drop_table_by_id(DropTableByIdReq {
+                        if_exists: true,
+                        tenant: self.plan.tenant.clone(),
+                        tb_id: reply.table_id,
+                    })
+                    .await?;
+                return Err(e);
+            }
+        }
+
         Ok(PipelineBuildResult::create())
     }
 
+    /// Gets the source table of `CREATE TABLE ... CLONE`, navigated to the point being cloned.
+    #[async_backtrace::framed]
+    async fn get_clone_source(&self, clone_from: &CloneTableSource) -> Result<Arc<dyn Table>> {
+        let source = self
+            .ctx
+            .get_table(&clone_from.catalog, &clone_from.database, &clone_from.table)
+            .await?;
+        match &clone_from.point {
+            Some(point) => source.navigate_to(point).await,
+            None => Ok(source),
+        }
+    }
+
+    /// Shares the files of the source table of `CREATE TABLE ... CLONE` with the created table.
+    #[async_backtrace::framed]
+    async fn clone_table_files(&self, source: &dyn Table) -> Result<()> {
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let table = catalog
+            .get_table(&self.plan.tenant, &self.plan.database, &self.plan.table)
+            .await?;
+        FuseTable::try_from_table(table.as_ref())?
+            .do_clone_from(self.ctx.clone(), FuseTable::try_from_table(source)?)
+            .await
+    }
+
+    fn is_temp_table(&self) -> bool {
+        self.plan.options.contains_key(OPT_KEY_TEMP_PREFIX)
+    }
+
     /// Build CreateTableReq from CreateTablePlanV2.
     ///
     /// - Rebuild `DataSchema` with default exprs.
@@ -320,7 +385,12 @@ impl CreateTableInterpreter {
         is_valid_block_per_segment(&table_meta.options)?;
         is_valid_row_per_block(&table_meta.options)?;
         // check bloom_index_columns.
-        is_valid_bloom_index_columns(&table_meta.options, schema)?;
+        is_valid_bloom_index_columns(&table_meta.options, schema.clone())?;
+        // check ngram_index_columns.
+        is_valid_ngram_index_columns(&table_meta.options, schema.clone())?;
+        // check ttl.
+        is_valid_ttl(self.ctx.clone(), &table_meta.options, schema)?;
+        is_valid_iceberg_export(&table_meta.options)?;
 
         for table_option in table_meta.options.iter() {
             let key = table_option.0.to_lowercase();
@@ -424,8 +494,12 @@ pub static CREATE_TABLE_OPTIONS: Lazy<HashSet<&'static str>> = Lazy::new(|| {
     r.insert(FUSE_OPT_KEY_ROW_PER_BLOCK);
     r.insert(FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD);
     r.insert(FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD);
+    r.insert(FUSE_OPT_KEY_MERGE_ON_READ);
+    r.insert(FUSE_OPT_KEY_ICEBERG_EXPORT);
 
     r.insert(OPT_KEY_BLOOM_INDEX_COLUMNS);
+    r.insert(OPT_KEY_NGRAM_INDEX_COLUMNS);
+    r.insert(OPT_KEY_TTL);
     r.insert(OPT_KEY_TABLE_COMPRESSION);
     r.insert(OPT_KEY_STORAGE_FORMAT);
     r.insert(OPT_KEY_DATABASE_ID);
@@ -434,6 +508,7 @@ pub static CREATE_TABLE_OPTIONS: Lazy<HashSet<&'static str>> = Lazy::new(|| {
     r.insert(OPT_KEY_ENGINE);
 
     r.insert("transient");
+    r.insert(OPT_KEY_TEMP_PREFIX);
     r
 });
 
@@ -503,3 +578,35 @@ pub fn is_valid_bloom_index_columns(
     }
     Ok(())
 }
+
+pub fn is_valid_ttl(
+    ctx: Arc<QueryContext>,
+    options: &BTreeMap<String, String>,
+    schema: TableSchemaRef,
+) -> Result<()> {
+    if let Some(value) = options.get(OPT_KEY_TTL) {
+        TableTtl::try_create(ctx, value, schema)?;
+    }
+    Ok(())
+}
+
+pub fn is_valid_iceberg_export(options: &BTreeMap<String, String>) -> Result<()> {
+    if let Some(value) = options.get(FUSE_OPT_KEY_ICEBERG_EXPORT) {
+        if value.parse::<bool>().is_err() {
+            let error_str = format!("invalid iceberg_export option {value}, must be true or false");
+            error!("{}", error_str);
+            return Err(ErrorCode::TableOptionInvalid(error_str));
+        }
+    }
+    Ok(())
+}
+
+pub fn is_valid_ngram_index_columns(
+    options: &BTreeMap<String, String>,
+    schema: TableSchemaRef,
+) -> Result<()> {
+    if let Some(value) = options.get(OPT_KEY_NGRAM_INDEX_COLUMNS) {
+        BloomIndexColumns::verify_definition(value, schema, BloomIndex::supported_ngram_type)?;
+    }
+    Ok(())
+}
@@ -25,6 +25,7 @@ use common_storages_fuse::FuseTable;
 use common_storages_share::save_share_spec;
 use common_storages_view::view_table::VIEW_ENGINE;
 use common_users::UserApiProvider;
+use storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
 
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -73,18 +74,23 @@ impl Interpreter for DropTableInterpreter {
                 )));
             }
             let catalog = self.ctx.get_catalog(catalog_name).await?;
+            // temporary tables are not registered in meta, their data is purged once dropped.
+            let is_temp = tbl.options().contains_key(OPT_KEY_TEMP_PREFIX);
 
             // drop the ownership
-            let tenant = self.ctx.get_tenant();
-            let role_api = UserApiProvider::instance().get_role_api_client(&self.plan.tenant)?;
-            let db = catalog.get_database(&tenant, &self.plan.database).await?;
-            role_api
-                .drop_ownership(&GrantObjectByID::Table {
-                    catalog_name: self.plan.catalog.clone(),
-                    db_id: db.get_db_info().ident.db_id,
-                    table_id: tbl.get_table_info().ident.table_id,
-                })
-                .await?;
+            if !is_temp {
+                let tenant = self.ctx.get_tenant();
+                let role_api =
+                    UserApiProvider::instance().get_role_api_client(&self.plan.tenant)?;
+                let db = catalog.get_database(&tenant, &self.plan.database).await?;
+                role_api
+                    .drop_ownership(&GrantObjectByID::Table {
+                        catalog_name: self.plan.catalog.clone(),
+                        db_id: db.get_db_info().ident.db_id,
+                        table_id: tbl.get_table_info().ident.table_id,
+                    })
+                    .await?;
+            }
 
             // Although even if data is in READ_ONLY mode,
             // as a catalog object, the table itself is allowed to be dropped (and undropped later),
@@ -106,7 +112,7 @@ impl Interpreter for DropTableInterpreter {
                 .await?;
 
             // if `plan.all`, truncate, then purge the historical data
-            if self.plan.all {
+            if self.plan.all && !is_temp {
                 // the above `catalog.drop_table` operation changed the table meta version,
                 // thus if we do not refresh the table instance, `truncate` will fail
                 let latest = tbl.as_ref().refresh(self.ctx.as_ref()).await?;
@@ -25,7 +25,10 @@ use common_sql::plans::DropTableColumnPlan;
 use common_sql::BloomIndexColumns;
 use common_storages_share::save_share_table_info;
 use common_storages_view::view_table::VIEW_ENGINE;
+use storages_common_table_meta::table::read_inverted_indexes;
+use storages_common_table_meta::table::write_inverted_indexes;
 use storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
+use storages_common_table_meta::table::OPT_KEY_NGRAM_INDEX_COLUMNS;
 
 use crate::interpreters::common::check_referenced_computed_columns;
 use crate::interpreters::Interpreter;
@@ -93,20 +96,33 @@ impl Interpreter for DropTableColumnInterpreter {
 
         let catalog = self.ctx.get_catalog(catalog_name).await?;
         let mut new_table_meta = table.get_table_info().meta.clone();
+        let column_id = new_table_meta
+            .schema
+            .field_with_name(&self.plan.column)
+            .map(|f| f.column_id())
+            .ok();
         new_table_meta.drop_column(&self.plan.column)?;
 
         // update table options
         let opts = &mut new_table_meta.options;
-        if let Some(value) = opts.get_mut(OPT_KEY_BLOOM_INDEX_COLUMNS) {
-            let bloom_index_cols = value.parse::<BloomIndexColumns>()?;
-            if let BloomIndexColumns::Specify(mut cols) = bloom_index_cols {
-                if let Some(pos) = cols.iter().position(|x| *x == self.plan.column) {
-                    // remove from the bloom index columns.
-                    cols.remove(pos);
-                    *value = cols.join(",");
+        for key in [OPT_KEY_BLOOM_INDEX_COLUMNS, OPT_KEY_NGRAM_INDEX_COLUMNS] {
+            if let Some(value) = opts.get_mut(key) {
+                let index_cols = value.parse::<BloomIndexColumns>()?;
+                if let BloomIndexColumns::Specify(mut cols) = index_cols {
+                    if let Some(pos) = cols.iter().position(|x| *x == self.plan.column) {
+                        // remove from the index columns.
+                        cols.remove(pos);
+                        *value = cols.join(",");
+                    }
                 }
             }
         }
+        // drop the inverted indexes of the column.
+        let mut inverted_indexes = read_inverted_indexes(opts)?;
+        if !inverted_indexes.is_empty() {
+            inverted_indexes.retain(|_, index| Some(index.column_id) != column_id);
+            write_inverted_indexes(opts, &inverted_indexes)?;
+        }
 
         let table_id = table_info.ident.table_id;
         let table_version = table_info.ident.seq;
@@ -0,0 +1,61 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table::TableExt;
+use common_exception::Result;
+use common_sql::plans::DropTableSnapshotRefPlan;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct DropTableSnapshotRefInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DropTableSnapshotRefPlan,
+}
+
+impl DropTableSnapshotRefInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DropTableSnapshotRefPlan) -> Result<Self> {
+        Ok(DropTableSnapshotRefInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropTableSnapshotRefInterpreter {
+    fn name(&self) -> &str {
+        "DropTableSnapshotRefInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(self.plan.catalog.as_str()).await?;
+
+        let table = catalog
+            .get_table(tenant.as_str(), &self.plan.database, &self.plan.table)
+            .await?;
+
+        // check mutability
+        table.check_mutable()?;
+
+        table
+            .drop_snapshot_ref(self.ctx.clone(), self.plan.ref_type, &self.plan.name)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
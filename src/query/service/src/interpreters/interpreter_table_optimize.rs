@@ -147,7 +147,7 @@ impl OptimizeTableInterpreter {
         need_purge: bool,
     ) -> Result<PipelineBuildResult> {
         let tenant = self.ctx.get_tenant();
-        let table_info = table.get_table_info().clone();
+        let mut table_info = table.get_table_info().clone();
 
         // check if the table is locked.
         let table_lock = LockManager::create_table_lock(table_info.clone())?;
@@ -158,6 +158,16 @@ impl OptimizeTableInterpreter {
             )));
         }
 
+        // drop the blocks whose rows are all expired by the ttl, before the compaction.
+        if let Ok(fuse_table) = FuseTable::try_from_table(table.as_ref()) {
+            if fuse_table.drop_expired_blocks(self.ctx.clone()).await? {
+                table = catalog
+                    .get_table(tenant.as_str(), &self.plan.database, &self.plan.table)
+                    .await?;
+                table_info = table.get_table_info().clone();
+            }
+        }
+
         if matches!(target, CompactTarget::Segments) {
             table
                 .compact_segments(self.ctx.clone(), self.plan.limit)
@@ -21,7 +21,9 @@ use common_exception::Result;
 use common_meta_app::schema::UpsertTableOptionReq;
 use common_meta_types::MatchSeq;
 use common_sql::plans::SetOptionsPlan;
+use common_storages_fuse::FuseTable;
 use common_storages_fuse::TableContext;
+use common_storages_fuse::FUSE_OPT_KEY_ICEBERG_EXPORT;
 use log::error;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
@@ -29,7 +31,10 @@ use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use super::interpreter_table_create::is_valid_block_per_segment;
 use super::interpreter_table_create::is_valid_bloom_index_columns;
 use super::interpreter_table_create::is_valid_create_opt;
+use super::interpreter_table_create::is_valid_iceberg_export;
+use super::interpreter_table_create::is_valid_ngram_index_columns;
 use super::interpreter_table_create::is_valid_row_per_block;
+use super::interpreter_table_create::is_valid_ttl;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -59,6 +64,8 @@ impl Interpreter for SetOptionsInterpreter {
         is_valid_block_per_segment(&self.plan.set_options)?;
         // check row_per_block
         is_valid_row_per_block(&self.plan.set_options)?;
+        // check iceberg_export
+        is_valid_iceberg_export(&self.plan.set_options)?;
         // check storage_format
         let error_str = "invalid opt for fuse table in alter table statement";
         if self.plan.set_options.get(OPT_KEY_STORAGE_FORMAT).is_some() {
@@ -108,6 +115,10 @@ impl Interpreter for SetOptionsInterpreter {
 
         // check bloom_index_columns.
         is_valid_bloom_index_columns(&self.plan.set_options, table.schema())?;
+        // check ngram_index_columns.
+        is_valid_ngram_index_columns(&self.plan.set_options, table.schema())?;
+        // check ttl.
+        is_valid_ttl(self.ctx.clone(), &self.plan.set_options, table.schema())?;
 
         let req = UpsertTableOptionReq {
             table_id: table.get_id(),
@@ -118,6 +129,13 @@ impl Interpreter for SetOptionsInterpreter {
         catalog
             .upsert_table_option(self.ctx.get_tenant().as_str(), database, req)
             .await?;
+
+        // export the current snapshot, the following ones are exported on commit.
+        let iceberg_export = self.plan.set_options.get(FUSE_OPT_KEY_ICEBERG_EXPORT);
+        if iceberg_export.is_some_and(|v| v.parse::<bool>().unwrap_or(false)) {
+            let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+            fuse_table.export_iceberg_metadata().await?;
+        }
         Ok(PipelineBuildResult::create())
     }
 }
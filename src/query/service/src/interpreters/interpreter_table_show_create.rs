@@ -31,6 +31,7 @@ use storages_common_table_meta::table::is_internal_opt_key;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_DATA_URI;
 use storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_READ_ONLY;
+use storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
 
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -87,6 +88,9 @@ impl ShowCreateTableInterpreter {
         if table.options().contains_key("TRANSIENT") {
             table_create_sql = format!("CREATE TRANSIENT TABLE `{}` (\n", name)
         }
+        if table.options().contains_key(OPT_KEY_TEMP_PREFIX) {
+            table_create_sql = format!("CREATE TEMPORARY TABLE `{}` (\n", name)
+        }
 
         // Append columns.
         {
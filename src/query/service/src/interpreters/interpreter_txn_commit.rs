@@ -0,0 +1,95 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
+use common_storages_fuse::FuseTable;
+use log::info;
+use log::warn;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Commit the explicit transaction, the buffered table updates are committed to meta
+/// in one transaction.
+pub struct CommitInterpreter {
+    ctx: Arc<QueryContext>,
+}
+
+impl CommitInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>) -> Result<Self> {
+        Ok(CommitInterpreter { ctx })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CommitInterpreter {
+    fn name(&self) -> &str {
+        "CommitInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        // The transaction is ended whether the commit succeeds or not.
+        let txn_mgr = self.ctx.txn_mgr();
+        let (is_fail, mutated_tables) = {
+            let mut txn_mgr = txn_mgr.lock();
+            (txn_mgr.is_fail(), txn_mgr.end())
+        };
+
+        if is_fail {
+            return Err(ErrorCode::CurrentTransactionIsAborted(
+                "Current transaction is aborted, it has been rolled back",
+            ));
+        }
+
+        // `COMMIT` without a transaction, or a transaction without writes.
+        if mutated_tables.is_empty() {
+            return Ok(PipelineBuildResult::create());
+        }
+
+        info!(
+            "Commit transaction of query {}, {} tables updated",
+            self.ctx.get_id(),
+            mutated_tables.len()
+        );
+        let (table_infos, update_table_metas): (Vec<_>, Vec<_>) =
+            mutated_tables.into_iter().unzip();
+        let catalog = self.ctx.get_default_catalog()?;
+        catalog
+            .update_multi_table_meta(UpdateMultiTableMetaReq { update_table_metas })
+            .await?;
+
+        // The side effects of the commits in the transaction are deferred until now,
+        // e.g. the hint file of the last snapshot. The transaction is committed already,
+        // they are done in the best effort.
+        for table_info in table_infos {
+            let table = catalog.get_table_by_info(&table_info)?;
+            if let Ok(fuse_table) = FuseTable::try_from_table(table.as_ref()) {
+                if let Err(e) = fuse_table.post_txn_commit().await {
+                    warn!(
+                        "post commit of table {} in transaction failure. {}",
+                        table_info.desc, e
+                    );
+                }
+            }
+        }
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -32,7 +32,9 @@ use log::debug;
 use storages_common_locks::LockManager;
 
 use crate::interpreters::common::check_deduplicate_label;
+use crate::interpreters::common::hook_auto_compact;
 use crate::interpreters::common::hook_refresh_agg_index;
+use crate::interpreters::common::CompactTargetTableDescription;
 use crate::interpreters::common::RefreshAggIndexDesc;
 use crate::interpreters::interpreter_delete::replace_subquery;
 use crate::interpreters::interpreter_delete::subquery_filter;
@@ -187,6 +189,7 @@ impl Interpreter for UpdateInterpreter {
                 catalog: catalog_name.to_string(),
                 database: db_name.to_string(),
                 table: tbl_name.to_string(),
+                branch: None,
             };
 
             hook_refresh_agg_index(
@@ -197,6 +200,23 @@ impl Interpreter for UpdateInterpreter {
             .await?;
         }
 
+        // compact in background if the auto compaction is enabled.
+        {
+            let compact_target = CompactTargetTableDescription {
+                catalog: catalog_name.to_string(),
+                database: db_name.to_string(),
+                table: tbl_name.to_string(),
+            };
+
+            hook_auto_compact(
+                self.ctx.clone(),
+                &mut build_res.main_pipeline,
+                compact_target,
+                "update",
+            )
+            .await;
+        }
+
         build_res.main_pipeline.add_lock_guard(lock_guard);
         Ok(build_res)
     }
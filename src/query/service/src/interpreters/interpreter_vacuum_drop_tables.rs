@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::cmp::min;
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use common_exception::Result;
@@ -22,12 +23,15 @@ use common_expression::FromData;
 use common_license::license::Feature::Vacuum;
 use common_license::license_manager::get_license_manager;
 use common_meta_app::schema::DatabaseNameIdent;
+use common_meta_app::schema::DroppedId;
 use common_meta_app::schema::GcDroppedTableReq;
 use common_meta_app::schema::ListDroppedTableReq;
 use common_meta_app::schema::TableInfoFilter;
 use common_sql::plans::VacuumDropTablePlan;
+use common_storages_fuse::FuseTable;
 use log::as_debug;
 use log::info;
+use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use vacuum_handler::get_vacuum_handler;
 
 use crate::interpreters::Interpreter;
@@ -94,10 +98,44 @@ impl Interpreter for VacuumDropTablesInterpreter {
             .filter(|tbl| !tbl.as_ref().is_read_only())
             .collect::<Vec<_>>();
 
+        // the files of a table are shared with the tables cloned from it, keep the table and
+        // its meta data until none of its clones remains, not counting the clones vacuumed here.
+        let vacuuming = tables.iter().map(|t| t.get_id()).collect::<HashSet<_>>();
+        let mut kept_tables = HashSet::new();
+        let mut kept_dbs = HashSet::new();
+        for table in &tables {
+            let table_info = table.get_table_info();
+            if FuseTable::has_existing_clones(catalog.as_ref(), table_info, &vacuuming).await? {
+                info!(
+                    "keep table {} whose files are shared with its clones",
+                    table_info.desc
+                );
+                kept_tables.insert(table.get_id());
+                if let Some(db_id) = table_info
+                    .options()
+                    .get(OPT_KEY_DATABASE_ID)
+                    .and_then(|id| id.parse::<u64>().ok())
+                {
+                    kept_dbs.insert(db_id);
+                }
+            }
+        }
+        let tables = tables
+            .into_iter()
+            .filter(|t| !kept_tables.contains(&t.get_id()))
+            .collect::<Vec<_>>();
+        let drop_ids = drop_ids
+            .into_iter()
+            .filter(|id| match id {
+                DroppedId::Db(db_id, _) => !kept_dbs.contains(db_id),
+                DroppedId::Table(_, table_id, _) => !kept_tables.contains(table_id),
+            })
+            .collect::<Vec<_>>();
+
         let handler = get_vacuum_handler();
         let files_opt = handler
             .do_vacuum_drop_tables(
-                tables,
+                tables.clone(),
                 if self.plan.option.dry_run.is_some() {
                     Some(DRY_RUN_LIMIT)
                 } else {
@@ -107,6 +145,10 @@ impl Interpreter for VacuumDropTablesInterpreter {
             .await?;
         // gc meta data only when not dry run
         if self.plan.option.dry_run.is_none() {
+            // the vacuumed clones no longer share the files of the tables they were cloned from
+            for table in &tables {
+                FuseTable::unregister_clone(catalog.as_ref(), table.get_table_info()).await?;
+            }
             info!(drop_ids = as_debug!(&drop_ids); "vacuum drop table");
             let req = GcDroppedTableReq {
                 tenant: self.ctx.get_tenant(),
@@ -45,6 +45,8 @@ mod interpreter_index_create;
 mod interpreter_index_drop;
 mod interpreter_index_refresh;
 mod interpreter_insert;
+mod interpreter_inverted_index_create;
+mod interpreter_inverted_index_drop;
 mod interpreter_kill;
 mod interpreter_merge_into;
 mod interpreter_merge_into_static_filter;
@@ -65,6 +67,9 @@ mod interpreter_role_revoke;
 mod interpreter_role_set;
 mod interpreter_role_show;
 mod interpreter_select;
+mod interpreter_sequence_create;
+mod interpreter_sequence_drop;
+mod interpreter_sequence_show;
 mod interpreter_setting;
 mod interpreter_share_alter_tenants;
 mod interpreter_share_create;
@@ -79,12 +84,16 @@ mod interpreter_share_show;
 mod interpreter_share_show_grant_tenants;
 mod interpreter_show_grants;
 mod interpreter_show_object_grant_privileges;
+mod interpreter_stream_create;
+mod interpreter_stream_drop;
 mod interpreter_table_add_column;
 mod interpreter_table_analyze;
 mod interpreter_table_create;
+mod interpreter_table_create_snapshot_ref;
 mod interpreter_table_describe;
 mod interpreter_table_drop;
 mod interpreter_table_drop_column;
+mod interpreter_table_drop_snapshot_ref;
 mod interpreter_table_exists;
 mod interpreter_table_modify_column;
 mod interpreter_table_optimize;
@@ -103,6 +112,9 @@ mod interpreter_task_describe;
 mod interpreter_task_drop;
 mod interpreter_task_execute;
 mod interpreter_tasks_show;
+mod interpreter_txn_begin;
+mod interpreter_txn_commit;
+mod interpreter_txn_rollback;
 mod interpreter_unsetting;
 mod interpreter_update;
 mod interpreter_use_database;
@@ -125,6 +137,7 @@ mod interpreter_virtual_column_drop;
 mod interpreter_virtual_column_refresh;
 
 pub use access::ManagementModeAccess;
+pub use common::CompactTargetTableDescription;
 pub use common::InterpreterQueryLog;
 pub use interpreter::Interpreter;
 pub use interpreter::InterpreterPtr;
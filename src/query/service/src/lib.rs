@@ -39,6 +39,7 @@ extern crate core;
 
 pub mod api;
 pub mod auth;
+pub mod auto_compaction;
 pub mod catalogs;
 pub mod clusters;
 pub mod databases;
@@ -47,6 +48,7 @@ pub mod local;
 pub mod metrics;
 pub mod pipelines;
 pub mod schedulers;
+pub mod sequences;
 pub mod servers;
 pub mod sessions;
 pub mod spillers;
@@ -0,0 +1,36 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_sql::executor::AsyncFunction;
+
+use crate::pipelines::processors::transforms::TransformAsyncFunction;
+use crate::pipelines::PipelineBuilder;
+
+impl PipelineBuilder {
+    pub(crate) fn build_async_function(&mut self, async_func: &AsyncFunction) -> Result<()> {
+        self.build_pipeline(&async_func.input)?;
+
+        let tenant = self.ctx.get_tenant();
+        self.main_pipeline.add_transform(|input, output| {
+            Ok(TransformAsyncFunction::create(
+                input,
+                output,
+                tenant.clone(),
+                async_func.async_func_descs.clone(),
+            ))
+        })
+    }
+}
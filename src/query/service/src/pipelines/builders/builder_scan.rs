@@ -28,10 +28,12 @@ use common_sql::evaluator::BlockOperator;
 use common_sql::evaluator::CompoundBlockOperator;
 use common_sql::executor::ConstantTableScan;
 use common_sql::executor::CteScan;
+use common_sql::executor::RecursiveCteScan;
 use common_sql::executor::TableScan;
 use common_storages_fuse::operations::FillInternalColumnProcessor;
 
 use crate::pipelines::processors::transforms::MaterializedCteSource;
+use crate::pipelines::processors::transforms::RecursiveCteScanSource;
 use crate::pipelines::PipelineBuilder;
 
 impl PipelineBuilder {
@@ -132,6 +134,20 @@ impl PipelineBuilder {
         )
     }
 
+    pub(crate) fn build_recursive_cte_scan(&mut self, scan: &RecursiveCteScan) -> Result<()> {
+        self.main_pipeline.add_source(
+            |output| {
+                RecursiveCteScanSource::create(
+                    self.ctx.clone(),
+                    output,
+                    scan.cte_idx,
+                    scan.offsets.clone(),
+                )
+            },
+            1,
+        )
+    }
+
     pub(crate) fn build_constant_table_scan(&mut self, scan: &ConstantTableScan) -> Result<()> {
         self.main_pipeline.add_source(
             |output| {
@@ -12,16 +12,27 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_catalog::table_context::TableContext;
 use common_exception::Result;
 use common_expression::DataSchemaRef;
 use common_expression::SortColumnDescription;
 use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_core::query_spill_prefix;
+use common_pipeline_transforms::processors::profile_wrapper::ProcessorProfileWrapper;
 use common_pipeline_transforms::processors::transforms::build_full_sort_pipeline;
+use common_pipeline_transforms::processors::transforms::build_partial_sort_pipeline;
+use common_pipeline_transforms::processors::transforms::try_add_multi_sort_merge;
+use common_profile::SharedProcessorProfiles;
 use common_sql::evaluator::BlockOperator;
 use common_sql::evaluator::CompoundBlockOperator;
 use common_sql::executor::Sort;
+use common_storage::DataOperator;
 
+use crate::pipelines::processors::transforms::create_transform_sort_spill;
 use crate::pipelines::PipelineBuilder;
+use crate::spillers::Spiller;
+use crate::spillers::SpillerConfig;
+use crate::spillers::SpillerType;
 
 impl PipelineBuilder {
     pub(crate) fn build_sort(&mut self, sort: &Sort) -> Result<()> {
@@ -98,6 +109,21 @@ impl PipelineBuilder {
             None
         };
 
+        // A sort with limit never spills: `TransformSortMergeLimit` only keeps the top `limit`
+        // rows of each pipeline in memory, so its memory usage is bounded by the limit.
+        let spilling_bytes_threshold =
+            self.settings.get_sort_spilling_bytes_threshold_per_proc()?;
+        if limit.is_none() && spilling_bytes_threshold > 0 {
+            return self.build_spilling_sort_pipeline(
+                input_schema,
+                sort_desc,
+                block_size,
+                spilling_bytes_threshold,
+                prof_info,
+                after_exchange,
+            );
+        }
+
         build_full_sort_pipeline(
             &mut self.main_pipeline,
             input_schema,
@@ -109,4 +135,67 @@ impl PipelineBuilder {
             after_exchange,
         )
     }
+
+    // Sort without limit, the sorted runs will be spilled if the memory threshold is exceeded.
+    fn build_spilling_sort_pipeline(
+        &mut self,
+        input_schema: DataSchemaRef,
+        sort_desc: Vec<SortColumnDescription>,
+        block_size: usize,
+        spilling_bytes_threshold: usize,
+        prof_info: Option<(u32, SharedProcessorProfiles)>,
+        after_exchange: bool,
+    ) -> Result<()> {
+        build_partial_sort_pipeline(
+            &mut self.main_pipeline,
+            sort_desc.clone(),
+            None,
+            prof_info.clone(),
+            after_exchange,
+        )?;
+
+        // Merge sort with spilling
+        let need_multi_merge = self.main_pipeline.output_len() > 1;
+        let tenant = self.ctx.get_tenant();
+        self.main_pipeline.add_transform(|input, output| {
+            let spiller = Spiller::create(
+                self.ctx.clone(),
+                DataOperator::instance().operator(),
+                SpillerConfig::create(query_spill_prefix(&tenant)),
+                SpillerType::OrderBy,
+            );
+            let transform = create_transform_sort_spill(
+                input,
+                output,
+                input_schema.clone(),
+                block_size,
+                sort_desc.clone(),
+                need_multi_merge,
+                spilling_bytes_threshold,
+                spiller,
+            )?;
+            if let Some((plan_id, prof)) = &prof_info {
+                Ok(ProcessorPtr::create(ProcessorProfileWrapper::create(
+                    transform,
+                    *plan_id,
+                    prof.clone(),
+                )))
+            } else {
+                Ok(ProcessorPtr::create(transform))
+            }
+        })?;
+
+        if need_multi_merge {
+            // Multi-pipelines merge sort
+            try_add_multi_sort_merge(
+                &mut self.main_pipeline,
+                input_schema,
+                block_size,
+                None,
+                sort_desc,
+            )?;
+        }
+
+        Ok(())
+    }
 }
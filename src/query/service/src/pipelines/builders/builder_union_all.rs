@@ -22,11 +22,15 @@ use common_sql::executor::PhysicalPlan;
 use common_sql::executor::UnionAll;
 
 use crate::pipelines::processors::transforms::TransformMergeBlock;
+use crate::pipelines::processors::transforms::TransformRecursiveCteSource;
 use crate::pipelines::PipelineBuilder;
 use crate::sessions::QueryContext;
 
 impl PipelineBuilder {
     pub fn build_union_all(&mut self, union_all: &UnionAll) -> Result<()> {
+        if union_all.recursive_cte.is_some() {
+            return self.build_recursive_union_all(union_all);
+        }
         self.build_pipeline(&union_all.left)?;
         let union_all_receiver = self.expand_union_all(&union_all.right, union_all)?;
         self.main_pipeline
@@ -53,6 +57,13 @@ impl PipelineBuilder {
         Ok(())
     }
 
+    fn build_recursive_union_all(&mut self, union_all: &UnionAll) -> Result<()> {
+        self.main_pipeline.add_source(
+            |output| TransformRecursiveCteSource::create(self.ctx.clone(), output, union_all),
+            1,
+        )
+    }
+
     fn expand_union_all(
         &mut self,
         input: &PhysicalPlan,
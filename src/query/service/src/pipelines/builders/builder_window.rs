@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_catalog::table_context::TableContext;
 use common_exception::Result;
 use common_expression::types::DataType;
 use common_expression::types::NumberDataType;
@@ -19,12 +20,20 @@ use common_expression::with_number_mapped_type;
 use common_expression::SortColumnDescription;
 use common_pipeline_core::processors::processor::ProcessorPtr;
 use common_pipeline_core::processors::Processor;
+use common_pipeline_core::query_spill_prefix;
+use common_pipeline_transforms::processors::transforms::AccumulatingTransformer;
 use common_sql::executor::Window;
+use common_sql::executor::WindowFunction;
+use common_storage::DataOperator;
 
 use crate::pipelines::processors::transforms::FrameBound;
+use crate::pipelines::processors::transforms::TransformWindowPartitionTopN;
 use crate::pipelines::processors::transforms::WindowFunctionInfo;
 use crate::pipelines::processors::TransformWindow;
 use crate::pipelines::PipelineBuilder;
+use crate::spillers::Spiller;
+use crate::spillers::SpillerConfig;
+use crate::spillers::SpillerType;
 
 impl PipelineBuilder {
     pub(crate) fn build_window(&mut self, window: &Window) -> Result<()> {
@@ -70,11 +79,40 @@ impl PipelineBuilder {
 
             sort_desc.extend(order_by.clone());
 
+            // Only the top rows of each partition are required, drop the others before sorting.
+            if let Some(limit) = window.limit {
+                let with_ties = matches!(window.func, WindowFunction::Rank);
+                self.main_pipeline.add_transform(|input, output| {
+                    Ok(ProcessorPtr::create(AccumulatingTransformer::create(
+                        input,
+                        output,
+                        TransformWindowPartitionTopN::new(
+                            sort_desc.clone(),
+                            partition_by.len(),
+                            limit,
+                            with_ties,
+                        ),
+                    )))
+                })?;
+            }
+
             self.build_sort_pipeline(input_schema.clone(), sort_desc, window.plan_id, None, false)?;
         }
         // `TransformWindow` is a pipeline breaker.
         self.main_pipeline.try_resize(1)?;
         let func = WindowFunctionInfo::try_create(&window.func, &input_schema)?;
+        let spilling_bytes_threshold = self
+            .settings
+            .get_window_spilling_bytes_threshold_per_proc()?;
+        let tenant = self.ctx.get_tenant();
+        let create_spiller = || {
+            Spiller::create(
+                self.ctx.clone(),
+                DataOperator::instance().operator(),
+                SpillerConfig::create(query_spill_prefix(&tenant)),
+                SpillerType::Window,
+            )
+        };
         // Window
         self.main_pipeline.add_transform(|input, output| {
             // The transform can only be created here, because it cannot be cloned.
@@ -82,14 +120,17 @@ impl PipelineBuilder {
             let transform = if window.window_frame.units.is_rows() {
                 let start_bound = FrameBound::try_from(&window.window_frame.start_bound)?;
                 let end_bound = FrameBound::try_from(&window.window_frame.end_bound)?;
-                Box::new(TransformWindow::<u64>::try_create_rows(
-                    input,
-                    output,
-                    func.clone(),
-                    partition_by.clone(),
-                    order_by.clone(),
-                    (start_bound, end_bound),
-                )?) as Box<dyn Processor>
+                Box::new(
+                    TransformWindow::<u64>::try_create_rows(
+                        input,
+                        output,
+                        func.clone(),
+                        partition_by.clone(),
+                        order_by.clone(),
+                        (start_bound, end_bound),
+                    )?
+                    .with_spiller(create_spiller(), spilling_bytes_threshold),
+                ) as Box<dyn Processor>
             } else {
                 if order_by.len() == 1 {
                     // If the length of order_by is 1, there may be a RANGE frame.
@@ -110,7 +151,8 @@ impl PipelineBuilder {
                                     partition_by.clone(),
                                     order_by.clone(),
                                     (start_bound, end_bound),
-                                )?,
+                                )?
+                                .with_spiller(create_spiller(), spilling_bytes_threshold),
                             )
                                 as Box<dyn Processor>));
                         }
@@ -122,14 +164,17 @@ impl PipelineBuilder {
                 // So we can use any number type to create the transform.
                 let start_bound = FrameBound::try_from(&window.window_frame.start_bound)?;
                 let end_bound = FrameBound::try_from(&window.window_frame.end_bound)?;
-                Box::new(TransformWindow::<u8>::try_create_range(
-                    input,
-                    output,
-                    func.clone(),
-                    partition_by.clone(),
-                    order_by.clone(),
-                    (start_bound, end_bound),
-                )?) as Box<dyn Processor>
+                Box::new(
+                    TransformWindow::<u8>::try_create_range(
+                        input,
+                        output,
+                        func.clone(),
+                        partition_by.clone(),
+                        order_by.clone(),
+                        (start_bound, end_bound),
+                    )?
+                    .with_spiller(create_spiller(), spilling_bytes_threshold),
+                ) as Box<dyn Processor>
             };
             Ok(ProcessorPtr::create(transform))
         })?;
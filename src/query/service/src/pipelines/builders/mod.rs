@@ -14,6 +14,7 @@
 
 mod builder_aggregate;
 mod builder_append_table;
+mod builder_async_function;
 mod builder_commit;
 mod builder_compact;
 mod builder_copy_into;
@@ -0,0 +1,96 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::UInt64Type;
+use common_expression::BlockEntry;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::Value;
+use common_meta_app::schema::SequenceNameIdent;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_transforms::processors::transforms::AsyncTransform;
+use common_pipeline_transforms::processors::transforms::AsyncTransformer;
+use common_sql::executor::AsyncFunctionDesc;
+
+use crate::sequences::SequenceCache;
+
+/// Evaluate the async functions of each block and append the results as new columns.
+///
+/// `nextval` takes the values for a whole block from the [`SequenceCache`] of this node,
+/// which only talks to the meta service when its pre-allocated values run out.
+pub struct TransformAsyncFunction {
+    tenant: String,
+    async_func_descs: Vec<AsyncFunctionDesc>,
+}
+
+impl TransformAsyncFunction {
+    pub fn create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        tenant: String,
+        async_func_descs: Vec<AsyncFunctionDesc>,
+    ) -> ProcessorPtr {
+        ProcessorPtr::create(AsyncTransformer::create(input, output, Self {
+            tenant,
+            async_func_descs,
+        }))
+    }
+
+    async fn next_values(&self, sequence_name: &str, count: usize) -> Result<Vec<u64>> {
+        if count == 0 {
+            return Ok(vec![]);
+        }
+
+        let name_ident = SequenceNameIdent::new(&self.tenant, sequence_name);
+        SequenceCache::instance()
+            .next_values(&name_ident, count)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncTransform for TransformAsyncFunction {
+    const NAME: &'static str = "AsyncFunction";
+
+    #[async_backtrace::framed]
+    async fn transform(&mut self, mut data: DataBlock) -> Result<DataBlock> {
+        let num_rows = data.num_rows();
+        for desc in self.async_func_descs.iter() {
+            match desc.func_name.as_str() {
+                "nextval" => {
+                    let values = self.next_values(&desc.arguments[0], num_rows).await?;
+                    data.add_column(BlockEntry::new(
+                        DataType::Number(NumberDataType::UInt64),
+                        Value::Column(UInt64Type::from_data(values)),
+                    ));
+                }
+                _ => {
+                    return Err(ErrorCode::Internal(format!(
+                        "Unsupported async function: {}",
+                        desc.func_name
+                    )));
+                }
+            }
+        }
+        Ok(data)
+    }
+}
@@ -0,0 +1,210 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::BlockEntry;
+use common_expression::DataBlock;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_sources::AsyncSource;
+use common_pipeline_sources::AsyncSourcer;
+use common_sql::executor::PhysicalPlan;
+use common_sql::executor::UnionAll;
+use common_sql::IndexType;
+use futures_util::TryStreamExt;
+
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelinePullingExecutor;
+use crate::schedulers::build_local_pipeline;
+use crate::sessions::QueryContext;
+use crate::stream::PullingExecutorStream;
+
+/// Evaluate a recursive cte to a fixpoint.
+///
+/// The non-recursive term is executed once and its rows become the first working table,
+/// then the recursive term is executed repeatedly against the working table produced by
+/// the last iteration, until an iteration produces no rows.
+pub struct TransformRecursiveCteSource {
+    ctx: Arc<QueryContext>,
+    cte_idx: (IndexType, IndexType),
+    left: PhysicalPlan,
+    right: PhysicalPlan,
+    pairs: Vec<(String, String)>,
+    max_depth: usize,
+
+    iteration: usize,
+    finished: bool,
+    output_blocks: VecDeque<DataBlock>,
+}
+
+impl TransformRecursiveCteSource {
+    pub fn create(
+        ctx: Arc<QueryContext>,
+        output_port: Arc<OutputPort>,
+        union_all: &UnionAll,
+    ) -> Result<ProcessorPtr> {
+        let cte_idx = union_all
+            .recursive_cte
+            .ok_or_else(|| ErrorCode::Internal("UnionAll is not the body of a recursive cte"))?;
+        let max_depth = ctx.get_settings().get_max_cte_recursive_depth()?;
+        AsyncSourcer::create(ctx.clone(), output_port, TransformRecursiveCteSource {
+            ctx,
+            cte_idx,
+            left: union_all.left.as_ref().clone(),
+            right: union_all.right.as_ref().clone(),
+            pairs: union_all.pairs.clone(),
+            max_depth,
+            iteration: 0,
+            finished: false,
+            output_blocks: VecDeque::new(),
+        })
+    }
+
+    async fn execute_term(&self, plan: &PhysicalPlan, is_left: bool) -> Result<Vec<DataBlock>> {
+        let ctx = QueryContext::create_from(self.ctx.clone());
+        let build_res = build_local_pipeline(&ctx, plan, false).await?;
+        let settings = ExecutorSettings::try_create(&ctx.get_settings(), ctx.get_id())?;
+        let pulling_executor = PipelinePullingExecutor::from_pipelines(build_res, settings)?;
+        let blocks = PullingExecutorStream::create(pulling_executor)?
+            .try_collect::<Vec<DataBlock>>()
+            .await?;
+
+        // Both terms have been casted to the output types of the union,
+        // so we only need to reorder the columns by the pairs.
+        let schema = plan.output_schema()?;
+        let offsets = self
+            .pairs
+            .iter()
+            .map(|(left, right)| schema.index_of(if is_left { left } else { right }))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(blocks
+            .into_iter()
+            .filter(|block| !block.is_empty())
+            .map(|block| {
+                let num_rows = block.num_rows();
+                let columns = offsets
+                    .iter()
+                    .map(|offset| block.get_by_offset(*offset).clone())
+                    .collect::<Vec<BlockEntry>>();
+                DataBlock::new(columns, num_rows)
+            })
+            .collect())
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for TransformRecursiveCteSource {
+    const NAME: &'static str = "RecursiveCteSource";
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        loop {
+            if let Some(block) = self.output_blocks.pop_front() {
+                return Ok(Some(block));
+            }
+            if self.finished {
+                return Ok(None);
+            }
+
+            let blocks = if self.iteration == 0 {
+                self.execute_term(&self.left, true).await?
+            } else {
+                if self.iteration > self.max_depth {
+                    self.ctx.remove_recursive_cte_working_table(self.cte_idx);
+                    return Err(ErrorCode::Overflow(format!(
+                        "Recursive cte exceeds the maximum recursive depth {}, \
+                        please check the termination condition or increase `max_cte_recursive_depth`",
+                        self.max_depth
+                    )));
+                }
+                self.execute_term(&self.right, false).await?
+            };
+            self.iteration += 1;
+
+            if blocks.is_empty() {
+                self.finished = true;
+                self.ctx.remove_recursive_cte_working_table(self.cte_idx);
+                continue;
+            }
+            self.ctx
+                .set_recursive_cte_working_table(self.cte_idx, Arc::new(blocks.clone()));
+            self.output_blocks.extend(blocks);
+        }
+    }
+}
+
+/// Read the working table of a recursive cte.
+pub struct RecursiveCteScanSource {
+    ctx: Arc<QueryContext>,
+    cte_idx: (IndexType, IndexType),
+    offsets: Vec<IndexType>,
+
+    blocks: Option<Arc<Vec<DataBlock>>>,
+    next_block: usize,
+}
+
+impl RecursiveCteScanSource {
+    pub fn create(
+        ctx: Arc<QueryContext>,
+        output_port: Arc<OutputPort>,
+        cte_idx: (IndexType, IndexType),
+        offsets: Vec<IndexType>,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx.clone(), output_port, RecursiveCteScanSource {
+            ctx,
+            cte_idx,
+            offsets,
+            blocks: None,
+            next_block: 0,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for RecursiveCteScanSource {
+    const NAME: &'static str = "RecursiveCteScanSource";
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if self.blocks.is_none() {
+            let blocks = self
+                .ctx
+                .get_recursive_cte_working_table(self.cte_idx)
+                .unwrap_or_default();
+            self.blocks = Some(blocks);
+        }
+
+        let blocks = self.blocks.as_ref().unwrap();
+        match blocks.get(self.next_block) {
+            Some(block) => {
+                self.next_block += 1;
+                let num_rows = block.num_rows();
+                let columns = self
+                    .offsets
+                    .iter()
+                    .map(|offset| block.get_by_offset(*offset).clone())
+                    .collect::<Vec<BlockEntry>>();
+                Ok(Some(DataBlock::new(columns, num_rows)))
+            }
+            None => Ok(None),
+        }
+    }
+}
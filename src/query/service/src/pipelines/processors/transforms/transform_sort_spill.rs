@@ -0,0 +1,454 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::types::string::StringColumn;
+use common_expression::types::DataType;
+use common_expression::types::DateType;
+use common_expression::types::NumberDataType;
+use common_expression::types::NumberType;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::with_number_mapped_type;
+use common_expression::BlockEntry;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_expression::RowConverter as CommonRowConverter;
+use common_expression::SortColumnDescription;
+use common_expression::Value;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::Event;
+use common_pipeline_core::processors::Processor;
+use common_pipeline_transforms::processors::transforms::sort::Merger;
+use common_pipeline_transforms::processors::transforms::sort::RowConverter;
+use common_pipeline_transforms::processors::transforms::sort::Rows;
+use common_pipeline_transforms::processors::transforms::sort::SimpleRowConverter;
+use common_pipeline_transforms::processors::transforms::sort::SimpleRows;
+use common_pipeline_transforms::processors::transforms::sort::SortedStream;
+use common_pipeline_transforms::processors::transforms::Compactor;
+use common_pipeline_transforms::processors::transforms::SortMergeCompactor;
+use log::info;
+
+use crate::spillers::Spiller;
+
+enum State {
+    /// Collect the partially sorted blocks from upstream.
+    Collect,
+    /// The collected blocks need to be merged into a sorted run.
+    Sort,
+    /// The sorted run need to be spilled.
+    Spill(Vec<DataBlock>),
+    /// Merge the spilled runs, the pending runs are loaded asynchronously.
+    Merge,
+    Finish,
+}
+
+/// A spilled sorted run, only the block which is merging is kept in memory.
+pub struct SpilledRun {
+    locations: VecDeque<String>,
+    loaded: Option<DataBlock>,
+}
+
+impl SortedStream for SpilledRun {
+    fn next(&mut self) -> Result<(Option<DataBlock>, bool)> {
+        match self.loaded.take() {
+            Some(block) => Ok((Some(block), false)),
+            None => Ok((None, !self.locations.is_empty())),
+        }
+    }
+}
+
+/// Merge sort blocks with spilling.
+///
+/// Once the collected blocks exceed the memory threshold, they are merged into a sorted run
+/// by [`SortMergeCompactor`] and spilled through the [`Spiller`]. After all the input is
+/// consumed, the spilled runs are merged back by [`Merger`] block by block, so only one
+/// block of each run is kept in memory.
+///
+/// The output is sorted, and can be merged with other pipelines by the multi-way merge.
+pub struct TransformSortSpill<R: Rows, Converter> {
+    input: Arc<InputPort>,
+    output: Arc<OutputPort>,
+
+    block_size: usize,
+    sort_desc: Vec<SortColumnDescription>,
+    order_by_cols: Vec<usize>,
+    row_converter: Converter,
+    /// If the next transform of current transform is `MultiSortMergeProcessor`,
+    /// we need to generate the order column.
+    gen_order_col: bool,
+    spilling_bytes_threshold: usize,
+    spiller: Spiller,
+
+    /// Merge the in-memory blocks, the output keeps the order column if `gen_order_col`.
+    compactor: SortMergeCompactor<R, Converter>,
+    /// Merge the blocks into a sorted run, the order column is kept for the restore merge.
+    run_compactor: SortMergeCompactor<R, Converter>,
+
+    state: State,
+    buffer: Vec<DataBlock>,
+    buffer_bytes: usize,
+    /// The spilled runs, each run is sorted and split into multiple blocks.
+    runs: Vec<SpilledRun>,
+    merger: Option<Merger<R, SpilledRun>>,
+    output_data: VecDeque<DataBlock>,
+}
+
+impl<R, Converter> TransformSortSpill<R, Converter>
+where
+    R: Rows + Send + 'static,
+    Converter: RowConverter<R> + Send + 'static,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        schema: DataSchemaRef,
+        block_size: usize,
+        sort_desc: Vec<SortColumnDescription>,
+        gen_order_col: bool,
+        spilling_bytes_threshold: usize,
+        spiller: Spiller,
+    ) -> Result<Box<dyn Processor>> {
+        let order_by_cols = sort_desc.iter().map(|i| i.offset).collect::<Vec<_>>();
+        let row_converter = Converter::create(sort_desc.clone(), schema.clone())?;
+        let compactor = SortMergeCompactor::try_create(
+            schema.clone(),
+            block_size,
+            sort_desc.clone(),
+            gen_order_col,
+        )?;
+        let run_compactor =
+            SortMergeCompactor::try_create(schema, block_size, sort_desc.clone(), true)?;
+        Ok(Box::new(TransformSortSpill {
+            input,
+            output,
+            block_size,
+            sort_desc,
+            order_by_cols,
+            row_converter,
+            gen_order_col,
+            spilling_bytes_threshold,
+            spiller,
+            compactor,
+            run_compactor,
+            state: State::Collect,
+            buffer: vec![],
+            buffer_bytes: 0,
+            runs: vec![],
+            merger: None,
+            output_data: VecDeque::new(),
+        }))
+    }
+
+    fn output_block(&mut self, mut block: DataBlock) -> Result<()> {
+        if self.gen_order_col {
+            // The merger removes the order column, add it back for the multi-way merge.
+            let columns = self
+                .order_by_cols
+                .iter()
+                .map(|i| block.get_by_offset(*i).clone())
+                .collect::<Vec<_>>();
+            let order_col = self
+                .row_converter
+                .convert(&columns, block.num_rows())?
+                .to_column();
+            block.add_column(BlockEntry {
+                data_type: order_col.data_type(),
+                value: Value::Column(order_col),
+            });
+        }
+        self.output_data.push_back(block);
+        Ok(())
+    }
+
+    fn start_merge(&mut self) {
+        let runs = std::mem::take(&mut self.runs);
+        self.merger = Some(Merger::create(
+            runs,
+            self.sort_desc.clone(),
+            self.block_size,
+            None,
+        ));
+        self.state = State::Merge;
+    }
+
+    /// Load the next block of the pending runs.
+    async fn load_pending_runs(&mut self) -> Result<()> {
+        let merger = self.merger.as_mut().unwrap();
+        let pending = merger.pending_streams().collect::<Vec<_>>();
+        for index in pending {
+            let run = &mut merger.streams_mut()[index];
+            if run.loaded.is_some() {
+                continue;
+            }
+            if let Some(location) = run.locations.pop_front() {
+                let block = self.spiller.read_spilled_block(&location).await?;
+                self.spiller.remove_spilled_block(&location).await?;
+                merger.streams_mut()[index].loaded = Some(block);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl<R, Converter> Processor for TransformSortSpill<R, Converter>
+where
+    R: Rows + Send + 'static,
+    Converter: RowConverter<R> + Send + 'static,
+{
+    fn name(&self) -> String {
+        String::from("TransformSortSpill")
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn interrupt(&self) {
+        self.compactor.interrupt();
+        self.run_compactor.interrupt();
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output.is_finished() {
+            self.input.finish();
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            self.input.set_not_need_data();
+            return Ok(Event::NeedConsume);
+        }
+
+        if let Some(block) = self.output_data.pop_front() {
+            self.output.push_data(Ok(block));
+            return Ok(Event::NeedConsume);
+        }
+
+        match &self.state {
+            State::Sort => {
+                self.input.set_not_need_data();
+                return Ok(Event::Sync);
+            }
+            State::Spill(_) => {
+                self.input.set_not_need_data();
+                return Ok(Event::Async);
+            }
+            State::Merge => {
+                self.input.set_not_need_data();
+                let merger = self.merger.as_mut().unwrap();
+                if merger.is_finished() {
+                    self.output.finish();
+                    return Ok(Event::Finished);
+                }
+                merger.poll_pending_streams()?;
+                if merger.has_pending_streams() {
+                    return Ok(Event::Async);
+                }
+                return Ok(Event::Sync);
+            }
+            State::Finish => {
+                self.input.finish();
+                self.output.finish();
+                return Ok(Event::Finished);
+            }
+            State::Collect => {}
+        }
+
+        if self.input.has_data() {
+            let block = self.input.pull_data().unwrap()?;
+            if !block.is_empty() {
+                self.buffer_bytes += block.memory_size();
+                self.buffer.push(block);
+            }
+            if self.spilling_bytes_threshold > 0
+                && self.buffer_bytes >= self.spilling_bytes_threshold
+            {
+                self.input.set_not_need_data();
+                self.state = State::Sort;
+                return Ok(Event::Sync);
+            }
+        }
+
+        if self.input.is_finished() {
+            if !self.buffer.is_empty() {
+                self.state = State::Sort;
+                return Ok(Event::Sync);
+            }
+            if self.runs.is_empty() {
+                self.output.finish();
+                return Ok(Event::Finished);
+            }
+            self.start_merge();
+            return Ok(Event::Async);
+        }
+
+        self.input.set_need_data();
+        Ok(Event::NeedData)
+    }
+
+    fn process(&mut self) -> Result<()> {
+        match std::mem::replace(&mut self.state, State::Collect) {
+            State::Sort => {
+                let blocks = std::mem::take(&mut self.buffer);
+                self.buffer_bytes = 0;
+                if self.input.is_finished() && self.runs.is_empty() {
+                    // All the data fits in memory, no need to spill.
+                    self.output_data
+                        .extend(self.compactor.compact_final(blocks)?);
+                    self.state = State::Finish;
+                } else {
+                    let run = self.run_compactor.compact_final(blocks)?;
+                    self.state = State::Spill(run);
+                }
+                Ok(())
+            }
+            State::Merge => {
+                self.state = State::Merge;
+                if let Some(block) = self.merger.as_mut().unwrap().next_block()? {
+                    self.output_block(block)?;
+                }
+                Ok(())
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn async_process(&mut self) -> Result<()> {
+        match std::mem::replace(&mut self.state, State::Collect) {
+            State::Spill(blocks) => {
+                let mut locations = VecDeque::with_capacity(blocks.len());
+                for block in blocks {
+                    locations.push_back(self.spiller.spill_block(block).await?);
+                }
+                info!("Sort spilled a run with {} blocks", locations.len());
+                self.runs.push(SpilledRun {
+                    locations,
+                    loaded: None,
+                });
+                if self.input.is_finished() {
+                    self.start_merge();
+                }
+                Ok(())
+            }
+            State::Merge => {
+                self.state = State::Merge;
+                self.load_pending_runs().await
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+
+type CommonSortSpill = TransformSortSpill<StringColumn, CommonRowConverter>;
+
+#[allow(clippy::too_many_arguments)]
+pub fn create_transform_sort_spill(
+    input: Arc<InputPort>,
+    output: Arc<OutputPort>,
+    schema: DataSchemaRef,
+    block_size: usize,
+    sort_desc: Vec<SortColumnDescription>,
+    gen_order_col: bool,
+    spilling_bytes_threshold: usize,
+    spiller: Spiller,
+) -> Result<Box<dyn Processor>> {
+    if sort_desc.len() == 1 {
+        let sort_type = schema.field(sort_desc[0].offset).data_type();
+        match sort_type {
+            DataType::Number(num_ty) => with_number_mapped_type!(|NUM_TYPE| match num_ty {
+                NumberDataType::NUM_TYPE => TransformSortSpill::<
+                    SimpleRows<NumberType<NUM_TYPE>>,
+                    SimpleRowConverter<NumberType<NUM_TYPE>>,
+                >::try_create(
+                    input,
+                    output,
+                    schema,
+                    block_size,
+                    sort_desc,
+                    gen_order_col,
+                    spilling_bytes_threshold,
+                    spiller,
+                ),
+            }),
+            DataType::Date => {
+                TransformSortSpill::<SimpleRows<DateType>, SimpleRowConverter<DateType>>::try_create(
+                    input,
+                    output,
+                    schema,
+                    block_size,
+                    sort_desc,
+                    gen_order_col,
+                    spilling_bytes_threshold,
+                    spiller,
+                )
+            }
+            DataType::Timestamp => TransformSortSpill::<
+                SimpleRows<TimestampType>,
+                SimpleRowConverter<TimestampType>,
+            >::try_create(
+                input,
+                output,
+                schema,
+                block_size,
+                sort_desc,
+                gen_order_col,
+                spilling_bytes_threshold,
+                spiller,
+            ),
+            DataType::String => TransformSortSpill::<
+                SimpleRows<StringType>,
+                SimpleRowConverter<StringType>,
+            >::try_create(
+                input,
+                output,
+                schema,
+                block_size,
+                sort_desc,
+                gen_order_col,
+                spilling_bytes_threshold,
+                spiller,
+            ),
+            _ => CommonSortSpill::try_create(
+                input,
+                output,
+                schema,
+                block_size,
+                sort_desc,
+                gen_order_col,
+                spilling_bytes_threshold,
+                spiller,
+            ),
+        }
+    } else {
+        CommonSortSpill::try_create(
+            input,
+            output,
+            schema,
+            block_size,
+            sort_desc,
+            gen_order_col,
+            spilling_bytes_threshold,
+            spiller,
+        )
+    }
+}
@@ -44,6 +44,7 @@ use super::frame_bound::FrameBound;
 use super::window_function::WindowFuncAggImpl;
 use super::window_function::WindowFunctionImpl;
 use super::WindowFunctionInfo;
+use crate::spillers::Spiller;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Default)]
 struct RowPtr {
@@ -62,6 +63,10 @@ impl RowPtr {
 struct WindowBlock {
     block: DataBlock,
     builder: ColumnBuilder,
+    // The location of the spilled data of the block.
+    location: Option<String>,
+    // If the block is spilled, `block` only keeps the number of rows.
+    spilled: bool,
 }
 
 /// The input [`DataBlock`] of [`TransformWindow`] should be sorted by partition and order by columns.
@@ -137,6 +142,19 @@ pub struct TransformWindow<T: Number> {
 
     // If `is_empty_frame`, the window function result of non-NULL rows will be NULL.
     is_empty_frame: bool,
+
+    /// Spill the buffered blocks to storage if they use more memory than `spilling_bytes_threshold`.
+    spiller: Option<Spiller>,
+    spilling_bytes_threshold: usize,
+    /// The spilled blocks that need to be restored before the computation can go on.
+    blocks_to_restore: Vec<usize>,
+    /// The spilled files of the blocks that have been released.
+    spilled_files_to_remove: Vec<String>,
+    /// The peer group and the frame of `current_row` have been advanced,
+    /// but the result has not been computed because some blocks are spilled.
+    row_prepared: bool,
+    /// Where to resume the aggregation of the current frame if it is suspended by a spilled block.
+    aggregate_progress: Option<RowPtr>,
 }
 
 impl<T: Number> TransformWindow<T> {
@@ -164,6 +182,135 @@ impl<T: Number> TransformWindow<T> {
             .unwrap()
     }
 
+    #[inline(always)]
+    fn is_spilled(&self, index: &RowPtr) -> bool {
+        index.block >= self.first_block
+            && index.block < self.blocks_end().block
+            && self.blocks[index.block - self.first_block].spilled
+    }
+
+    /// Check if the blocks of the rows are in memory, return false
+    /// and record the spilled ones in `blocks_to_restore` if not.
+    fn ensure_in_memory(&mut self, rows: &[RowPtr]) -> bool {
+        for row in rows {
+            if self.is_spilled(row) && !self.blocks_to_restore.contains(&row.block) {
+                self.blocks_to_restore.push(row.block);
+            }
+        }
+        self.blocks_to_restore.is_empty()
+    }
+
+    /// Spill the buffered blocks to storage if they use more memory than `spilling_bytes_threshold`,
+    /// 0 means no limit.
+    ///
+    /// The frames of `RANGE` with offset and the peer groups of `cume_dist` are searched
+    /// by scanning the values of the buffered blocks, so they are not spilled in these cases.
+    pub fn with_spiller(mut self, spiller: Spiller, spilling_bytes_threshold: usize) -> Self {
+        let scan_values = self.need_peer
+            || (self.frame_unit.is_range()
+                && (self.start_bound.get_inner().is_some()
+                    || self.end_bound.get_inner().is_some()));
+        if spilling_bytes_threshold > 0 && !scan_values {
+            self.spiller = Some(spiller);
+            self.spilling_bytes_threshold = spilling_bytes_threshold;
+        }
+        self
+    }
+
+    fn memory_size(&self) -> usize {
+        self.blocks
+            .iter()
+            .filter(|block| !block.spilled)
+            .map(|block| block.block.memory_size())
+            .sum()
+    }
+
+    fn need_spill(&self) -> bool {
+        self.spiller.is_some() && self.memory_size() > self.spilling_bytes_threshold
+    }
+
+    /// The blocks that can be spilled, the ones behind the current row come first.
+    ///
+    /// The blocks pointed by the row pointers and the last block are always kept in memory,
+    /// the other blocks are restored when the pointers reach them.
+    fn spillable_blocks(&self) -> Vec<usize> {
+        let last_block = self.blocks_end().block.saturating_sub(1);
+        let mut pinned = vec![
+            self.partition_start,
+            self.partition_end,
+            self.frame_start,
+            self.frame_end,
+            self.prev_frame_start,
+            self.prev_frame_end,
+            self.current_row,
+            self.peer_group_start,
+            self.peer_group_end,
+        ];
+        pinned.extend(self.aggregate_progress);
+        let is_spillable = |block: &usize| {
+            *block != last_block
+                && !self.blocks[*block - self.first_block].spilled
+                && pinned.iter().all(|row| row.block != *block)
+        };
+
+        let mut blocks = (self.first_block..self.next_output_block)
+            .filter(is_spillable)
+            .collect::<Vec<_>>();
+        blocks.extend(
+            (self.current_row.block + 1..last_block)
+                .rev()
+                .filter(is_spillable),
+        );
+        blocks
+    }
+
+    #[async_backtrace::framed]
+    async fn spill_blocks(&mut self) -> Result<()> {
+        for block in self.spillable_blocks() {
+            if !self.need_spill() {
+                break;
+            }
+            let spiller = self.spiller.as_mut().unwrap();
+            let window_block = &mut self.blocks[block - self.first_block];
+            let num_rows = window_block.block.num_rows();
+            let data = std::mem::replace(&mut window_block.block, DataBlock::new(vec![], num_rows));
+            // The data is not changed once spilled, so it only needs to be written once.
+            if window_block.location.is_none() {
+                window_block.location = Some(spiller.spill_block(data).await?);
+            }
+            window_block.spilled = true;
+        }
+
+        if let Some(spiller) = self.spiller.as_mut() {
+            for location in std::mem::take(&mut self.spilled_files_to_remove) {
+                spiller.remove_spilled_block(&location).await?;
+            }
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn restore_blocks(&mut self) -> Result<()> {
+        let spiller = self.spiller.as_ref().unwrap();
+        for block in std::mem::take(&mut self.blocks_to_restore) {
+            let window_block = &mut self.blocks[block - self.first_block];
+            let location = window_block.location.as_ref().unwrap();
+            window_block.block = spiller.read_spilled_block(location).await?;
+            window_block.spilled = false;
+        }
+        Ok(())
+    }
+
+    /// Release all the blocks after all the results are output.
+    fn release_blocks(&mut self) {
+        for block in self.blocks.drain(..) {
+            if let Some(location) = block.location {
+                self.spilled_files_to_remove.push(location);
+            }
+        }
+        self.first_block = self.next_output_block;
+    }
+
     fn add_rows_within_partition(&self, mut cur: RowPtr, mut n: usize) -> RowPtr {
         debug_assert!(cur.ge(&self.partition_start) && cur.le(&self.partition_end));
 
@@ -416,6 +563,7 @@ impl<T: Number> TransformWindow<T> {
 
             if block.block.num_rows() == block.builder.len() {
                 // Can output
+                debug_assert!(!block.spilled);
                 let mut output = block.block.clone();
                 let data_type = block.builder.data_type();
                 // The memory of the builder can be released.
@@ -441,12 +589,17 @@ impl<T: Number> TransformWindow<T> {
             .min(self.current_row.block);
 
         if self.first_block < first_used_block {
-            self.blocks.drain(..first_used_block - self.first_block);
+            for block in self.blocks.drain(..first_used_block - self.first_block) {
+                if let Some(location) = block.location {
+                    self.spilled_files_to_remove.push(location);
+                }
+            }
             self.first_block = first_used_block;
         }
     }
 
-    fn apply_aggregate(&self, agg: &WindowFuncAggImpl) -> Result<()> {
+    /// Returns the row to resume the aggregation from if it reaches a spilled block.
+    fn apply_aggregate(&self, agg: &WindowFuncAggImpl) -> Result<Option<RowPtr>> {
         debug_assert!(self.frame_started);
         debug_assert!(self.frame_ended);
         debug_assert!(self.frame_start <= self.frame_end);
@@ -462,9 +615,16 @@ impl<T: Number> TransformWindow<T> {
             (self.frame_start, self.frame_end, true)
         };
 
-        if reset {
-            agg.reset();
-        }
+        let rows_start = match self.aggregate_progress {
+            // The aggregation has been reset before it is suspended.
+            Some(progress) => progress,
+            None => {
+                if reset {
+                    agg.reset();
+                }
+                rows_start
+            }
+        };
 
         let end_block = if rows_end.row == 0 {
             rows_end.block
@@ -473,12 +633,16 @@ impl<T: Number> TransformWindow<T> {
         };
 
         for block in rows_start.block..end_block {
-            let data = &self.blocks[block - self.first_block].block;
             let start_row = if block == rows_start.block {
                 rows_start.row
             } else {
                 0
             };
+            let window_block = &self.blocks[block - self.first_block];
+            if window_block.spilled {
+                return Ok(Some(RowPtr::new(block, start_row)));
+            }
+            let data = &window_block.block;
             let end_row = if block == rows_end.block {
                 rows_end.row
             } else {
@@ -490,7 +654,7 @@ impl<T: Number> TransformWindow<T> {
             }
         }
 
-        Ok(())
+        Ok(None)
     }
 
     #[inline]
@@ -553,30 +717,14 @@ impl<T: Number> TransformWindow<T> {
                 builder.push(value.as_ref());
             }
             WindowFunctionImpl::NthValue(func) => {
-                let value = if self.frame_start == self.frame_end {
-                    Scalar::Null
-                } else if let Some(mut n) = func.n {
-                    let mut cur = self.frame_start;
-                    // n is counting from 1
-                    while n > 1 && cur < self.frame_end {
-                        cur = self.advance_row(cur);
-                        n -= 1;
-                    }
-                    if cur != self.frame_end {
+                let value = match self.nth_value_row(func.n) {
+                    Some(cur) => {
                         let block = &self.blocks.get(cur.block - self.first_block).unwrap().block;
                         let col = block.get_by_offset(func.arg).value.as_column().unwrap();
                         col.index(cur.row).unwrap().to_owned()
-                    } else {
-                        // No such row
-                        Scalar::Null
                     }
-                } else {
-                    // last_value
-                    let cur = self.goback_row(self.frame_end);
-                    debug_assert!(self.frame_start <= cur);
-                    let block = &self.blocks.get(cur.block - self.first_block).unwrap().block;
-                    let col = block.get_by_offset(func.arg).value.as_column().unwrap();
-                    col.index(cur.row).unwrap().to_owned()
+                    // No such row
+                    None => Scalar::Null,
                 };
                 let builder = &mut self.blocks[self.current_row.block - self.first_block].builder;
                 builder.push(value.as_ref());
@@ -621,6 +769,35 @@ impl<T: Number> TransformWindow<T> {
         Ok(())
     }
 
+    /// The row of the nth value (the last value if `n` is `None`) in the frame.
+    fn nth_value_row(&self, n: Option<u64>) -> Option<RowPtr> {
+        if self.frame_start == self.frame_end {
+            None
+        } else if let Some(mut n) = n {
+            let mut cur = self.frame_start;
+            // n is counting from 1
+            while n > 1 && cur < self.frame_end {
+                cur = self.advance_row(cur);
+                n -= 1;
+            }
+            (cur != self.frame_end).then_some(cur)
+        } else {
+            // last_value
+            let cur = self.goback_row(self.frame_end);
+            debug_assert!(self.frame_start <= cur);
+            Some(cur)
+        }
+    }
+
+    /// The rows that will be read when merging the result of current row.
+    fn rows_of_result(&self) -> Vec<RowPtr> {
+        match &self.func {
+            WindowFunctionImpl::LagLead(_) => vec![self.current_row, self.frame_start],
+            WindowFunctionImpl::NthValue(func) => self.nth_value_row(func.n).into_iter().collect(),
+            _ => vec![],
+        }
+    }
+
     #[inline]
     fn if_need_check_null_frame(&self) -> bool {
         self.frame_unit.is_range() && self.order_by.len() == 1 && self.order_by[0].is_nullable
@@ -698,6 +875,12 @@ impl TransformWindow<u64> {
             current_dense_rank: 1,
             input_is_finished: false,
             is_empty_frame,
+            spiller: None,
+            spilling_bytes_threshold: 0,
+            blocks_to_restore: vec![],
+            spilled_files_to_remove: vec![],
+            row_prepared: false,
+            aggregate_progress: None,
         })
     }
 }
@@ -768,6 +951,12 @@ where T: Number + ResultTypeOfUnary
             current_dense_rank: 1,
             input_is_finished: false,
             is_empty_frame,
+            spiller: None,
+            spilling_bytes_threshold: 0,
+            blocks_to_restore: vec![],
+            spilled_files_to_remove: vec![],
+            row_prepared: false,
+            aggregate_progress: None,
         })
     }
 
@@ -889,10 +1078,93 @@ where T: Number + ResultTypeOfUnary
         }
     }
 
-    fn compute_on_frame(&mut self) -> Result<()> {
-        match &self.func {
-            WindowFunctionImpl::Aggregate(agg) => self.apply_aggregate(agg),
-            _ => Ok(()),
+    /// Advance the peer group and the frame of current row.
+    ///
+    /// Returns false if more input is needed or some blocks need to be restored.
+    fn prepare_current_row(&mut self) -> bool {
+        if !self.ensure_in_memory(&[
+            self.current_row,
+            self.peer_group_start,
+            self.frame_start,
+            self.frame_end,
+        ]) {
+            return false;
+        }
+
+        if !self.are_peers(&self.peer_group_start, &self.current_row, false) {
+            self.peer_group_start = self.current_row;
+            self.peer_group_end = self.current_row;
+            self.peer_group_ended = false;
+            self.current_dense_rank += 1;
+            self.current_rank = self.current_row_in_partition;
+
+            // peer changed, re-calculate peer end.
+            self.advance_peer_group_end(self.peer_group_start);
+
+            // If current peer group is a null frame, there will be no null frame in this partition again;
+            // if current peer group is not a null frame, we may need to check it in the codes below.
+            self.is_null_frame = false;
+        } else if self.is_null_frame {
+            // Only one null frame can exist in one partition, so we don't need to check it again.
+            self.need_check_null_frame = false;
+        }
+
+        // execute only once for each partition.
+        if self.peer_group_start == self.partition_start {
+            self.advance_peer_group_end(self.current_row);
+        }
+
+        if self.need_peer && self.partition_ended {
+            self.peer_group_ended = true;
+        }
+
+        if self.need_peer && !self.peer_group_ended {
+            debug_assert!(!self.input_is_finished);
+            debug_assert!(!self.partition_ended);
+            return false;
+        }
+
+        // 2.
+        if self.need_check_null_frame {
+            self.is_null_frame = self.is_in_null_frame();
+        }
+
+        if self.is_empty_frame && !self.is_null_frame {
+            // Non-NULL empty frame, no need to advance bounds.
+            self.func.reset();
+        } else {
+            self.advance_frame_start();
+            if !self.frame_started {
+                debug_assert!(!self.input_is_finished);
+                debug_assert!(!self.partition_ended);
+                return false;
+            }
+
+            if self.frame_end < self.frame_start {
+                self.frame_end = self.frame_start;
+            }
+
+            self.advance_frame_end();
+            if !self.frame_ended {
+                debug_assert!(!self.input_is_finished);
+                debug_assert!(!self.partition_ended);
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Returns false if the computation is suspended by a spilled block.
+    fn compute_on_frame(&mut self) -> Result<bool> {
+        let progress = match &self.func {
+            WindowFunctionImpl::Aggregate(agg) => self.apply_aggregate(agg)?,
+            _ => None,
+        };
+        self.aggregate_progress = progress;
+        match progress {
+            Some(row) => Ok(self.ensure_in_memory(&[row])),
+            None => Ok(true),
         }
     }
 
@@ -910,6 +1182,8 @@ where T: Number + ResultTypeOfUnary
             self.blocks.push_back(WindowBlock {
                 block: data.convert_to_full(),
                 builder: ColumnBuilder::with_capacity(&self.func.return_type()?, num_rows),
+                location: None,
+                spilled: false,
             });
         }
 
@@ -922,6 +1196,9 @@ where T: Number + ResultTypeOfUnary
         //    else start next partition.
         loop {
             // 1.
+            if !self.ensure_in_memory(&[self.prev_frame_start]) {
+                return Ok(());
+            }
             self.advance_partition();
 
             debug_assert!(self.partition_ended || self.partition_end == self.blocks_end());
@@ -934,73 +1211,24 @@ where T: Number + ResultTypeOfUnary
             });
 
             while self.current_row < self.partition_end {
-                if !self.are_peers(&self.peer_group_start, &self.current_row, false) {
-                    self.peer_group_start = self.current_row;
-                    self.peer_group_end = self.current_row;
-                    self.peer_group_ended = false;
-                    self.current_dense_rank += 1;
-                    self.current_rank = self.current_row_in_partition;
-
-                    // peer changed, re-calculate peer end.
-                    self.advance_peer_group_end(self.peer_group_start);
-
-                    // If current peer group is a null frame, there will be no null frame in this partition again;
-                    // if current peer group is not a null frame, we may need to check it in the codes below.
-                    self.is_null_frame = false;
-                } else if self.is_null_frame {
-                    // Only one null frame can exist in one partition, so we don't need to check it again.
-                    self.need_check_null_frame = false;
-                }
-
-                // execute only once for each partition.
-                if self.peer_group_start == self.partition_start {
-                    self.advance_peer_group_end(self.current_row);
-                }
-
-                if self.need_peer && self.partition_ended {
-                    self.peer_group_ended = true;
-                }
-
-                if self.need_peer && !self.peer_group_ended {
-                    debug_assert!(!self.input_is_finished);
-                    debug_assert!(!self.partition_ended);
-                    break;
-                }
-
-                // 2.
-                if self.need_check_null_frame {
-                    self.is_null_frame = self.is_in_null_frame();
-                }
-
-                if self.is_empty_frame && !self.is_null_frame {
-                    // Non-NULL empty frame, no need to advance bounds.
-                    self.func.reset();
-                } else {
-                    self.advance_frame_start();
-                    if !self.frame_started {
-                        debug_assert!(!self.input_is_finished);
-                        debug_assert!(!self.partition_ended);
-                        break;
-                    }
-
-                    if self.frame_end < self.frame_start {
-                        self.frame_end = self.frame_start;
-                    }
-
-                    self.advance_frame_end();
-                    if !self.frame_ended {
-                        debug_assert!(!self.input_is_finished);
-                        debug_assert!(!self.partition_ended);
+                if !self.row_prepared {
+                    if !self.prepare_current_row() {
                         break;
                     }
-
-                    // 3.1
-                    self.compute_on_frame()?;
+                    self.row_prepared = true;
                 }
 
+                // 3.1
+                if !(self.is_empty_frame && !self.is_null_frame) && !self.compute_on_frame()? {
+                    return Ok(());
+                }
+                if !self.ensure_in_memory(&self.rows_of_result()) {
+                    return Ok(());
+                }
                 self.merge_result_of_current_row()?;
 
                 // 3.2
+                self.row_prepared = false;
                 self.current_row = self.advance_row(self.current_row);
                 self.current_row_in_partition += 1;
                 self.prev_frame_start = self.frame_start;
@@ -1009,6 +1237,10 @@ where T: Number + ResultTypeOfUnary
                 self.frame_ended = false;
             }
 
+            if !self.blocks_to_restore.is_empty() {
+                return Ok(());
+            }
+
             if self.input_is_finished {
                 return Ok(());
             }
@@ -1156,6 +1388,7 @@ enum ProcessorState {
     Consume,
     AddBlock(Option<DataBlock>),
     Output,
+    Spill,
 }
 
 #[async_trait::async_trait]
@@ -1200,6 +1433,11 @@ where T: Number + ResultTypeOfUnary
                             self.state = ProcessorState::AddBlock(None);
                             Ok(Event::Sync)
                         } else {
+                            self.release_blocks();
+                            if !self.spilled_files_to_remove.is_empty() {
+                                self.state = ProcessorState::Spill;
+                                return Ok(Event::Async);
+                            }
                             self.output.finish();
                             Ok(Event::Finished)
                         }
@@ -1214,7 +1452,8 @@ where T: Number + ResultTypeOfUnary
                 }
                 Ok(Event::NeedConsume)
             }
-            _ => unreachable!(),
+            ProcessorState::AddBlock(_) => Ok(Event::Sync),
+            ProcessorState::Spill => Ok(Event::Async),
         }
     }
 
@@ -1224,7 +1463,28 @@ where T: Number + ResultTypeOfUnary
         {
             self.add_block(data)?;
             self.check_outputs();
-            self.state = if self.outputs.is_empty() {
+            self.state = if !self.blocks_to_restore.is_empty() || self.need_spill() {
+                ProcessorState::Spill
+            } else if self.outputs.is_empty() {
+                ProcessorState::Consume
+            } else {
+                ProcessorState::Output
+            };
+        } else {
+            unreachable!()
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn async_process(&mut self) -> Result<()> {
+        if let ProcessorState::Spill = std::mem::replace(&mut self.state, ProcessorState::Consume) {
+            self.spill_blocks().await?;
+            self.state = if !self.blocks_to_restore.is_empty() {
+                // Continue the computation after the needed blocks are restored.
+                self.restore_blocks().await?;
+                ProcessorState::AddBlock(None)
+            } else if self.outputs.is_empty() {
                 ProcessorState::Consume
             } else {
                 ProcessorState::Output
@@ -1292,6 +1552,8 @@ mod tests {
         transform.blocks.push_back(WindowBlock {
             block: DataBlock::new_from_columns(vec![column]),
             builder: ColumnBuilder::with_capacity(&data_type, num_rows),
+            location: None,
+            spilled: false,
         });
         Ok(transform)
     }
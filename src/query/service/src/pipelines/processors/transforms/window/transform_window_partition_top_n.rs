@@ -0,0 +1,122 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::SortColumnDescription;
+use common_pipeline_transforms::processors::transforms::AccumulatingTransform;
+
+const COMPACT_ROWS_THRESHOLD: usize = 65536;
+
+/// Keep only the top `limit` rows of each partition, ordered by the window's order keys.
+///
+/// This is used for `row_number()` and `rank()` whose results are filtered by a constant,
+/// such as `QUALIFY row_number() OVER (PARTITION BY a ORDER BY b) = 1`, so that the window
+/// only needs to sort the remaining rows. For `rank()`, the rows tied with the last kept row
+/// are also kept.
+pub struct TransformWindowPartitionTopN {
+    // The partition keys followed by the order keys.
+    sort_desc: Vec<SortColumnDescription>,
+    num_partition_keys: usize,
+    limit: usize,
+    with_ties: bool,
+
+    blocks: Vec<DataBlock>,
+    num_rows: usize,
+    compact_threshold: usize,
+}
+
+impl TransformWindowPartitionTopN {
+    pub fn new(
+        sort_desc: Vec<SortColumnDescription>,
+        num_partition_keys: usize,
+        limit: usize,
+        with_ties: bool,
+    ) -> Self {
+        TransformWindowPartitionTopN {
+            sort_desc,
+            num_partition_keys,
+            limit,
+            with_ties,
+            blocks: vec![],
+            num_rows: 0,
+            compact_threshold: COMPACT_ROWS_THRESHOLD,
+        }
+    }
+
+    fn is_same_keys(&self, block: &DataBlock, keys: &[SortColumnDescription], lhs: usize) -> bool {
+        keys.iter().all(|desc| {
+            let value = &block.get_by_offset(desc.offset).value;
+            value.index(lhs) == value.index(lhs + 1)
+        })
+    }
+
+    fn compact(&mut self) -> Result<()> {
+        if self.blocks.is_empty() {
+            return Ok(());
+        }
+
+        let block = DataBlock::concat(&std::mem::take(&mut self.blocks))?;
+        let block = DataBlock::sort(&block, &self.sort_desc, None)?;
+        let (partition_keys, order_keys) = self.sort_desc.split_at(self.num_partition_keys);
+
+        let mut indices = Vec::with_capacity(block.num_rows());
+        let mut rows_in_partition = 0;
+        let mut last_kept = false;
+        for row in 0..block.num_rows() {
+            if row == 0 || !self.is_same_keys(&block, partition_keys, row - 1) {
+                rows_in_partition = 0;
+            }
+            rows_in_partition += 1;
+
+            let keep = rows_in_partition <= self.limit
+                || (self.with_ties && last_kept && self.is_same_keys(&block, order_keys, row - 1));
+            if keep {
+                indices.push(row as u32);
+            }
+            last_kept = keep;
+        }
+
+        let block = block.take(&indices, &mut None)?;
+        self.num_rows = block.num_rows();
+        // Avoid compacting too frequently if there are many partitions.
+        self.compact_threshold = self.compact_threshold.max(self.num_rows * 2);
+        self.blocks.push(block);
+        Ok(())
+    }
+}
+
+impl AccumulatingTransform for TransformWindowPartitionTopN {
+    const NAME: &'static str = "TransformWindowPartitionTopN";
+
+    fn transform(&mut self, data: DataBlock) -> Result<Vec<DataBlock>> {
+        if data.is_empty() {
+            return Ok(vec![]);
+        }
+        self.num_rows += data.num_rows();
+        self.blocks.push(data);
+        if self.num_rows >= self.compact_threshold {
+            self.compact()?;
+        }
+        Ok(vec![])
+    }
+
+    fn on_finish(&mut self, output: bool) -> Result<Vec<DataBlock>> {
+        if !output || self.blocks.is_empty() {
+            return Ok(vec![]);
+        }
+        self.compact()?;
+        Ok(std::mem::take(&mut self.blocks))
+    }
+}
@@ -0,0 +1,180 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use common_base::base::GlobalInstance;
+use common_exception::Result;
+use common_meta_api::kv_app_error::KVAppError;
+use common_meta_api::SequenceApi;
+use common_meta_app::app_error::AppError;
+use common_meta_app::schema::GetSequenceNextValuesReq;
+use common_meta_app::schema::SequenceNameIdent;
+use common_users::UserApiProvider;
+use parking_lot::Mutex;
+
+/// The number of values a node allocates from the meta service in advance,
+/// in addition to the ones it needs right now.
+pub const SEQUENCE_CACHE_BATCH_SIZE: u64 = 1000;
+
+/// Values `start, start + step, ...` allocated from a sequence, `count` in total.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SequenceRange {
+    pub start: u64,
+    pub step: u64,
+    pub count: u64,
+}
+
+/// The allocated but not yet used values of a sequence, in allocation order.
+///
+/// The ranges are not contiguous if other nodes allocated from the same sequence in between.
+#[derive(Debug, Default)]
+pub struct SequenceRanges {
+    ranges: VecDeque<SequenceRange>,
+}
+
+impl SequenceRanges {
+    pub fn push(&mut self, range: SequenceRange) {
+        if range.count > 0 {
+            self.ranges.push_back(range);
+        }
+    }
+
+    /// Move at most `count` values into `values`, the oldest ones first.
+    pub fn take(&mut self, count: usize, values: &mut Vec<u64>) {
+        let mut remaining = count as u64;
+        while remaining > 0 {
+            let Some(range) = self.ranges.front_mut() else {
+                return;
+            };
+
+            let n = remaining.min(range.count);
+            values.extend((0..n).map(|i| range.start + i * range.step));
+            remaining -= n;
+
+            // The meta service has already checked that the last value does not overflow.
+            range.start += n * range.step;
+            range.count -= n;
+            if range.count == 0 {
+                self.ranges.pop_front();
+            }
+        }
+    }
+
+    /// The number of values left.
+    pub fn len(&self) -> u64 {
+        self.ranges.iter().map(|r| r.count).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+}
+
+/// A per-node cache of the values allocated from sequences.
+///
+/// `nextval` is served from the cache and only calls the meta service when the cached values
+/// run out, then it allocates the missing values plus [`SEQUENCE_CACHE_BATCH_SIZE`] more.
+/// Values are unique across nodes but not ordered across nodes, and the values cached by a node
+/// are lost when it restarts.
+///
+/// The cached values of a sequence are dropped when it is created or dropped through this node.
+/// Other nodes keep handing out their cached values until they run out.
+pub struct SequenceCache {
+    batch_size: u64,
+    sequences: Mutex<HashMap<SequenceNameIdent, SequenceRanges>>,
+}
+
+impl SequenceCache {
+    pub fn init() -> Result<()> {
+        GlobalInstance::set(Arc::new(Self::create(SEQUENCE_CACHE_BATCH_SIZE)));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<SequenceCache> {
+        GlobalInstance::get()
+    }
+
+    pub fn create(batch_size: u64) -> Self {
+        SequenceCache {
+            batch_size,
+            sequences: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the next `count` values of a sequence.
+    pub async fn next_values(
+        &self,
+        name_ident: &SequenceNameIdent,
+        count: usize,
+    ) -> Result<Vec<u64>> {
+        let mut values = Vec::with_capacity(count);
+        if let Some(ranges) = self.sequences.lock().get_mut(name_ident) {
+            ranges.take(count, &mut values);
+        }
+
+        let missing = count - values.len();
+        if missing == 0 {
+            return Ok(values);
+        }
+
+        // The lock is not held while talking to the meta service; concurrent callers may both
+        // allocate, and both keep what is left in the cache.
+        let mut ranges = SequenceRanges::default();
+        ranges.push(self.allocate(name_ident, missing as u64).await?);
+        ranges.take(missing, &mut values);
+
+        if !ranges.is_empty() {
+            let mut sequences = self.sequences.lock();
+            let cached = sequences.entry(name_ident.clone()).or_default();
+            cached.ranges.extend(ranges.ranges);
+        }
+        Ok(values)
+    }
+
+    /// Drop the cached values of a sequence.
+    pub fn invalidate(&self, name_ident: &SequenceNameIdent) {
+        self.sequences.lock().remove(name_ident);
+    }
+
+    /// Allocate `count` values plus a batch to cache from the meta service.
+    ///
+    /// Fall back to allocating exactly `count` values if the sequence is about to run out.
+    async fn allocate(&self, name_ident: &SequenceNameIdent, count: u64) -> Result<SequenceRange> {
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+
+        let req = GetSequenceNextValuesReq {
+            name_ident: name_ident.clone(),
+            count: count.saturating_add(self.batch_size),
+        };
+        let reply = match meta_api.get_sequence_next_values(req).await {
+            Err(KVAppError::AppError(AppError::SequenceOutOfRange(_))) if self.batch_size > 0 => {
+                let req = GetSequenceNextValuesReq {
+                    name_ident: name_ident.clone(),
+                    count,
+                };
+                meta_api.get_sequence_next_values(req).await?
+            }
+            res => res?,
+        };
+
+        Ok(SequenceRange {
+            start: reply.start,
+            step: reply.step,
+            count: reply.count,
+        })
+    }
+}
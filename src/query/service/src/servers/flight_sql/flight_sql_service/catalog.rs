@@ -15,26 +15,38 @@
 use std::sync::Arc;
 
 use arrow_array::builder::StringBuilder;
+use arrow_array::new_empty_array;
 use arrow_array::ArrayRef;
 use arrow_array::RecordBatch;
+use arrow_flight::sql::CommandGetCatalogs;
+use arrow_flight::sql::CommandGetDbSchemas;
+use arrow_flight::sql::CommandGetPrimaryKeys;
+use arrow_flight::sql::CommandGetTables;
 use arrow_flight::utils::batches_to_flight_data;
 use arrow_schema::DataType;
 use arrow_schema::Field;
 use arrow_schema::Schema;
+use arrow_schema::SchemaRef;
 use common_catalog::catalog::Catalog;
 use common_catalog::catalog::CatalogManager;
+use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
+use common_expression::DataSchema;
 use futures_util::stream;
 use log::warn;
 use tonic::Status;
 
 use crate::servers::flight_sql::flight_sql_service::DoGetStream;
 
+const TABLE_TYPE_TABLE: &str = "TABLE";
+const TABLE_TYPE_VIEW: &str = "VIEW";
+const TABLE_TYPE_SYSTEM_TABLE: &str = "SYSTEM TABLE";
+
 pub(super) struct CatalogInfoProvider {}
 
 impl CatalogInfoProvider {
-    fn batch_to_get_stream(batch: RecordBatch) -> Result<DoGetStream, Status> {
+    pub(super) fn batch_to_get_stream(batch: RecordBatch) -> Result<DoGetStream, Status> {
         let schema = (*batch.schema()).clone();
         let batches = vec![batch];
         let flight_data = batches_to_flight_data(&schema, batches)
@@ -45,14 +57,24 @@ impl CatalogInfoProvider {
         Ok(Box::pin(stream))
     }
 
-    async fn get_tables_internal(
-        ctx: Arc<dyn TableContext>,
+    fn empty_batch_to_get_stream(schema: SchemaRef) -> Result<DoGetStream, Status> {
+        let columns = schema
+            .fields()
+            .iter()
+            .map(|f| new_empty_array(f.data_type()))
+            .collect::<Vec<_>>();
+        let batch = RecordBatch::try_new(schema, columns)
+            .map_err(|e| Status::internal(format!("RecordBatch::try_new fail {:?}", e)))?;
+        Self::batch_to_get_stream(batch)
+    }
+
+    async fn list_catalogs(
+        ctx: &Arc<dyn TableContext>,
         catalog_name: Option<String>,
-        database_name: Option<String>,
-    ) -> common_exception::Result<(Vec<String>, Vec<String>, Vec<String>, Vec<String>)> {
+    ) -> common_exception::Result<Vec<(String, Arc<dyn Catalog>)>> {
         let tenant = ctx.get_tenant();
         let catalog_mgr = CatalogManager::instance();
-        let catalogs: Vec<(String, Arc<dyn Catalog>)> = if let Some(catalog_name) = catalog_name {
+        let catalogs = if let Some(catalog_name) = catalog_name {
             vec![(
                 catalog_name.clone(),
                 catalog_mgr.get_catalog(&tenant, &catalog_name).await?,
@@ -65,22 +87,87 @@ impl CatalogInfoProvider {
                 .map(|r| (r.name(), r.clone()))
                 .collect()
         };
+        Ok(catalogs)
+    }
+
+    fn table_type(db_name: &str, table: &dyn Table) -> &'static str {
+        if table.engine().eq_ignore_ascii_case("VIEW") {
+            TABLE_TYPE_VIEW
+        } else if db_name.eq_ignore_ascii_case("system")
+            || db_name.eq_ignore_ascii_case("information_schema")
+        {
+            TABLE_TYPE_SYSTEM_TABLE
+        } else {
+            TABLE_TYPE_TABLE
+        }
+    }
+
+    pub(crate) async fn get_catalogs(
+        ctx: Arc<dyn TableContext>,
+        query: CommandGetCatalogs,
+    ) -> Result<DoGetStream, Status> {
+        let catalogs = Self::list_catalogs(&ctx, None)
+            .await
+            .map_err(|e| Status::internal(format!("{e:?}")))?;
+        let mut builder = query.into_builder();
+        for (catalog_name, _) in catalogs {
+            builder.append(catalog_name);
+        }
+        let batch = builder
+            .build()
+            .map_err(|e| Status::internal(format!("{e:?}")))?;
+        Self::batch_to_get_stream(batch)
+    }
+
+    async fn get_schemas_internal(
+        ctx: Arc<dyn TableContext>,
+        catalog_name: Option<String>,
+    ) -> common_exception::Result<Vec<(String, String)>> {
+        let tenant = ctx.get_tenant();
+        let mut schemas = vec![];
+        for (catalog_name, catalog) in Self::list_catalogs(&ctx, catalog_name).await? {
+            for db in catalog.list_databases(tenant.as_str()).await? {
+                schemas.push((catalog_name.clone(), db.name().to_string()));
+            }
+        }
+        Ok(schemas)
+    }
 
-        let mut catalog_names = vec![];
-        let mut database_names = vec![];
-        let mut table_names = vec![];
-        let mut table_types = vec![];
-        let table_type = "table".to_string();
-        for (catalog_name, catalog) in catalogs.into_iter() {
+    /// The db_schema_filter_pattern of the command is applied by the builder.
+    pub(crate) async fn get_schemas(
+        ctx: Arc<dyn TableContext>,
+        query: CommandGetDbSchemas,
+    ) -> Result<DoGetStream, Status> {
+        let catalog_name = query.catalog.clone().filter(|c| !c.is_empty());
+        let schemas = Self::get_schemas_internal(ctx, catalog_name)
+            .await
+            .map_err(|e| Status::internal(format!("{e:?}")))?;
+        let mut builder = query.into_builder();
+        for (catalog_name, db_name) in schemas {
+            builder.append(catalog_name, db_name);
+        }
+        let batch = builder
+            .build()
+            .map_err(|e| Status::internal(format!("{e:?}")))?;
+        Self::batch_to_get_stream(batch)
+    }
+
+    async fn get_tables_internal(
+        ctx: Arc<dyn TableContext>,
+        catalog_name: Option<String>,
+        database_name: Option<String>,
+    ) -> common_exception::Result<Vec<(String, String, Arc<dyn Table>)>> {
+        let tenant = ctx.get_tenant();
+        let mut tables = vec![];
+        for (catalog_name, catalog) in Self::list_catalogs(&ctx, catalog_name).await? {
             let dbs = if let Some(database_name) = &database_name {
                 vec![catalog.get_database(tenant.as_str(), database_name).await?]
             } else {
                 catalog.list_databases(tenant.as_str()).await?
             };
             for db in dbs {
-                let db_name = db.name().to_string().into_boxed_str();
-                let db_name: &str = Box::leak(db_name);
-                let tables = match catalog.list_tables(tenant.as_str(), db_name).await {
+                let db_name = db.name();
+                let db_tables = match catalog.list_tables(tenant.as_str(), db_name).await {
                     Ok(tables) => tables,
                     Err(err) if err.code() == ErrorCode::EMPTY_SHARE_ENDPOINT_CONFIG => {
                         warn!("list tables failed on db {}: {}", db.name(), err);
@@ -88,40 +175,108 @@ impl CatalogInfoProvider {
                     }
                     Err(err) => return Err(err),
                 };
-                for table in tables {
-                    catalog_names.push(catalog_name.clone());
-                    database_names.push(db_name.to_string());
-                    table_names.push(table.name().to_string());
-                    table_types.push(table_type.clone());
+                for table in db_tables {
+                    tables.push((catalog_name.clone(), db_name.to_string(), table));
                 }
             }
         }
-        Ok((catalog_names, database_names, table_names, table_types))
+        Ok(tables)
     }
 
+    /// The name patterns, table types and include_schema of the command are applied by the builder.
     pub(crate) async fn get_tables(
         ctx: Arc<dyn TableContext>,
-        catalog_name: Option<String>,
-        database_name: Option<String>,
+        query: CommandGetTables,
     ) -> Result<DoGetStream, Status> {
+        let catalog_name = query.catalog.clone().filter(|c| !c.is_empty());
+        let tables = Self::get_tables_internal(ctx.clone(), catalog_name, None)
+            .await
+            .map_err(|e| Status::internal(format!("{e:?}")))?;
+        let mut builder = query.into_builder();
+        for (catalog_name, db_name, table) in tables {
+            let table_type = Self::table_type(&db_name, table.as_ref());
+            let data_schema = DataSchema::from(table.schema());
+            let arrow_schema = Schema::from(&data_schema);
+            builder
+                .append(
+                    catalog_name,
+                    db_name,
+                    table.name(),
+                    table_type,
+                    &arrow_schema,
+                )
+                .map_err(|e| Status::internal(format!("{e:?}")))?;
+        }
+        let batch = builder
+            .build()
+            .map_err(|e| Status::internal(format!("{e:?}")))?;
+        Self::batch_to_get_stream(batch)
+    }
+
+    pub(crate) fn get_table_types() -> Result<DoGetStream, Status> {
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "table_type",
+            DataType::Utf8,
+            false,
+        )]));
+        let table_types = vec![
+            TABLE_TYPE_TABLE.to_string(),
+            TABLE_TYPE_VIEW.to_string(),
+            TABLE_TYPE_SYSTEM_TABLE.to_string(),
+        ];
+        let batch = RecordBatch::try_new(schema, vec![Self::string_array(table_types)])
+            .map_err(|e| Status::internal(format!("RecordBatch::try_new fail {:?}", e)))?;
+        Self::batch_to_get_stream(batch)
+    }
+
+    /// Databend has no primary key constraint, so the result is always empty,
+    /// but the table is still resolved to report unknown tables to the client.
+    pub(crate) async fn get_primary_keys(
+        ctx: Arc<dyn TableContext>,
+        query: CommandGetPrimaryKeys,
+    ) -> Result<DoGetStream, Status> {
+        let catalog_name = query
+            .catalog
+            .filter(|c| !c.is_empty())
+            .unwrap_or_else(|| ctx.get_current_catalog());
+        let database_name = query
+            .db_schema
+            .filter(|d| !d.is_empty())
+            .unwrap_or_else(|| ctx.get_current_database());
+        ctx.get_table(&catalog_name, &database_name, &query.table)
+            .await
+            .map_err(|e| Status::not_found(format!("{e:?}")))?;
+
         let schema = Arc::new(Schema::new(vec![
-            Field::new("catalog_name", DataType::Utf8, false),
-            Field::new("db_schema_name", DataType::Utf8, false),
+            Field::new("catalog_name", DataType::Utf8, true),
+            Field::new("db_schema_name", DataType::Utf8, true),
             Field::new("table_name", DataType::Utf8, false),
-            Field::new("table_type", DataType::Utf8, false),
+            Field::new("column_name", DataType::Utf8, false),
+            Field::new("key_sequence", DataType::Int32, false),
+            Field::new("key_name", DataType::Utf8, true),
         ]));
-        let (catalog_name, db_schema_name, table_name, table_type) =
-            Self::get_tables_internal(ctx.clone(), catalog_name, database_name)
-                .await
-                .map_err(|e| Status::internal(format!("{e:?}")))?;
-        let batch = RecordBatch::try_new(schema, vec![
-            Self::string_array(catalog_name),
-            Self::string_array(db_schema_name),
-            Self::string_array(table_name),
-            Self::string_array(table_type),
-        ])
-        .map_err(|e| Status::internal(format!("RecordBatch::try_new fail {:?}", e)))?;
-        Self::batch_to_get_stream(batch)
+        Self::empty_batch_to_get_stream(schema)
+    }
+
+    /// Databend has no foreign key constraint, the result of exported keys,
+    /// imported keys and cross reference is always empty.
+    pub(crate) fn get_foreign_keys() -> Result<DoGetStream, Status> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("pk_catalog_name", DataType::Utf8, true),
+            Field::new("pk_db_schema_name", DataType::Utf8, true),
+            Field::new("pk_table_name", DataType::Utf8, false),
+            Field::new("pk_column_name", DataType::Utf8, false),
+            Field::new("fk_catalog_name", DataType::Utf8, true),
+            Field::new("fk_db_schema_name", DataType::Utf8, true),
+            Field::new("fk_table_name", DataType::Utf8, false),
+            Field::new("fk_column_name", DataType::Utf8, false),
+            Field::new("key_sequence", DataType::Int32, false),
+            Field::new("fk_key_name", DataType::Utf8, true),
+            Field::new("pk_key_name", DataType::Utf8, true),
+            Field::new("update_rule", DataType::UInt8, false),
+            Field::new("delete_rule", DataType::UInt8, false),
+        ]));
+        Self::empty_batch_to_get_stream(schema)
     }
 
     fn string_array(values: Vec<String>) -> ArrayRef {
@@ -19,22 +19,27 @@ mod query;
 mod service;
 mod session;
 mod sql_info;
+mod xdbc_type_info;
 
 use std::pin::Pin;
 use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
 
 use arrow_flight::FlightData;
 use catalog::CatalogInfoProvider;
 use common_sql::plans::Plan;
 use common_sql::PlanExtras;
-use dashmap::DashMap;
 use futures::Stream;
 use parking_lot::Mutex;
 use sql_info::SqlInfoProvider;
 use tonic::Status;
 use uuid::Uuid;
+use xdbc_type_info::XdbcTypeInfoProvider;
 
+use crate::servers::http::v1::query::expirable::Expirable;
 use crate::servers::http::v1::ExpiringMap;
+use crate::servers::http::v1::ExpiringState;
 use crate::sessions::Session;
 
 #[macro_export]
@@ -49,9 +54,39 @@ pub(crate) use status;
 
 type DoGetStream = Pin<Box<dyn Stream<Item = Result<FlightData, Status>> + Send + 'static>>;
 
+/// A statement that has not been used for this duration is removed,
+/// e.g., the ticket of a statement that is never fetched, or a prepared statement never closed.
+const STATEMENT_MAX_IDLE: Duration = Duration::from_secs(360);
+
+/// A planned statement, waiting for its ticket to be fetched, or prepared.
+#[derive(Clone)]
+struct Statement {
+    plan: (Plan, PlanExtras),
+    last_access: Arc<Mutex<Instant>>,
+}
+
+impl Statement {
+    fn create(plan: (Plan, PlanExtras)) -> Self {
+        Statement {
+            plan,
+            last_access: Arc::new(Mutex::new(Instant::now())),
+        }
+    }
+}
+
+impl Expirable for Statement {
+    fn expire_state(&self) -> ExpiringState {
+        ExpiringState::Idle {
+            idle_time: self.last_access.lock().elapsed(),
+        }
+    }
+
+    fn on_expire(&self) {}
+}
+
 pub struct FlightSqlServiceImpl {
     pub sessions: Mutex<ExpiringMap<String, Arc<Session>>>,
-    statements: Arc<DashMap<Uuid, (Plan, PlanExtras)>>,
+    statements: Mutex<ExpiringMap<Uuid, Statement>>,
 }
 
 /// in current official JDBC driver, Statement is based on PreparedStatement too, so we impl it first.
@@ -59,7 +94,31 @@ impl FlightSqlServiceImpl {
     pub fn create() -> Self {
         FlightSqlServiceImpl {
             sessions: Mutex::new(Default::default()),
-            statements: Arc::new(Default::default()),
+            statements: Mutex::new(Default::default()),
         }
     }
+
+    fn insert_plan(&self, handle: Uuid, plan: (Plan, PlanExtras)) {
+        self.statements
+            .lock()
+            .insert(handle, Statement::create(plan), Some(STATEMENT_MAX_IDLE));
+    }
+
+    fn get_plan(&self, handle: &Uuid) -> Result<(Plan, PlanExtras), Status> {
+        let statement = self
+            .statements
+            .lock()
+            .get(handle)
+            .ok_or_else(|| Status::not_found(format!("unknown statement handle {handle}")))?;
+        *statement.last_access.lock() = Instant::now();
+        Ok(statement.plan)
+    }
+
+    /// Removes a statement, returns the plan of it if it exists.
+    fn remove_plan(&self, handle: &Uuid) -> Option<(Plan, PlanExtras)> {
+        let mut statements = self.statements.lock();
+        let statement = statements.get(handle)?;
+        statements.remove(handle);
+        Some(statement.plan)
+    }
 }
@@ -107,6 +107,21 @@ impl FlightSqlServiceImpl {
         Ok(affected_rows as i64)
     }
 
+    pub(super) async fn execute_txn_statement(
+        &self,
+        session: Arc<Session>,
+        sql: &str,
+    ) -> std::result::Result<(), Status> {
+        let (plan, plan_extras) = self
+            .plan_sql(&session, sql)
+            .await
+            .map_err(|e| status!("Error planning transaction statement", e))?;
+        self.execute_update(session, &plan, &plan_extras)
+            .await
+            .map_err(|e| status!("fail to execute", e))?;
+        Ok(())
+    }
+
     pub async fn execute_query(
         &self,
         session: Arc<Session>,
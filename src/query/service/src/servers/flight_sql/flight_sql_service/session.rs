@@ -25,6 +25,7 @@ use tonic::Status;
 
 use super::status;
 use crate::servers::flight_sql::flight_sql_service::FlightSqlServiceImpl;
+use crate::sessions::QueryContext;
 use crate::sessions::Session;
 use crate::sessions::SessionManager;
 use crate::sessions::SessionType;
@@ -54,6 +55,18 @@ impl FlightSqlServiceImpl {
         }
     }
 
+    #[async_backtrace::framed]
+    pub(super) async fn get_query_context<T>(
+        &self,
+        req: &Request<T>,
+    ) -> Result<Arc<QueryContext>, Status> {
+        let session = self.get_session(req)?;
+        session
+            .create_query_context()
+            .await
+            .map_err(|e| status!("Could not create_query_context", e))
+    }
+
     pub(super) fn get_header_value(metadata: &MetadataMap, key: &str) -> Option<String> {
         metadata
             .get(key)
@@ -0,0 +1,158 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use arrow_flight::sql::metadata::XdbcTypeInfo;
+use arrow_flight::sql::metadata::XdbcTypeInfoData;
+use arrow_flight::sql::metadata::XdbcTypeInfoDataBuilder;
+use arrow_flight::sql::CommandGetXdbcTypeInfo;
+use arrow_flight::sql::Nullable;
+use arrow_flight::sql::Searchable;
+use arrow_flight::sql::XdbcDataType;
+use once_cell::sync::Lazy;
+use tonic::Status;
+
+use super::CatalogInfoProvider;
+use crate::servers::flight_sql::flight_sql_service::DoGetStream;
+
+struct TypeDesc {
+    name: &'static str,
+    data_type: XdbcDataType,
+    column_size: Option<i32>,
+    literal_quote: bool,
+    create_params: Option<Vec<String>>,
+    case_sensitive: bool,
+    unsigned: Option<bool>,
+    fixed_prec_scale: bool,
+    maximum_scale: Option<i32>,
+    num_prec_radix: Option<i32>,
+}
+
+impl TypeDesc {
+    fn new(name: &'static str, data_type: XdbcDataType) -> Self {
+        TypeDesc {
+            name,
+            data_type,
+            column_size: None,
+            literal_quote: false,
+            create_params: None,
+            case_sensitive: false,
+            unsigned: None,
+            fixed_prec_scale: false,
+            maximum_scale: None,
+            num_prec_radix: None,
+        }
+    }
+
+    fn number(name: &'static str, data_type: XdbcDataType, size: i32, unsigned: bool) -> Self {
+        TypeDesc {
+            column_size: Some(size),
+            unsigned: Some(unsigned),
+            num_prec_radix: Some(10),
+            ..Self::new(name, data_type)
+        }
+    }
+
+    fn quoted(name: &'static str, data_type: XdbcDataType) -> Self {
+        TypeDesc {
+            literal_quote: true,
+            ..Self::new(name, data_type)
+        }
+    }
+
+    fn into_type_info(self) -> XdbcTypeInfo {
+        let quote = self.literal_quote.then(|| "'".to_string());
+        XdbcTypeInfo {
+            type_name: self.name.to_string(),
+            data_type: self.data_type,
+            column_size: self.column_size,
+            literal_prefix: quote.clone(),
+            literal_suffix: quote,
+            create_params: self.create_params,
+            nullable: Nullable::NullabilityNullable,
+            case_sensitive: self.case_sensitive,
+            searchable: Searchable::Full,
+            unsigned_attribute: self.unsigned,
+            fixed_prec_scale: self.fixed_prec_scale,
+            auto_increment: Some(false),
+            local_type_name: Some(self.name.to_string()),
+            minimum_scale: self.maximum_scale.map(|_| 0),
+            maximum_scale: self.maximum_scale,
+            sql_data_type: self.data_type,
+            datetime_subcode: None,
+            num_prec_radix: self.num_prec_radix,
+            interval_precision: None,
+        }
+    }
+}
+
+/// The data types of databend, ordered by the XDBC data type code.
+static XDBC_TYPE_INFO_DATA: Lazy<XdbcTypeInfoData> = Lazy::new(|| {
+    let types = vec![
+        TypeDesc::new("BOOLEAN", XdbcDataType::XdbcBit),
+        TypeDesc::number("TINYINT", XdbcDataType::XdbcTinyint, 3, false),
+        TypeDesc::number("TINYINT UNSIGNED", XdbcDataType::XdbcTinyint, 3, true),
+        TypeDesc::number("BIGINT", XdbcDataType::XdbcBigint, 19, false),
+        TypeDesc::number("BIGINT UNSIGNED", XdbcDataType::XdbcBigint, 20, true),
+        TypeDesc::quoted("VARIANT", XdbcDataType::XdbcLongvarchar),
+        TypeDesc {
+            create_params: Some(vec!["precision".to_string(), "scale".to_string()]),
+            fixed_prec_scale: true,
+            maximum_scale: Some(76),
+            ..TypeDesc::number("DECIMAL", XdbcDataType::XdbcDecimal, 76, false)
+        },
+        TypeDesc::number("INT", XdbcDataType::XdbcInteger, 10, false),
+        TypeDesc::number("INT UNSIGNED", XdbcDataType::XdbcInteger, 10, true),
+        TypeDesc::number("SMALLINT", XdbcDataType::XdbcSmallint, 5, false),
+        TypeDesc::number("SMALLINT UNSIGNED", XdbcDataType::XdbcSmallint, 5, true),
+        TypeDesc {
+            num_prec_radix: Some(2),
+            ..TypeDesc::number("FLOAT", XdbcDataType::XdbcFloat, 24, false)
+        },
+        TypeDesc {
+            num_prec_radix: Some(2),
+            ..TypeDesc::number("DOUBLE", XdbcDataType::XdbcDouble, 53, false)
+        },
+        TypeDesc {
+            case_sensitive: true,
+            ..TypeDesc::quoted("VARCHAR", XdbcDataType::XdbcVarchar)
+        },
+        TypeDesc {
+            column_size: Some(10),
+            ..TypeDesc::quoted("DATE", XdbcDataType::XdbcDate)
+        },
+        TypeDesc {
+            column_size: Some(26),
+            ..TypeDesc::quoted("TIMESTAMP", XdbcDataType::XdbcTimestamp)
+        },
+    ];
+
+    let mut builder = XdbcTypeInfoDataBuilder::new();
+    for ty in types {
+        builder.append(ty.into_type_info());
+    }
+    builder.build().expect("build xdbc type info data")
+});
+
+pub(super) struct XdbcTypeInfoProvider {}
+
+impl XdbcTypeInfoProvider {
+    /// The data_type filter of the command is applied by the builder.
+    pub fn type_info(query: CommandGetXdbcTypeInfo) -> Result<DoGetStream, Status> {
+        let batch = query
+            .into_builder(&XDBC_TYPE_INFO_DATA)
+            .build()
+            .map_err(|e| Status::internal(format!("{e:?}")))?;
+        CatalogInfoProvider::batch_to_get_stream(batch)
+    }
+}
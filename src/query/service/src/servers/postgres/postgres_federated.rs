@@ -0,0 +1,267 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_config::DATABEND_COMMIT_VERSION;
+use common_expression::types::StringType;
+use common_expression::utils::FromData;
+use common_expression::DataBlock;
+use common_expression::DataSchema;
+use common_expression::DataSchemaRef;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRef;
+use common_expression::TableSchemaRefExt;
+use ctor::ctor;
+use regex::Regex;
+
+use crate::servers::federated_helper::FederatedHelper;
+use crate::servers::federated_helper::LazyBlockFunc;
+use crate::servers::postgres::POSTGRES_VERSION;
+
+pub struct PostgresFederated {}
+
+impl PostgresFederated {
+    pub fn create() -> Self {
+        PostgresFederated {}
+    }
+
+    // Values of the run-time parameters drivers ask for with SHOW or current_setting().
+    fn parameter_value(name: &str) -> Option<&'static str> {
+        match name.to_lowercase().as_str() {
+            "server_version" => Some(POSTGRES_VERSION),
+            "server_version_num" => Some("150003"),
+            "server_encoding" | "client_encoding" => Some("UTF8"),
+            "datestyle" => Some("ISO, MDY"),
+            "integer_datetimes" | "standard_conforming_strings" => Some("on"),
+            "search_path" => Some("\"$user\", public"),
+            "transaction_isolation"
+            | "transaction_isolation_level"
+            | "default_transaction_isolation" => Some("read committed"),
+            "transaction_read_only" | "default_transaction_read_only" => Some("off"),
+            "max_identifier_length" => Some("63"),
+            "lc_collate" | "lc_ctype" => Some("en_US.UTF-8"),
+            _ => None,
+        }
+    }
+
+    // Build block for select function.
+    // Format:
+    // |function_name|
+    // |value|
+    fn select_function_block(name: &str, value: &str) -> Option<(TableSchemaRef, DataBlock)> {
+        let schema = TableSchemaRefExt::create(vec![TableField::new(name, TableDataType::String)]);
+        let block = DataBlock::new_from_columns(vec![StringType::from_data(vec![
+            value.as_bytes().to_vec(),
+        ])]);
+        Some((schema, block))
+    }
+
+    // SELECT pg_catalog.version()
+    fn select_version_block(_query: &str) -> Option<(TableSchemaRef, DataBlock)> {
+        let version = format!(
+            "PostgreSQL {} (DatabendQuery {})",
+            POSTGRES_VERSION, *DATABEND_COMMIT_VERSION
+        );
+        Self::select_function_block("version", &version)
+    }
+
+    // SHOW name
+    fn show_parameter_block(query: &str) -> Option<(TableSchemaRef, DataBlock)> {
+        let name = query
+            .trim()
+            .trim_end_matches(';')
+            .split_whitespace()
+            .skip(1)
+            .collect::<Vec<_>>()
+            .join("_")
+            .to_lowercase();
+        let value = Self::parameter_value(&name)?;
+        Self::select_function_block(&name, value)
+    }
+
+    // SELECT current_setting('name')
+    fn current_setting_block(query: &str) -> Option<(TableSchemaRef, DataBlock)> {
+        let name = query.split('\'').nth(1)?;
+        let value = Self::parameter_value(name)?;
+        Self::select_function_block("current_setting", value)
+    }
+
+    // SELECT a, b AS c FROM pg_catalog.pg_xx ...
+    // Databend has no pg_catalog, returns an empty result with the selected columns.
+    fn pg_catalog_block(query: &str) -> Option<(TableSchemaRef, DataBlock)> {
+        let fields = Self::select_list(query)
+            .into_iter()
+            .map(|name| TableField::new(&name, TableDataType::String))
+            .collect::<Vec<_>>();
+        if fields.is_empty() {
+            return None;
+        }
+
+        let columns = fields
+            .iter()
+            .map(|_| StringType::from_data(Vec::<Vec<u8>>::new()))
+            .collect();
+        Some((
+            TableSchemaRefExt::create(fields),
+            DataBlock::new_from_columns(columns),
+        ))
+    }
+
+    // Split the select list of a query into the output column names.
+    fn select_list(query: &str) -> Vec<String> {
+        let lower = query.to_lowercase();
+        let (Some(start), Some(end)) = (lower.find("select"), lower.find(" from ")) else {
+            return vec![];
+        };
+        if start + 6 > end {
+            return vec![];
+        }
+
+        let mut items = vec![];
+        let mut depth = 0;
+        let mut current = String::new();
+        for c in query[start + 6..end].chars() {
+            match c {
+                '(' => depth += 1,
+                ')' => depth -= 1,
+                ',' if depth == 0 => {
+                    items.push(std::mem::take(&mut current));
+                    continue;
+                }
+                _ => {}
+            }
+            current.push(c);
+        }
+        items.push(current);
+
+        items
+            .iter()
+            .map(|item| {
+                let item = item.trim();
+                let lower = item.to_lowercase();
+                let name = match lower.rfind(" as ") {
+                    Some(pos) => &item[pos + 4..],
+                    None if item.ends_with(')') => "?column?",
+                    None => item.rsplit('.').next().unwrap_or(item),
+                };
+                name.trim().trim_matches('"').to_string()
+            })
+            .filter(|name| !name.is_empty())
+            .collect()
+    }
+
+    // Check the driver setup queries which need a computed result.
+    fn federated_lazy_check(&self, query: &str) -> Option<(TableSchemaRef, DataBlock)> {
+        #[ctor]
+        static LAZY_RULES: Vec<(Regex, LazyBlockFunc)> = vec![
+            (
+                Regex::new("(?i)^(\\s*SELECT pg_catalog\\.version\\(\\)\\s*;?\\s*)$").unwrap(),
+                PostgresFederated::select_version_block,
+            ),
+            (
+                Regex::new("(?i)^(\\s*SHOW (.*))$").unwrap(),
+                PostgresFederated::show_parameter_block,
+            ),
+            (
+                Regex::new("(?i)^(\\s*SELECT (pg_catalog\\.)?current_setting\\('(.*)'\\)\\s*;?\\s*)$")
+                    .unwrap(),
+                PostgresFederated::current_setting_block,
+            ),
+            (
+                Regex::new("(?is)^(\\s*SELECT (.*) FROM (pg_catalog\\.pg_\\w+|pg_(am|attrdef|attribute|class|constraint|database|description|enum|extension|index|inherits|namespace|proc|range|roles|settings|tables|type|user|views))\\b(.*))").unwrap(),
+                PostgresFederated::pg_catalog_block,
+            ),
+        ];
+
+        for (regex, func) in LAZY_RULES.iter() {
+            if regex.is_match(query) {
+                if let Some(result) = func(query) {
+                    return Some(result);
+                }
+            }
+        }
+        None
+    }
+
+    // Check for SET or others query, this is the final check of the federated query.
+    fn federated_mixed_check(&self, query: &str) -> Option<(TableSchemaRef, DataBlock)> {
+        #[ctor]
+        static MIXED_RULES: Vec<(Regex, Option<(TableSchemaRef, DataBlock)>)> = vec![
+            (
+                Regex::new("(?i)^(\\s*SELECT (pg_catalog\\.)?current_schema\\(\\)\\s*;?\\s*)$")
+                    .unwrap(),
+                PostgresFederated::select_function_block("current_schema", "public"),
+            ),
+            // PostgreSQL only settings, Databend settings like timezone are set as usual.
+            (
+                Regex::new("(?i)^(\\s*SET (SESSION )?extra_float_digits(.*))").unwrap(),
+                None,
+            ),
+            (
+                Regex::new("(?i)^(\\s*SET (SESSION )?application_name(.*))").unwrap(),
+                None,
+            ),
+            (
+                Regex::new("(?i)^(\\s*SET (SESSION )?client_encoding(.*))").unwrap(),
+                None,
+            ),
+            (
+                Regex::new("(?i)^(\\s*SET (SESSION )?client_min_messages(.*))").unwrap(),
+                None,
+            ),
+            (
+                Regex::new("(?i)^(\\s*SET (SESSION )?DateStyle(.*))").unwrap(),
+                None,
+            ),
+            (
+                Regex::new("(?i)^(\\s*SET (SESSION )?IntervalStyle(.*))").unwrap(),
+                None,
+            ),
+            (
+                Regex::new("(?i)^(\\s*SET (SESSION )?search_path(.*))").unwrap(),
+                None,
+            ),
+            (
+                Regex::new("(?i)^(\\s*SET (SESSION )?statement_timeout(.*))").unwrap(),
+                None,
+            ),
+            (
+                Regex::new("(?i)^(\\s*SET (SESSION )?standard_conforming_strings(.*))").unwrap(),
+                None,
+            ),
+            (
+                Regex::new("(?i)^(\\s*SET SESSION CHARACTERISTICS(.*))").unwrap(),
+                None,
+            ),
+            (Regex::new("(?i)^(\\s*DISCARD ALL(.*))").unwrap(), None),
+        ];
+
+        FederatedHelper::block_match_rule(query, &MIXED_RULES)
+    }
+
+    // Check the query is a federated or driver setup command.
+    // Here we fake some values for the command which Databend not supported.
+    pub fn check(&self, query: &str) -> Option<(DataSchemaRef, DataBlock)> {
+        let lazy = self
+            .federated_lazy_check(query)
+            .map(|(schema, chunk)| (Arc::new(DataSchema::from(schema)), chunk));
+        if lazy.is_some() {
+            return lazy;
+        }
+
+        self.federated_mixed_check(query)
+            .map(|(schema, chunk)| (Arc::new(DataSchema::from(schema)), chunk))
+    }
+}
@@ -0,0 +1,260 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::HashMap;
+use std::future::Future;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use common_base::base::tokio;
+use common_base::base::tokio::io::AsyncWriteExt;
+use common_base::base::tokio::net::TcpStream;
+use common_base::base::tokio::task::JoinHandle;
+use common_base::runtime::Runtime;
+use common_base::runtime::TrySpawn;
+use common_base::GLOBAL_TASK;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use futures::future::AbortHandle;
+use futures::future::AbortRegistration;
+use futures::future::Abortable;
+use futures::StreamExt;
+use log::error;
+use log::info;
+use log::warn;
+use parking_lot::Mutex;
+use socket2::SockRef;
+use socket2::TcpKeepalive;
+use tokio_stream::wrappers::TcpListenerStream;
+
+use crate::servers::postgres::postgres_message::read_startup;
+use crate::servers::postgres::postgres_message::BackendMessage;
+use crate::servers::postgres::postgres_message::StartupPacket;
+use crate::servers::postgres::postgres_session::PostgresConnection;
+use crate::servers::server::ListeningStream;
+use crate::servers::server::Server;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+
+/// The secret keys of the connections, by the process id(the connection id) sent
+/// in `BackendKeyData`. A `CancelRequest` must present both to kill the query.
+pub(crate) type CancelKeys = Arc<Mutex<HashMap<u32, u32>>>;
+
+pub struct PostgresHandler {
+    abort_handle: AbortHandle,
+    abort_registration: Option<AbortRegistration>,
+    join_handle: Option<JoinHandle<()>>,
+    keepalive: TcpKeepalive,
+    cancel_keys: CancelKeys,
+}
+
+impl PostgresHandler {
+    pub fn create(tcp_keepalive_timeout_secs: u64) -> Result<Box<dyn Server>> {
+        let (abort_handle, registration) = AbortHandle::new_pair();
+        let keepalive = TcpKeepalive::new()
+            .with_time(std::time::Duration::from_secs(tcp_keepalive_timeout_secs));
+
+        Ok(Box::new(PostgresHandler {
+            abort_handle,
+            abort_registration: Some(registration),
+            join_handle: None,
+            keepalive,
+            cancel_keys: Arc::new(Mutex::new(HashMap::new())),
+        }))
+    }
+
+    #[async_backtrace::framed]
+    async fn listener_tcp(listening: SocketAddr) -> Result<(TcpListenerStream, SocketAddr)> {
+        let listener = tokio::net::TcpListener::bind(listening)
+            .await
+            .map_err(|e| {
+                ErrorCode::TokioError(format!("{{{}:{}}} {}", listening.ip(), listening.port(), e))
+            })?;
+        let listener_addr = listener.local_addr()?;
+        Ok((TcpListenerStream::new(listener), listener_addr))
+    }
+
+    fn listen_loop(&self, stream: ListeningStream, rt: Arc<Runtime>) -> impl Future<Output = ()> {
+        let keepalive = self.keepalive.clone();
+        let cancel_keys = self.cancel_keys.clone();
+
+        stream.for_each(move |accept_socket| {
+            let keepalive = keepalive.clone();
+            let cancel_keys = cancel_keys.clone();
+            let executor = rt.clone();
+            let sessions = SessionManager::instance();
+            async move {
+                match accept_socket {
+                    Err(error) => error!("Broken session connection: {}", error),
+                    Ok(socket) => PostgresHandler::accept_socket(
+                        sessions,
+                        executor,
+                        socket,
+                        keepalive,
+                        cancel_keys,
+                    ),
+                };
+            }
+        })
+    }
+
+    fn accept_socket(
+        sessions: Arc<SessionManager>,
+        executor: Arc<Runtime>,
+        mut socket: TcpStream,
+        keepalive: TcpKeepalive,
+        cancel_keys: CancelKeys,
+    ) {
+        executor.spawn(GLOBAL_TASK, async move {
+            let params = match Self::read_startup_params(&mut socket, &sessions, &cancel_keys).await
+            {
+                Ok(Some(params)) => params,
+                Ok(None) => return,
+                Err(error) => {
+                    warn!("PostgreSQL startup failed, {:?}", error);
+                    Self::reject_session(socket, "08P01", error).await;
+                    return;
+                }
+            };
+
+            match sessions.create_session(SessionType::PostgreSQL).await {
+                Err(error) => {
+                    warn!("create session failed, {:?}", error);
+                    Self::reject_session(socket, "53300", error).await
+                }
+                Ok(session) => {
+                    info!("PostgreSQL connection coming: {:?}", socket.peer_addr());
+
+                    // TcpStream must implement AsFd for socket2 0.5, wait https://github.com/tokio-rs/tokio/pull/5514
+                    if let Err(e) = SockRef::from(&socket).set_tcp_keepalive(&keepalive) {
+                        warn!("failed to set socket option keepalive {}", e);
+                    }
+
+                    if let Err(error) =
+                        PostgresConnection::run_on_stream(session, socket, params, cancel_keys)
+                    {
+                        error!("Unexpected error occurred during query: {:?}", error);
+                    };
+                }
+            }
+        });
+    }
+
+    // Negotiate the startup, SSL and GSS encryption are not supported, the client
+    // continues in plaintext or gives up. Returns `None` for a cancel request.
+    #[async_backtrace::framed]
+    async fn read_startup_params(
+        socket: &mut TcpStream,
+        sessions: &Arc<SessionManager>,
+        cancel_keys: &CancelKeys,
+    ) -> Result<Option<HashMap<String, String>>> {
+        loop {
+            match read_startup(socket).await? {
+                StartupPacket::SslRequest | StartupPacket::GssEncRequest => {
+                    socket.write_all(b"N").await?;
+                }
+                StartupPacket::CancelRequest {
+                    process_id,
+                    secret_key,
+                } => {
+                    Self::cancel_query(sessions, cancel_keys, process_id, secret_key);
+                    return Ok(None);
+                }
+                StartupPacket::Startup { params } => return Ok(Some(params)),
+            }
+        }
+    }
+
+    fn cancel_query(
+        sessions: &Arc<SessionManager>,
+        cancel_keys: &CancelKeys,
+        process_id: u32,
+        secret_key: u32,
+    ) {
+        if cancel_keys.lock().get(&process_id) != Some(&secret_key) {
+            warn!(
+                "PostgreSQL cancel request with wrong key for connection {}",
+                process_id
+            );
+            return;
+        }
+
+        let session = sessions
+            .get_id_by_mysql_conn_id(&Some(process_id))
+            .and_then(|id| sessions.get_session_by_id(&id));
+        if let Some(session) = session {
+            info!("PostgreSQL cancel query of connection {}", process_id);
+            session.force_kill_query(ErrorCode::AbortedQuery(
+                "canceling statement due to user request",
+            ));
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn reject_session(mut stream: TcpStream, code: &'static str, error: ErrorCode) {
+        let mut buf = vec![];
+        BackendMessage::ErrorResponse {
+            severity: "FATAL",
+            code,
+            message: error.message(),
+        }
+        .encode(&mut buf);
+
+        if let Err(error) = stream.write_all(&buf).await {
+            error!(
+                "Unexpected error occurred during reject connection: {:?}",
+                error
+            );
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Server for PostgresHandler {
+    #[async_backtrace::framed]
+    async fn shutdown(&mut self, graceful: bool) {
+        if !graceful {
+            return;
+        }
+
+        self.abort_handle.abort();
+
+        if let Some(join_handle) = self.join_handle.take() {
+            if let Err(error) = join_handle.await {
+                error!(
+                    "Unexpected error during shutdown PostgresHandler. cause {}",
+                    error
+                );
+            }
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn start(&mut self, listening: SocketAddr) -> Result<SocketAddr> {
+        match self.abort_registration.take() {
+            None => Err(ErrorCode::Internal("PostgresHandler already running.")),
+            Some(registration) => {
+                let rejected_rt = Arc::new(Runtime::with_worker_threads(
+                    1,
+                    Some("postgres-handler".to_string()),
+                )?);
+                let (stream, listener) = Self::listener_tcp(listening).await?;
+                let stream = Abortable::new(stream, registration);
+                self.join_handle = Some(tokio::spawn(
+                    async_backtrace::location!().frame(self.listen_loop(stream, rejected_rt)),
+                ));
+                Ok(listener)
+            }
+        }
+    }
+}
@@ -0,0 +1,719 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono_tz::Tz;
+use common_base::base::tokio::io::AsyncRead;
+use common_base::base::tokio::io::AsyncWrite;
+use common_base::base::tokio::io::AsyncWriteExt;
+use common_base::runtime::TrySpawn;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::ToErrorCode;
+use common_expression::Column;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_expression::SendableDataBlockStream;
+use common_meta_app::principal::AuthInfo;
+use common_meta_app::principal::UserIdentity;
+use common_sql::Planner;
+use common_users::UserApiProvider;
+use futures_util::StreamExt;
+use log::info;
+use rand::RngCore;
+
+use crate::auth::AuthMgr;
+use crate::auth::Credential;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterFactory;
+use crate::interpreters::InterpreterQueryLog;
+use crate::servers::postgres::postgres_handler::CancelKeys;
+use crate::servers::postgres::postgres_message::decode_password;
+use crate::servers::postgres::postgres_message::read_message;
+use crate::servers::postgres::postgres_message::BackendMessage;
+use crate::servers::postgres::postgres_message::FrontendMessage;
+use crate::servers::postgres::postgres_message::FORMAT_TEXT;
+use crate::servers::postgres::postgres_types::decode_param;
+use crate::servers::postgres::postgres_types::param_count;
+use crate::servers::postgres::postgres_types::split_statements;
+use crate::servers::postgres::postgres_types::substitute_params;
+use crate::servers::postgres::postgres_types::DataRowEncoder;
+use crate::servers::postgres::postgres_types::TEXT_OID;
+use crate::servers::postgres::PostgresFederated;
+use crate::servers::postgres::POSTGRES_VERSION;
+use crate::sessions::QueryContext;
+use crate::sessions::Session;
+use crate::sessions::TableContext;
+use crate::stream::DataBlockStream;
+
+// Write the buffered messages to the client once they exceed this size: 100KB
+const FLUSH_BUFFER_SIZE: usize = 100 * 1024;
+
+struct PreparedStatement {
+    query: String,
+    param_types: Vec<u32>,
+}
+
+struct Portal {
+    sql: String,
+    result_formats: Vec<i16>,
+    // Started by the first Describe or Execute of the portal.
+    execution: Option<Execution>,
+}
+
+/// A running query and the progress of sending its result.
+struct Execution {
+    schema: DataSchemaRef,
+    has_result_set: bool,
+    blocks: SendableDataBlockStream,
+    context: Option<Arc<QueryContext>>,
+    encoder: DataRowEncoder,
+    // The columns of the current block, and the next row to send.
+    current: Option<(Vec<Column>, usize)>,
+    sent_rows: usize,
+    command: String,
+    finished: bool,
+}
+
+impl Execution {
+    // Send at most `max_rows` rows(0 means all), returns true if the result is exhausted.
+    #[async_backtrace::framed]
+    async fn send_rows<W: AsyncWrite + Unpin>(
+        &mut self,
+        max_rows: usize,
+        out: &mut Vec<u8>,
+        writer: &mut W,
+    ) -> Result<bool> {
+        let mut sent = 0;
+        while !self.finished {
+            if let Some((columns, row)) = &mut self.current {
+                let num_rows = columns.first().map(|c| c.len()).unwrap_or(0);
+                if *row < num_rows {
+                    if max_rows > 0 && sent >= max_rows {
+                        return Ok(false);
+                    }
+                    BackendMessage::DataRow(self.encoder.encode_row(columns, *row)?).encode(out);
+                    *row += 1;
+                    sent += 1;
+                    self.sent_rows += 1;
+                    if out.len() >= FLUSH_BUFFER_SIZE {
+                        writer.write_all(out).await?;
+                        out.clear();
+                    }
+                    continue;
+                }
+            }
+
+            match self.blocks.next().await {
+                None => self.finished = true,
+                Some(block) => {
+                    let block = block?;
+                    let columns = block
+                        .convert_to_full()
+                        .columns()
+                        .iter()
+                        .map(|column| column.value.clone().into_column().unwrap())
+                        .collect::<Vec<_>>();
+                    self.current = Some((columns, 0));
+                }
+            }
+        }
+        Ok(true)
+    }
+
+    fn command_tag(&self) -> String {
+        if self.has_result_set {
+            return format!("SELECT {}", self.sent_rows);
+        }
+
+        let affected_rows = self
+            .context
+            .as_ref()
+            .map(|ctx| ctx.get_write_progress_value().rows)
+            .unwrap_or(0);
+        match self.command.as_str() {
+            "INSERT" => format!("INSERT 0 {}", affected_rows),
+            "UPDATE" | "DELETE" => format!("{} {}", self.command, affected_rows),
+            _ => self.command.clone(),
+        }
+    }
+}
+
+pub struct InteractiveWorker {
+    session: Arc<Session>,
+    client_addr: String,
+    cancel_keys: CancelKeys,
+    statements: HashMap<String, PreparedStatement>,
+    portals: HashMap<String, Portal>,
+    out: Vec<u8>,
+}
+
+impl InteractiveWorker {
+    pub fn create(
+        session: Arc<Session>,
+        client_addr: String,
+        cancel_keys: CancelKeys,
+    ) -> InteractiveWorker {
+        InteractiveWorker {
+            session,
+            client_addr,
+            cancel_keys,
+            statements: HashMap::new(),
+            portals: HashMap::new(),
+            out: Vec::new(),
+        }
+    }
+
+    #[async_backtrace::framed]
+    pub async fn run<R, W>(
+        &mut self,
+        mut reader: R,
+        mut writer: W,
+        params: HashMap<String, String>,
+    ) -> Result<()>
+    where
+        R: AsyncRead + Unpin,
+        W: AsyncWrite + Unpin,
+    {
+        if let Err(error) = self.startup(&mut reader, &mut writer, &params).await {
+            let code = match error.code() {
+                ErrorCode::UNKNOWN_DATABASE => "3D000",
+                _ => "28P01",
+            };
+            BackendMessage::ErrorResponse {
+                severity: "FATAL",
+                code,
+                message: error.message(),
+            }
+            .encode(&mut self.out);
+            self.flush(&mut writer).await?;
+            return Err(error);
+        }
+
+        let result = self.message_loop(&mut reader, &mut writer).await;
+        if let Some(process_id) = self.session.get_mysql_conn_id() {
+            self.cancel_keys.lock().remove(&process_id);
+        }
+        result
+    }
+
+    #[async_backtrace::framed]
+    async fn startup<R, W>(
+        &mut self,
+        reader: &mut R,
+        writer: &mut W,
+        params: &HashMap<String, String>,
+    ) -> Result<()>
+    where
+        R: AsyncRead + Unpin,
+        W: AsyncWrite + Unpin,
+    {
+        let user = match params.get("user") {
+            Some(user) if !user.is_empty() => user.clone(),
+            _ => {
+                return Err(ErrorCode::AuthenticateFailure(
+                    "no PostgreSQL user name specified in startup packet",
+                ));
+            }
+        };
+        let client_ip = self.client_addr.split(':').collect::<Vec<_>>()[0].to_string();
+
+        // MD5 and SCRAM need the plaintext or a PostgreSQL specific verifier on the
+        // server side, but users only keep a double SHA-1 or SHA-256 hash of the
+        // password, so the password is asked in cleartext and checked by AuthMgr.
+        let user_info = UserApiProvider::instance()
+            .get_user_with_client_ip(
+                &self.session.get_current_tenant(),
+                UserIdentity::new(&user, "%"),
+                Some(&client_ip),
+            )
+            .await
+            .map_err(|e| {
+                ErrorCode::AuthenticateFailure(format!(
+                    "password authentication failed for user \"{}\": {}",
+                    user,
+                    e.message()
+                ))
+            })?;
+        let credential = match user_info.auth_info {
+            AuthInfo::None => Credential::Password {
+                name: user.clone(),
+                password: None,
+                client_ip: Some(client_ip),
+            },
+            AuthInfo::JWT => Credential::Jwt {
+                token: String::from_utf8(self.ask_password(reader, writer).await?)?,
+                client_ip: Some(client_ip),
+            },
+            AuthInfo::Password { .. } => Credential::Password {
+                name: user.clone(),
+                password: Some(self.ask_password(reader, writer).await?),
+                client_ip: Some(client_ip),
+            },
+        };
+        AuthMgr::instance()
+            .auth(self.session.clone(), &credential)
+            .await
+            .map_err(|e| {
+                ErrorCode::AuthenticateFailure(format!(
+                    "password authentication failed for user \"{}\": {}",
+                    user,
+                    e.message()
+                ))
+            })?;
+
+        // Drivers often default the database to the user name, which does not exist in Databend.
+        if let Some(database) = params.get("database") {
+            if !database.is_empty() && database != &user {
+                let mut execution = self
+                    .start_query(&format!("USE `{}`", database), &[])
+                    .await?;
+                execution.send_rows(0, &mut vec![], writer).await?;
+            }
+        }
+
+        BackendMessage::AuthenticationOk.encode(&mut self.out);
+        let timezone = self.session.get_format_settings().timezone.to_string();
+        let application_name = params.get("application_name").cloned().unwrap_or_default();
+        for (name, value) in [
+            ("server_version", POSTGRES_VERSION),
+            ("server_encoding", "UTF8"),
+            ("client_encoding", "UTF8"),
+            ("DateStyle", "ISO, MDY"),
+            ("TimeZone", timezone.as_str()),
+            ("integer_datetimes", "on"),
+            ("standard_conforming_strings", "on"),
+            ("application_name", application_name.as_str()),
+        ] {
+            BackendMessage::ParameterStatus {
+                name: name.to_string(),
+                value: value.to_string(),
+            }
+            .encode(&mut self.out);
+        }
+
+        if let Some(process_id) = self.session.get_mysql_conn_id() {
+            let secret_key = rand::thread_rng().next_u32();
+            self.cancel_keys.lock().insert(process_id, secret_key);
+            BackendMessage::BackendKeyData {
+                process_id,
+                secret_key,
+            }
+            .encode(&mut self.out);
+        }
+
+        self.ready_for_query();
+        self.flush(writer).await
+    }
+
+    #[async_backtrace::framed]
+    async fn ask_password<R, W>(&mut self, reader: &mut R, writer: &mut W) -> Result<Vec<u8>>
+    where
+        R: AsyncRead + Unpin,
+        W: AsyncWrite + Unpin,
+    {
+        BackendMessage::AuthenticationCleartextPassword.encode(&mut self.out);
+        self.flush(writer).await?;
+
+        match read_message(reader).await? {
+            Some(FrontendMessage::Password(body)) => Ok(decode_password(&body)),
+            Some(_) => Err(ErrorCode::AuthenticateFailure("expected password response")),
+            None => Err(ErrorCode::AbortedSession(
+                "connection closed during authentication",
+            )),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn message_loop<R, W>(&mut self, reader: &mut R, writer: &mut W) -> Result<()>
+    where
+        R: AsyncRead + Unpin,
+        W: AsyncWrite + Unpin,
+    {
+        // After an error of the extended query, messages are discarded until Sync.
+        let mut skip_until_sync = false;
+        while let Some(message) = read_message(reader).await? {
+            if self.session.is_aborting() {
+                return Err(ErrorCode::AbortedSession("Aborting this connection."));
+            }
+
+            match message {
+                FrontendMessage::Terminate => break,
+                FrontendMessage::Sync => {
+                    skip_until_sync = false;
+                    // Portals only live until the end of the transaction.
+                    if !self.session.txn_mgr().lock().is_active() {
+                        self.portals.clear();
+                    }
+                    self.ready_for_query();
+                    self.flush(writer).await?;
+                }
+                FrontendMessage::Flush => self.flush(writer).await?,
+                _ if skip_until_sync => {}
+                FrontendMessage::Query(query) => {
+                    self.simple_query(&query, writer).await?;
+                    self.ready_for_query();
+                    self.flush(writer).await?;
+                }
+                message => {
+                    if let Err(error) = self.extended_query(message, writer).await {
+                        if error.code() == ErrorCode::ABORTED_SESSION {
+                            return Err(error);
+                        }
+                        self.send_error(&error);
+                        skip_until_sync = true;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn simple_query<W: AsyncWrite + Unpin>(
+        &mut self,
+        query: &str,
+        writer: &mut W,
+    ) -> Result<()> {
+        let statements = split_statements(query);
+        if statements.is_empty() {
+            BackendMessage::EmptyQueryResponse.encode(&mut self.out);
+            return Ok(());
+        }
+
+        for statement in statements {
+            let result = match self.start_query(statement, &[]).await {
+                Ok(mut execution) => {
+                    if execution.has_result_set {
+                        let fields = execution.encoder.describe(&execution.schema);
+                        BackendMessage::RowDescription(fields).encode(&mut self.out);
+                    }
+                    match execution.send_rows(0, &mut self.out, writer).await {
+                        Ok(_) => Ok(execution.command_tag()),
+                        Err(error) => Err(error),
+                    }
+                }
+                Err(error) => Err(error),
+            };
+
+            match result {
+                Ok(tag) => BackendMessage::CommandComplete(tag).encode(&mut self.out),
+                Err(error) => {
+                    if error.code() == ErrorCode::ABORTED_SESSION {
+                        return Err(error);
+                    }
+                    // The remaining statements are skipped, like PostgreSQL does.
+                    self.send_error(&error);
+                    break;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn extended_query<W: AsyncWrite + Unpin>(
+        &mut self,
+        message: FrontendMessage,
+        writer: &mut W,
+    ) -> Result<()> {
+        match message {
+            FrontendMessage::Parse {
+                name,
+                query,
+                param_types,
+            } => {
+                if !name.is_empty() && self.statements.contains_key(&name) {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "prepared statement \"{}\" already exists",
+                        name
+                    )));
+                }
+                self.statements
+                    .insert(name, PreparedStatement { query, param_types });
+                BackendMessage::ParseComplete.encode(&mut self.out);
+            }
+            FrontendMessage::Bind {
+                portal,
+                statement,
+                param_formats,
+                params,
+                result_formats,
+            } => {
+                let prepared = self.statements.get(&statement).ok_or_else(|| {
+                    ErrorCode::BadArguments(format!(
+                        "prepared statement \"{}\" does not exist",
+                        statement
+                    ))
+                })?;
+                let mut literals = Vec::with_capacity(params.len());
+                for (i, param) in params.iter().enumerate() {
+                    let format = match param_formats.len() {
+                        0 => FORMAT_TEXT,
+                        1 => param_formats[0],
+                        _ => param_formats.get(i).copied().unwrap_or(FORMAT_TEXT),
+                    };
+                    let type_oid = prepared.param_types.get(i).copied().unwrap_or(0);
+                    literals.push(decode_param(param.as_deref(), format, type_oid)?);
+                }
+                let sql = substitute_params(&prepared.query, &literals)?;
+
+                if !portal.is_empty() && self.portals.contains_key(&portal) {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "portal \"{}\" already exists",
+                        portal
+                    )));
+                }
+                self.portals.insert(portal, Portal {
+                    sql,
+                    result_formats,
+                    execution: None,
+                });
+                BackendMessage::BindComplete.encode(&mut self.out);
+            }
+            FrontendMessage::Describe { kind: b'S', name } => {
+                let prepared = self.statements.get(&name).ok_or_else(|| {
+                    ErrorCode::BadArguments(format!(
+                        "prepared statement \"{}\" does not exist",
+                        name
+                    ))
+                })?;
+                let count = param_count(&prepared.query).max(prepared.param_types.len());
+                let param_types = (0..count)
+                    .map(|i| match prepared.param_types.get(i) {
+                        Some(oid) if *oid != 0 => *oid,
+                        _ => TEXT_OID,
+                    })
+                    .collect::<Vec<_>>();
+                // Plan the query with NULL parameters to know the result columns.
+                let sql = substitute_params(&prepared.query, &vec!["NULL".to_string(); count])?;
+                BackendMessage::ParameterDescription(param_types).encode(&mut self.out);
+
+                match self.describe_query(&sql).await {
+                    Some(schema) => {
+                        let encoder = DataRowEncoder::create(&schema, &[], self.timezone());
+                        BackendMessage::RowDescription(encoder.describe(&schema))
+                            .encode(&mut self.out);
+                    }
+                    None => BackendMessage::NoData.encode(&mut self.out),
+                }
+            }
+            FrontendMessage::Describe { kind: _, name } => {
+                self.ensure_portal_started(&name).await?;
+                let execution = self.portals[&name].execution.as_ref().unwrap();
+                if execution.has_result_set {
+                    let fields = execution.encoder.describe(&execution.schema);
+                    BackendMessage::RowDescription(fields).encode(&mut self.out);
+                } else {
+                    BackendMessage::NoData.encode(&mut self.out);
+                }
+            }
+            FrontendMessage::Execute { portal, max_rows } => {
+                self.ensure_portal_started(&portal).await?;
+                let execution = self
+                    .portals
+                    .get_mut(&portal)
+                    .and_then(|portal| portal.execution.as_mut())
+                    .unwrap();
+                let max_rows = max_rows.max(0) as usize;
+                if execution.send_rows(max_rows, &mut self.out, writer).await? {
+                    BackendMessage::CommandComplete(execution.command_tag()).encode(&mut self.out);
+                } else {
+                    BackendMessage::PortalSuspended.encode(&mut self.out);
+                }
+            }
+            FrontendMessage::Close { kind, name } => {
+                // Closing a nonexistent statement or portal is not an error.
+                if kind == b'S' {
+                    self.statements.remove(&name);
+                } else {
+                    self.portals.remove(&name);
+                }
+                BackendMessage::CloseComplete.encode(&mut self.out);
+            }
+            FrontendMessage::Password(_) => {
+                return Err(ErrorCode::BadBytes("unexpected password message"));
+            }
+            _ => unreachable!("handled in the message loop"),
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn ensure_portal_started(&mut self, name: &str) -> Result<()> {
+        let portal = self.portals.get(name).ok_or_else(|| {
+            ErrorCode::BadArguments(format!("portal \"{}\" does not exist", name))
+        })?;
+        if portal.execution.is_some() {
+            return Ok(());
+        }
+
+        let (sql, result_formats) = (portal.sql.clone(), portal.result_formats.clone());
+        let execution = self.start_query(&sql, &result_formats).await?;
+        if let Some(portal) = self.portals.get_mut(name) {
+            portal.execution = Some(execution);
+        }
+        Ok(())
+    }
+
+    // The result columns of a query, None if the query has no result set or fails to plan.
+    #[async_backtrace::framed]
+    async fn describe_query(&self, query: &str) -> Option<DataSchemaRef> {
+        if let Some((schema, _)) = self.federated_server_command_check(query) {
+            return (!schema.fields().is_empty()).then_some(schema);
+        }
+
+        let context = self.session.create_query_context().await.ok()?;
+        let mut planner = Planner::new(context);
+        let (plan, _) = planner.plan_sql(query).await.ok()?;
+        plan.has_result_set().then(|| plan.schema())
+    }
+
+    // Check the query is a federated or driver setup command.
+    fn federated_server_command_check(&self, query: &str) -> Option<(DataSchemaRef, DataBlock)> {
+        // INSERT don't need PostgreSQL federated check
+        // Ensure the query is start with ASCII chars so we won't
+        // panic when we slice the query string.
+        if query.len() > 6
+            && query.char_indices().take(6).all(|(_, c)| c.is_ascii())
+            && query[..6].eq_ignore_ascii_case("INSERT")
+        {
+            return None;
+        }
+        let federated = PostgresFederated::create();
+        federated.check(query)
+    }
+
+    #[async_backtrace::framed]
+    async fn start_query(&self, query: &str, result_formats: &[i16]) -> Result<Execution> {
+        let command = command_keyword(query);
+        if let Some((schema, data_block)) = self.federated_server_command_check(query) {
+            info!("Federated query: {}", query);
+            return Ok(Execution {
+                has_result_set: !schema.fields().is_empty(),
+                encoder: DataRowEncoder::create(&schema, result_formats, self.timezone()),
+                schema,
+                blocks: DataBlockStream::create(None, vec![data_block]).boxed(),
+                context: None,
+                current: None,
+                sent_rows: 0,
+                command,
+                finished: false,
+            });
+        }
+
+        info!("Normal query: {}", query);
+        let context = self.session.create_query_context().await?;
+        let mut planner = Planner::new(context.clone());
+        let (plan, extras) = planner.plan_sql(query).await?;
+        context.attach_query_str(plan.kind(), extras.statement.to_mask_sql());
+
+        let interpreter = match InterpreterFactory::get(context.clone(), &plan).await {
+            Ok(interpreter) => interpreter,
+            Err(e) => {
+                InterpreterQueryLog::fail_to_start(context, e.clone());
+                return Err(e);
+            }
+        };
+
+        let query_result = context.try_spawn(context.get_id(), {
+            let ctx = context.clone();
+            async move { interpreter.execute(ctx).await }
+        })?;
+        let blocks = query_result.await.map_err_to_code(
+            ErrorCode::TokioError,
+            || "Cannot join handle from context's runtime",
+        )??;
+
+        let schema = plan.schema();
+        let timezone = context.get_format_settings()?.timezone;
+        Ok(Execution {
+            has_result_set: plan.has_result_set(),
+            encoder: DataRowEncoder::create(&schema, result_formats, timezone),
+            schema,
+            blocks,
+            context: Some(context),
+            current: None,
+            sent_rows: 0,
+            command,
+            finished: false,
+        })
+    }
+
+    fn timezone(&self) -> Tz {
+        self.session.get_format_settings().timezone
+    }
+
+    fn ready_for_query(&mut self) {
+        let status = {
+            let txn_mgr = self.session.txn_mgr();
+            let txn_mgr = txn_mgr.lock();
+            if txn_mgr.is_fail() {
+                b'E'
+            } else if txn_mgr.is_active() {
+                b'T'
+            } else {
+                b'I'
+            }
+        };
+        BackendMessage::ReadyForQuery(status).encode(&mut self.out);
+    }
+
+    fn send_error(&mut self, error: &ErrorCode) {
+        let code = match error.code() {
+            ErrorCode::SYNTAX_EXCEPTION => "42601",
+            ErrorCode::UNKNOWN_TABLE => "42P01",
+            ErrorCode::UNKNOWN_DATABASE => "3D000",
+            ErrorCode::AUTHENTICATE_FAILURE => "28P01",
+            ErrorCode::PERMISSION_DENIED => "42501",
+            ErrorCode::ABORTED_QUERY => "57014",
+            ErrorCode::BAD_BYTES => "08P01",
+            _ => "XX000",
+        };
+        BackendMessage::ErrorResponse {
+            severity: "ERROR",
+            code,
+            message: error.message(),
+        }
+        .encode(&mut self.out);
+    }
+
+    #[async_backtrace::framed]
+    async fn flush<W: AsyncWrite + Unpin>(&mut self, writer: &mut W) -> Result<()> {
+        writer.write_all(&self.out).await?;
+        writer.flush().await?;
+        self.out.clear();
+        Ok(())
+    }
+}
+
+// The leading keyword of a statement, used for the tag of CommandComplete.
+fn command_keyword(query: &str) -> String {
+    let mut words = query
+        .split(|c: char| c.is_whitespace() || c == '(' || c == ';')
+        .filter(|w| !w.is_empty())
+        .map(|w| w.to_uppercase());
+    let first = words.next().unwrap_or_default();
+    match first.as_str() {
+        "CREATE" | "DROP" | "ALTER" => {
+            let object = words
+                .find(|w| !matches!(w.as_str(), "OR" | "REPLACE" | "TRANSIENT" | "TEMPORARY"))
+                .unwrap_or_default();
+            format!("{} {}", first, object).trim().to_string()
+        }
+        "REPLACE" => "INSERT".to_string(),
+        _ => first,
+    }
+}
@@ -0,0 +1,435 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Messages of the PostgreSQL frontend/backend protocol version 3.0.
+//!
+//! https://www.postgresql.org/docs/current/protocol-message-formats.html
+
+use std::collections::HashMap;
+
+use common_base::base::tokio::io::AsyncRead;
+use common_base::base::tokio::io::AsyncReadExt;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+pub const PROTOCOL_VERSION_3: i32 = 196608;
+pub const SSL_REQUEST_CODE: i32 = 80877103;
+pub const GSSENC_REQUEST_CODE: i32 = 80877104;
+pub const CANCEL_REQUEST_CODE: i32 = 80877102;
+
+pub const FORMAT_TEXT: i16 = 0;
+pub const FORMAT_BINARY: i16 = 1;
+
+// A message larger than this is considered as a broken stream.
+const MAX_MESSAGE_LEN: usize = 1 << 30;
+
+/// The first packet a client sends, which has no message type byte.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StartupPacket {
+    SslRequest,
+    GssEncRequest,
+    CancelRequest { process_id: u32, secret_key: u32 },
+    Startup { params: HashMap<String, String> },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FrontendMessage {
+    /// Simple query.
+    Query(String),
+    Parse {
+        name: String,
+        query: String,
+        param_types: Vec<u32>,
+    },
+    Bind {
+        portal: String,
+        statement: String,
+        param_formats: Vec<i16>,
+        params: Vec<Option<Vec<u8>>>,
+        result_formats: Vec<i16>,
+    },
+    /// Describe a prepared statement(`b'S'`) or a portal(`b'P'`).
+    Describe {
+        kind: u8,
+        name: String,
+    },
+    Execute {
+        portal: String,
+        max_rows: i32,
+    },
+    /// Close a prepared statement(`b'S'`) or a portal(`b'P'`).
+    Close {
+        kind: u8,
+        name: String,
+    },
+    Sync,
+    Flush,
+    Terminate,
+    /// A password, or a SASL response, depending on the context.
+    Password(Vec<u8>),
+}
+
+/// Describes a field in a `RowDescription`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldDescription {
+    pub name: String,
+    pub type_oid: u32,
+    pub type_size: i16,
+    pub format: i16,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BackendMessage {
+    AuthenticationOk,
+    AuthenticationCleartextPassword,
+    ParameterStatus {
+        name: String,
+        value: String,
+    },
+    BackendKeyData {
+        process_id: u32,
+        secret_key: u32,
+    },
+    /// Transaction status: `b'I'` idle, `b'T'` in a transaction, `b'E'` in a failed transaction.
+    ReadyForQuery(u8),
+    RowDescription(Vec<FieldDescription>),
+    ParameterDescription(Vec<u32>),
+    /// Already encoded field values, `None` for NULL.
+    DataRow(Vec<Option<Vec<u8>>>),
+    CommandComplete(String),
+    EmptyQueryResponse,
+    ErrorResponse {
+        severity: &'static str,
+        code: &'static str,
+        message: String,
+    },
+    ParseComplete,
+    BindComplete,
+    CloseComplete,
+    NoData,
+    PortalSuspended,
+}
+
+impl BackendMessage {
+    /// Append the encoded message to `out`.
+    pub fn encode(&self, out: &mut Vec<u8>) {
+        match self {
+            BackendMessage::AuthenticationOk => {
+                write_frame(out, b'R', |buf| put_i32(buf, 0));
+            }
+            BackendMessage::AuthenticationCleartextPassword => {
+                write_frame(out, b'R', |buf| put_i32(buf, 3));
+            }
+            BackendMessage::ParameterStatus { name, value } => {
+                write_frame(out, b'S', |buf| {
+                    put_cstr(buf, name);
+                    put_cstr(buf, value);
+                });
+            }
+            BackendMessage::BackendKeyData {
+                process_id,
+                secret_key,
+            } => {
+                write_frame(out, b'K', |buf| {
+                    put_i32(buf, *process_id as i32);
+                    put_i32(buf, *secret_key as i32);
+                });
+            }
+            BackendMessage::ReadyForQuery(status) => {
+                write_frame(out, b'Z', |buf| buf.push(*status));
+            }
+            BackendMessage::RowDescription(fields) => {
+                write_frame(out, b'T', |buf| {
+                    put_i16(buf, fields.len() as i16);
+                    for field in fields {
+                        put_cstr(buf, &field.name);
+                        // table oid and column attribute number
+                        put_i32(buf, 0);
+                        put_i16(buf, 0);
+                        put_i32(buf, field.type_oid as i32);
+                        put_i16(buf, field.type_size);
+                        // type modifier
+                        put_i32(buf, -1);
+                        put_i16(buf, field.format);
+                    }
+                });
+            }
+            BackendMessage::ParameterDescription(types) => {
+                write_frame(out, b't', |buf| {
+                    put_i16(buf, types.len() as i16);
+                    for oid in types {
+                        put_i32(buf, *oid as i32);
+                    }
+                });
+            }
+            BackendMessage::DataRow(values) => {
+                write_frame(out, b'D', |buf| {
+                    put_i16(buf, values.len() as i16);
+                    for value in values {
+                        match value {
+                            None => put_i32(buf, -1),
+                            Some(v) => {
+                                put_i32(buf, v.len() as i32);
+                                buf.extend_from_slice(v);
+                            }
+                        }
+                    }
+                });
+            }
+            BackendMessage::CommandComplete(tag) => {
+                write_frame(out, b'C', |buf| put_cstr(buf, tag));
+            }
+            BackendMessage::EmptyQueryResponse => write_frame(out, b'I', |_| {}),
+            BackendMessage::ErrorResponse {
+                severity,
+                code,
+                message,
+            } => {
+                write_frame(out, b'E', |buf| {
+                    buf.push(b'S');
+                    put_cstr(buf, severity);
+                    buf.push(b'V');
+                    put_cstr(buf, severity);
+                    buf.push(b'C');
+                    put_cstr(buf, code);
+                    buf.push(b'M');
+                    put_cstr(buf, message);
+                    buf.push(0);
+                });
+            }
+            BackendMessage::ParseComplete => write_frame(out, b'1', |_| {}),
+            BackendMessage::BindComplete => write_frame(out, b'2', |_| {}),
+            BackendMessage::CloseComplete => write_frame(out, b'3', |_| {}),
+            BackendMessage::NoData => write_frame(out, b'n', |_| {}),
+            BackendMessage::PortalSuspended => write_frame(out, b's', |_| {}),
+        }
+    }
+}
+
+/// Read the startup packet, or an SSL, GSS encryption or cancel request.
+#[async_backtrace::framed]
+pub async fn read_startup<R: AsyncRead + Unpin>(reader: &mut R) -> Result<StartupPacket> {
+    let len = reader.read_i32().await?;
+    let body = read_body(reader, len, 4).await?;
+    let mut buf = MessageBuf::new(&body);
+
+    let code = buf.get_i32()?;
+    match code {
+        SSL_REQUEST_CODE => Ok(StartupPacket::SslRequest),
+        GSSENC_REQUEST_CODE => Ok(StartupPacket::GssEncRequest),
+        CANCEL_REQUEST_CODE => Ok(StartupPacket::CancelRequest {
+            process_id: buf.get_i32()? as u32,
+            secret_key: buf.get_i32()? as u32,
+        }),
+        PROTOCOL_VERSION_3 => {
+            let mut params = HashMap::new();
+            loop {
+                let name = buf.get_cstr()?;
+                if name.is_empty() {
+                    break;
+                }
+                let value = buf.get_cstr()?;
+                params.insert(name, value);
+            }
+            Ok(StartupPacket::Startup { params })
+        }
+        _ => Err(ErrorCode::BadBytes(format!(
+            "Unsupported PostgreSQL protocol version: {}.{}",
+            code >> 16,
+            code & 0xffff
+        ))),
+    }
+}
+
+/// Read a message with a type byte, returns `None` if the client closed the connection.
+#[async_backtrace::framed]
+pub async fn read_message<R: AsyncRead + Unpin>(reader: &mut R) -> Result<Option<FrontendMessage>> {
+    let tag = match reader.read_u8().await {
+        Ok(tag) => tag,
+        Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+    let len = reader.read_i32().await?;
+    let body = read_body(reader, len, 4).await?;
+    let mut buf = MessageBuf::new(&body);
+
+    let message = match tag {
+        b'Q' => FrontendMessage::Query(buf.get_cstr()?),
+        b'P' => {
+            let name = buf.get_cstr()?;
+            let query = buf.get_cstr()?;
+            let n = buf.get_i16()?;
+            let mut param_types = Vec::with_capacity(n.max(0) as usize);
+            for _ in 0..n {
+                param_types.push(buf.get_i32()? as u32);
+            }
+            FrontendMessage::Parse {
+                name,
+                query,
+                param_types,
+            }
+        }
+        b'B' => {
+            let portal = buf.get_cstr()?;
+            let statement = buf.get_cstr()?;
+
+            let n = buf.get_i16()?;
+            let mut param_formats = Vec::with_capacity(n.max(0) as usize);
+            for _ in 0..n {
+                param_formats.push(buf.get_i16()?);
+            }
+
+            let n = buf.get_i16()?;
+            let mut params = Vec::with_capacity(n.max(0) as usize);
+            for _ in 0..n {
+                let len = buf.get_i32()?;
+                if len < 0 {
+                    params.push(None);
+                } else {
+                    params.push(Some(buf.get_bytes(len as usize)?.to_vec()));
+                }
+            }
+
+            let n = buf.get_i16()?;
+            let mut result_formats = Vec::with_capacity(n.max(0) as usize);
+            for _ in 0..n {
+                result_formats.push(buf.get_i16()?);
+            }
+
+            FrontendMessage::Bind {
+                portal,
+                statement,
+                param_formats,
+                params,
+                result_formats,
+            }
+        }
+        b'D' => FrontendMessage::Describe {
+            kind: buf.get_u8()?,
+            name: buf.get_cstr()?,
+        },
+        b'E' => FrontendMessage::Execute {
+            portal: buf.get_cstr()?,
+            max_rows: buf.get_i32()?,
+        },
+        b'C' => FrontendMessage::Close {
+            kind: buf.get_u8()?,
+            name: buf.get_cstr()?,
+        },
+        b'S' => FrontendMessage::Sync,
+        b'H' => FrontendMessage::Flush,
+        b'X' => FrontendMessage::Terminate,
+        b'p' => FrontendMessage::Password(body.clone()),
+        _ => {
+            return Err(ErrorCode::BadBytes(format!(
+                "Unsupported PostgreSQL message type: '{}'",
+                tag as char
+            )));
+        }
+    };
+
+    Ok(Some(message))
+}
+
+/// Decode the password from a `PasswordMessage`, which is a null-terminated string.
+pub fn decode_password(body: &[u8]) -> Vec<u8> {
+    match body.iter().position(|b| *b == 0) {
+        Some(pos) => body[..pos].to_vec(),
+        None => body.to_vec(),
+    }
+}
+
+#[async_backtrace::framed]
+async fn read_body<R: AsyncRead + Unpin>(
+    reader: &mut R,
+    len: i32,
+    header_len: usize,
+) -> Result<Vec<u8>> {
+    if len < header_len as i32 || len as usize > MAX_MESSAGE_LEN {
+        return Err(ErrorCode::BadBytes(format!(
+            "Invalid PostgreSQL message length: {}",
+            len
+        )));
+    }
+
+    let mut body = vec![0; len as usize - header_len];
+    reader.read_exact(&mut body).await?;
+    Ok(body)
+}
+
+fn write_frame(out: &mut Vec<u8>, tag: u8, f: impl FnOnce(&mut Vec<u8>)) {
+    out.push(tag);
+    let len_pos = out.len();
+    put_i32(out, 0);
+    f(out);
+    let len = (out.len() - len_pos) as i32;
+    out[len_pos..len_pos + 4].copy_from_slice(&len.to_be_bytes());
+}
+
+fn put_i16(buf: &mut Vec<u8>, v: i16) {
+    buf.extend_from_slice(&v.to_be_bytes());
+}
+
+fn put_i32(buf: &mut Vec<u8>, v: i32) {
+    buf.extend_from_slice(&v.to_be_bytes());
+}
+
+fn put_cstr(buf: &mut Vec<u8>, s: &str) {
+    buf.extend_from_slice(s.as_bytes());
+    buf.push(0);
+}
+
+/// A cursor to decode the body of a message.
+struct MessageBuf<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> MessageBuf<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        MessageBuf { data }
+    }
+
+    fn get_bytes(&mut self, n: usize) -> Result<&'a [u8]> {
+        if self.data.len() < n {
+            return Err(ErrorCode::BadBytes("PostgreSQL message is truncated"));
+        }
+        let (bytes, rest) = self.data.split_at(n);
+        self.data = rest;
+        Ok(bytes)
+    }
+
+    fn get_u8(&mut self) -> Result<u8> {
+        Ok(self.get_bytes(1)?[0])
+    }
+
+    fn get_i16(&mut self) -> Result<i16> {
+        let bytes = self.get_bytes(2)?;
+        Ok(i16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn get_i32(&mut self) -> Result<i32> {
+        let bytes = self.get_bytes(4)?;
+        Ok(i32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn get_cstr(&mut self) -> Result<String> {
+        let pos = self
+            .data
+            .iter()
+            .position(|b| *b == 0)
+            .ok_or_else(|| ErrorCode::BadBytes("PostgreSQL string is not null-terminated"))?;
+        let s = String::from_utf8_lossy(&self.data[..pos]).to_string();
+        self.data = &self.data[pos + 1..];
+        Ok(s)
+    }
+}
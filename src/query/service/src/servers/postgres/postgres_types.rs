@@ -0,0 +1,449 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use chrono_tz::Tz;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::date_helper::DateConverter;
+use common_expression::types::date::date_to_string;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::timestamp::timestamp_to_string;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::Column;
+use common_expression::DataSchemaRef;
+use common_expression::ScalarRef;
+use common_formats::field_encoder::FieldEncoderValues;
+
+use crate::servers::postgres::postgres_message::FieldDescription;
+use crate::servers::postgres::postgres_message::FORMAT_BINARY;
+use crate::servers::postgres::postgres_message::FORMAT_TEXT;
+
+// Type oids from `pg_type.dat` of PostgreSQL.
+pub const BOOL_OID: u32 = 16;
+pub const INT8_OID: u32 = 20;
+pub const INT2_OID: u32 = 21;
+pub const INT4_OID: u32 = 23;
+pub const TEXT_OID: u32 = 25;
+pub const JSON_OID: u32 = 114;
+pub const FLOAT4_OID: u32 = 700;
+pub const FLOAT8_OID: u32 = 701;
+pub const UNKNOWN_OID: u32 = 705;
+pub const BPCHAR_OID: u32 = 1042;
+pub const VARCHAR_OID: u32 = 1043;
+pub const DATE_OID: u32 = 1082;
+pub const TIMESTAMP_OID: u32 = 1114;
+pub const TIMESTAMPTZ_OID: u32 = 1184;
+pub const NUMERIC_OID: u32 = 1700;
+
+// Days and microseconds between the unix epoch and the PostgreSQL epoch 2000-01-01.
+const PG_EPOCH_DAYS: i32 = 10957;
+const PG_EPOCH_MICROS: i64 = 946_684_800_000_000;
+
+/// Map a Databend type to the PostgreSQL type reported to the client.
+///
+/// Unsigned integers are widened to the next signed type, since PostgreSQL has
+/// no unsigned integers; `UInt64` only fits into `numeric`.
+pub fn type_oid(data_type: &DataType) -> u32 {
+    match data_type.remove_nullable() {
+        DataType::Boolean => BOOL_OID,
+        DataType::Number(num) => match num {
+            NumberDataType::Int8 | NumberDataType::UInt8 | NumberDataType::Int16 => INT2_OID,
+            NumberDataType::UInt16 | NumberDataType::Int32 => INT4_OID,
+            NumberDataType::UInt32 | NumberDataType::Int64 => INT8_OID,
+            NumberDataType::UInt64 => NUMERIC_OID,
+            NumberDataType::Float32 => FLOAT4_OID,
+            NumberDataType::Float64 => FLOAT8_OID,
+        },
+        DataType::Decimal(_) => NUMERIC_OID,
+        DataType::Date => DATE_OID,
+        DataType::Timestamp => TIMESTAMP_OID,
+        DataType::Variant => JSON_OID,
+        _ => TEXT_OID,
+    }
+}
+
+fn type_size(oid: u32) -> i16 {
+    match oid {
+        BOOL_OID => 1,
+        INT2_OID => 2,
+        INT4_OID | FLOAT4_OID | DATE_OID => 4,
+        INT8_OID | FLOAT8_OID | TIMESTAMP_OID => 8,
+        _ => -1,
+    }
+}
+
+fn has_binary_format(data_type: &DataType) -> bool {
+    !matches!(type_oid(data_type), NUMERIC_OID)
+        && matches!(
+            data_type.remove_nullable(),
+            DataType::Boolean
+                | DataType::Number(_)
+                | DataType::Date
+                | DataType::Timestamp
+                | DataType::String
+                | DataType::Variant
+        )
+}
+
+/// Encodes the rows of a result set into `DataRow` values in the formats
+/// requested by the client.
+pub struct DataRowEncoder {
+    text_encoder: FieldEncoderValues,
+    timezone: Tz,
+    formats: Vec<i16>,
+}
+
+impl DataRowEncoder {
+    /// `result_formats` follows the `Bind` message: empty means all text, a single
+    /// code applies to all columns, otherwise one code per column. Columns without
+    /// a binary form are always sent as text.
+    pub fn create(schema: &DataSchemaRef, result_formats: &[i16], timezone: Tz) -> Self {
+        let formats = schema
+            .fields()
+            .iter()
+            .enumerate()
+            .map(|(i, field)| {
+                let format = match result_formats.len() {
+                    0 => FORMAT_TEXT,
+                    1 => result_formats[0],
+                    _ => result_formats.get(i).copied().unwrap_or(FORMAT_TEXT),
+                };
+                if format == FORMAT_BINARY && has_binary_format(field.data_type()) {
+                    FORMAT_BINARY
+                } else {
+                    FORMAT_TEXT
+                }
+            })
+            .collect();
+
+        DataRowEncoder {
+            text_encoder: FieldEncoderValues::create_for_postgres_handler(timezone),
+            timezone,
+            formats,
+        }
+    }
+
+    pub fn describe(&self, schema: &DataSchemaRef) -> Vec<FieldDescription> {
+        schema
+            .fields()
+            .iter()
+            .zip(self.formats.iter())
+            .map(|(field, format)| {
+                let type_oid = type_oid(field.data_type());
+                FieldDescription {
+                    name: field.name().clone(),
+                    type_oid,
+                    type_size: type_size(type_oid),
+                    format: *format,
+                }
+            })
+            .collect()
+    }
+
+    pub fn encode_row(&self, columns: &[Column], row: usize) -> Result<Vec<Option<Vec<u8>>>> {
+        let mut values = Vec::with_capacity(columns.len());
+        for (column, format) in columns.iter().zip(self.formats.iter()) {
+            let value = match column.index(row) {
+                None => {
+                    return Err(ErrorCode::Internal(format!(
+                        "Row index {} out of range of the column",
+                        row
+                    )));
+                }
+                Some(ScalarRef::Null) => None,
+                Some(scalar) if *format == FORMAT_BINARY => {
+                    Some(self.encode_binary(&column.data_type(), scalar)?)
+                }
+                Some(_) => {
+                    let mut buf = Vec::new();
+                    self.text_encoder.write_field(column, row, &mut buf, false);
+                    Some(buf)
+                }
+            };
+            values.push(value);
+        }
+        Ok(values)
+    }
+
+    fn encode_binary(&self, data_type: &DataType, scalar: ScalarRef) -> Result<Vec<u8>> {
+        let oid = type_oid(data_type);
+        let bytes = match scalar {
+            ScalarRef::Boolean(v) => vec![v as u8],
+            ScalarRef::Number(num) => {
+                let v = match num {
+                    NumberScalar::Float32(v) => return Ok(v.0.to_be_bytes().to_vec()),
+                    NumberScalar::Float64(v) => return Ok(v.0.to_be_bytes().to_vec()),
+                    NumberScalar::UInt8(v) => v as i64,
+                    NumberScalar::UInt16(v) => v as i64,
+                    NumberScalar::UInt32(v) => v as i64,
+                    NumberScalar::Int8(v) => v as i64,
+                    NumberScalar::Int16(v) => v as i64,
+                    NumberScalar::Int32(v) => v as i64,
+                    NumberScalar::Int64(v) => v,
+                    NumberScalar::UInt64(_) => {
+                        return Err(ErrorCode::Internal("UInt64 has no binary format"));
+                    }
+                };
+                match oid {
+                    INT2_OID => (v as i16).to_be_bytes().to_vec(),
+                    INT4_OID => (v as i32).to_be_bytes().to_vec(),
+                    _ => v.to_be_bytes().to_vec(),
+                }
+            }
+            ScalarRef::Date(v) => (v - PG_EPOCH_DAYS).to_be_bytes().to_vec(),
+            ScalarRef::Timestamp(v) => {
+                // `timestamp` is a wall-clock time, shift it to the session time zone.
+                let local = v.to_timestamp(self.timezone).naive_local();
+                (local.timestamp_micros() - PG_EPOCH_MICROS)
+                    .to_be_bytes()
+                    .to_vec()
+            }
+            ScalarRef::String(v) => v.to_vec(),
+            ScalarRef::Variant(v) => jsonb::to_string(v).into_bytes(),
+            other => {
+                return Err(ErrorCode::Internal(format!(
+                    "Value {:?} has no binary format",
+                    other
+                )));
+            }
+        };
+        Ok(bytes)
+    }
+}
+
+/// Decode a parameter value of `Bind` into a SQL literal.
+pub fn decode_param(value: Option<&[u8]>, format: i16, type_oid: u32) -> Result<String> {
+    let Some(value) = value else {
+        return Ok("NULL".to_string());
+    };
+
+    if format == FORMAT_BINARY {
+        return decode_binary_param(value, type_oid);
+    }
+
+    let text = std::str::from_utf8(value)
+        .map_err(|_| ErrorCode::BadBytes("Parameter value is not valid UTF-8"))?;
+    match type_oid {
+        INT2_OID | INT4_OID | INT8_OID | FLOAT4_OID | FLOAT8_OID | NUMERIC_OID
+            if text.trim().parse::<f64>().is_ok() =>
+        {
+            Ok(text.trim().to_string())
+        }
+        BOOL_OID => match text.trim().to_ascii_lowercase().as_str() {
+            "t" | "true" | "1" | "y" | "yes" | "on" => Ok("TRUE".to_string()),
+            "f" | "false" | "0" | "n" | "no" | "off" => Ok("FALSE".to_string()),
+            _ => Err(ErrorCode::BadArguments(format!(
+                "Invalid boolean parameter: {}",
+                text
+            ))),
+        },
+        _ => Ok(quote_string(text)),
+    }
+}
+
+fn decode_binary_param(value: &[u8], type_oid: u32) -> Result<String> {
+    let invalid = || {
+        ErrorCode::BadBytes(format!(
+            "Invalid binary parameter for type oid {}",
+            type_oid
+        ))
+    };
+    let literal = match type_oid {
+        BOOL_OID => match value {
+            [0] => "FALSE".to_string(),
+            [_] => "TRUE".to_string(),
+            _ => return Err(invalid()),
+        },
+        INT2_OID => i16::from_be_bytes(value.try_into().map_err(|_| invalid())?).to_string(),
+        INT4_OID => i32::from_be_bytes(value.try_into().map_err(|_| invalid())?).to_string(),
+        INT8_OID => i64::from_be_bytes(value.try_into().map_err(|_| invalid())?).to_string(),
+        FLOAT4_OID => {
+            let v = f32::from_be_bytes(value.try_into().map_err(|_| invalid())?);
+            format!("'{}'::FLOAT", v)
+        }
+        FLOAT8_OID => {
+            let v = f64::from_be_bytes(value.try_into().map_err(|_| invalid())?);
+            format!("'{}'::DOUBLE", v)
+        }
+        DATE_OID => {
+            let days = i32::from_be_bytes(value.try_into().map_err(|_| invalid())?);
+            format!(
+                "'{}'::DATE",
+                date_to_string(days as i64 + PG_EPOCH_DAYS as i64, Tz::UTC)
+            )
+        }
+        TIMESTAMP_OID | TIMESTAMPTZ_OID => {
+            let micros = i64::from_be_bytes(value.try_into().map_err(|_| invalid())?);
+            format!(
+                "'{}'::TIMESTAMP",
+                timestamp_to_string(micros + PG_EPOCH_MICROS, Tz::UTC)
+            )
+        }
+        0 | TEXT_OID | VARCHAR_OID | BPCHAR_OID | UNKNOWN_OID | JSON_OID => {
+            let text = std::str::from_utf8(value)
+                .map_err(|_| ErrorCode::BadBytes("Parameter value is not valid UTF-8"))?;
+            quote_string(text)
+        }
+        _ => {
+            return Err(ErrorCode::Unimplemented(format!(
+                "Binary parameter of type oid {} is not supported",
+                type_oid
+            )));
+        }
+    };
+    Ok(literal)
+}
+
+fn quote_string(s: &str) -> String {
+    let mut quoted = String::with_capacity(s.len() + 2);
+    quoted.push('\'');
+    for c in s.chars() {
+        match c {
+            '\'' => quoted.push_str("''"),
+            '\\' => quoted.push_str("\\\\"),
+            _ => quoted.push(c),
+        }
+    }
+    quoted.push('\'');
+    quoted
+}
+
+// If a quoted literal or identifier, or a comment starts at `i`, returns the
+// position right after it.
+fn skip_quoted_or_comment(bytes: &[u8], mut i: usize) -> Option<usize> {
+    match bytes[i] {
+        quote @ (b'\'' | b'"' | b'`') => {
+            i += 1;
+            while i < bytes.len() {
+                if bytes[i] == b'\\' && quote == b'\'' {
+                    i += 2;
+                    continue;
+                }
+                if bytes[i] == quote {
+                    // A doubled quote is an escaped quote.
+                    if bytes.get(i + 1) == Some(&quote) {
+                        i += 2;
+                        continue;
+                    }
+                    break;
+                }
+                i += 1;
+            }
+            Some((i + 1).min(bytes.len()))
+        }
+        b'-' if bytes.get(i + 1) == Some(&b'-') => {
+            while i < bytes.len() && bytes[i] != b'\n' {
+                i += 1;
+            }
+            Some(i)
+        }
+        b'/' if bytes.get(i + 1) == Some(&b'*') => {
+            i += 2;
+            while i < bytes.len() && !(bytes[i] == b'*' && bytes.get(i + 1) == Some(&b'/')) {
+                i += 1;
+            }
+            Some((i + 2).min(bytes.len()))
+        }
+        _ => None,
+    }
+}
+
+/// Find the `$n` placeholders of a query, outside of quotes and comments.
+/// Returns the byte range and the 1-based index of each placeholder.
+fn placeholders(query: &str) -> Vec<(std::ops::Range<usize>, usize)> {
+    let bytes = query.as_bytes();
+    let mut result = vec![];
+    let mut i = 0;
+    while i < bytes.len() {
+        if let Some(next) = skip_quoted_or_comment(bytes, i) {
+            i = next;
+            continue;
+        }
+        if bytes[i] == b'$' {
+            let start = i;
+            i += 1;
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+            if let Ok(index) = query[start + 1..i].parse::<usize>() {
+                if index > 0 {
+                    result.push((start..i, index));
+                }
+            }
+            continue;
+        }
+        i += 1;
+    }
+    result
+}
+
+/// Split a simple query into its statements, which are separated by `;`.
+/// Statements that are blank or only comments are dropped.
+pub fn split_statements(query: &str) -> Vec<&str> {
+    let bytes = query.as_bytes();
+    let mut statements = vec![];
+    let mut has_code = false;
+    let mut start = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        if let Some(next) = skip_quoted_or_comment(bytes, i) {
+            has_code |= matches!(bytes[i], b'\'' | b'"' | b'`');
+            i = next;
+            continue;
+        }
+        if bytes[i] == b';' {
+            if has_code {
+                statements.push(query[start..i].trim());
+            }
+            has_code = false;
+            start = i + 1;
+        } else if !bytes[i].is_ascii_whitespace() {
+            has_code = true;
+        }
+        i += 1;
+    }
+    if has_code {
+        statements.push(query[start..].trim());
+    }
+    statements
+}
+
+/// The number of parameters a query expects, the largest `$n` placeholder.
+pub fn param_count(query: &str) -> usize {
+    placeholders(query)
+        .iter()
+        .map(|(_, index)| *index)
+        .max()
+        .unwrap_or(0)
+}
+
+/// Replace the `$n` placeholders of a query with the decoded SQL literals.
+pub fn substitute_params(query: &str, params: &[String]) -> Result<String> {
+    let mut sql = String::with_capacity(query.len());
+    let mut last = 0;
+    for (range, index) in placeholders(query) {
+        let param = params.get(index - 1).ok_or_else(|| {
+            ErrorCode::BadArguments(format!(
+                "Query expects parameter ${}, but only {} bound",
+                index,
+                params.len()
+            ))
+        })?;
+        sql.push_str(&query[last..range.start]);
+        sql.push_str(param);
+        last = range.end;
+    }
+    sql.push_str(&query[last..]);
+    Ok(sql)
+}
@@ -23,6 +23,7 @@ mod session_mgr_status;
 mod session_privilege_mgr;
 mod session_status;
 mod session_type;
+pub mod txn_mgr;
 
 pub use common_catalog::table_context::TableContext;
 pub use query_affect::QueryAffect;
@@ -37,3 +38,6 @@ pub use session_mgr::SessionManager;
 pub use session_mgr_status::SessionManagerStatus;
 pub use session_status::SessionStatus;
 pub use session_type::SessionType;
+pub use txn_mgr::TxnManager;
+pub use txn_mgr::TxnManagerRef;
+pub use txn_mgr::TxnState;
@@ -58,6 +58,7 @@ use common_meta_app::principal::UserInfo;
 use common_meta_app::schema::CatalogInfo;
 use common_meta_app::schema::GetTableCopiedFileReq;
 use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::UpdateTableMetaReq;
 use common_metrics::storage::*;
 use common_pipeline_core::InputError;
 use common_settings::ChangeValue;
@@ -92,16 +93,19 @@ use crate::sessions::QueryContextShared;
 use crate::sessions::Session;
 use crate::sessions::SessionManager;
 use crate::sessions::SessionType;
+use crate::sessions::TxnManagerRef;
 use crate::storages::Table;
 
 const MYSQL_VERSION: &str = "8.0.26";
 const CLICKHOUSE_VERSION: &str = "8.12.14";
+const POSTGRES_VERSION: &str = "15.3";
 const MAX_QUERY_COPIED_FILES_NUM: usize = 1000;
 
 #[derive(Clone)]
 pub struct QueryContext {
     version: String,
     mysql_version: String,
+    postgres_version: String,
     clickhouse_version: String,
     partition_queue: Arc<RwLock<VecDeque<PartInfoPtr>>>,
     shared: Arc<QueryContextShared>,
@@ -125,6 +129,10 @@ impl QueryContext {
             partition_queue: Arc::new(RwLock::new(VecDeque::new())),
             version: format!("DatabendQuery {}", *DATABEND_COMMIT_VERSION),
             mysql_version: format!("{}-{}", MYSQL_VERSION, *DATABEND_COMMIT_VERSION),
+            postgres_version: format!(
+                "PostgreSQL {} (DatabendQuery {})",
+                POSTGRES_VERSION, *DATABEND_COMMIT_VERSION
+            ),
             clickhouse_version: CLICKHOUSE_VERSION.to_string(),
             shared,
             query_settings,
@@ -213,6 +221,11 @@ impl QueryContext {
         self.shared.session.clone()
     }
 
+    // Get the explicit transaction of the current session.
+    pub fn txn_mgr(&self) -> TxnManagerRef {
+        self.shared.session.txn_mgr()
+    }
+
     // Get one session by session id.
     pub fn get_session_by_id(self: &Arc<Self>, id: &str) -> Option<Arc<Session>> {
         SessionManager::instance().get_session_by_id(id)
@@ -285,6 +298,28 @@ impl QueryContext {
     pub fn evict_table_from_cache(&self, catalog: &str, database: &str, table: &str) -> Result<()> {
         self.shared.evict_table_from_cache(catalog, database, table)
     }
+
+    pub fn set_recursive_cte_working_table(
+        &self,
+        idx: (IndexType, IndexType),
+        blocks: Arc<Vec<DataBlock>>,
+    ) {
+        let mut working_tables = self.shared.recursive_cte_working_tables.write();
+        working_tables.insert(idx, blocks);
+    }
+
+    pub fn get_recursive_cte_working_table(
+        &self,
+        idx: (IndexType, IndexType),
+    ) -> Option<Arc<Vec<DataBlock>>> {
+        let working_tables = self.shared.recursive_cte_working_tables.read();
+        working_tables.get(&idx).cloned()
+    }
+
+    pub fn remove_recursive_cte_working_table(&self, idx: (IndexType, IndexType)) {
+        let mut working_tables = self.shared.recursive_cte_working_tables.write();
+        working_tables.remove(&idx);
+    }
 }
 
 #[async_trait::async_trait]
@@ -346,6 +381,14 @@ impl TableContext for QueryContext {
         self.shared.group_by_spill_progress.clone()
     }
 
+    fn get_sort_spill_progress(&self) -> Arc<Progress> {
+        self.shared.sort_spill_progress.clone()
+    }
+
+    fn get_window_spill_progress(&self) -> Arc<Progress> {
+        self.shared.window_spill_progress.clone()
+    }
+
     fn get_write_progress_value(&self) -> ProgressValues {
         self.shared.write_progress.as_ref().get_values()
     }
@@ -362,6 +405,14 @@ impl TableContext for QueryContext {
         self.shared.group_by_spill_progress.as_ref().get_values()
     }
 
+    fn get_sort_spill_progress_value(&self) -> ProgressValues {
+        self.shared.sort_spill_progress.as_ref().get_values()
+    }
+
+    fn get_window_spill_progress_value(&self) -> ProgressValues {
+        self.shared.window_spill_progress.as_ref().get_values()
+    }
+
     fn get_result_progress(&self) -> Arc<Progress> {
         self.shared.result_progress.clone()
     }
@@ -465,14 +516,11 @@ impl TableContext for QueryContext {
 
     #[async_backtrace::framed]
     async fn get_catalog(&self, catalog_name: &str) -> Result<Arc<dyn Catalog>> {
-        self.shared
-            .catalog_manager
-            .get_catalog(&self.get_tenant(), catalog_name.as_ref())
-            .await
+        self.shared.get_catalog(catalog_name).await
     }
 
     fn get_default_catalog(&self) -> Result<Arc<dyn Catalog>> {
-        self.shared.catalog_manager.get_default_catalog()
+        self.shared.get_default_catalog()
     }
 
     fn get_id(&self) -> String {
@@ -519,6 +567,7 @@ impl TableContext for QueryContext {
         match session.get_type() {
             SessionType::ClickHouseHttpHandler => self.clickhouse_version.clone(),
             SessionType::MySQL => self.mysql_version.clone(),
+            SessionType::PostgreSQL => self.postgres_version.clone(),
             _ => self.version.clone(),
         }
     }
@@ -794,6 +843,32 @@ impl TableContext for QueryContext {
             .collect::<Vec<_>>())
     }
 
+    fn add_stream_offset(&self, req: UpdateTableMetaReq) -> Result<()> {
+        self.shared.stream_offsets.write().insert(req.table_id, req);
+        Ok(())
+    }
+
+    fn get_stream_offsets(&self) -> Vec<UpdateTableMetaReq> {
+        self.shared
+            .stream_offsets
+            .read()
+            .values()
+            .cloned()
+            .collect()
+    }
+
+    fn clear_stream_offsets(&self) {
+        self.shared.stream_offsets.write().clear();
+    }
+
+    fn is_txn_active(&self) -> bool {
+        self.txn_mgr().lock().is_active()
+    }
+
+    fn set_txn_fail(&self) {
+        self.txn_mgr().lock().set_fail();
+    }
+
     fn add_file_status(&self, file_path: &str, file_status: FileStatus) -> Result<()> {
         if matches!(self.get_query_kind(), QueryKind::CopyIntoTable) {
             self.shared.copy_status.add_chunk(file_path, file_status);
@@ -22,15 +22,19 @@ use std::time::SystemTime;
 
 use common_base::base::Progress;
 use common_base::runtime::Runtime;
+use common_catalog::catalog::Catalog;
 use common_catalog::catalog::CatalogManager;
+use common_catalog::catalog::CATALOG_DEFAULT;
 use common_catalog::query_kind::QueryKind;
 use common_catalog::table_context::MaterializedCtesBlocks;
 use common_catalog::table_context::StageAttachment;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::DataBlock;
 use common_meta_app::principal::OnErrorMode;
 use common_meta_app::principal::RoleInfo;
 use common_meta_app::principal::UserInfo;
+use common_meta_app::schema::UpdateTableMetaReq;
 use common_pipeline_core::InputError;
 use common_settings::ChangeValue;
 use common_settings::Settings;
@@ -42,6 +46,7 @@ use parking_lot::Mutex;
 use parking_lot::RwLock;
 use uuid::Uuid;
 
+use crate::catalogs::default::SessionCatalog;
 use crate::clusters::Cluster;
 use crate::pipelines::executor::PipelineExecutor;
 use crate::sessions::query_affect::QueryAffect;
@@ -64,6 +69,10 @@ pub struct QueryContextShared {
     pub(in crate::sessions) agg_spill_progress: Arc<Progress>,
     /// Record how many bytes/rows have been spilled in group by
     pub(in crate::sessions) group_by_spill_progress: Arc<Progress>,
+    /// Record how many bytes/rows have been spilled in sort.
+    pub(in crate::sessions) sort_spill_progress: Arc<Progress>,
+    /// Record how many bytes/rows have been spilled in window.
+    pub(in crate::sessions) window_spill_progress: Arc<Progress>,
     /// result_progress for metrics of result datablocks (uncompressed)
     pub(in crate::sessions) result_progress: Arc<Progress>,
     pub(in crate::sessions) error: Arc<Mutex<Option<ErrorCode>>>,
@@ -101,6 +110,13 @@ pub struct QueryContextShared {
     pub(in crate::sessions) user_agent: Arc<RwLock<String>>,
     /// Key is (cte index, used_count), value contains cte's materialized blocks
     pub(in crate::sessions) materialized_cte_tables: MaterializedCtesBlocks,
+    /// Key is the working table index of a recursive cte, value contains the rows
+    /// produced by the last iteration of the recursive cte
+    pub(in crate::sessions) recursive_cte_working_tables:
+        Arc<RwLock<HashMap<(usize, usize), Arc<Vec<DataBlock>>>>>,
+    /// Key is the table id of a stream consumed by the query, value is the request
+    /// to advance its offset, committed along with the table written by the query
+    pub(in crate::sessions) stream_offsets: Arc<RwLock<HashMap<u64, UpdateTableMetaReq>>>,
 }
 
 impl QueryContextShared {
@@ -138,9 +154,13 @@ impl QueryContextShared {
             status: Arc::new(RwLock::new("null".to_string())),
             user_agent: Arc::new(RwLock::new("null".to_string())),
             materialized_cte_tables: Arc::new(Default::default()),
+            recursive_cte_working_tables: Arc::new(Default::default()),
+            stream_offsets: Arc::new(Default::default()),
             join_spill_progress: Arc::new(Progress::create()),
             agg_spill_progress: Arc::new(Progress::create()),
             group_by_spill_progress: Arc::new(Progress::create()),
+            sort_spill_progress: Arc::new(Progress::create()),
+            window_spill_progress: Arc::new(Progress::create()),
         }))
     }
 
@@ -287,6 +307,31 @@ impl QueryContextShared {
         }
     }
 
+    /// Get the catalog by name, the default catalog is wrapped with the temporary tables
+    /// and the explicit transaction of the current session.
+    #[async_backtrace::framed]
+    pub async fn get_catalog(&self, catalog_name: &str) -> Result<Arc<dyn Catalog>> {
+        let catalog = self
+            .catalog_manager
+            .get_catalog(&self.get_tenant(), catalog_name)
+            .await?;
+        Ok(self.with_session_state(catalog))
+    }
+
+    pub fn get_default_catalog(&self) -> Result<Arc<dyn Catalog>> {
+        let catalog = self.catalog_manager.get_default_catalog()?;
+        Ok(self.with_session_state(catalog))
+    }
+
+    fn with_session_state(&self, catalog: Arc<dyn Catalog>) -> Arc<dyn Catalog> {
+        if catalog.name() != CATALOG_DEFAULT {
+            return catalog;
+        }
+        let temp_tbl_mgr = self.session.session_ctx.get_temp_tbl_mgr();
+        let txn_mgr = self.session.session_ctx.get_txn_mgr();
+        Arc::new(SessionCatalog::create(catalog, temp_tbl_mgr, txn_mgr))
+    }
+
     #[async_backtrace::framed]
     async fn get_table_to_cache(
         &self,
@@ -296,7 +341,7 @@ impl QueryContextShared {
     ) -> Result<Arc<dyn Table>> {
         let tenant = self.get_tenant();
         let table_meta_key = (catalog.to_string(), database.to_string(), table.to_string());
-        let catalog = self.catalog_manager.get_catalog(&tenant, catalog).await?;
+        let catalog = self.get_catalog(catalog).await?;
         let cache_table = catalog.get_table(tenant.as_str(), database, table).await?;
 
         let mut tables_refs = self.tables_refs.lock();
@@ -16,6 +16,8 @@ use std::collections::HashMap;
 use std::net::SocketAddr;
 use std::sync::Arc;
 
+use common_base::runtime::GlobalIORuntime;
+use common_base::runtime::TrySpawn;
 use common_config::GlobalConfig;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -27,9 +29,13 @@ use common_meta_app::principal::UserInfo;
 use common_meta_app::principal::UserPrivilegeType;
 use common_settings::ChangeValue;
 use common_settings::Settings;
+use common_storage::DataOperator;
 use common_users::GrantObjectVisibilityChecker;
 use log::debug;
+use log::info;
+use log::warn;
 use parking_lot::RwLock;
+use storages_common_table_meta::table::temp_table_storage_prefix;
 
 use crate::clusters::ClusterDiscovery;
 use crate::servers::http::v1::HttpQueryManager;
@@ -41,6 +47,7 @@ use crate::sessions::SessionContext;
 use crate::sessions::SessionManager;
 use crate::sessions::SessionStatus;
 use crate::sessions::SessionType;
+use crate::sessions::TxnManagerRef;
 
 pub struct Session {
     pub(in crate::sessions) id: String,
@@ -172,6 +179,10 @@ impl Session {
         self.session_ctx.get_current_catalog()
     }
 
+    pub fn txn_mgr(&self) -> TxnManagerRef {
+        self.session_ctx.get_txn_mgr()
+    }
+
     pub fn get_current_tenant(self: &Arc<Self>) -> String {
         self.session_ctx.get_current_tenant()
     }
@@ -294,9 +305,34 @@ impl Session {
     }
 }
 
+impl Session {
+    /// Drop the temporary tables of the session and purge their data in background.
+    fn drop_temp_tables(&self) {
+        let temp_tbl_mgr = self.session_ctx.get_temp_tbl_mgr();
+        let table_infos = temp_tbl_mgr.lock().drop_all_tables();
+        if table_infos.is_empty() {
+            return;
+        }
+
+        info!(
+            "Drop {} temporary tables of session {}",
+            table_infos.len(),
+            self.id
+        );
+        let prefix = format!("{}/", temp_table_storage_prefix(&self.id));
+        GlobalIORuntime::instance().spawn(self.id.clone(), async move {
+            let operator = DataOperator::instance().operator();
+            if let Err(e) = operator.remove_all(&prefix).await {
+                warn!("Failed to purge temporary tables under {}: {:?}", prefix, e);
+            }
+        });
+    }
+}
+
 impl Drop for Session {
     fn drop(&mut self) {
         debug!("Drop session {}", self.id.clone());
+        self.drop_temp_tables();
         SessionManager::instance().destroy_session(&self.id.clone());
     }
 }
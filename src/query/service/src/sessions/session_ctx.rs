@@ -29,6 +29,10 @@ use common_settings::Settings;
 use parking_lot::RwLock;
 
 use super::SessionType;
+use crate::catalogs::default::TempTblMgr;
+use crate::catalogs::default::TempTblMgrRef;
+use crate::sessions::txn_mgr::TxnManager;
+use crate::sessions::txn_mgr::TxnManagerRef;
 use crate::sessions::QueryContextShared;
 
 pub struct SessionContext {
@@ -62,6 +66,10 @@ pub struct SessionContext {
     // We store `query_id -> query_result_cache_key` to session context, so that we can fetch
     // query result through previous query_id easily.
     query_ids_results: RwLock<Vec<(String, Option<String>)>>,
+    // The temporary tables created in this session, they are dropped when the session closes.
+    temp_tbl_mgr: TempTblMgrRef,
+    // The explicit transaction of this session, started by `BEGIN`.
+    txn_mgr: TxnManagerRef,
     typ: SessionType,
 }
 
@@ -80,6 +88,8 @@ impl SessionContext {
             io_shutdown_tx: Default::default(),
             query_context_shared: Default::default(),
             query_ids_results: Default::default(),
+            temp_tbl_mgr: TempTblMgr::init(),
+            txn_mgr: TxnManager::init(),
             typ,
         }))
     }
@@ -109,6 +119,14 @@ impl SessionContext {
         Ok(())
     }
 
+    pub fn get_temp_tbl_mgr(&self) -> TempTblMgrRef {
+        self.temp_tbl_mgr.clone()
+    }
+
+    pub fn get_txn_mgr(&self) -> TxnManagerRef {
+        self.txn_mgr.clone()
+    }
+
     // Get current catalog name.
     pub fn get_current_catalog(&self) -> String {
         let lock = self.current_catalog.read();
@@ -81,7 +81,7 @@ impl SessionManager {
             self.validate_max_active_sessions(sessions.len(), "active sessions")?;
         }
 
-        if matches!(typ, SessionType::MySQL) {
+        if matches!(typ, SessionType::MySQL | SessionType::PostgreSQL) {
             let mysql_conn_map = self.mysql_conn_map.read();
             self.validate_max_active_sessions(mysql_conn_map.len(), "mysql conns")?;
         }
@@ -116,8 +116,12 @@ impl SessionManager {
         settings: Arc<Settings>,
     ) -> Result<Arc<Session>> {
         let id = uuid::Uuid::new_v4().to_string();
+        // PostgreSQL sessions share the connection id with MySQL ones,
+        // it is the process id a PostgreSQL client uses to cancel a query.
         let mysql_conn_id = match typ {
-            SessionType::MySQL => Some(self.mysql_basic_conn_id.fetch_add(1, Ordering::Relaxed)),
+            SessionType::MySQL | SessionType::PostgreSQL => {
+                Some(self.mysql_basic_conn_id.fetch_add(1, Ordering::Relaxed))
+            }
             _ => None,
         };
 
@@ -138,7 +142,7 @@ impl SessionManager {
             }
         }
 
-        if let SessionType::MySQL = typ {
+        if let SessionType::MySQL | SessionType::PostgreSQL = typ {
             let mut mysql_conn_map = self.mysql_conn_map.write();
             self.validate_max_active_sessions(mysql_conn_map.len(), "mysql conns")?;
             mysql_conn_map.insert(mysql_conn_id, id);
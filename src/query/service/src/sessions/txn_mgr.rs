@@ -0,0 +1,143 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeqExt;
+use parking_lot::Mutex;
+
+pub type TxnManagerRef = Arc<Mutex<TxnManager>>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TxnState {
+    /// Each statement commits by itself, it's the default state of a session.
+    AutoCommit,
+    /// Inside an explicit transaction, started by `BEGIN`.
+    Active,
+    /// A statement of the transaction failed, the transaction can only be rolled back.
+    Fail,
+}
+
+/// The explicit transaction of a session.
+///
+/// Inside a transaction, the table meta updates (i.e. new snapshots) are not sent to
+/// meta, they are buffered here and committed all at once by `COMMIT`. The statements
+/// of the transaction read the buffered table infos, so they see their own writes.
+pub struct TxnManager {
+    state: TxnState,
+    txn_buffer: TxnBuffer,
+}
+
+#[derive(Default)]
+struct TxnBuffer {
+    /// `table id -> (table info visible in the transaction, pending update request)`
+    mutated_tables: BTreeMap<u64, (TableInfo, UpdateTableMetaReq)>,
+}
+
+impl TxnManager {
+    pub fn init() -> TxnManagerRef {
+        Arc::new(Mutex::new(TxnManager {
+            state: TxnState::AutoCommit,
+            txn_buffer: TxnBuffer::default(),
+        }))
+    }
+
+    pub fn state(&self) -> TxnState {
+        self.state
+    }
+
+    pub fn begin(&mut self) {
+        if self.state == TxnState::AutoCommit {
+            self.state = TxnState::Active;
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.state == TxnState::Active
+    }
+
+    pub fn is_fail(&self) -> bool {
+        self.state == TxnState::Fail
+    }
+
+    pub fn set_fail(&mut self) {
+        if self.state == TxnState::Active {
+            self.state = TxnState::Fail;
+        }
+    }
+
+    /// End the transaction, and take the buffered updates out, along with the table infos
+    /// visible in the transaction.
+    pub fn end(&mut self) -> Vec<(TableInfo, UpdateTableMetaReq)> {
+        self.state = TxnState::AutoCommit;
+        std::mem::take(&mut self.txn_buffer.mutated_tables)
+            .into_values()
+            .collect()
+    }
+
+    /// Buffer the update of a table.
+    ///
+    /// All the updates of a table are merged into one request, which keeps the
+    /// table version read by the first update of the transaction.
+    pub fn update_table_meta(
+        &mut self,
+        table_info: &TableInfo,
+        req: UpdateTableMetaReq,
+    ) -> Result<()> {
+        let table_id = req.table_id;
+        let Some((visible, pending)) = self.txn_buffer.mutated_tables.get_mut(&table_id) else {
+            let mut visible = table_info.clone();
+            visible.meta = req.new_table_meta.clone();
+            self.txn_buffer
+                .mutated_tables
+                .insert(table_id, (visible, req));
+            return Ok(());
+        };
+
+        if req.seq.match_seq(visible.ident.seq).is_err() {
+            return Err(ErrorCode::TableVersionMismatched(format!(
+                "Table {} has been changed in the transaction, expect version {:?}, got {}",
+                table_id, req.seq, visible.ident.seq
+            )));
+        }
+
+        visible.meta = req.new_table_meta.clone();
+        pending.new_table_meta = req.new_table_meta;
+        match (&mut pending.copied_files, req.copied_files) {
+            (Some(pending_files), Some(files)) => {
+                pending_files.file_info.extend(files.file_info);
+                pending_files.fail_if_duplicated |= files.fail_if_duplicated;
+            }
+            (pending_files @ None, files) => *pending_files = files,
+            _ => {}
+        }
+        if req.deduplicated_label.is_some() {
+            pending.deduplicated_label = req.deduplicated_label;
+        }
+        Ok(())
+    }
+
+    /// Get the table info updated in the transaction.
+    pub fn get_table_from_buffer(&self, table_id: u64) -> Option<TableInfo> {
+        self.txn_buffer
+            .mutated_tables
+            .get(&table_id)
+            .map(|(table_info, _)| table_info.clone())
+    }
+}
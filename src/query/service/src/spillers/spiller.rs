@@ -21,6 +21,7 @@ use std::sync::Arc;
 use common_base::base::GlobalUniqName;
 use common_base::base::ProgressValues;
 use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::arrow::deserialize_column;
 use common_expression::arrow::serialize_column;
@@ -30,13 +31,14 @@ use opendal::Operator;
 
 use crate::sessions::QueryContext;
 
-/// Spiller type, currently only supports HashJoin
+/// Spiller type, currently only supports HashJoin, OrderBy and Window
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum SpillerType {
     HashJoinBuild,
-    HashJoinProbe, /* Todo: Add more spillers type
-                    * OrderBy
-                    * Aggregation */
+    HashJoinProbe,
+    OrderBy,
+    Window, /* Todo: Add more spillers type
+             * Aggregation */
 }
 
 impl Display for SpillerType {
@@ -44,6 +46,8 @@ impl Display for SpillerType {
         match self {
             SpillerType::HashJoinBuild => write!(f, "HashJoinBuild"),
             SpillerType::HashJoinProbe => write!(f, "HashJoinProbe"),
+            SpillerType::OrderBy => write!(f, "OrderBy"),
+            SpillerType::Window => write!(f, "Window"),
         }
     }
 }
@@ -125,28 +129,38 @@ impl Spiller {
         worker_id: usize,
     ) -> Result<()> {
         self.spilled_partition_set.insert(p_id);
-        let unique_name = GlobalUniqName::unique();
-        let location = format!("{}/{}", self.config.location_prefix, unique_name);
+        let num_rows = data.num_rows();
+        let location = self.spill_block(data).await?;
         self.partition_location
             .entry(p_id)
             .and_modify(|locs| {
                 locs.push(location.clone());
             })
-            .or_insert(vec![location.clone()]);
+            .or_insert(vec![location]);
+        info!(
+            "{:?} spilled {:?} rows data, partition id is {:?}, worker id is {:?}",
+            self.spiller_type, num_rows, p_id, worker_id
+        );
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    /// Spill data block to a new file, return the location of the file
+    pub async fn spill_block(&mut self, data: DataBlock) -> Result<String> {
+        let unique_name = GlobalUniqName::unique();
+        let location = format!("{}/{}", self.config.location_prefix, unique_name);
         let mut writer = self.operator.writer(location.as_str()).await?;
+        let data = data.convert_to_full();
         let columns = data.columns().to_vec();
         let mut columns_data = Vec::with_capacity(columns.len());
+        let mut columns_layout = Vec::with_capacity(columns.len());
         for column in columns.into_iter() {
             let column = column.value.as_column().unwrap();
             let column_data = serialize_column(column);
-            self.columns_layout
-                .entry(location.clone())
-                .and_modify(|layouts| {
-                    layouts.push(column_data.len());
-                })
-                .or_insert(vec![column_data.len()]);
+            columns_layout.push(column_data.len());
             columns_data.push(column_data);
         }
+        self.columns_layout.insert(location.clone(), columns_layout);
         for data in columns_data.into_iter() {
             writer.write(data).await?;
         }
@@ -156,15 +170,54 @@ impl Spiller {
                 rows: data.num_rows(),
                 bytes: data.memory_size(),
             };
-            self.ctx.get_join_spill_progress().incr(&progress_val);
+            match self.spiller_type {
+                SpillerType::HashJoinBuild | SpillerType::HashJoinProbe => {
+                    self.ctx.get_join_spill_progress().incr(&progress_val)
+                }
+                SpillerType::OrderBy => self.ctx.get_sort_spill_progress().incr(&progress_val),
+                SpillerType::Window => self.ctx.get_window_spill_progress().incr(&progress_val),
+            }
         }
-        info!(
-            "{:?} spilled {:?} rows data, partition id is {:?}, worker id is {:?}",
-            self.spiller_type,
-            data.num_rows(),
-            p_id,
-            worker_id
-        );
+        Ok(location)
+    }
+
+    #[async_backtrace::framed]
+    /// Read spilled data block from the location
+    pub async fn read_spilled_block(&self, location: &str) -> Result<DataBlock> {
+        let columns_layout = self.columns_layout.get(location).ok_or_else(|| {
+            ErrorCode::Internal(format!("Unknown spilled block location: {}", location))
+        })?;
+        let data = self.operator.read(location).await?;
+        if data.len() != columns_layout.iter().sum::<usize>() {
+            return Err(ErrorCode::StorageOther(format!(
+                "Spilled block {} has {} bytes, expected {}",
+                location,
+                data.len(),
+                columns_layout.iter().sum::<usize>()
+            )));
+        }
+
+        let mut begin = 0;
+        let mut columns = Vec::with_capacity(columns_layout.len());
+        for column_layout in columns_layout.iter() {
+            let column =
+                deserialize_column(&data[begin..begin + column_layout]).ok_or_else(|| {
+                    ErrorCode::StorageOther(format!(
+                        "Cannot deserialize spilled block {}",
+                        location
+                    ))
+                })?;
+            columns.push(column);
+            begin += column_layout;
+        }
+        Ok(DataBlock::new_from_columns(columns))
+    }
+
+    #[async_backtrace::framed]
+    /// Remove spilled data block from the location
+    pub async fn remove_spilled_block(&mut self, location: &str) -> Result<()> {
+        self.columns_layout.remove(location);
+        self.operator.delete(location).await?;
         Ok(())
     }
 
@@ -190,15 +243,7 @@ impl Spiller {
         let mut spilled_data = Vec::with_capacity(files.len());
         // Todo: make it parallel
         for file in files.iter() {
-            let data = self.operator.read(file).await?;
-            let mut begin = 0;
-            let mut columns = Vec::with_capacity(self.columns_layout.len());
-            let columns_layout = self.columns_layout.get(file).unwrap();
-            for column_layout in columns_layout.iter() {
-                columns.push(deserialize_column(&data[begin..begin + column_layout]).unwrap());
-                begin += column_layout;
-            }
-            let block = DataBlock::new_from_columns(columns);
+            let block = self.read_spilled_block(file).await?;
             if block.num_rows() != 0 {
                 spilled_data.push(block);
             }
@@ -38,6 +38,8 @@ use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
 use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_sources::input_formats::read_avro_schema_async;
+use common_pipeline_sources::input_formats::read_orc_schema_async;
 use common_pipeline_sources::AsyncSource;
 use common_pipeline_sources::AsyncSourcer;
 use common_sql::binder::resolve_stage_location;
@@ -209,9 +211,13 @@ impl AsyncSource for InferSchemaSource {
                     TableSchema::try_from(&arrow_schema)?
                 }
             }
+            StageFileFormatType::Orc => read_orc_schema_async(&operator, &first_file.path).await?,
+            StageFileFormatType::Avro => {
+                read_avro_schema_async(&operator, &first_file.path).await?
+            }
             _ => {
                 return Err(ErrorCode::BadArguments(
-                    "infer_schema is currently limited to format Parquet",
+                    "infer_schema is currently limited to format Parquet, ORC and Avro",
                 ));
             }
         };
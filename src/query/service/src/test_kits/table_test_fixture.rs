@@ -189,6 +189,7 @@ impl TestFixture {
             .into(),
             field_comments: vec!["number".to_string(), "tuple".to_string()],
             as_select: None,
+            clone_from: None,
             cluster_key: Some("(id)".to_string()),
         }
     }
@@ -213,6 +214,7 @@ impl TestFixture {
             .into(),
             field_comments: vec!["number".to_string(), "tuple".to_string()],
             as_select: None,
+            clone_from: None,
             cluster_key: None,
         }
     }
@@ -248,6 +250,7 @@ impl TestFixture {
             .into(),
             field_comments: vec![],
             as_select: None,
+            clone_from: None,
             cluster_key: None,
         }
     }
@@ -292,6 +295,7 @@ impl TestFixture {
             .into(),
             field_comments: vec![],
             as_select: None,
+            clone_from: None,
             cluster_key: None,
         }
     }
@@ -0,0 +1,118 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+use std::time::Instant;
+
+use common_config::InnerBackgroundAutoCompactionConfig;
+use databend_query::auto_compaction::check_auto_compaction;
+use databend_query::auto_compaction::AutoCompactAction;
+use databend_query::auto_compaction::AutoCompactSchedule;
+use databend_query::auto_compaction::ClusteringDepth;
+use databend_query::auto_compaction::Schedule;
+use storages_common_table_meta::meta::Statistics;
+
+#[test]
+fn test_check_auto_compaction() {
+    let config = InnerBackgroundAutoCompactionConfig {
+        block_threshold: 10,
+        segment_threshold: 5,
+        ..Default::default()
+    };
+    let summary = |block_count, perfect_block_count| Statistics {
+        block_count,
+        perfect_block_count,
+        ..Default::default()
+    };
+
+    // nothing to do.
+    let actions = check_auto_compaction(&config, &summary(100, 95), 3, 100, None);
+    assert!(actions.is_empty());
+
+    // too many small blocks.
+    let actions = check_auto_compaction(&config, &summary(100, 90), 3, 100, None);
+    assert_eq!(actions, vec![AutoCompactAction::CompactBlocks]);
+
+    // the table with cluster keys is reclustered by the depth of its blocks.
+    let clustering = |average_depth| {
+        Some(ClusteringDepth {
+            average_depth,
+            threshold: 1.0,
+        })
+    };
+    let actions = check_auto_compaction(&config, &summary(100, 95), 3, 100, clustering(1.0));
+    assert!(actions.is_empty());
+    let actions = check_auto_compaction(&config, &summary(100, 95), 3, 100, clustering(2.5));
+    assert_eq!(actions, vec![AutoCompactAction::Recluster]);
+    let actions = check_auto_compaction(&config, &summary(100, 90), 3, 100, clustering(2.5));
+    assert_eq!(actions, vec![
+        AutoCompactAction::CompactBlocks,
+        AutoCompactAction::Recluster
+    ]);
+
+    // 2 segments are enough for 150 blocks, and there are 5 more.
+    let actions = check_auto_compaction(&config, &summary(150, 150), 6, 100, None);
+    assert!(actions.is_empty());
+    let actions = check_auto_compaction(&config, &summary(150, 150), 7, 100, None);
+    assert_eq!(actions, vec![AutoCompactAction::CompactSegments]);
+
+    // segments are compacted before blocks.
+    let actions = check_auto_compaction(&config, &summary(150, 0), 7, 100, None);
+    assert_eq!(actions, vec![
+        AutoCompactAction::CompactSegments,
+        AutoCompactAction::CompactBlocks
+    ]);
+}
+
+#[test]
+fn test_auto_compact_schedule() {
+    let cooldown = Duration::from_secs(60);
+    let mut schedule = AutoCompactSchedule::create(cooldown);
+    let now = Instant::now();
+
+    // the first notification starts the compaction.
+    assert_eq!(schedule.notify("t1", 1, now), Schedule::Start(1));
+    // other tables are not affected.
+    assert_eq!(schedule.notify("t2", 2, now), Schedule::Start(2));
+
+    // notifications during the compaction are kept, only the latest one.
+    assert_eq!(schedule.notify("t1", 3, now), Schedule::Pending(None));
+    assert_eq!(schedule.notify("t1", 4, now), Schedule::Pending(None));
+
+    // the table is re-checked once the cool down ends.
+    let finished = now + Duration::from_secs(10);
+    assert_eq!(
+        schedule.finish("t1", finished),
+        Some(Duration::from_secs(50))
+    );
+    assert_eq!(schedule.take_pending("t1"), Some(4));
+    assert_eq!(schedule.take_pending("t1"), None);
+
+    // no re-check without notifications during the compaction.
+    assert_eq!(schedule.finish("t2", finished), None);
+
+    // a notification during the cool down schedules one re-check.
+    let cooling = now + Duration::from_secs(20);
+    assert_eq!(
+        schedule.notify("t2", 5, cooling),
+        Schedule::Pending(Some(Duration::from_secs(40)))
+    );
+    assert_eq!(schedule.notify("t2", 6, cooling), Schedule::Pending(None));
+    assert_eq!(schedule.take_pending("t2"), Some(6));
+
+    // the re-check after the cool down starts the compaction.
+    let cooled = now + cooldown;
+    assert_eq!(schedule.notify("t2", 6, cooled), Schedule::Start(6));
+    assert_eq!(schedule.take_pending("t2"), None);
+}
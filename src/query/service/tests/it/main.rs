@@ -21,6 +21,7 @@ extern crate core;
 
 mod api;
 mod auth;
+mod auto_compaction;
 mod catalogs;
 mod clusters;
 mod configs;
@@ -30,6 +31,7 @@ mod interpreters;
 mod metrics;
 mod parquet_rs;
 mod pipelines;
+mod sequences;
 mod servers;
 mod sessions;
 mod spillers;
@@ -0,0 +1,54 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_query::sequences::SequenceRange;
+use databend_query::sequences::SequenceRanges;
+
+#[test]
+fn test_sequence_ranges() {
+    let mut ranges = SequenceRanges::default();
+    ranges.push(SequenceRange {
+        start: 10,
+        step: 5,
+        count: 3,
+    });
+    // Another node allocated the values in between.
+    ranges.push(SequenceRange {
+        start: 100,
+        step: 5,
+        count: 2,
+    });
+    ranges.push(SequenceRange {
+        start: 200,
+        step: 5,
+        count: 0,
+    });
+    assert_eq!(ranges.len(), 5);
+
+    let mut values = vec![];
+    ranges.take(2, &mut values);
+    assert_eq!(values, vec![10, 15]);
+    assert_eq!(ranges.len(), 3);
+
+    // Values are taken across ranges, in allocation order.
+    let mut values = vec![];
+    ranges.take(2, &mut values);
+    assert_eq!(values, vec![20, 100]);
+
+    // Takes what is left if there are not enough values.
+    let mut values = vec![];
+    ranges.take(3, &mut values);
+    assert_eq!(values, vec![105]);
+    assert!(ranges.is_empty());
+}
@@ -20,8 +20,16 @@ use std::io::Write;
 use arrow_cast::pretty::pretty_format_batches;
 use arrow_flight::flight_service_server::FlightServiceServer;
 use arrow_flight::sql::client::FlightSqlServiceClient;
+use arrow_flight::sql::server::FlightSqlService;
+use arrow_flight::sql::ActionBeginSavepointRequest;
+use arrow_flight::sql::ActionCreatePreparedSubstraitPlanRequest;
+use arrow_flight::sql::ActionEndSavepointRequest;
+use arrow_flight::sql::CommandStatementSubstraitPlan;
 use arrow_flight::utils::flight_data_to_batches;
+use arrow_flight::Action;
 use arrow_flight::FlightData;
+use arrow_flight::FlightDescriptor;
+use arrow_flight::FlightInfo;
 use arrow_schema::ArrowError;
 use common_base::base::tokio;
 use common_config::InnerConfig;
@@ -41,6 +49,8 @@ use tokio_stream::wrappers::UnixListenerStream;
 use tonic::transport::Channel;
 use tonic::transport::Endpoint;
 use tonic::transport::Server;
+use tonic::Code;
+use tonic::Request;
 use tower::service_fn;
 
 const TEST_USER: &str = "test_user";
@@ -66,15 +76,22 @@ async fn run_query(
         affected_rows.to_string()
     } else {
         let flight_info = stmt.execute().await?;
-        let ticket = flight_info.endpoint[0].ticket.as_ref().unwrap().clone();
-        let flight_data = client.do_get(ticket).await?;
-        let flight_data: Vec<FlightData> = flight_data.try_collect().await.unwrap();
-        let batches = flight_data_to_batches(&flight_data)?;
-        pretty_format_batches(batches.as_slice())?.to_string()
+        fetch_result(client, &flight_info).await?
     };
     Ok(res)
 }
 
+async fn fetch_result(
+    client: &mut FlightSqlServiceClient<Channel>,
+    flight_info: &FlightInfo,
+) -> std::result::Result<String, ArrowError> {
+    let ticket = flight_info.endpoint[0].ticket.as_ref().unwrap().clone();
+    let flight_data = client.do_get(ticket).await?;
+    let flight_data: Vec<FlightData> = flight_data.try_collect().await.unwrap();
+    let batches = flight_data_to_batches(&flight_data)?;
+    Ok(pretty_format_batches(batches.as_slice())?.to_string())
+}
+
 fn prepare_config() -> InnerConfig {
     let hash_method = PasswordHashMethod::DoubleSha1;
     let hash_value = hash_method.hash(TEST_PASSWORD.as_bytes());
@@ -148,3 +165,107 @@ async fn test_query() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_statement() -> Result<()> {
+    let _guard = TestGlobalServices::setup(prepare_config()).await?;
+
+    let file = NamedTempFile::new().unwrap();
+    let path = file.into_temp_path().to_str().unwrap().to_string();
+    let _ = fs::remove_file(path.clone());
+
+    let uds = UnixListener::bind(path.clone()).unwrap();
+    let stream = UnixListenerStream::new(uds);
+
+    let service = FlightSqlServiceImpl::create();
+    let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
+    let serve_future = Server::builder()
+        .add_service(FlightServiceServer::new(service))
+        .serve_with_incoming_shutdown(stream, async { shutdown_rx.await.unwrap() });
+
+    let request_future = async {
+        let mut client = client_with_uds(path).await;
+        client.handshake(TEST_USER, TEST_PASSWORD).await.unwrap();
+
+        // The ticket of a statement can only be fetched once.
+        let flight_info = client
+            .execute("select 1 as a".to_string(), None)
+            .await
+            .unwrap();
+        let res = fetch_result(&mut client, &flight_info).await.unwrap();
+        assert!(res.contains("| 1 |"), "unexpected result: {res}");
+        let res = fetch_result(&mut client, &flight_info).await;
+        assert!(res.is_err(), "ticket should be consumed: {res:?}");
+
+        // A prepared statement can be executed until it is closed.
+        let mut stmt = client
+            .prepare("select 2 as a".to_string(), None)
+            .await
+            .unwrap();
+        for _ in 0..2 {
+            let flight_info = stmt.execute().await.unwrap();
+            let res = fetch_result(&mut client, &flight_info).await.unwrap();
+            assert!(res.contains("| 2 |"), "unexpected result: {res}");
+        }
+        stmt.close().await.unwrap();
+
+        // A statement that fails to plan is not kept.
+        let res = client
+            .prepare("select * from not_exists_table".to_string(), None)
+            .await;
+        assert!(res.is_err(), "prepare should fail: {res:?}");
+    };
+    tokio::pin!(serve_future);
+
+    tokio::select! {
+        _ = &mut serve_future => panic!("server returned first"),
+        _ = request_future => {
+            debug!("Client finished!");
+        }
+    }
+    shutdown_tx.send(()).unwrap();
+    serve_future.await.unwrap();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_unimplemented() -> Result<()> {
+    let _guard = TestGlobalServices::setup(prepare_config()).await?;
+
+    let service = FlightSqlServiceImpl::create();
+
+    let res = service
+        .get_flight_info_substrait_plan(
+            CommandStatementSubstraitPlan::default(),
+            Request::new(FlightDescriptor::new_cmd(vec![])),
+        )
+        .await;
+    assert_eq!(res.unwrap_err().code(), Code::Unimplemented);
+
+    let res = service
+        .do_action_create_prepared_substrait_plan(
+            ActionCreatePreparedSubstraitPlanRequest::default(),
+            Request::new(Action::default()),
+        )
+        .await;
+    assert_eq!(res.unwrap_err().code(), Code::Unimplemented);
+
+    let res = service
+        .do_action_begin_savepoint(
+            ActionBeginSavepointRequest::default(),
+            Request::new(Action::default()),
+        )
+        .await;
+    assert_eq!(res.unwrap_err().code(), Code::Unimplemented);
+
+    let res = service
+        .do_action_end_savepoint(
+            ActionEndSavepointRequest::default(),
+            Request::new(Action::default()),
+        )
+        .await;
+    assert_eq!(res.unwrap_err().code(), Code::Unimplemented);
+
+    Ok(())
+}
@@ -0,0 +1,110 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_exception::Result;
+use common_expression::block_debug::assert_blocks_eq;
+use databend_query::servers::PostgresFederated;
+
+#[test]
+fn test_postgres_federated() -> Result<()> {
+    let federated = PostgresFederated::create();
+
+    //
+    {
+        let query = "select 1";
+        let result = federated.check(query);
+        assert!(result.is_none());
+    }
+
+    // show parameter
+    {
+        let query = "SHOW TRANSACTION ISOLATION LEVEL";
+        let result = federated.check(query);
+        assert!(result.is_some());
+
+        if let Some((_, block)) = result {
+            let expect = vec![
+                "+------------------+",
+                "| Column 0         |",
+                "+------------------+",
+                "| 'read committed' |",
+                "+------------------+",
+            ];
+
+            assert_blocks_eq(expect, &[block]);
+        }
+    }
+
+    // unknown parameter falls through to Databend
+    {
+        let query = "SHOW TABLES";
+        let result = federated.check(query);
+        assert!(result.is_none());
+    }
+
+    // current_setting
+    {
+        let query = "select current_setting('standard_conforming_strings')";
+        let result = federated.check(query);
+        assert!(result.is_some());
+
+        if let Some((_, block)) = result {
+            let expect = vec![
+                "+----------+",
+                "| Column 0 |",
+                "+----------+",
+                "| 'on'     |",
+                "+----------+",
+            ];
+
+            assert_blocks_eq(expect, &[block]);
+        }
+    }
+
+    // pg_catalog
+    {
+        let query = "SELECT t.oid, typname AS name, coalesce(t.typarray, 0) FROM pg_catalog.pg_type t WHERE t.typname = 'hstore'";
+        let result = federated.check(query);
+        assert!(result.is_some());
+
+        if let Some((schema, block)) = result {
+            let names = schema
+                .fields()
+                .iter()
+                .map(|f| f.name().as_str())
+                .collect::<Vec<_>>();
+            assert_eq!(names, vec!["oid", "name", "?column?"]);
+            assert_eq!(block.num_rows(), 0);
+        }
+    }
+
+    // set
+    {
+        let query = "SET extra_float_digits = 3";
+        let result = federated.check(query);
+        assert!(result.is_some());
+
+        if let Some((schema, _)) = result {
+            assert!(schema.fields().is_empty());
+        }
+    }
+
+    // timezone is a Databend setting
+    {
+        let query = "SET timezone = 'UTC'";
+        let result = federated.check(query);
+        assert!(result.is_none());
+    }
+
+    Ok(())
+}
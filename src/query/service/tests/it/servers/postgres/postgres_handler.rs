@@ -0,0 +1,138 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::net::SocketAddr;
+
+use common_base::base::tokio;
+use common_base::base::tokio::io::AsyncReadExt;
+use common_base::base::tokio::io::AsyncWriteExt;
+use common_base::base::tokio::net::TcpStream;
+use common_exception::Result;
+use databend_query::servers::PostgresHandler;
+use databend_query::test_kits::ConfigBuilder;
+use databend_query::test_kits::TestGlobalServices;
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_simple_query() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let mut handler = PostgresHandler::create(120)?;
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+    let mut stream = connect(runnable_server.port()).await?;
+
+    stream
+        .write_all(&frame(b'Q', b"SELECT 1 AS a, 'x' AS b\0"))
+        .await?;
+    let messages = read_until_ready(&mut stream).await?;
+    let tags = messages.iter().map(|(tag, _)| *tag).collect::<Vec<_>>();
+    assert_eq!(tags, vec![b'T', b'D', b'C', b'Z']);
+
+    // DataRow: 2 fields, "1" and "x".
+    assert_eq!(messages[1].1, vec![
+        0, 2, 0, 0, 0, 1, b'1', 0, 0, 0, 1, b'x'
+    ]);
+    assert_eq!(messages[2].1, b"SELECT 1\0".to_vec());
+    assert_eq!(messages[3].1, vec![b'I']);
+
+    // An error is reported and the connection is still usable.
+    stream
+        .write_all(&frame(b'Q', b"SELECT * FROM not_exists\0"))
+        .await?;
+    let messages = read_until_ready(&mut stream).await?;
+    assert_eq!(messages[0].0, b'E');
+
+    stream.write_all(&frame(b'X', b"")).await?;
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_extended_query() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let mut handler = PostgresHandler::create(120)?;
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+    let mut stream = connect(runnable_server.port()).await?;
+
+    // Parse: unnamed statement, the parameter is an int4.
+    let mut buf = frame(
+        b'P',
+        b"\0SELECT number + $1 FROM numbers(3)\0\0\x01\0\0\0\x17",
+    );
+    // Bind: unnamed portal, one text parameter "10", all results in binary.
+    buf.extend(frame(b'B', &[
+        0, 0, 0, 0, 0, 1, 0, 0, 0, 2, b'1', b'0', 0, 1, 0, 1,
+    ]));
+    // Describe the portal, execute at most 2 rows, then the rest.
+    buf.extend(frame(b'D', b"P\0"));
+    buf.extend(frame(b'E', &[0, 0, 0, 0, 2]));
+    buf.extend(frame(b'E', &[0, 0, 0, 0, 0]));
+    buf.extend(frame(b'S', b""));
+    stream.write_all(&buf).await?;
+
+    let messages = read_until_ready(&mut stream).await?;
+    let tags = messages.iter().map(|(tag, _)| *tag).collect::<Vec<_>>();
+    assert_eq!(tags, vec![
+        b'1', b'2', b'T', b'D', b'D', b's', b'D', b'C', b'Z'
+    ]);
+
+    // number + 10 is UInt64 without a binary format, it is sent as text.
+    assert_eq!(messages[3].1, vec![0, 1, 0, 0, 0, 2, b'1', b'0']);
+    assert_eq!(messages[7].1, b"SELECT 3\0".to_vec());
+
+    stream.write_all(&frame(b'X', b"")).await?;
+    Ok(())
+}
+
+async fn connect(port: u16) -> Result<TcpStream> {
+    let mut stream = TcpStream::connect(("127.0.0.1", port)).await?;
+
+    // SSLRequest is refused, the client continues in plaintext.
+    stream.write_all(&[0, 0, 0, 8, 4, 210, 22, 47]).await?;
+    assert_eq!(stream.read_u8().await?, b'N');
+
+    let mut body = vec![0, 3, 0, 0];
+    body.extend_from_slice(b"user\0root\0\0");
+    let mut startup = ((body.len() + 4) as i32).to_be_bytes().to_vec();
+    startup.extend(body);
+    stream.write_all(&startup).await?;
+
+    let messages = read_until_ready(&mut stream).await?;
+    // AuthenticationOk
+    assert_eq!(messages[0], (b'R', vec![0, 0, 0, 0]));
+    assert!(messages.iter().any(|(tag, _)| *tag == b'K'));
+    Ok(stream)
+}
+
+fn frame(tag: u8, body: &[u8]) -> Vec<u8> {
+    let mut buf = vec![tag];
+    buf.extend(((body.len() + 4) as i32).to_be_bytes());
+    buf.extend(body);
+    buf
+}
+
+async fn read_until_ready(stream: &mut TcpStream) -> Result<Vec<(u8, Vec<u8>)>> {
+    let mut messages = vec![];
+    loop {
+        let tag = stream.read_u8().await?;
+        let len = stream.read_i32().await?;
+        let mut body = vec![0; len as usize - 4];
+        stream.read_exact(&mut body).await?;
+        let ready = tag == b'Z';
+        messages.push((tag, body));
+        if ready {
+            return Ok(messages);
+        }
+    }
+}
@@ -405,6 +405,14 @@ impl TableContext for CtxDelegation {
         self.ctx.get_group_by_spill_progress()
     }
 
+    fn get_sort_spill_progress(&self) -> Arc<Progress> {
+        self.ctx.get_sort_spill_progress()
+    }
+
+    fn get_window_spill_progress(&self) -> Arc<Progress> {
+        self.ctx.get_window_spill_progress()
+    }
+
     fn get_write_progress_value(&self) -> ProgressValues {
         todo!()
     }
@@ -417,6 +425,14 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    fn get_sort_spill_progress_value(&self) -> ProgressValues {
+        todo!()
+    }
+
+    fn get_window_spill_progress_value(&self) -> ProgressValues {
+        todo!()
+    }
+
     fn get_aggregate_spill_progress_value(&self) -> ProgressValues {
         todo!()
     }
@@ -686,6 +702,26 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    fn add_stream_offset(&self, _req: UpdateTableMetaReq) -> Result<()> {
+        todo!()
+    }
+
+    fn get_stream_offsets(&self) -> Vec<UpdateTableMetaReq> {
+        todo!()
+    }
+
+    fn clear_stream_offsets(&self) {
+        todo!()
+    }
+
+    fn is_txn_active(&self) -> bool {
+        todo!()
+    }
+
+    fn set_txn_fail(&self) {
+        self.ctx.set_txn_fail()
+    }
+
     fn add_file_status(&self, _file_path: &str, _file_status: FileStatus) -> Result<()> {
         todo!()
     }
@@ -13,6 +13,7 @@
 //  limitations under the License.
 
 use common_exception::Result;
+use databend_query::storages::fuse::io::DeletionVector;
 use databend_query::storages::fuse::io::TableMetaLocationGenerator;
 use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::Versioned;
@@ -29,5 +30,27 @@ fn test_meta_locations() -> Result<()> {
     let uuid = Uuid::new_v4();
     let snapshot_loc = locs.snapshot_location_from_uuid(&uuid, TableSnapshot::VERSION)?;
     assert!(snapshot_loc.starts_with(test_prefix));
+    let dv_loc = locs.gen_deletion_vector_location();
+    assert!(dv_loc.0.starts_with(test_prefix));
+    Ok(())
+}
+
+#[test]
+fn test_deletion_vector_serialization() -> Result<()> {
+    let mut deletion_vector = DeletionVector::default();
+    for row in [7, 1, 3, 7] {
+        deletion_vector.insert(row);
+    }
+    assert_eq!(deletion_vector.len(), 3);
+    assert_eq!(deletion_vector.live_rows(8), vec![0, 2, 4, 5, 6]);
+
+    let selection = deletion_vector.selection(8);
+    assert_eq!(selection.unset_bits(), 3);
+    assert!(!selection.get_bit(1));
+    assert!(selection.get_bit(2));
+
+    let bytes = deletion_vector.to_bytes();
+    assert_eq!(DeletionVector::from_slice(&bytes)?, deletion_vector);
+    assert!(DeletionVector::from_slice(&bytes[1..]).is_err());
     Ok(())
 }
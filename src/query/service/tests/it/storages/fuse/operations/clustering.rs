@@ -53,6 +53,7 @@ async fn test_fuse_alter_table_cluster_key() -> common_exception::Result<()> {
         .into(),
         field_comments: vec![],
         as_select: None,
+        clone_from: None,
         cluster_key: None,
     };
 
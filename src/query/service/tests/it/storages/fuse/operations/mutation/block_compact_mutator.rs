@@ -230,6 +230,7 @@ async fn test_safety() -> Result<()> {
             compact_params,
             operator.clone(),
             cluster_key_id,
+            None,
         );
         let selections = block_compact_mutator.target_select().await?;
         if selections.is_empty() {
@@ -38,6 +38,7 @@ use common_expression::TableSchema;
 use common_functions::aggregates::eval_aggr;
 use common_functions::BUILTIN_FUNCTIONS;
 use common_sql::evaluator::BlockOperator;
+use common_storages_fuse::statistics::build_column_histogram;
 use common_storages_fuse::statistics::reducers::reduce_block_metas;
 use common_storages_fuse::statistics::Trim;
 use common_storages_fuse::statistics::STATS_REPLACEMENT_CHAR;
@@ -643,3 +644,54 @@ fn test_reduce_block_meta() -> common_exception::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_ft_stats_build_column_histogram() -> common_exception::Result<()> {
+    // 1..=100 once each, and 7 repeated 100 more times.
+    let mut samples = (1..=100)
+        .map(|v| Scalar::Number(NumberScalar::Int32(v)))
+        .collect::<Vec<_>>();
+    samples.extend((0..100).map(|_| Scalar::Number(NumberScalar::Int32(7))));
+
+    // sampled 1/10 of the rows.
+    let histogram = build_column_histogram(samples, 2000, 1000, 10).unwrap();
+    assert_eq!(
+        histogram.lower_bound,
+        Scalar::Number(NumberScalar::Int32(1))
+    );
+    assert_eq!(histogram.num_values(), 2000.0);
+    // every bucket holds at least 20 sampled rows, except the last one.
+    for bucket in &histogram.buckets[..histogram.buckets.len() - 1] {
+        assert!(bucket.num_values >= 200.0);
+    }
+    // the skewed value takes a bucket of its own.
+    assert!(histogram.buckets.iter().any(|bucket| bucket.upper_bound
+        == Scalar::Number(NumberScalar::Int32(7))
+        && bucket.num_values >= 1000.0));
+    let upper_bounds = histogram
+        .buckets
+        .iter()
+        .map(|bucket| bucket.upper_bound.clone())
+        .collect::<Vec<_>>();
+    let mut sorted = upper_bounds.clone();
+    sorted.sort();
+    sorted.dedup();
+    assert_eq!(upper_bounds, sorted);
+    assert_eq!(
+        histogram.buckets.last().unwrap().upper_bound,
+        Scalar::Number(NumberScalar::Int32(100))
+    );
+    let total_ndv: f64 = histogram.buckets.iter().map(|b| b.num_distinct).sum();
+    assert_eq!(total_ndv, 1000.0);
+
+    // the skewed value is the only common one.
+    assert_eq!(histogram.most_common_values.len(), 1);
+    assert_eq!(
+        histogram.most_common_values[0].value,
+        Scalar::Number(NumberScalar::Int32(7))
+    );
+    assert_eq!(histogram.most_common_values[0].num_values, 1010.0);
+
+    assert!(build_column_histogram(vec![], 0, 0, 10).is_none());
+    Ok(())
+}
@@ -26,6 +26,7 @@ use crate::executor::physical_plans::common::FragmentKind;
 use crate::executor::physical_plans::physical_aggregate_expand::AggregateExpand;
 use crate::executor::physical_plans::physical_aggregate_final::AggregateFinal;
 use crate::executor::physical_plans::physical_aggregate_partial::AggregatePartial;
+use crate::executor::physical_plans::physical_async_function::AsyncFunction;
 use crate::executor::physical_plans::physical_commit_sink::CommitSink;
 use crate::executor::physical_plans::physical_constant_table_scan::ConstantTableScan;
 use crate::executor::physical_plans::physical_copy_into::CopyIntoTable;
@@ -45,6 +46,7 @@ use crate::executor::physical_plans::physical_project_set::ProjectSet;
 use crate::executor::physical_plans::physical_range_join::RangeJoin;
 use crate::executor::physical_plans::physical_range_join::RangeJoinType;
 use crate::executor::physical_plans::physical_recluster_sink::ReclusterSink;
+use crate::executor::physical_plans::physical_recursive_cte_scan::RecursiveCteScan;
 use crate::executor::physical_plans::physical_row_fetch::RowFetch;
 use crate::executor::physical_plans::physical_runtime_filter_source::RuntimeFilterSource;
 use crate::executor::physical_plans::physical_sort::Sort;
@@ -135,6 +137,13 @@ impl PhysicalPlan {
                 ),
                 vec![],
             )),
+            PhysicalPlan::RecursiveCteScan(scan) => Ok(FormatTreeNode::with_children(
+                format!(
+                    "RecursiveCteScan: {}, sub index: {}",
+                    scan.cte_idx.0, scan.cte_idx.1
+                ),
+                vec![],
+            )),
             PhysicalPlan::MaterializedCte(materialized_cte) => {
                 let left_child = materialized_cte.left.format_join(metadata)?;
                 let right_child = materialized_cte.right.format_join(metadata)?;
@@ -202,6 +211,7 @@ fn to_format_tree(
         PhysicalPlan::CommitSink(plan) => commit_sink_to_format_tree(plan, metadata, profs),
         PhysicalPlan::ProjectSet(plan) => project_set_to_format_tree(plan, metadata, profs),
         PhysicalPlan::Lambda(plan) => lambda_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::AsyncFunction(plan) => async_function_to_format_tree(plan, metadata, profs),
         PhysicalPlan::RuntimeFilterSource(plan) => {
             runtime_filter_source_to_format_tree(plan, metadata, profs)
         }
@@ -227,6 +237,9 @@ fn to_format_tree(
             materialized_cte_to_format_tree(plan, metadata, profs)
         }
         PhysicalPlan::ConstantTableScan(plan) => constant_table_scan_to_format_tree(plan, metadata),
+        PhysicalPlan::RecursiveCteScan(plan) => {
+            recursive_cte_scan_to_format_tree(plan, metadata, profs)
+        }
     }
 }
 
@@ -384,6 +397,35 @@ fn cte_scan_to_format_tree(plan: &CteScan) -> Result<FormatTreeNode<String>> {
     ]))
 }
 
+fn recursive_cte_scan_to_format_tree(
+    plan: &RecursiveCteScan,
+    metadata: &Metadata,
+    prof_span_set: &SharedProcessorProfiles,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![
+        FormatTreeNode::new(format!(
+            "output columns: [{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        )),
+        FormatTreeNode::new(format!(
+            "CTE index: {}, sub index: {}",
+            plan.cte_idx.0, plan.cte_idx.1
+        )),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, prof_span_set, plan.plan_id);
+
+    Ok(FormatTreeNode::with_children(
+        "RecursiveCteScan".to_string(),
+        children,
+    ))
+}
+
 fn constant_table_scan_to_format_tree(
     plan: &ConstantTableScan,
     metadata: &Metadata,
@@ -687,6 +729,10 @@ fn window_to_format_tree(
         FormatTreeNode::new(format!("frame: [{frame}]")),
     ];
 
+    if let Some(limit) = plan.limit {
+        children.push(FormatTreeNode::new(format!("limit: [{limit}]")));
+    }
+
     append_profile_info(&mut children, prof_span_set, plan.plan_id);
 
     children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
@@ -975,10 +1021,12 @@ fn union_all_to_format_tree(
         to_format_tree(&plan.right, metadata, prof_span_set)?,
     ]);
 
-    Ok(FormatTreeNode::with_children(
-        "UnionAll".to_string(),
-        children,
-    ))
+    let name = if plan.recursive_cte.is_some() {
+        "RecursiveUnionAll"
+    } else {
+        "UnionAll"
+    };
+    Ok(FormatTreeNode::with_children(name.to_string(), children))
 }
 
 fn part_stats_info_to_format_tree(info: &PartStatistics) -> Vec<FormatTreeNode<String>> {
@@ -1167,6 +1215,40 @@ fn lambda_to_format_tree(
     ))
 }
 
+fn async_function_to_format_tree(
+    plan: &AsyncFunction,
+    metadata: &Metadata,
+    prof_span_set: &SharedProcessorProfiles,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![FormatTreeNode::new(format!(
+        "output columns: [{}]",
+        format_output_columns(plan.output_schema()?, metadata, true)
+    ))];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, prof_span_set, plan.plan_id);
+
+    children.extend(vec![FormatTreeNode::new(format!(
+        "async functions: {}",
+        plan.async_func_descs
+            .iter()
+            .map(|desc| desc.display_name.clone())
+            .collect::<Vec<_>>()
+            .join(", ")
+    ))]);
+
+    children.extend(vec![to_format_tree(&plan.input, metadata, prof_span_set)?]);
+
+    Ok(FormatTreeNode::with_children(
+        "AsyncFunction".to_string(),
+        children,
+    ))
+}
+
 fn runtime_filter_source_to_format_tree(
     plan: &RuntimeFilterSource,
     metadata: &Metadata,
@@ -33,6 +33,8 @@ pub use physical_plans::common::OnConflictField;
 pub use physical_plans::physical_aggregate_expand::AggregateExpand;
 pub use physical_plans::physical_aggregate_final::AggregateFinal;
 pub use physical_plans::physical_aggregate_partial::AggregatePartial;
+pub use physical_plans::physical_async_function::AsyncFunction;
+pub use physical_plans::physical_async_function::AsyncFunctionDesc;
 pub use physical_plans::physical_commit_sink::CommitSink;
 pub use physical_plans::physical_compact_source::CompactSource;
 pub use physical_plans::physical_constant_table_scan::ConstantTableScan;
@@ -65,6 +67,7 @@ pub use physical_plans::physical_range_join::RangeJoinType;
 pub use physical_plans::physical_recluster_sink::ReclusterSink;
 pub use physical_plans::physical_recluster_source::ReclusterSource;
 pub use physical_plans::physical_recluster_source::ReclusterTask;
+pub use physical_plans::physical_recursive_cte_scan::RecursiveCteScan;
 pub use physical_plans::physical_replace_async_source::ReplaceAsyncSourcer;
 pub use physical_plans::physical_replace_deduplicate::ReplaceDeduplicate;
 pub use physical_plans::physical_replace_deduplicate::SelectCtx;
@@ -117,7 +117,15 @@ impl PhysicalPlanBuilder {
             RelOperator::ConstantTableScan(scan) => {
                 self.build_constant_table_scan(scan, required).await
             }
+            RelOperator::RecursiveCteScan(scan) => {
+                self.build_recursive_cte_scan(scan, required, stat_info)
+                    .await
+            }
             RelOperator::AddRowNumber(_) => self.build_add_row_number(s_expr, required).await,
+            RelOperator::AsyncFunction(async_func) => {
+                self.build_async_function(s_expr, async_func, required, stat_info)
+                    .await
+            }
             _ => Err(ErrorCode::Internal(format!(
                 "Unsupported physical plan: {:?}",
                 s_expr.plan()
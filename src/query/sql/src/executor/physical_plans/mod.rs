@@ -16,6 +16,7 @@ pub mod common;
 pub mod physical_aggregate_expand;
 pub mod physical_aggregate_final;
 pub mod physical_aggregate_partial;
+pub mod physical_async_function;
 pub mod physical_commit_sink;
 pub mod physical_compact_source;
 pub mod physical_constant_table_scan;
@@ -40,6 +41,7 @@ pub mod physical_project_set;
 pub mod physical_range_join;
 pub mod physical_recluster_sink;
 pub mod physical_recluster_source;
+pub mod physical_recursive_cte_scan;
 pub mod physical_refresh_index;
 pub mod physical_replace_async_source;
 pub mod physical_replace_deduplicate;
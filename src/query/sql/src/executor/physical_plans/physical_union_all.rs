@@ -43,6 +43,9 @@ pub struct UnionAll {
     pub right: Box<PhysicalPlan>,
     pub pairs: Vec<(String, String)>,
     pub schema: DataSchemaRef,
+    // The working table of a recursive cte, the right child is evaluated
+    // repeatedly on the rows produced by its last iteration.
+    pub recursive_cte: Option<(IndexType, IndexType)>,
 
     // Only used for explain
     pub stat_info: Option<PlanStatsInfo>,
@@ -185,6 +188,7 @@ impl PhysicalPlanBuilder {
             right: Box::new(right_plan),
             pairs,
             schema: DataSchemaRefExt::create(fields),
+            recursive_cte: union_all.recursive_cte,
 
             stat_info: Some(stat_info),
         }))
@@ -55,6 +55,8 @@ pub struct Window {
     pub partition_by: Vec<IndexType>,
     pub order_by: Vec<SortDesc>,
     pub window_frame: WindowFuncFrame,
+    // Keep only the top `limit` rows of each partition before sorting.
+    pub limit: Option<usize>,
 }
 
 impl Window {
@@ -357,6 +359,7 @@ impl PhysicalPlanBuilder {
             partition_by: partition_items,
             order_by: order_by_items,
             window_frame: w.frame.clone(),
+            limit: w.limit,
         }))
     }
 }
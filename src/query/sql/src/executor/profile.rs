@@ -101,6 +101,19 @@ fn flatten_plan_node_profile(
             };
             plan_node_profs.push(prof);
         }
+        PhysicalPlan::RecursiveCteScan(scan) => {
+            let proc_prof = profs.get(&scan.plan_id).copied().unwrap_or_default();
+            let prof = OperatorProfile {
+                id: scan.plan_id,
+                operator_type: OperatorType::CteScan,
+                children: vec![],
+                execution_info: proc_prof.into(),
+                attribute: OperatorAttribute::CteScan(CteScanAttribute {
+                    cte_idx: scan.cte_idx.0,
+                }),
+            };
+            plan_node_profs.push(prof);
+        }
         PhysicalPlan::Filter(filter) => {
             flatten_plan_node_profile(metadata, &filter.input, profs, plan_node_profs)?;
             let proc_prof = profs.get(&filter.plan_id).copied().unwrap_or_default();
@@ -194,6 +207,24 @@ fn flatten_plan_node_profile(
             };
             plan_node_profs.push(prof);
         }
+        PhysicalPlan::AsyncFunction(async_func) => {
+            flatten_plan_node_profile(metadata, &async_func.input, profs, plan_node_profs)?;
+            let proc_prof = profs.get(&async_func.plan_id).copied().unwrap_or_default();
+            let prof = OperatorProfile {
+                id: async_func.plan_id,
+                operator_type: OperatorType::AsyncFunction,
+                execution_info: proc_prof.into(),
+                children: vec![async_func.input.get_id()],
+                attribute: OperatorAttribute::EvalScalar(EvalScalarAttribute {
+                    scalars: async_func
+                        .async_func_descs
+                        .iter()
+                        .map(|desc| desc.display_name.clone())
+                        .join(", "),
+                }),
+            };
+            plan_node_profs.push(prof);
+        }
         PhysicalPlan::AggregateExpand(expand) => {
             flatten_plan_node_profile(metadata, &expand.input, profs, plan_node_profs)?;
             let proc_prof = profs.get(&expand.plan_id).copied().unwrap_or_default();
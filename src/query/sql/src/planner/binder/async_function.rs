@@ -0,0 +1,52 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+
+use crate::optimizer::SExpr;
+use crate::plans::AsyncFunction;
+use crate::plans::AsyncFunctionItem;
+use crate::BindContext;
+use crate::Binder;
+
+#[derive(Default, Clone, PartialEq, Eq, Debug)]
+pub struct AsyncFunctionInfo {
+    /// Async functions, each of them is evaluated once per row,
+    /// so the same function with the same arguments is not deduplicated.
+    pub async_functions: Vec<AsyncFunctionItem>,
+}
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub async fn bind_async_function(
+        &mut self,
+        bind_context: &mut BindContext,
+        child: SExpr,
+    ) -> Result<SExpr> {
+        let async_function_info = &bind_context.async_function_info;
+        if async_function_info.async_functions.is_empty() {
+            return Ok(child);
+        }
+
+        let async_function_plan = AsyncFunction {
+            items: async_function_info.async_functions.clone(),
+        };
+        Ok(SExpr::create_unary(
+            Arc::new(async_function_plan.into()),
+            Arc::new(child),
+        ))
+    }
+}
@@ -39,6 +39,7 @@ use super::INTERNAL_COLUMN_FACTORY;
 use crate::binder::column_binding::ColumnBinding;
 use crate::binder::lambda::LambdaInfo;
 use crate::binder::window::WindowInfo;
+use crate::binder::AsyncFunctionInfo;
 use crate::binder::ColumnBindingBuilder;
 use crate::normalize_identifier;
 use crate::optimizer::SExpr;
@@ -57,6 +58,7 @@ pub enum ExprContext {
     WhereClause,
     GroupClaue,
     HavingClause,
+    QualifyClause,
     OrderByClause,
     LimitClause,
 
@@ -119,6 +121,8 @@ pub struct BindContext {
 
     pub lambda_info: LambdaInfo,
 
+    pub async_function_info: AsyncFunctionInfo,
+
     /// If the `BindContext` is created from a CTE, record the cte name
     pub cte_name: Option<String>,
 
@@ -160,6 +164,10 @@ pub struct CteInfo {
     pub stat_info: Option<Arc<StatInfo>>,
     // If cte is materialized, save it's columns
     pub columns: Vec<ColumnBinding>,
+    pub recursive: bool,
+    // If cte is recursive, save the columns of its non-recursive term while binding the
+    // recursive term, the self reference will be bound to a `RecursiveCteScan` on them.
+    pub recursive_columns: Vec<ColumnBinding>,
 }
 
 impl BindContext {
@@ -171,6 +179,7 @@ impl BindContext {
             aggregate_info: AggregateInfo::default(),
             windows: WindowInfo::default(),
             lambda_info: LambdaInfo::default(),
+            async_function_info: AsyncFunctionInfo::default(),
             cte_name: None,
             cte_map_ref: Box::default(),
             allow_internal_columns: true,
@@ -191,6 +200,7 @@ impl BindContext {
             aggregate_info: Default::default(),
             windows: Default::default(),
             lambda_info: LambdaInfo::default(),
+            async_function_info: AsyncFunctionInfo::default(),
             cte_name: parent.cte_name,
             cte_map_ref: parent.cte_map_ref.clone(),
             allow_internal_columns: parent.allow_internal_columns,
@@ -174,7 +174,7 @@ impl<'a> Binder {
     ) -> Result<Plan> {
         if matches!(
             plan.stage_table_info.stage_info.file_format_params,
-            FileFormatParams::Parquet(_)
+            FileFormatParams::Parquet(_) | FileFormatParams::Orc(_)
         ) {
             let select_list = plan
                 .required_source_schema
@@ -481,6 +481,7 @@ fn check_transform_query(
             if select.group_by.is_none()
                 && !select.distinct
                 && select.having.is_none()
+                && select.qualify.is_none()
                 && select.from.len() == 1
             {
                 if let TableReference::Location {
@@ -21,8 +21,10 @@ mod database;
 mod index;
 mod network_policy;
 mod role;
+mod sequence;
 mod share;
 mod stage;
+mod stream;
 mod table;
 mod task;
 mod view;
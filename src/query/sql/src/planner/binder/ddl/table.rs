@@ -43,8 +43,10 @@ use common_ast::ast::ShowDropTablesStmt;
 use common_ast::ast::ShowLimit;
 use common_ast::ast::ShowTablesStatusStmt;
 use common_ast::ast::ShowTablesStmt;
+use common_ast::ast::SnapshotRefType as AstSnapshotRefType;
 use common_ast::ast::Statement;
 use common_ast::ast::TableReference;
+use common_ast::ast::TimeTravelPoint;
 use common_ast::ast::TruncateTableStmt;
 use common_ast::ast::UndropTableStmt;
 use common_ast::ast::UriLocation;
@@ -54,6 +56,8 @@ use common_ast::parser::parse_sql;
 use common_ast::parser::tokenize_sql;
 use common_ast::walk_expr_mut;
 use common_ast::Dialect;
+use common_catalog::catalog::CATALOG_DEFAULT;
+use common_catalog::table::Table;
 use common_config::GlobalConfig;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -74,12 +78,18 @@ use common_storages_view::view_table::QUERY;
 use common_storages_view::view_table::VIEW_ENGINE;
 use log::debug;
 use log::error;
+use storages_common_table_meta::table::is_internal_opt_key;
 use storages_common_table_meta::table::is_reserved_opt_key;
+use storages_common_table_meta::table::temp_table_storage_prefix;
+use storages_common_table_meta::table::SnapshotRefType;
+use storages_common_table_meta::table::OPT_KEY_COMMENT;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_DATA_URI;
 use storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
+use storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
 
 use crate::binder::location::parse_uri_location;
 use crate::binder::scalar::ScalarBinder;
@@ -98,11 +108,14 @@ use crate::plans::AddColumnOption;
 use crate::plans::AddTableColumnPlan;
 use crate::plans::AlterTableClusterKeyPlan;
 use crate::plans::AnalyzeTablePlan;
+use crate::plans::CloneTableSource;
 use crate::plans::CreateTablePlan;
+use crate::plans::CreateTableSnapshotRefPlan;
 use crate::plans::DescribeTablePlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
 use crate::plans::DropTablePlan;
+use crate::plans::DropTableSnapshotRefPlan;
 use crate::plans::ExistsTablePlan;
 use crate::plans::ModifyColumnAction as ModifyColumnActionInPlan;
 use crate::plans::ModifyTableColumnPlan;
@@ -389,6 +402,7 @@ impl Binder {
             cluster_by,
             as_query,
             transient,
+            temporary,
             engine,
             uri_location,
         } = stmt;
@@ -438,8 +452,103 @@ impl Binder {
             options.insert("TRANSIENT".to_owned(), "T".to_owned());
         }
 
+        // If table is TEMPORARY, its data is kept under the prefix of the current session,
+        // the table itself is only registered in the session, see `SessionCatalog`.
+        if *temporary {
+            if engine != Engine::Fuse {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Temporary table only supports FUSE engine, but got {}",
+                    engine
+                )));
+            }
+            if uri_location.is_some() || *transient {
+                return Err(ErrorCode::SemanticError(
+                    "Temporary table can not be TRANSIENT or created with an external location",
+                ));
+            }
+            if catalog != CATALOG_DEFAULT {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Temporary table can only be created in the default catalog, but got {}",
+                    catalog
+                )));
+            }
+            options.insert(
+                OPT_KEY_TEMP_PREFIX.to_owned(),
+                temp_table_storage_prefix(self.ctx.get_current_session_id()),
+            );
+        }
+
+        // `CREATE TABLE ... CLONE` shares the files of the source table, the new table takes
+        // over the schema, the storage options and the cluster key of the source table.
+        let clone_source = match source {
+            Some(CreateTableSource::Clone {
+                catalog: source_catalog,
+                database: source_database,
+                table: source_table,
+                travel_point,
+            }) => {
+                if engine != Engine::Fuse || uri_location.is_some() || *temporary {
+                    return Err(ErrorCode::SemanticError(
+                        "Cloned table only supports FUSE engine, and can not be TEMPORARY or created with an external location",
+                    ));
+                }
+                let (source_catalog, source_database, source_table) = self
+                    .normalize_object_identifier_triple(
+                        source_catalog,
+                        source_database,
+                        source_table,
+                    );
+                if source_catalog != catalog {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "Can not clone table {source_catalog}.{source_database}.{source_table} into another catalog {catalog}",
+                    )));
+                }
+                let (source_table, clone_from) = self
+                    .resolve_clone_source(
+                        source_catalog,
+                        source_database,
+                        source_table,
+                        travel_point,
+                    )
+                    .await?;
+                for (key, value) in source_table.options() {
+                    if is_reserved_opt_key(key)
+                        || is_internal_opt_key(key)
+                        || key == OPT_KEY_SNAPSHOT_LOCATION
+                        || key == OPT_KEY_COMMENT
+                    {
+                        continue;
+                    }
+                    // blocks of the source table are read with the storage format of the new table
+                    if key == OPT_KEY_STORAGE_FORMAT
+                        && options
+                            .get(key)
+                            .is_some_and(|v| !v.eq_ignore_ascii_case(value))
+                    {
+                        return Err(ErrorCode::TableOptionInvalid(format!(
+                            "table option {key} of a cloned table must be the same as the source table: {value}",
+                        )));
+                    }
+                    options.entry(key.clone()).or_insert_with(|| value.clone());
+                }
+                Some((source_table, clone_from))
+            }
+            _ => None,
+        };
+
         // Build table schema
         let (schema, field_comments) = match (&source, &as_query) {
+            (Some(CreateTableSource::Clone { .. }), None) => {
+                // `CREATE TABLE ... CLONE`, the schema is the one at the cloned point
+                let (source_table, _) = clone_source.as_ref().unwrap();
+                let schema = source_table.schema();
+                let field_comments = if source_table.field_comments().len() == schema.num_fields() {
+                    source_table.field_comments().clone()
+                } else {
+                    vec![]
+                };
+                (schema, field_comments)
+            }
             (Some(source), None) => {
                 // `CREATE TABLE` without `AS SELECT ...`
                 self.analyze_create_table_schema(source).await?
@@ -561,10 +670,16 @@ impl Binder {
             let keys = self
                 .analyze_cluster_keys(cluster_by, schema.clone())
                 .await?;
-            if keys.is_empty() {
-                None
-            } else {
+            if !keys.is_empty() {
                 Some(format!("({})", keys.join(", ")))
+            } else if let Some((source_table, _)) = &clone_source {
+                source_table
+                    .get_table_info()
+                    .meta
+                    .default_cluster_key
+                    .clone()
+            } else {
+                None
             }
         };
 
@@ -593,6 +708,7 @@ impl Binder {
             } else {
                 None
             },
+            clone_from: clone_source.map(|(_, clone_from)| clone_from),
         };
         Ok(Plan::CreateTable(Box::new(plan)))
     }
@@ -662,6 +778,7 @@ impl Binder {
             field_comments: vec![],
             cluster_key: None,
             as_select: None,
+            clone_from: None,
         })))
     }
 
@@ -929,6 +1046,39 @@ impl Binder {
                     table,
                 })))
             }
+            AlterTableAction::CreateSnapshotRef {
+                ref_type,
+                name,
+                travel_point,
+                retain_days,
+            } => {
+                let point = match travel_point {
+                    Some(point) => Some(self.resolve_data_travel_point(bind_context, point).await?),
+                    None => None,
+                };
+                Ok(Plan::CreateTableSnapshotRef(Box::new(
+                    CreateTableSnapshotRefPlan {
+                        tenant,
+                        catalog,
+                        database,
+                        table,
+                        ref_type: resolve_snapshot_ref_type(ref_type),
+                        name: name.clone(),
+                        point,
+                        retain_days: *retain_days,
+                    },
+                )))
+            }
+            AlterTableAction::DropSnapshotRef { ref_type, name } => Ok(Plan::DropTableSnapshotRef(
+                Box::new(DropTableSnapshotRefPlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                    ref_type: resolve_snapshot_ref_type(ref_type),
+                    name: name.clone(),
+                }),
+            )),
         }
     }
 
@@ -1350,7 +1500,52 @@ impl Binder {
                     Ok((table.schema(), table.field_comments().clone()))
                 }
             }
+            CreateTableSource::Clone { .. } => Err(ErrorCode::SemanticError(
+                "Cloned table can not be created with AS SELECT",
+            )),
+        }
+    }
+
+    /// Resolve the source table of `CREATE TABLE ... CLONE`, at the given time travel point.
+    #[async_backtrace::framed]
+    async fn resolve_clone_source(
+        &self,
+        catalog: String,
+        database: String,
+        table: String,
+        travel_point: &Option<TimeTravelPoint>,
+    ) -> Result<(Arc<dyn Table>, CloneTableSource)> {
+        let source_table = self.ctx.get_table(&catalog, &database, &table).await?;
+        let source_info = source_table.get_table_info();
+        if source_table.engine() != "FUSE"
+            || source_info.meta.storage_params.is_some()
+            || source_info.options().contains_key(OPT_KEY_TEMP_PREFIX)
+        {
+            return Err(ErrorCode::SemanticError(format!(
+                "Table {database}.{table} can not be cloned, only FUSE tables without external location can be cloned",
+            )));
         }
+
+        let point = match travel_point {
+            Some(travel_point) => {
+                let mut bind_context = BindContext::new();
+                Some(
+                    self.resolve_data_travel_point(&mut bind_context, travel_point)
+                        .await?,
+                )
+            }
+            None => None,
+        };
+        let source_table = match &point {
+            Some(point) => source_table.navigate_to(point).await?,
+            None => source_table,
+        };
+        Ok((source_table, CloneTableSource {
+            catalog,
+            database,
+            table,
+            point,
+        }))
     }
 
     /// Validate the schema of the table to be created.
@@ -1481,3 +1676,10 @@ impl Binder {
         Ok(not_null)
     }
 }
+
+fn resolve_snapshot_ref_type(ref_type: &AstSnapshotRefType) -> SnapshotRefType {
+    match ref_type {
+        AstSnapshotRefType::Tag => SnapshotRefType::Tag,
+        AstSnapshotRefType::Branch => SnapshotRefType::Branch,
+    }
+}
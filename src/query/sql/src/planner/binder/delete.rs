@@ -132,6 +132,10 @@ impl Binder {
         let filter = Filter {
             predicates: vec![scalar.clone()],
         };
+        // the ttl filter of the table is not applied to the mutation.
+        if table_expr.plan.rel_op() == RelOp::Filter {
+            table_expr = table_expr.child(0)?.clone();
+        }
         debug_assert_eq!(table_expr.plan.rel_op(), RelOp::Scan);
         let mut scan = match &*table_expr.plan {
             Scan(scan) => scan.clone(),
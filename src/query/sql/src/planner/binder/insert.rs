@@ -80,6 +80,7 @@ impl Binder {
             catalog,
             database,
             table,
+            branch,
             columns,
             source,
             overwrite,
@@ -125,6 +126,9 @@ impl Binder {
             InsertSource::Values { rest_str, start } => {
                 let values_str = rest_str.trim_end_matches(';').trim_start().to_owned();
                 match self.ctx.get_stage_attachment() {
+                    Some(_) if branch.is_some() => Err(ErrorCode::Unimplemented(
+                        "Insert into a branch with a stage attachment is not supported",
+                    )),
                     Some(attachment) => {
                         return self
                             .bind_copy_from_attachment(
@@ -163,6 +167,7 @@ impl Binder {
             database: database_name.to_string(),
             table: table_name,
             table_id,
+            branch: branch.clone(),
             schema,
             overwrite: *overwrite,
             source: input_source?,
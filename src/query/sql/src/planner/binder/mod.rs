@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod aggregate;
+mod async_function;
 mod bind_context;
 #[allow(clippy::module_inception)]
 mod binder;
@@ -37,6 +38,8 @@ mod merge_into;
 mod presign;
 mod project;
 mod project_set;
+mod qualify;
+mod recursive_cte;
 mod replace;
 mod scalar;
 mod scalar_common;
@@ -54,6 +57,7 @@ mod values;
 mod window;
 
 pub use aggregate::AggregateInfo;
+pub use async_function::AsyncFunctionInfo;
 pub use bind_context::*;
 pub use binder::Binder;
 pub use builders::*;
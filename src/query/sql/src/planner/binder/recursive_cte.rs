@@ -0,0 +1,221 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_ast::ast::SetExpr;
+use common_ast::ast::SetOperation;
+use common_ast::ast::SetOperator;
+use common_ast::ast::TableAlias;
+use common_ast::ast::TableReference;
+use common_ast::Visitor;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::Span;
+use common_expression::DataField;
+
+use crate::binder::CteInfo;
+use crate::normalize_identifier;
+use crate::optimizer::RelExpr;
+use crate::optimizer::SExpr;
+use crate::plans::RecursiveCteScan;
+use crate::plans::UnionAll;
+use crate::BindContext;
+use crate::Binder;
+use crate::NameResolutionContext;
+
+/// Check if a set expression references the given cte by an unqualified table name.
+struct CteReferenceFinder<'a> {
+    cte_name: &'a str,
+    name_resolution_ctx: &'a NameResolutionContext,
+    found: bool,
+}
+
+impl<'a, 'ast> Visitor<'ast> for CteReferenceFinder<'a> {
+    fn visit_table_reference(&mut self, table: &'ast TableReference) {
+        if let TableReference::Table {
+            catalog: None,
+            database: None,
+            table,
+            ..
+        } = table
+        {
+            if normalize_identifier(table, self.name_resolution_ctx).name == self.cte_name {
+                self.found = true;
+            }
+        }
+        common_ast::walk_table_reference(self, table);
+    }
+}
+
+impl Binder {
+    /// Returns the union of a recursive cte if the cte is in form of
+    /// `<non-recursive term> UNION [ALL] <recursive term>` and the recursive term references itself.
+    pub(crate) fn recursive_cte_body<'a>(
+        &self,
+        table_name: &str,
+        cte_info: &'a CteInfo,
+    ) -> Option<&'a SetOperation> {
+        if !cte_info.recursive {
+            return None;
+        }
+        match &cte_info.query.body {
+            SetExpr::SetOperation(set_operation) if set_operation.op == SetOperator::Union => {
+                let mut finder = CteReferenceFinder {
+                    cte_name: table_name,
+                    name_resolution_ctx: &self.name_resolution_ctx,
+                    found: false,
+                };
+                finder.visit_set_expr(&set_operation.right);
+                finder.found.then_some(set_operation.as_ref())
+            }
+            _ => None,
+        }
+    }
+
+    /// Bind the body of a recursive cte to a `UnionAll` whose right child
+    /// will be evaluated repeatedly on the rows produced by its last iteration.
+    #[async_backtrace::framed]
+    pub(crate) async fn bind_recursive_cte(
+        &mut self,
+        span: Span,
+        bind_context: &mut BindContext,
+        table_name: &str,
+        cte_info: &CteInfo,
+        set_operation: &SetOperation,
+    ) -> Result<(SExpr, BindContext)> {
+        let query = &cte_info.query;
+        if !query.order_by.is_empty() || !query.limit.is_empty() || query.offset.is_some() {
+            return Err(ErrorCode::SemanticError(format!(
+                "ORDER BY/LIMIT/OFFSET in recursive cte `{table_name}` is not supported"
+            ))
+            .set_span(span));
+        }
+        if !set_operation.all {
+            return Err(ErrorCode::Unimplemented(format!(
+                "Recursive cte `{table_name}` only supports UNION ALL"
+            ))
+            .set_span(span));
+        }
+
+        // 1. Bind the non-recursive term, its output is the schema of the working table.
+        let (left_expr, left_bind_context) = self
+            .bind_set_expr(bind_context, &set_operation.left, &[], 0)
+            .await?;
+        if left_bind_context.columns.len() < cte_info.columns_alias.len() {
+            return Err(ErrorCode::SemanticError(format!(
+                "table has {} columns available but {} columns specified",
+                left_bind_context.columns.len(),
+                cte_info.columns_alias.len()
+            ))
+            .set_span(span));
+        }
+        let stat_info = RelExpr::with_s_expr(&left_expr).derive_cardinality()?;
+        self.ctes_map
+            .entry(table_name.to_string())
+            .and_modify(|cte_info| {
+                cte_info.recursive_columns = left_bind_context.columns.clone();
+                cte_info.stat_info = Some(stat_info);
+            });
+
+        // 2. Bind the recursive term, the self reference will be bound to `RecursiveCteScan`.
+        let right_result = self
+            .bind_set_expr(bind_context, &set_operation.right, &[], 0)
+            .await;
+        self.ctes_map
+            .entry(table_name.to_string())
+            .and_modify(|cte_info| {
+                cte_info.recursive_columns.clear();
+                cte_info.stat_info = None;
+            });
+        let (right_expr, right_bind_context) = right_result?;
+
+        if left_bind_context.columns.len() != right_bind_context.columns.len() {
+            return Err(ErrorCode::SemanticError(
+                "SetOperation must have the same number of columns",
+            ));
+        }
+
+        // 3. The recursive term is casted to the types of the non-recursive term,
+        // so that the working table keeps the same schema in every iteration.
+        let working_table_idx = (cte_info.cte_idx, left_bind_context.columns[0].index);
+        let coercion_types = left_bind_context
+            .columns
+            .iter()
+            .map(|column| *column.data_type.clone())
+            .collect();
+        let (new_bind_context, pairs, left_expr, right_expr) = self.coercion_union_type(
+            set_operation.left.span(),
+            set_operation.right.span(),
+            left_bind_context,
+            right_bind_context,
+            left_expr,
+            right_expr,
+            coercion_types,
+        )?;
+
+        let union_plan = UnionAll {
+            pairs,
+            recursive_cte: Some(working_table_idx),
+        };
+        let s_expr = SExpr::create_binary(
+            Arc::new(union_plan.into()),
+            Arc::new(left_expr),
+            Arc::new(right_expr),
+        );
+        Ok((s_expr, new_bind_context))
+    }
+
+    /// Bind the self reference in the recursive term of a recursive cte.
+    pub(crate) fn bind_recursive_cte_scan(
+        &mut self,
+        bind_context: &BindContext,
+        table_name: &str,
+        alias: &Option<TableAlias>,
+        cte_info: &CteInfo,
+    ) -> Result<(SExpr, BindContext)> {
+        let mut new_bind_context = BindContext::with_parent(Box::new(bind_context.clone()));
+        let mut fields = Vec::with_capacity(cte_info.recursive_columns.len());
+        for (idx, column) in cte_info.recursive_columns.iter().enumerate() {
+            let column_name = cte_info
+                .columns_alias
+                .get(idx)
+                .cloned()
+                .unwrap_or_else(|| column.column_name.clone());
+            let mut column_binding =
+                self.create_derived_column_binding(column_name, *column.data_type.clone());
+            column_binding.table_name = Some(table_name.to_string());
+            fields.push(DataField::new(
+                &column_binding.index.to_string(),
+                *column_binding.data_type.clone(),
+            ));
+            new_bind_context.add_column_binding(column_binding);
+        }
+        if let Some(alias) = alias {
+            new_bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
+        }
+
+        let working_table_idx = (cte_info.cte_idx, cte_info.recursive_columns[0].index);
+        let s_expr = SExpr::create_leaf(Arc::new(
+            RecursiveCteScan {
+                cte_idx: working_table_idx,
+                fields,
+                // It is safe to unwrap here because the stat info is derived along with `recursive_columns`.
+                stat: cte_info.stat_info.clone().unwrap(),
+            }
+            .into(),
+        ));
+        Ok((s_expr, new_bind_context))
+    }
+}
@@ -213,6 +213,15 @@ impl Binder {
             None
         };
 
+        let qualify = if let Some(qualify) = &stmt.qualify {
+            Some(
+                self.analyze_window_qualify(&mut from_context, &aliases, qualify)
+                    .await?,
+            )
+        } else {
+            None
+        };
+
         let order_items = self
             .analyze_order_items(
                 &mut from_context,
@@ -238,6 +247,10 @@ impl Binder {
             )?;
         }
 
+        if !from_context.async_function_info.async_functions.is_empty() {
+            s_expr = self.bind_async_function(&mut from_context, s_expr).await?;
+        }
+
         if !from_context.lambda_info.lambda_functions.is_empty() {
             s_expr = self.bind_lambda(&mut from_context, s_expr).await?;
         }
@@ -260,6 +273,13 @@ impl Binder {
             s_expr = self.bind_window_function(window_info, s_expr).await?;
         }
 
+        // QUALIFY filters the results of window functions.
+        if let Some((qualify, span)) = qualify {
+            s_expr = self
+                .bind_qualify(&mut from_context, qualify, span, s_expr)
+                .await?;
+        }
+
         if stmt.distinct {
             s_expr = self.bind_distinct(
                 stmt.span,
@@ -352,6 +372,8 @@ impl Binder {
                     used_count: 0,
                     stat_info: None,
                     columns: vec![],
+                    recursive: with.recursive,
+                    recursive_columns: vec![],
                 };
                 self.ctes_map.insert(table_name.clone(), cte_info.clone());
                 bind_context.cte_map_ref.insert(table_name, cte_info);
@@ -560,7 +582,10 @@ impl Binder {
             coercion_types,
         )?;
 
-        let union_plan = UnionAll { pairs };
+        let union_plan = UnionAll {
+            pairs,
+            recursive_cte: None,
+        };
         let mut new_expr = SExpr::create_binary(
             Arc::new(union_plan.into()),
             Arc::new(left_expr),
@@ -673,7 +698,7 @@ impl Binder {
 
     #[allow(clippy::type_complexity)]
     #[allow(clippy::too_many_arguments)]
-    fn coercion_union_type(
+    pub(crate) fn coercion_union_type(
         &self,
         left_span: Span,
         right_span: Span,
@@ -796,6 +821,7 @@ impl Binder {
         // SELECT ... FROM t WHERE ... ORDER BY ... LIMIT ...
         if stmt.group_by.is_some()
             || stmt.having.is_some()
+            || stmt.qualify.is_some()
             || stmt.distinct
             || !bind_context.aggregate_info.group_items.is_empty()
             || !bind_context.aggregate_info.aggregate_functions.is_empty()
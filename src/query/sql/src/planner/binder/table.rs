@@ -36,6 +36,7 @@ use common_ast::ast::TableAlias;
 use common_ast::ast::TableReference;
 use common_ast::ast::TimeTravelPoint;
 use common_ast::ast::UriLocation;
+use common_ast::parser::parse_expr;
 use common_ast::parser::parse_sql;
 use common_ast::parser::tokenize_sql;
 use common_ast::Dialect;
@@ -67,6 +68,8 @@ use common_meta_app::principal::StageInfo;
 use common_meta_app::schema::IndexMeta;
 use common_meta_app::schema::ListIndexesReq;
 use common_meta_types::MetaId;
+use common_pipeline_sources::input_formats::read_avro_schema_async;
+use common_pipeline_sources::input_formats::read_orc_schema_async;
 use common_storage::DataOperator;
 use common_storage::StageFileInfo;
 use common_storage::StageFilesInfo;
@@ -81,6 +84,7 @@ use common_users::UserApiProvider;
 use dashmap::DashMap;
 use log::info;
 use parking_lot::RwLock;
+use storages_common_table_meta::table::OPT_KEY_TTL;
 
 use crate::binder::copy_into_table::resolve_file_location;
 use crate::binder::scalar::ScalarBinder;
@@ -95,6 +99,7 @@ use crate::optimizer::SExpr;
 use crate::planner::semantic::normalize_identifier;
 use crate::planner::semantic::TypeChecker;
 use crate::plans::CteScan;
+use crate::plans::Filter;
 use crate::plans::Scan;
 use crate::plans::Statistics;
 use crate::BaseTableColumn;
@@ -202,6 +207,9 @@ impl Binder {
                     self.bind_m_cte(bind_context, cte_info, &table_name, alias, span)
                         .await
                 };
+            } else if !cte_info.recursive_columns.is_empty() {
+                // Self reference in the recursive term of a recursive cte
+                return self.bind_recursive_cte_scan(bind_context, &table_name, alias, cte_info);
             }
         }
 
@@ -293,6 +301,7 @@ impl Binder {
                 }
             }
             _ => {
+                let ttl = table_meta.options().get(OPT_KEY_TTL).cloned();
                 let table_index = self.metadata.write().add_table(
                     catalog,
                     database.clone(),
@@ -302,9 +311,16 @@ impl Binder {
                     bind_context.planning_agg_index,
                 );
 
-                let (s_expr, mut bind_context) = self
+                let (mut s_expr, mut bind_context) = self
                     .bind_base_table(bind_context, database.as_str(), table_index)
                     .await?;
+                if let Some(ttl) = ttl {
+                    if !bind_context.planning_agg_index {
+                        s_expr = self
+                            .bind_ttl_filter(&mut bind_context, &ttl, s_expr)
+                            .await?;
+                    }
+                }
                 if let Some(alias) = alias {
                     bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
                 }
@@ -427,6 +443,7 @@ impl Binder {
                 group_by: None,
                 having: None,
                 window_list: None,
+                qualify: None,
             };
             self.bind_select_stmt(&mut bind_context, &stmt, &[], 0)
                 .await
@@ -658,6 +675,31 @@ impl Binder {
                 };
                 StageTable::try_create(info)?
             }
+            FileFormatParams::Orc(..) | FileFormatParams::Avro(..) => {
+                let operator = StageTable::get_op(&stage_info)?;
+                let first_file = match &files_to_copy {
+                    Some(files) => files
+                        .first()
+                        .cloned()
+                        .ok_or_else(|| ErrorCode::BadArguments("no file found"))?,
+                    None => files_info.first_file(&operator).await?,
+                };
+                let schema = match &stage_info.file_format_params {
+                    FileFormatParams::Orc(..) => {
+                        read_orc_schema_async(&operator, &first_file.path).await?
+                    }
+                    _ => read_avro_schema_async(&operator, &first_file.path).await?,
+                };
+                let info = StageTableInfo {
+                    schema: Arc::new(schema),
+                    stage_info,
+                    files_info,
+                    files_to_copy,
+                    is_select: true,
+                    default_values: None,
+                };
+                StageTable::try_create(info)?
+            }
             FileFormatParams::Csv(..) | FileFormatParams::Tsv(..) => {
                 let max_column_position = self.metadata.read().get_max_column_position();
                 if max_column_position == 0 {
@@ -687,7 +729,7 @@ impl Binder {
             }
             _ => {
                 return Err(ErrorCode::Unimplemented(
-                    "query stage files only support parquet/NDJson/CSV/TSV format for now",
+                    "query stage files only support parquet/ORC/Avro/NDJson/CSV/TSV format for now",
                 ));
             }
         };
@@ -835,6 +877,7 @@ impl Binder {
             aggregate_info: Default::default(),
             windows: Default::default(),
             lambda_info: Default::default(),
+            async_function_info: Default::default(),
             cte_name: Some(table_name.to_string()),
             cte_map_ref: Box::default(),
             in_grouping: false,
@@ -846,9 +889,20 @@ impl Binder {
             window_definitions: DashMap::new(),
         };
 
-        let (s_expr, mut res_bind_context) = self
-            .bind_query(&mut new_bind_context, &cte_info.query)
-            .await?;
+        let (s_expr, mut res_bind_context) =
+            if let Some(set_operation) = self.recursive_cte_body(table_name, cte_info) {
+                self.bind_recursive_cte(
+                    span,
+                    &mut new_bind_context,
+                    table_name,
+                    cte_info,
+                    set_operation,
+                )
+                .await?
+            } else {
+                self.bind_query(&mut new_bind_context, &cte_info.query)
+                    .await?
+            };
         let mut cols_alias = cte_info.columns_alias.clone();
         if let Some(alias) = alias {
             for (idx, col_alias) in alias.columns.iter().enumerate() {
@@ -1009,6 +1063,34 @@ impl Binder {
         ))
     }
 
+    /// Filter out the rows expired by the row-level TTL of the table.
+    async fn bind_ttl_filter(
+        &mut self,
+        bind_context: &mut BindContext,
+        ttl: &str,
+        child: SExpr,
+    ) -> Result<SExpr> {
+        let tokens = tokenize_sql(&format!("NOT is_true(({ttl}) <= now())"))?;
+        let expr = parse_expr(&tokens, Dialect::PostgreSQL)?;
+        let mut scalar_binder = ScalarBinder::new(
+            bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+            self.m_cte_bound_ctx.clone(),
+            self.ctes_map.clone(),
+        );
+        let (predicate, _) = scalar_binder.bind(&expr).await?;
+        let filter = Filter {
+            predicates: vec![predicate],
+        };
+        Ok(SExpr::create_unary(
+            Arc::new(filter.into()),
+            Arc::new(child),
+        ))
+    }
+
     #[async_backtrace::framed]
     pub async fn resolve_data_source(
         &self,
@@ -1041,6 +1123,8 @@ impl Binder {
     ) -> Result<NavigationPoint> {
         match travel_point {
             TimeTravelPoint::Snapshot(s) => Ok(NavigationPoint::SnapshotID(s.to_owned())),
+            TimeTravelPoint::Tag(name) => Ok(NavigationPoint::Tag(name.to_owned())),
+            TimeTravelPoint::Branch(name) => Ok(NavigationPoint::Branch(name.to_owned())),
             TimeTravelPoint::Timestamp(expr) => {
                 let mut type_checker = TypeChecker::new(
                     bind_context,
@@ -60,6 +60,7 @@ impl Binder {
             partition_by: window_info.partition_by_items.clone(),
             order_by: window_info.order_by_items.clone(),
             frame: window_info.frame.clone(),
+            limit: None,
         };
 
         Ok(SExpr::create_unary(
@@ -302,6 +303,15 @@ impl<'a> WindowRewriter<'a> {
             }
 
             ScalarExpr::WindowFunction(window) => {
+                // The window function has been rewritten, e.g. it's referenced by an alias in `QUALIFY`.
+                if self
+                    .bind_context
+                    .windows
+                    .window_functions_map
+                    .contains_key(&window.display_name)
+                {
+                    return Ok(scalar.clone());
+                }
                 self.in_window = true;
                 let scalar = self.replace_window_function(window)?;
                 self.in_window = false;
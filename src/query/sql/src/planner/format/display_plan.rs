@@ -104,6 +104,8 @@ impl Plan {
             Plan::CreateIndex(index) => Ok(format!("{:?}", index)),
             Plan::DropIndex(index) => Ok(format!("{:?}", index)),
             Plan::RefreshIndex(index) => Ok(format!("{index:?}")),
+            Plan::CreateInvertedIndex(index) => Ok(format!("{index:?}")),
+            Plan::DropInvertedIndex(index) => Ok(format!("{index:?}")),
 
             // Virtual Columns
             Plan::CreateVirtualColumn(create_virtual_column) => {
@@ -172,6 +174,8 @@ impl Plan {
             Plan::ShowObjectGrantPrivileges(p) => Ok(format!("{:?}", p)),
             Plan::ShowGrantTenantsOfShare(p) => Ok(format!("{:?}", p)),
             Plan::RevertTable(p) => Ok(format!("{:?}", p)),
+            Plan::CreateTableSnapshotRef(p) => Ok(format!("{:?}", p)),
+            Plan::DropTableSnapshotRef(p) => Ok(format!("{:?}", p)),
 
             // data mask
             Plan::CreateDatamaskPolicy(p) => Ok(format!("{:?}", p)),
@@ -198,6 +202,15 @@ impl Plan {
             Plan::DescConnection(p) => Ok(format!("{:?}", p)),
             Plan::DropConnection(p) => Ok(format!("{:?}", p)),
             Plan::ShowConnections(p) => Ok(format!("{:?}", p)),
+            Plan::CreateSequence(p) => Ok(format!("{:?}", p)),
+            Plan::DropSequence(p) => Ok(format!("{:?}", p)),
+            Plan::ShowSequences(p) => Ok(format!("{:?}", p)),
+            Plan::CreateStream(p) => Ok(format!("{:?}", p)),
+            Plan::DropStream(p) => Ok(format!("{:?}", p)),
+
+            Plan::Begin => Ok("Begin".to_string()),
+            Plan::Commit => Ok("Commit".to_string()),
+            Plan::Rollback => Ok("Rollback".to_string()),
         }
     }
 }
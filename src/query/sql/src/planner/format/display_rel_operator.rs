@@ -81,6 +81,8 @@ impl Display for FormatContext {
                 RelOperator::Lambda(_) => write!(f, "Lambda"),
                 RelOperator::ConstantTableScan(_) => write!(f, "ConstantTableScan"),
                 RelOperator::AddRowNumber(_) => write!(f, "AddRowNumber"),
+                RelOperator::RecursiveCteScan(_) => write!(f, "RecursiveCteScan"),
+                RelOperator::AsyncFunction(_) => write!(f, "AsyncFunction"),
             },
             Self::Text(text) => write!(f, "{}", text),
         }
@@ -54,6 +54,8 @@ pub fn display_rel_op(rel_op: &RelOperator) -> String {
         RelOperator::Lambda(_) => "LambdaFunc".to_string(),
         RelOperator::ConstantTableScan(_) => "ConstantTableScan".to_string(),
         RelOperator::AddRowNumber(_) => "AddRowNumber".to_string(),
+        RelOperator::RecursiveCteScan(_) => "RecursiveCteScan".to_string(),
+        RelOperator::AsyncFunction(_) => "AsyncFunction".to_string(),
     }
 }
 
@@ -160,6 +160,7 @@ impl DPhpy {
                     RelOperator::EvalScalar(_)
                         | RelOperator::Aggregate(_)
                         | RelOperator::Lambda(_)
+                        | RelOperator::AsyncFunction(_)
                         | RelOperator::Sort(_)
                         | RelOperator::Limit(_)
                         | RelOperator::ProjectSet(_)
@@ -172,6 +173,7 @@ impl DPhpy {
                     RelOperator::EvalScalar(_)
                         | RelOperator::Aggregate(_)
                         | RelOperator::Lambda(_)
+                        | RelOperator::AsyncFunction(_)
                         | RelOperator::Sort(_)
                         | RelOperator::Limit(_)
                         | RelOperator::ProjectSet(_)
@@ -216,6 +218,7 @@ impl DPhpy {
             RelOperator::ProjectSet(_)
             | RelOperator::Aggregate(_)
             | RelOperator::Lambda(_)
+            | RelOperator::AsyncFunction(_)
             | RelOperator::Sort(_)
             | RelOperator::Limit(_)
             | RelOperator::EvalScalar(_)
@@ -259,6 +262,7 @@ impl DPhpy {
             RelOperator::DummyTableScan(_)
             | RelOperator::ConstantTableScan(_)
             | RelOperator::CteScan(_)
+            | RelOperator::RecursiveCteScan(_)
             | RelOperator::MaterializedCte(_) => Ok((s_expr, true)),
         }
     }
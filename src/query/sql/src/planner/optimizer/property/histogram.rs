@@ -18,7 +18,9 @@ use std::fmt::Debug;
 
 use common_exception::Result;
 use common_expression::arithmetics_type::ResultTypeOfUnary;
+use common_expression::Scalar;
 use common_storage::Datum;
+use storages_common_table_meta::meta::ColumnHistogram;
 
 pub const DEFAULT_HISTOGRAM_BUCKETS: usize = 100;
 
@@ -33,18 +35,27 @@ pub const DEFAULT_HISTOGRAM_BUCKETS: usize = 100;
 /// it is difficult to give the exact frequency of the skew data
 /// when the skew data and other data fall into the same bucket
 ///
-/// We choose this approach because so far the histogram is originally
-/// constructed from NDV(number of distinct values) and the total number
-/// of rows instead of maintaining a real histogram for each column,
-/// which brings the assumption that the data is uniformly distributed.
+/// The histogram is collected by `ANALYZE TABLE` from sampled blocks if
+/// available, otherwise it is constructed from NDV(number of distinct values)
+/// and the total number of rows, which brings the assumption that the data
+/// is uniformly distributed.
 #[derive(Debug, Clone)]
 pub struct Histogram {
     pub buckets: Vec<HistogramBucket>,
+    /// Most common values and their number of rows, only available
+    /// if the histogram is collected from the real data.
+    pub most_common_values: Vec<(Datum, f64)>,
+    /// Whether the histogram is collected from the real data.
+    pub collected: bool,
 }
 
 impl Histogram {
     pub fn new(buckets: Vec<HistogramBucket>) -> Self {
-        Self { buckets }
+        Self {
+            buckets,
+            most_common_values: vec![],
+            collected: false,
+        }
     }
 
     /// Get number of buckets
@@ -95,7 +106,7 @@ pub fn histogram_from_ndv(
                 ndv, num_rows
             ))
         } else {
-            Ok(Histogram { buckets: vec![] })
+            Ok(Histogram::new(vec![]))
         };
     }
 
@@ -150,7 +161,53 @@ pub fn histogram_from_ndv(
         buckets.push(bucket);
     }
 
-    Ok(Histogram { buckets })
+    Ok(Histogram::new(buckets))
+}
+
+/// Construct a histogram from the one collected by `ANALYZE TABLE`.
+///
+/// The table may have changed since the histogram was collected, so the
+/// number of values is scaled to the current number of (non-null) rows.
+pub fn histogram_from_collected(
+    collected: &ColumnHistogram,
+    num_rows: u64,
+) -> Result<Histogram, String> {
+    let collected_rows = collected.num_values();
+    if collected_rows <= 0.0 {
+        return Err("Collected histogram is empty".to_string());
+    }
+    let scale = num_rows as f64 / collected_rows;
+    let to_datum = |scalar: &Scalar| {
+        Datum::from_scalar(scalar.clone())
+            .ok_or_else(|| format!("Unsupported scalar in histogram: {:?}", scalar))
+    };
+
+    let mut buckets = Vec::with_capacity(collected.buckets.len() + 1);
+    // The first bucket is a dummy bucket which records the min value of the column,
+    // same as the histogram constructed from NDV.
+    buckets.push(HistogramBucket {
+        upper_bound: to_datum(&collected.lower_bound)?,
+        num_values: 0.0,
+        num_distinct: 0.0,
+    });
+    for bucket in collected.buckets.iter() {
+        buckets.push(HistogramBucket {
+            upper_bound: to_datum(&bucket.upper_bound)?,
+            num_values: bucket.num_values * scale,
+            num_distinct: bucket.num_distinct,
+        });
+    }
+    let most_common_values = collected
+        .most_common_values
+        .iter()
+        .map(|mcv| Ok((to_datum(&mcv.value)?, mcv.num_values * scale)))
+        .collect::<Result<Vec<_>, String>>()?;
+
+    Ok(Histogram {
+        buckets,
+        most_common_values,
+        collected: true,
+    })
 }
 
 #[derive(Debug, Clone)]
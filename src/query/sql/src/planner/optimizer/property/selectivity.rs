@@ -26,6 +26,7 @@ use common_storage::F64;
 
 use crate::optimizer::histogram_from_ndv;
 use crate::optimizer::ColumnStat;
+use crate::optimizer::Histogram;
 use crate::optimizer::Statistics;
 use crate::optimizer::DEFAULT_HISTOGRAM_BUCKETS;
 use crate::plans::ComparisonOp;
@@ -132,9 +133,10 @@ impl<'a> SelectivityEstimator<'a> {
 
             return match op {
                 ComparisonOp::Equal => {
-                    // For equal predicate, we just use cardinality of a single
-                    // value to estimate the selectivity. This assumes that
-                    // the column is in a uniform distribution.
+                    // For equal predicate, we use the frequency of the value in
+                    // the collected histogram if there is one, otherwise we just
+                    // use cardinality of a single value to estimate the selectivity,
+                    // which assumes that the column is in a uniform distribution.
                     let selectivity = evaluate_equal(column_stat, constant);
                     if update {
                         update_statistic(
@@ -354,6 +356,13 @@ fn compare_equal(datum: &Option<Datum>, column_stat: &ColumnStat) -> f64 {
                 return 0.0;
             }
         }
+        if let Some(histogram) = &column_stat.histogram {
+            if histogram.collected {
+                if let Some(selectivity) = evaluate_equal_by_histogram(histogram, constant_datum) {
+                    return selectivity;
+                }
+            }
+        }
     }
 
     if column_stat.ndv == 0.0 {
@@ -363,6 +372,36 @@ fn compare_equal(datum: &Option<Datum>, column_stat: &ColumnStat) -> f64 {
     }
 }
 
+// Estimate the selectivity of an equal predicate by the histogram collected from the real data.
+fn evaluate_equal_by_histogram(histogram: &Histogram, datum: &Datum) -> Option<f64> {
+    let num_values = histogram.num_values();
+    if num_values <= 0.0 {
+        return None;
+    }
+    for (value, count) in histogram.most_common_values.iter() {
+        if value.type_comparable(datum) && value.compare(datum).ok()? == Ordering::Equal {
+            return Some((count / num_values).min(MAX_SELECTIVITY));
+        }
+    }
+    // The value is not a common one, assume the values are uniformly
+    // distributed within the bucket it falls in. The first bucket is
+    // a dummy one which only records the min value of the column.
+    for bucket in histogram.buckets_iter().skip(1) {
+        if !bucket.upper_bound().type_comparable(datum) {
+            return None;
+        }
+        if bucket.upper_bound().compare(datum).ok()? != Ordering::Less {
+            if bucket.num_distinct() <= 0.0 {
+                return None;
+            }
+            let selectivity = bucket.num_values() / bucket.num_distinct() / num_values;
+            return Some(selectivity.min(MAX_SELECTIVITY));
+        }
+    }
+    // Greater than the max value of the column.
+    Some(0.0)
+}
+
 fn update_statistic(
     column_stat: &mut ColumnStat,
     mut new_min: Datum,
@@ -388,12 +427,14 @@ fn update_statistic(
             column_stat.histogram = None;
             return Ok(());
         }
-        column_stat.histogram = Some(histogram_from_ndv(
+        // The histogram of string column can't be constructed from NDV.
+        column_stat.histogram = histogram_from_ndv(
             new_ndv,
             max(new_num_values, new_ndv),
             Some((new_min, new_max)),
             DEFAULT_HISTOGRAM_BUCKETS,
-        )?);
+        )
+        .ok();
     }
     Ok(())
 }
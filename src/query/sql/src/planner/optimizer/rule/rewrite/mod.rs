@@ -31,6 +31,7 @@ mod rule_push_down_filter_project_set;
 mod rule_push_down_filter_scan;
 mod rule_push_down_filter_sort;
 mod rule_push_down_filter_union;
+mod rule_push_down_filter_window;
 mod rule_push_down_limit_aggregate;
 mod rule_push_down_limit_expression;
 mod rule_push_down_limit_join;
@@ -60,6 +61,7 @@ pub use rule_push_down_filter_project_set::RulePushDownFilterProjectSet;
 pub use rule_push_down_filter_scan::RulePushDownFilterScan;
 pub use rule_push_down_filter_sort::RulePushDownFilterSort;
 pub use rule_push_down_filter_union::RulePushDownFilterUnion;
+pub use rule_push_down_filter_window::RulePushDownFilterWindow;
 pub use rule_push_down_limit_aggregate::RulePushDownLimitAggregate;
 pub use rule_push_down_limit_expression::RulePushDownLimitExpression;
 pub use rule_push_down_limit_join::RulePushDownLimitOuterJoin;
@@ -103,6 +103,11 @@ impl Rule for RulePushDownFilterUnion {
         let filter: Filter = s_expr.plan().clone().try_into()?;
         let union_s_expr = s_expr.child(0)?;
         let union: UnionAll = union_s_expr.plan().clone().try_into()?;
+        if union.recursive_cte.is_some() {
+            // The right child of a recursive union reads the rows produced by itself,
+            // filtering them will change the result of the recursion.
+            return Ok(());
+        }
 
         // Create a filter which matches union's right child.
         let index_pairs: HashMap<IndexType, IndexType> =
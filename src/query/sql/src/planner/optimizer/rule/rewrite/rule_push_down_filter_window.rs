@@ -0,0 +1,168 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+
+use crate::optimizer::rule::constant::check_uint_range;
+use crate::optimizer::rule::constant::remove_trivial_type_cast;
+use crate::optimizer::rule::Rule;
+use crate::optimizer::rule::TransformResult;
+use crate::optimizer::RuleID;
+use crate::optimizer::SExpr;
+use crate::plans::ComparisonOp;
+use crate::plans::Filter;
+use crate::plans::PatternPlan;
+use crate::plans::RelOp;
+use crate::plans::RelOp::Pattern;
+use crate::plans::RelOperator;
+use crate::plans::ScalarExpr;
+use crate::plans::Window;
+use crate::plans::WindowFuncType;
+use crate::IndexType;
+
+/// Input:  Filter
+///           \
+///          Window(row_number/rank)
+///             \
+///              *
+///
+/// Output: Filter
+///           \
+///          Window(padding limit)
+///             \
+///              *
+///
+/// Filters like `row_number() over (partition by a order by b) <= N` only need
+/// the top N rows of each partition, so the window can drop the other rows
+/// before sorting.
+pub struct RulePushDownFilterWindow {
+    id: RuleID,
+    patterns: Vec<SExpr>,
+}
+
+impl RulePushDownFilterWindow {
+    pub fn new() -> Self {
+        Self {
+            id: RuleID::PushDownFilterWindow,
+            patterns: vec![SExpr::create_unary(
+                Arc::new(
+                    PatternPlan {
+                        plan_type: RelOp::Filter,
+                    }
+                    .into(),
+                ),
+                Arc::new(SExpr::create_unary(
+                    Arc::new(
+                        PatternPlan {
+                            plan_type: RelOp::Window,
+                        }
+                        .into(),
+                    ),
+                    Arc::new(SExpr::create_leaf(Arc::new(
+                        PatternPlan { plan_type: Pattern }.into(),
+                    ))),
+                )),
+            )],
+        }
+    }
+}
+
+impl Rule for RulePushDownFilterWindow {
+    fn id(&self) -> RuleID {
+        self.id
+    }
+
+    fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
+        let filter: Filter = s_expr.plan().clone().try_into()?;
+        let window_expr = s_expr.child(0)?;
+        let mut window: Window = window_expr.plan().clone().try_into()?;
+
+        if !matches!(
+            window.function,
+            WindowFuncType::RowNumber | WindowFuncType::Rank
+        ) || (window.partition_by.is_empty() && window.order_by.is_empty())
+        {
+            return Ok(());
+        }
+
+        let limit = filter
+            .predicates
+            .iter()
+            .filter_map(|predicate| extract_window_limit(predicate, window.index))
+            .min();
+        let limit = match (limit, window.limit) {
+            (Some(limit), Some(old)) if limit < old => limit,
+            (Some(limit), None) => limit,
+            _ => return Ok(()),
+        };
+        window.limit = Some(limit);
+
+        let window_expr = SExpr::create_unary(
+            Arc::new(RelOperator::Window(window)),
+            Arc::new(window_expr.child(0)?.clone()),
+        );
+        let mut result = s_expr.replace_children(vec![Arc::new(window_expr)]);
+        result.set_applied_rule(&self.id);
+        state.add_result(result);
+        Ok(())
+    }
+
+    fn patterns(&self) -> &Vec<SExpr> {
+        &self.patterns
+    }
+}
+
+/// Extract the number of rows required in each partition from predicates
+/// like `window_column = N`, `window_column <= N` or `window_column < N`.
+fn extract_window_limit(predicate: &ScalarExpr, window_index: IndexType) -> Option<usize> {
+    let ScalarExpr::FunctionCall(func) = predicate else {
+        return None;
+    };
+    let op = ComparisonOp::try_from_func_name(&func.func_name)?;
+    if func.arguments.len() != 2 {
+        return None;
+    }
+    let (left, right) =
+        remove_trivial_type_cast(func.arguments[0].clone(), func.arguments[1].clone());
+    let (op, value) = match (&left, &right) {
+        (ScalarExpr::BoundColumnRef(col), ScalarExpr::ConstantExpr(constant))
+            if col.column.index == window_index =>
+        {
+            (op, &constant.value)
+        }
+        (ScalarExpr::ConstantExpr(constant), ScalarExpr::BoundColumnRef(col))
+            if col.column.index == window_index =>
+        {
+            (op.reverse(), &constant.value)
+        }
+        _ => return None,
+    };
+
+    let (is_valid, value) = check_uint_range(usize::MAX as u64, value);
+    if !is_valid {
+        return None;
+    }
+    let limit = match op {
+        ComparisonOp::Equal | ComparisonOp::LTE => value as usize,
+        ComparisonOp::LT => (value as usize).checked_sub(1)?,
+        _ => return None,
+    };
+    // Window functions are 1-based, the filter will drop all rows.
+    if limit == 0 {
+        return None;
+    }
+    Some(limit)
+}
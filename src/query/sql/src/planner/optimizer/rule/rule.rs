@@ -52,6 +52,7 @@ pub enum RuleID {
     PushDownFilterScan,
     PushDownFilterSort,
     PushDownFilterProjectSet,
+    PushDownFilterWindow,
     PushDownLimitUnion,
     PushDownLimitOuterJoin,
     PushDownLimitExpression,
@@ -84,6 +85,7 @@ impl Display for RuleID {
             RuleID::PushDownFilterScan => write!(f, "PushDownFilterScan"),
             RuleID::PushDownFilterSort => write!(f, "PushDownFilterSort"),
             RuleID::PushDownFilterProjectSet => write!(f, "PushDownFilterProjectSet"),
+            RuleID::PushDownFilterWindow => write!(f, "PushDownFilterWindow"),
             RuleID::PushDownLimitUnion => write!(f, "PushDownLimitUnion"),
             RuleID::PushDownLimitOuterJoin => write!(f, "PushDownLimitOuterJoin"),
             RuleID::PushDownLimitExpression => write!(f, "PushDownLimitExpression"),
@@ -17,14 +17,15 @@ use crate::plans::RelOperator;
 use crate::MetadataRef;
 
 /// Check if a query will read data from local tables(e.g. system tables).
+/// The working table of a recursive CTE only lives in the current node, so it's local as well.
 pub fn contains_local_table_scan(s_expr: &SExpr, metadata: &MetadataRef) -> bool {
     s_expr
         .children()
         .iter()
         .any(|s_expr| contains_local_table_scan(s_expr, metadata))
-        || if let RelOperator::Scan(get) = s_expr.plan() {
-            metadata.read().table(get.table_index).table().is_local()
-        } else {
-            false
+        || match s_expr.plan() {
+            RelOperator::Scan(get) => metadata.read().table(get.table_index).table().is_local(),
+            RelOperator::RecursiveCteScan(_) => true,
+            _ => false,
         }
 }
@@ -31,6 +31,7 @@ use parking_lot::RwLock;
 
 use super::semantic::AggregateRewriter;
 use super::semantic::DistinctToGroupBy;
+use super::semantic::SearchFunctionRewriter;
 use crate::optimizer::optimize;
 use crate::optimizer::OptimizerConfig;
 use crate::optimizer::OptimizerContext;
@@ -64,6 +65,16 @@ impl Planner {
     #[async_backtrace::framed]
     #[minitrace::trace]
     pub async fn plan_sql(&mut self, sql: &str) -> Result<(Plan, PlanExtras)> {
+        let res = self.plan_sql_inner(sql).await;
+        if res.is_err() {
+            // A statement that fails to parse, bind or optimize fails the explicit transaction
+            // it is in, as a statement that fails to execute does.
+            self.ctx.set_txn_fail();
+        }
+        res
+    }
+
+    async fn plan_sql_inner(&mut self, sql: &str) -> Result<(Plan, PlanExtras)> {
         let settings = self.ctx.get_settings();
         let sql_dialect = settings.get_sql_dialect()?;
         // Step 1: Tokenize the SQL.
@@ -186,6 +197,7 @@ impl Planner {
     fn replace_stmt(&self, stmt: &mut Statement, sql_dialect: Dialect) {
         walk_statement_mut(&mut DistinctToGroupBy::default(), stmt);
         walk_statement_mut(&mut AggregateRewriter { sql_dialect }, stmt);
+        walk_statement_mut(&mut SearchFunctionRewriter::default(), stmt);
 
         self.add_max_rows_limit(stmt);
     }
@@ -0,0 +1,96 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::DataType;
+
+use crate::optimizer::ColumnSet;
+use crate::optimizer::PhysicalProperty;
+use crate::optimizer::RelExpr;
+use crate::optimizer::RelationalProperty;
+use crate::optimizer::RequiredProperty;
+use crate::optimizer::StatInfo;
+use crate::plans::Operator;
+use crate::plans::RelOp;
+use crate::IndexType;
+
+/// `AsyncFunction` is a plan that evaluates functions which need to
+/// talk to an external service, such as `nextval` which allocates
+/// values from a sequence stored in the meta service.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AsyncFunction {
+    pub items: Vec<AsyncFunctionItem>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AsyncFunctionItem {
+    pub index: IndexType,
+    pub display_name: String,
+    pub func_name: String,
+    pub arguments: Vec<String>,
+    pub return_type: Box<DataType>,
+}
+
+impl AsyncFunction {
+    pub fn used_columns(&self) -> Result<ColumnSet> {
+        Ok(self.items.iter().map(|item| item.index).collect())
+    }
+}
+
+impl Operator for AsyncFunction {
+    fn rel_op(&self) -> RelOp {
+        RelOp::AsyncFunction
+    }
+
+    fn derive_relational_prop(&self, rel_expr: &RelExpr) -> Result<Arc<RelationalProperty>> {
+        let input_prop = rel_expr.derive_relational_prop_child(0)?;
+
+        // Derive output columns
+        let mut output_columns = input_prop.output_columns.clone();
+        for item in self.items.iter() {
+            output_columns.insert(item.index);
+        }
+
+        // Derive used columns
+        let mut used_columns = self.used_columns()?;
+        used_columns.extend(input_prop.used_columns.clone());
+
+        Ok(Arc::new(RelationalProperty {
+            output_columns,
+            outer_columns: input_prop.outer_columns.clone(),
+            used_columns,
+        }))
+    }
+
+    fn derive_physical_prop(&self, rel_expr: &RelExpr) -> Result<PhysicalProperty> {
+        rel_expr.derive_physical_prop_child(0)
+    }
+
+    fn derive_cardinality(&self, rel_expr: &RelExpr) -> Result<Arc<StatInfo>> {
+        rel_expr.derive_cardinality_child(0)
+    }
+
+    fn compute_required_prop_child(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _rel_expr: &RelExpr,
+        _child_index: usize,
+        required: &RequiredProperty,
+    ) -> Result<RequiredProperty> {
+        Ok(required.clone())
+    }
+}
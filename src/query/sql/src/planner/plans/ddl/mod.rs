@@ -18,7 +18,9 @@ mod connection;
 mod database;
 mod file_format;
 mod index;
+mod sequence;
 mod stage;
+mod stream;
 mod table;
 mod task;
 mod udf;
@@ -31,7 +33,9 @@ pub use connection::*;
 pub use database::*;
 pub use file_format::*;
 pub use index::*;
+pub use sequence::*;
 pub use stage::*;
+pub use stream::*;
 pub use table::*;
 pub use task::*;
 pub use udf::*;
@@ -29,6 +29,7 @@ use common_expression::TableSchemaRef;
 use common_meta_app::schema::TableNameIdent;
 use common_meta_app::schema::UndropTableReq;
 use common_meta_app::storage::StorageParams;
+use storages_common_table_meta::table::SnapshotRefType;
 
 use crate::plans::Plan;
 
@@ -51,6 +52,7 @@ pub struct CreateTablePlan {
     pub field_comments: Vec<String>,
     pub cluster_key: Option<String>,
     pub as_select: Option<Box<Plan>>,
+    pub clone_from: Option<CloneTableSource>,
 }
 
 impl CreateTablePlan {
@@ -59,6 +61,15 @@ impl CreateTablePlan {
     }
 }
 
+/// The table that `CREATE TABLE ... CLONE` shares the files of.
+#[derive(Clone, Debug)]
+pub struct CloneTableSource {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub point: Option<NavigationPoint>,
+}
+
 /// Desc.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct DescribeTablePlan {
@@ -214,6 +225,30 @@ impl SetOptionsPlan {
     }
 }
 
+/// Create a tag or a branch of the table.
+#[derive(Clone, Debug)]
+pub struct CreateTableSnapshotRefPlan {
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub ref_type: SnapshotRefType,
+    pub name: String,
+    pub point: Option<NavigationPoint>,
+    pub retain_days: Option<u64>,
+}
+
+/// Drop a tag or a branch of the table.
+#[derive(Clone, Debug)]
+pub struct DropTableSnapshotRefPlan {
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub ref_type: SnapshotRefType,
+    pub name: String,
+}
+
 // Table add column
 #[derive(Clone, Debug, PartialEq)]
 pub struct AddTableColumnPlan {
@@ -57,6 +57,8 @@ pub struct Insert {
     pub database: String,
     pub table: String,
     pub table_id: MetaId,
+    /// Insert into the branch of the table, instead of the table itself.
+    pub branch: Option<String>,
     pub schema: TableSchemaRef,
     pub overwrite: bool,
     pub source: InsertInputSource,
@@ -67,6 +69,7 @@ impl PartialEq for Insert {
         self.catalog == other.catalog
             && self.database == other.database
             && self.table == other.table
+            && self.branch == other.branch
             && self.schema == other.schema
     }
 }
@@ -88,6 +91,7 @@ impl std::fmt::Debug for Insert {
             .field("database", &self.database)
             .field("table", &self.table)
             .field("table_id", &self.table_id)
+            .field("branch", &self.branch)
             .field("schema", &self.schema)
             .field("overwrite", &self.overwrite)
             .finish()
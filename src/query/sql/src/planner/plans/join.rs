@@ -308,12 +308,14 @@ impl Join {
                                 left.min = Datum::Float(F64::from(left.min.to_double()?));
                                 left.max = Datum::Float(F64::from(left.max.to_double()?));
                             }
-                            Some(histogram_from_ndv(
+                            // The histogram of string column can't be constructed from NDV.
+                            histogram_from_ndv(
                                 left.ndv as u64,
                                 max(join_card as u64, left.ndv as u64),
                                 Some((left.min.clone(), left.max.clone())),
                                 DEFAULT_HISTOGRAM_BUCKETS,
-                            )?)
+                            )
+                            .ok()
                         }
                     }
                     continue;
@@ -332,12 +334,14 @@ impl Join {
                                 right.min = Datum::Float(F64::from(right.min.to_double()?));
                                 right.max = Datum::Float(F64::from(right.max.to_double()?));
                             }
-                            Some(histogram_from_ndv(
+                            // The histogram of string column can't be constructed from NDV.
+                            histogram_from_ndv(
                                 right.ndv as u64,
                                 max(join_card as u64, right.ndv as u64),
                                 Some((right.min.clone(), right.max.clone())),
                                 DEFAULT_HISTOGRAM_BUCKETS,
-                            )?)
+                            )
+                            .ok()
                         }
                     }
                     continue;
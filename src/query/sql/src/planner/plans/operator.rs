@@ -36,11 +36,13 @@ use crate::optimizer::RequiredProperty;
 use crate::optimizer::StatInfo;
 use crate::plans::materialized_cte::MaterializedCte;
 use crate::plans::runtime_filter_source::RuntimeFilterSource;
+use crate::plans::AsyncFunction;
 use crate::plans::ConstantTableScan;
 use crate::plans::CteScan;
 use crate::plans::Exchange;
 use crate::plans::Lambda;
 use crate::plans::ProjectSet;
+use crate::plans::RecursiveCteScan;
 use crate::plans::Window;
 
 pub trait Operator {
@@ -86,6 +88,8 @@ pub enum RelOp {
     Lambda,
     ConstantTableScan,
     AddRowNumber,
+    RecursiveCteScan,
+    AsyncFunction,
 
     // Pattern
     Pattern,
@@ -112,6 +116,8 @@ pub enum RelOperator {
     MaterializedCte(MaterializedCte),
     Lambda(Lambda),
     ConstantTableScan(ConstantTableScan),
+    RecursiveCteScan(RecursiveCteScan),
+    AsyncFunction(AsyncFunction),
     Pattern(PatternPlan),
 }
 
@@ -137,6 +143,8 @@ impl Operator for RelOperator {
             RelOperator::Lambda(rel_op) => rel_op.rel_op(),
             RelOperator::ConstantTableScan(rel_op) => rel_op.rel_op(),
             RelOperator::AddRowNumber(rel_op) => rel_op.rel_op(),
+            RelOperator::RecursiveCteScan(rel_op) => rel_op.rel_op(),
+            RelOperator::AsyncFunction(rel_op) => rel_op.rel_op(),
         }
     }
 
@@ -161,6 +169,8 @@ impl Operator for RelOperator {
             RelOperator::Lambda(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::ConstantTableScan(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::AddRowNumber(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::RecursiveCteScan(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::AsyncFunction(rel_op) => rel_op.derive_relational_prop(rel_expr),
         }
     }
 
@@ -185,6 +195,8 @@ impl Operator for RelOperator {
             RelOperator::Lambda(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::ConstantTableScan(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::AddRowNumber(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::RecursiveCteScan(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::AsyncFunction(rel_op) => rel_op.derive_physical_prop(rel_expr),
         }
     }
 
@@ -209,6 +221,8 @@ impl Operator for RelOperator {
             RelOperator::Lambda(rel_op) => rel_op.derive_cardinality(rel_expr),
             RelOperator::ConstantTableScan(rel_op) => rel_op.derive_cardinality(rel_expr),
             RelOperator::AddRowNumber(rel_op) => rel_op.derive_cardinality(rel_expr),
+            RelOperator::RecursiveCteScan(rel_op) => rel_op.derive_cardinality(rel_expr),
+            RelOperator::AsyncFunction(rel_op) => rel_op.derive_cardinality(rel_expr),
         }
     }
 
@@ -277,6 +291,12 @@ impl Operator for RelOperator {
             RelOperator::AddRowNumber(rel_op) => {
                 rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
             }
+            RelOperator::RecursiveCteScan(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
+            RelOperator::AsyncFunction(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
         }
     }
 }
@@ -341,6 +361,26 @@ impl TryFrom<RelOperator> for MaterializedCte {
     }
 }
 
+impl From<RecursiveCteScan> for RelOperator {
+    fn from(value: RecursiveCteScan) -> Self {
+        Self::RecursiveCteScan(value)
+    }
+}
+
+impl TryFrom<RelOperator> for RecursiveCteScan {
+    type Error = ErrorCode;
+
+    fn try_from(value: RelOperator) -> Result<Self> {
+        if let RelOperator::RecursiveCteScan(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(
+                "Cannot downcast RelOperator to RecursiveCteScan",
+            ))
+        }
+    }
+}
+
 impl From<Join> for RelOperator {
     fn from(v: Join) -> Self {
         Self::Join(v)
@@ -619,3 +659,23 @@ impl TryFrom<RelOperator> for ConstantTableScan {
         }
     }
 }
+
+impl From<AsyncFunction> for RelOperator {
+    fn from(value: AsyncFunction) -> Self {
+        Self::AsyncFunction(value)
+    }
+}
+
+impl TryFrom<RelOperator> for AsyncFunction {
+    type Error = ErrorCode;
+
+    fn try_from(value: RelOperator) -> std::result::Result<Self, Self::Error> {
+        if let RelOperator::AsyncFunction(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(
+                "Cannot downcast RelOperator to AsyncFunction",
+            ))
+        }
+    }
+}
@@ -24,6 +24,7 @@ use common_expression::TableSchemaRef;
 use itertools::Itertools;
 
 use super::ScalarItem;
+use crate::optimizer::histogram_from_collected;
 use crate::optimizer::histogram_from_ndv;
 use crate::optimizer::ColumnSet;
 use crate::optimizer::ColumnStat;
@@ -199,12 +200,18 @@ impl Operator for Scan {
                 let min = col_stat.min.unwrap();
                 let max = col_stat.max.unwrap();
                 let ndv = col_stat.ndv.unwrap();
-                let histogram = histogram_from_ndv(
-                    ndv,
-                    num_rows,
-                    Some((min.clone(), max.clone())),
-                    DEFAULT_HISTOGRAM_BUCKETS,
-                )
+                let histogram = match &col_stat.histogram {
+                    Some(collected) => histogram_from_collected(
+                        collected,
+                        num_rows.saturating_sub(col_stat.null_count),
+                    ),
+                    None => histogram_from_ndv(
+                        ndv,
+                        num_rows,
+                        Some((min.clone(), max.clone())),
+                        DEFAULT_HISTOGRAM_BUCKETS,
+                    ),
+                }
                 .ok();
                 let column_stat = ColumnStat {
                     min,
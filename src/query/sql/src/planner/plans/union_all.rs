@@ -33,6 +33,10 @@ use crate::IndexType;
 pub struct UnionAll {
     // Pairs of unioned columns
     pub pairs: Vec<(IndexType, IndexType)>,
+    // If the union is the body of a recursive CTE, record the index of the working table
+    // read by `RecursiveCteScan` in the right child. The right child will be evaluated
+    // repeatedly until it produces no rows.
+    pub recursive_cte: Option<(IndexType, IndexType)>,
 }
 
 impl UnionAll {
@@ -64,6 +64,9 @@ pub struct Window {
     pub order_by: Vec<WindowOrderByInfo>,
     // window frames
     pub frame: WindowFuncFrame,
+    // only the top `limit` rows of each partition are required,
+    // pushed down from filters like `row_number() over (...) <= limit`
+    pub limit: Option<usize>,
 }
 
 impl Window {
@@ -0,0 +1,154 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::ast::BinaryOperator;
+use common_ast::ast::Expr;
+use common_ast::ast::Identifier;
+use common_ast::ast::Lambda;
+use common_ast::ast::Literal;
+use common_ast::ast::Query;
+use common_ast::ast::SelectTarget;
+use common_ast::ast::SetExpr;
+use common_ast::ast::SubqueryModifier;
+use common_ast::ast::Window;
+use common_ast::walk_expr;
+use common_ast::walk_expr_mut;
+use common_ast::walk_query_mut;
+use common_ast::Visitor;
+use common_ast::VisitorMut;
+use common_exception::Span;
+
+/// Rewrite `score()` in the SELECT list and ORDER BY clause of a query to
+/// `match_score(column, '<query>')` of the `match(column, '<query>')` predicates
+/// in the WHERE clause, the scores of multiple predicates are summed up.
+#[derive(Debug, Clone, Default)]
+pub struct SearchFunctionRewriter {}
+
+impl VisitorMut for SearchFunctionRewriter {
+    fn visit_query(&mut self, query: &mut Query) {
+        // rewrite subqueries
+        walk_query_mut(self, query);
+
+        let SetExpr::Select(stmt) = &mut query.body else {
+            return;
+        };
+        let Some(selection) = &stmt.selection else {
+            return;
+        };
+
+        let mut collector = MatchCollector::default();
+        walk_expr(&mut collector, selection);
+        let Some(score) = collector.score_expr() else {
+            return;
+        };
+
+        let mut replacer = ScoreReplacer { score };
+        for target in stmt.select_list.iter_mut() {
+            if let SelectTarget::AliasedExpr { expr, .. } = target {
+                replacer.visit_expr(expr);
+            }
+        }
+        for order_by in query.order_by.iter_mut() {
+            replacer.visit_expr(&mut order_by.expr);
+        }
+    }
+}
+
+#[derive(Default)]
+struct MatchCollector {
+    match_args: Vec<Vec<Expr>>,
+}
+
+impl MatchCollector {
+    fn score_expr(self) -> Option<Expr> {
+        self.match_args
+            .into_iter()
+            .map(|args| Expr::FunctionCall {
+                span: None,
+                distinct: false,
+                name: Identifier::from_name("match_score"),
+                args,
+                params: vec![],
+                window: None,
+                lambda: None,
+            })
+            .reduce(|left, right| Expr::BinaryOp {
+                span: None,
+                op: BinaryOperator::Plus,
+                left: Box::new(left),
+                right: Box::new(right),
+            })
+    }
+}
+
+impl<'ast> Visitor<'ast> for MatchCollector {
+    fn visit_function_call(
+        &mut self,
+        _span: Span,
+        _distinct: bool,
+        name: &'ast Identifier,
+        args: &'ast [Expr],
+        _params: &'ast [Literal],
+        _over: &'ast Option<Window>,
+        _lambda: &'ast Option<Lambda>,
+    ) {
+        if name.name.eq_ignore_ascii_case("match") && args.len() == 2 {
+            self.match_args.push(args.to_vec());
+            return;
+        }
+        for arg in args {
+            walk_expr(self, arg);
+        }
+    }
+
+    // the predicates of subqueries belong to the subqueries.
+    fn visit_in_subquery(
+        &mut self,
+        _span: Span,
+        expr: &'ast Expr,
+        _subquery: &'ast Query,
+        _not: bool,
+    ) {
+        walk_expr(self, expr);
+    }
+
+    fn visit_exists(&mut self, _span: Span, _not: bool, _subquery: &'ast Query) {}
+
+    fn visit_subquery(
+        &mut self,
+        _span: Span,
+        _modifier: &'ast Option<SubqueryModifier>,
+        _subquery: &'ast Query,
+    ) {
+    }
+}
+
+struct ScoreReplacer {
+    score: Expr,
+}
+
+impl VisitorMut for ScoreReplacer {
+    fn visit_expr(&mut self, expr: &mut Expr) {
+        match expr {
+            Expr::FunctionCall {
+                name, args, window, ..
+            } if name.name.eq_ignore_ascii_case("score") && args.is_empty() && window.is_none() => {
+                *expr = self.score.clone();
+            }
+            // the subqueries have been rewritten with their own predicates.
+            Expr::Subquery { .. } | Expr::Exists { .. } | Expr::InSubquery { .. } => {}
+            _ => walk_expr_mut(self, expr),
+        }
+    }
+}
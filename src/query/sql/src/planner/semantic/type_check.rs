@@ -67,9 +67,12 @@ use common_functions::GENERAL_LAMBDA_FUNCTIONS;
 use common_functions::GENERAL_WINDOW_FUNCTIONS;
 use common_license::license::Feature::VirtualColumn;
 use common_license::license_manager::get_license_manager;
+use common_meta_api::SequenceApi;
 use common_meta_app::principal::LambdaUDF;
 use common_meta_app::principal::UDFDefinition;
 use common_meta_app::principal::UDFServer;
+use common_meta_app::schema::GetSequenceReq;
+use common_meta_app::schema::SequenceNameIdent;
 use common_users::UserApiProvider;
 use indexmap::IndexMap;
 use itertools::Itertools;
@@ -91,6 +94,7 @@ use crate::planner::metadata::optimize_remove_count_args;
 use crate::plans::Aggregate;
 use crate::plans::AggregateFunction;
 use crate::plans::AggregateMode;
+use crate::plans::AsyncFunctionItem;
 use crate::plans::BoundColumnRef;
 use crate::plans::CastExpr;
 use crate::plans::ComparisonOp;
@@ -710,6 +714,9 @@ impl<'a> TypeChecker<'a> {
             } => {
                 let func_name = normalize_identifier(name, self.name_resolution_ctx).to_string();
                 let func_name = func_name.as_str();
+                if Self::all_async_functions().contains(&func_name) {
+                    return self.resolve_async_function(*span, func_name, args).await;
+                }
                 if !is_builtin_function(func_name)
                     && !Self::all_sugar_functions().contains(&func_name)
                 {
@@ -728,6 +735,12 @@ impl<'a> TypeChecker<'a> {
                                     .iter()
                                     .cloned()
                                     .map(str::to_string),
+                            )
+                            .chain(
+                                Self::all_async_functions()
+                                    .iter()
+                                    .cloned()
+                                    .map(str::to_string),
                             );
                         let mut engine: SimSearch<String> = SimSearch::new();
                         for func_name in all_funcs {
@@ -806,6 +819,14 @@ impl<'a> TypeChecker<'a> {
                 }
 
                 let name = func_name.to_lowercase();
+                if name == "score" && args.is_empty() {
+                    // `score()` should have been rewritten by `SearchFunctionRewriter`
+                    return Err(ErrorCode::SemanticError(
+                        "score() can only be used in a query with MATCH in the WHERE clause"
+                            .to_string(),
+                    )
+                    .set_span(*span));
+                }
                 if GENERAL_WINDOW_FUNCTIONS.contains(&name.as_str()) {
                     if matches!(
                         self.bind_context.expr_context,
@@ -2212,6 +2233,80 @@ impl<'a> TypeChecker<'a> {
         ]
     }
 
+    /// Functions that need to call external services, they are evaluated
+    /// by the `AsyncFunction` operator instead of the expression evaluator.
+    pub fn all_async_functions() -> &'static [&'static str] {
+        &["nextval"]
+    }
+
+    #[async_backtrace::framed]
+    async fn resolve_async_function(
+        &mut self,
+        span: Span,
+        func_name: &str,
+        arguments: &[Expr],
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        if !matches!(self.bind_context.expr_context, ExprContext::SelectClause) {
+            return Err(ErrorCode::SemanticError(format!(
+                "async function `{func_name}` can only be used in SELECT clause"
+            ))
+            .set_span(span));
+        }
+
+        let sequence_name = match arguments {
+            [
+                Expr::Literal {
+                    lit: Literal::String(name),
+                    ..
+                },
+            ] => name.clone(),
+            _ => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "{func_name} function needs exactly one string literal argument"
+                ))
+                .set_span(span));
+            }
+        };
+
+        // Report an unknown sequence while binding instead of in the middle of execution.
+        let req = GetSequenceReq {
+            name_ident: SequenceNameIdent::new(self.ctx.get_tenant(), &sequence_name),
+        };
+        UserApiProvider::instance()
+            .get_meta_store_client()
+            .get_sequence(req)
+            .await?;
+
+        let display_name = format!("{func_name}('{sequence_name}')");
+        let data_type = DataType::Number(NumberDataType::UInt64);
+        let index = self
+            .metadata
+            .write()
+            .add_derived_column(display_name.clone(), data_type.clone());
+        self.bind_context
+            .async_function_info
+            .async_functions
+            .push(AsyncFunctionItem {
+                index,
+                display_name: display_name.clone(),
+                func_name: func_name.to_string(),
+                arguments: vec![sequence_name],
+                return_type: Box::new(data_type.clone()),
+            });
+
+        let column = ColumnBindingBuilder::new(
+            display_name,
+            index,
+            Box::new(data_type.clone()),
+            Visibility::Visible,
+        )
+        .build();
+        Ok(Box::new((
+            BoundColumnRef { span, column }.into(),
+            data_type,
+        )))
+    }
+
     #[async_recursion::async_recursion]
     #[async_backtrace::framed]
     async fn try_rewrite_sugar_function(
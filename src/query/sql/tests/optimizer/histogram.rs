@@ -12,9 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_expression::types::NumberScalar;
+use common_expression::Scalar;
+use common_sql::optimizer::histogram_from_collected;
 use common_sql::optimizer::Histogram;
 use common_sql::optimizer::HistogramBucket;
 use common_storage::Datum;
+use storages_common_table_meta::meta::ColumnHistogram;
+use storages_common_table_meta::meta::HistogramBucket as CollectedBucket;
+use storages_common_table_meta::meta::MostCommonValue;
 
 #[test]
 fn test_histogram() {
@@ -28,3 +34,35 @@ fn test_histogram() {
     assert_eq!(histogram.num_values(), 4.0);
     assert_eq!(histogram.num_distinct_values(), 2.0);
 }
+
+#[test]
+fn test_histogram_from_collected() {
+    let collected = ColumnHistogram {
+        lower_bound: Scalar::Number(NumberScalar::Int64(1)),
+        buckets: vec![
+            CollectedBucket {
+                upper_bound: Scalar::Number(NumberScalar::Int64(1)),
+                num_values: 60.0,
+                num_distinct: 1.0,
+            },
+            CollectedBucket {
+                upper_bound: Scalar::Number(NumberScalar::Int64(100)),
+                num_values: 40.0,
+                num_distinct: 99.0,
+            },
+        ],
+        most_common_values: vec![MostCommonValue {
+            value: Scalar::Number(NumberScalar::Int64(1)),
+            num_values: 60.0,
+        }],
+    };
+
+    // The table has doubled since the histogram was collected.
+    let histogram = histogram_from_collected(&collected, 200).unwrap();
+    assert!(histogram.collected);
+    assert_eq!(histogram.num_buckets(), 3);
+    assert_eq!(histogram.buckets[0].upper_bound(), &Datum::Int(1));
+    assert_eq!(histogram.num_values(), 200.0);
+    assert_eq!(histogram.num_distinct_values(), 100.0);
+    assert_eq!(histogram.most_common_values, vec![(Datum::Int(1), 120.0)]);
+}
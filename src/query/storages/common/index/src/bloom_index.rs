@@ -25,12 +25,15 @@ use common_exception::Result;
 use common_exception::Span;
 use common_expression::converts::scalar_to_datavalue;
 use common_expression::eval_function;
+use common_expression::types::string::StringColumnBuilder;
 use common_expression::types::AnyType;
 use common_expression::types::DataType;
 use common_expression::types::MapType;
 use common_expression::types::NullableType;
 use common_expression::types::Number;
 use common_expression::types::NumberDataType;
+use common_expression::types::NumberScalar;
+use common_expression::types::StringType;
 use common_expression::types::UInt64Type;
 use common_expression::types::ValueType;
 use common_expression::BlockEntry;
@@ -56,6 +59,10 @@ use crate::filters::FilterBuilder;
 use crate::filters::V2BloomBlock;
 use crate::filters::Xor8Builder;
 use crate::filters::Xor8Filter;
+use crate::ngram::collect_ngrams;
+use crate::ngram::like_literal_segments;
+use crate::ngram::regexp_literal_prefix;
+use crate::ngram::NGRAM_SIZE;
 use crate::Index;
 
 #[derive(Clone)]
@@ -123,6 +130,10 @@ impl TryFrom<ThriftFileMetaData> for BloomIndexMeta {
 ///         |  123456789abcd |  ac2345bcd   |
 ///         +----------------+--------------+
 /// ```
+///
+/// String columns can optionally have an n-gram token filter as well, which is
+/// stored with field name 'Ngram(column_id)', to prune blocks by `LIKE '%...%'`
+/// and other substring predicates.
 pub struct BloomIndex {
     pub func_ctx: FunctionContext,
 
@@ -178,6 +189,7 @@ impl BloomIndex {
         version: u64,
         data_blocks_tobe_indexed: &[&DataBlock],
         bloom_columns_map: BTreeMap<FieldIndex, TableField>,
+        ngram_columns_map: BTreeMap<FieldIndex, TableField>,
     ) -> Result<Option<Self>> {
         if data_blocks_tobe_indexed.is_empty() {
             return Err(ErrorCode::BadArguments("block is empty"));
@@ -267,6 +279,25 @@ impl BloomIndex {
             filters.push(Arc::new(filter));
         }
 
+        for (index, field) in ngram_columns_map.into_iter() {
+            let field_type = &data_blocks_tobe_indexed[0].get_by_offset(index).data_type;
+            if !Self::supported_ngram_data_type(field_type) {
+                continue;
+            }
+            let source_columns_iter = data_blocks_tobe_indexed.iter().map(|block| {
+                let value = &block.get_by_offset(index).value;
+                value.convert_to_full_column(field_type, block.num_rows())
+            });
+            let column = Column::concat_columns(source_columns_iter)?;
+
+            if let Some(filter) = Self::build_ngram_filter(&func_ctx, &column)? {
+                if let Some(filter_name) = Self::build_ngram_filter_column_name(version, &field)? {
+                    filter_fields.push(TableField::new(&filter_name, TableDataType::String));
+                    filters.push(Arc::new(filter));
+                }
+            }
+        }
+
         if filter_fields.is_empty() {
             return Ok(None);
         }
@@ -305,6 +336,17 @@ impl BloomIndex {
         scalar_map: &HashMap<Scalar, u64>,
         data_schema: TableSchemaRef,
     ) -> Result<FilterEvalResult> {
+        visit_expr_ngram_predicate(&mut expr, &mut |col_name, ngrams| {
+            let filter_column = Self::build_ngram_filter_column_name(
+                self.version,
+                data_schema.field_with_name(col_name)?,
+            )?;
+            match filter_column {
+                Some(filter_column) => self.find_ngrams(&filter_column, ngrams, scalar_map),
+                None => Ok(FilterEvalResult::Uncertain),
+            }
+        })?;
+
         visit_expr_column_eq_constant(
             &mut expr,
             &mut |span, col_name, scalar, ty, return_type| {
@@ -423,6 +465,74 @@ impl BloomIndex {
         Ok(cols)
     }
 
+    /// Find all string columns that match the substring predicates in the expression,
+    /// e.g. `col LIKE '%...%'`, `position(<constant> IN col)` and `col REGEXP '^...'`,
+    /// returns the n-grams that must be contained by the column.
+    pub fn find_ngram_columns(
+        expr: &Expr<String>,
+        fields: Vec<TableField>,
+    ) -> Result<Vec<(TableField, Vec<Vec<u8>>)>> {
+        let mut cols = Vec::new();
+        visit_expr_ngram_predicate(&mut expr.clone(), &mut |col_name, ngrams| {
+            if let Some(v) = fields.iter().find(|f: &&TableField| f.name() == col_name) {
+                cols.push((v.clone(), ngrams.to_vec()));
+            }
+            Ok(FilterEvalResult::Uncertain)
+        })?;
+        Ok(cols)
+    }
+
+    /// Build the n-gram token filter of a string column.
+    ///
+    /// Returns None if there is no n-gram in the column.
+    pub fn build_ngram_filter(
+        func_ctx: &FunctionContext,
+        column: &Column,
+    ) -> Result<Option<Xor8Filter>> {
+        let column = column.remove_nullable();
+        let column = StringType::try_downcast_column(&column).ok_or_else(|| {
+            ErrorCode::BadArguments("n-gram filter can only be built on string column")
+        })?;
+        let ngrams = collect_ngrams(column.iter());
+        if ngrams.is_empty() {
+            return Ok(None);
+        }
+
+        let mut builder =
+            StringColumnBuilder::with_capacity(ngrams.len(), ngrams.len() * NGRAM_SIZE);
+        for ngram in ngrams {
+            builder.put_slice(ngram);
+            builder.commit_row();
+        }
+        let ngram_column = Column::String(builder.build());
+        let digests = Self::calculate_column_digest(
+            func_ctx,
+            &ngram_column,
+            &DataType::String,
+            &DataType::Number(NumberDataType::UInt64),
+        )?;
+        let digests = UInt64Type::try_downcast_column(&digests).unwrap();
+        let mut filter_builder = Xor8Builder::create();
+        filter_builder.add_digests(digests.deref());
+        Ok(Some(filter_builder.build()?))
+    }
+
+    /// The n-gram token filter will be stored with field name 'Ngram(column_id)'.
+    ///
+    /// Returns None if the version of index doesn't support n-gram token filter.
+    pub fn build_ngram_filter_column_name(
+        version: u64,
+        field: &TableField,
+    ) -> Result<Option<String>> {
+        let index_version = BlockBloomFilterIndexVersion::try_from(version)?;
+        match index_version {
+            BlockBloomFilterIndexVersion::V4(_) => {
+                Ok(Some(format!("Ngram({})", field.column_id())))
+            }
+            _ => Ok(None),
+        }
+    }
+
     /// For every applicable column, we will create a filter.
     /// The filter will be stored with field name 'Bloom(column_name)'
     pub fn build_filter_column_name(version: u64, field: &TableField) -> Result<String> {
@@ -472,6 +582,39 @@ impl BloomIndex {
         }
     }
 
+    fn find_ngrams(
+        &self,
+        filter_column: &str,
+        ngrams: &[Vec<u8>],
+        scalar_map: &HashMap<Scalar, u64>,
+    ) -> Result<FilterEvalResult> {
+        if !self.filter_schema.has_field(filter_column) {
+            // The column doesn't have a n-gram filter.
+            return Ok(FilterEvalResult::Uncertain);
+        }
+
+        let idx = self.filter_schema.index_of(filter_column)?;
+        let filter = &self.filters[idx];
+        for ngram in ngrams {
+            if let Some(digest) = scalar_map.get(&Scalar::String(ngram.clone())) {
+                if !filter.contains_digest(*digest) {
+                    return Ok(FilterEvalResult::MustFalse);
+                }
+            }
+        }
+        Ok(FilterEvalResult::Uncertain)
+    }
+
+    /// N-gram token filter can be created for string columns.
+    pub fn supported_ngram_type(data_type: &TableDataType) -> bool {
+        let data_type = DataType::from(data_type);
+        Self::supported_ngram_data_type(&data_type)
+    }
+
+    pub fn supported_ngram_data_type(data_type: &DataType) -> bool {
+        matches!(data_type.remove_nullable(), DataType::String)
+    }
+
     pub fn supported_type(data_type: &TableDataType) -> bool {
         let data_type = DataType::from(data_type);
         Self::supported_data_type(&data_type)
@@ -612,3 +755,77 @@ fn visit_map_column(
     }
     Ok(None)
 }
+
+fn visit_expr_ngram_predicate(
+    expr: &mut Expr<String>,
+    visitor: &mut impl FnMut(&str, &[Vec<u8>]) -> Result<FilterEvalResult>,
+) -> Result<()> {
+    // Find patterns like `Column LIKE <constant>`, `Column REGEXP <constant>`,
+    // or `position(<constant> IN Column)`, `locate(<constant>, Column)`, `instr(Column, <constant>)`
+    if let Expr::FunctionCall {
+        span,
+        function,
+        args,
+        return_type,
+        ..
+    } = expr
+    {
+        let name = function.signature.name.as_str();
+        let operands = match (name, args.as_slice()) {
+            ("like" | "regexp" | "regexp_like" | "instr", [column, pattern])
+            | ("position" | "locate", [pattern, column]) => Some((column, pattern)),
+            _ => None,
+        };
+        if let Some((
+            Expr::ColumnRef { id, data_type, .. },
+            Expr::Constant {
+                scalar: Scalar::String(pattern),
+                ..
+            },
+        )) = operands
+        {
+            if matches!(data_type.remove_nullable(), DataType::String) {
+                let needles = match name {
+                    "like" => like_literal_segments(pattern),
+                    "regexp" | "regexp_like" => {
+                        regexp_literal_prefix(pattern).into_iter().collect()
+                    }
+                    _ => vec![pattern.clone()],
+                };
+                let ngrams = needles
+                    .iter()
+                    .flat_map(|needle| needle.windows(NGRAM_SIZE).map(|ngram| ngram.to_vec()))
+                    .collect::<Vec<_>>();
+                if !ngrams.is_empty() && visitor(id, &ngrams)? == FilterEvalResult::MustFalse {
+                    // If the column doesn't contain the needles, the predicate is false,
+                    // and the position of needles is 0.
+                    let scalar = match name {
+                        "like" | "regexp" | "regexp_like" => Scalar::Boolean(false),
+                        _ => Scalar::Number(NumberScalar::UInt64(0)),
+                    };
+                    *expr = Expr::Constant {
+                        span: *span,
+                        scalar,
+                        data_type: return_type.clone(),
+                    };
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    // Otherwise, rewrite sub expressions.
+    match expr {
+        Expr::Cast { expr, .. } => {
+            visit_expr_ngram_predicate(expr, visitor)?;
+        }
+        Expr::FunctionCall { args, .. } => {
+            for arg in args.iter_mut() {
+                visit_expr_ngram_predicate(arg, visitor)?;
+            }
+        }
+        _ => (),
+    }
+
+    Ok(())
+}
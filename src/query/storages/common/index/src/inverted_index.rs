@@ -0,0 +1,201 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::str::FromStr;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::StringType;
+use common_expression::types::ValueType;
+use common_expression::ColumnId;
+use common_expression::ConstantFolder;
+use common_expression::DataBlock;
+use common_expression::Expr;
+use common_expression::FieldIndex;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableSchema;
+use common_functions::scalars::Tokenizer;
+use common_functions::BUILTIN_FUNCTIONS;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::FilterEvalResult;
+
+/// The inverted index of a block, keeps the terms of the indexed string columns of the block.
+///
+/// It is used to prune the blocks by the full-text search predicate `match(column, '<query>')`,
+/// a block can be pruned if any term of the query is not in the block.
+/// The rows of the kept blocks are filtered by evaluating the predicate on the text.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct InvertedIndex {
+    pub columns: BTreeMap<ColumnId, InvertedIndexColumn>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct InvertedIndexColumn {
+    /// Name of the tokenizer that splits the text into terms.
+    pub tokenizer: String,
+    /// The terms of all the rows of the block.
+    pub terms: BTreeSet<String>,
+}
+
+impl InvertedIndex {
+    /// Build the inverted index of the given columns of the block,
+    /// the columns are specified as `field index -> (column id, tokenizer)`.
+    pub fn try_create(
+        block: &DataBlock,
+        index_columns: &BTreeMap<FieldIndex, (ColumnId, Tokenizer)>,
+    ) -> Result<Option<Self>> {
+        let mut columns = BTreeMap::new();
+        for (index, (column_id, tokenizer)) in index_columns.iter() {
+            let entry = block.get_by_offset(*index);
+            let column = entry
+                .value
+                .convert_to_full_column(&entry.data_type, block.num_rows())
+                .remove_nullable();
+            let column = StringType::try_downcast_column(&column).ok_or_else(|| {
+                ErrorCode::BadArguments("inverted index can only be built on string column")
+            })?;
+
+            let terms = column
+                .iter()
+                .flat_map(|text| tokenizer.tokenize(text))
+                .collect();
+            columns.insert(*column_id, InvertedIndexColumn {
+                tokenizer: tokenizer.to_string(),
+                terms,
+            });
+        }
+
+        if columns.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(Self { columns }))
+    }
+
+    /// Only string columns can be indexed.
+    pub fn supported_type(data_type: &TableDataType) -> bool {
+        matches!(data_type.remove_nullable(), TableDataType::String)
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        bincode::serialize(self).map_err(|e| {
+            ErrorCode::StorageOther(format!("failed to serialize inverted index: {}", e))
+        })
+    }
+
+    pub fn from_slice(data: &[u8]) -> Result<Self> {
+        bincode::deserialize(data).map_err(|e| {
+            ErrorCode::StorageOther(format!("failed to deserialize inverted index: {}", e))
+        })
+    }
+
+    /// Whether all the terms of the query are in the block.
+    ///
+    /// Returns None if the column is not indexed, or there are no terms in the query.
+    pub fn contains_terms(&self, column_id: ColumnId, query: &[u8]) -> Option<bool> {
+        let column = self.columns.get(&column_id)?;
+        let tokenizer = Tokenizer::from_str(&column.tokenizer).ok()?;
+        let terms = tokenizer.tokenize(query);
+        if terms.is_empty() {
+            return None;
+        }
+        Some(terms.iter().all(|term| column.terms.contains(term)))
+    }
+
+    /// Apply the inverted index to the filter expression of the block.
+    ///
+    /// Returns `MustFalse` if the expression is false when `match(column, '<query>')`
+    /// is false for all the rows of the block.
+    ///
+    /// Otherwise return `Uncertain`.
+    pub fn apply(
+        &self,
+        mut expr: Expr<String>,
+        data_schema: &TableSchema,
+        func_ctx: &FunctionContext,
+    ) -> Result<FilterEvalResult> {
+        visit_expr_match(&mut expr, &mut |col_name, query| {
+            let column_id = data_schema.field_with_name(col_name)?.column_id();
+            Ok(self.contains_terms(column_id, query) == Some(false))
+        })?;
+
+        let (new_expr, _) = ConstantFolder::fold(&expr, func_ctx, &BUILTIN_FUNCTIONS);
+        match new_expr {
+            Expr::Constant {
+                scalar: Scalar::Boolean(false),
+                ..
+            } => Ok(FilterEvalResult::MustFalse),
+            _ => Ok(FilterEvalResult::Uncertain),
+        }
+    }
+
+    /// Find the columns searched by `match(column, '<query>')` in the expression.
+    pub fn find_match_columns(expr: &Expr<String>) -> Result<Vec<String>> {
+        let mut columns = Vec::new();
+        visit_expr_match(&mut expr.clone(), &mut |col_name, _| {
+            columns.push(col_name.to_string());
+            Ok(false)
+        })?;
+        Ok(columns)
+    }
+}
+
+fn visit_expr_match(
+    expr: &mut Expr<String>,
+    visitor: &mut impl FnMut(&str, &[u8]) -> Result<bool>,
+) -> Result<()> {
+    // Find patterns like `match(Column, <constant>)`, the visitor returns true
+    // if the predicate must be false, and the expression is rewritten to `false`.
+    match expr {
+        Expr::FunctionCall {
+            span,
+            function,
+            args,
+            return_type,
+            ..
+        } if function.signature.name == "match" => {
+            if let [
+                Expr::ColumnRef { id, .. },
+                Expr::Constant {
+                    scalar: Scalar::String(query),
+                    ..
+                },
+            ] = args.as_slice()
+            {
+                if visitor(id, query)? {
+                    *expr = Expr::Constant {
+                        span: *span,
+                        scalar: Scalar::Boolean(false),
+                        data_type: return_type.clone(),
+                    };
+                }
+            }
+        }
+        Expr::Cast { expr, .. } => {
+            visit_expr_match(expr, visitor)?;
+        }
+        Expr::FunctionCall { args, .. } => {
+            for arg in args.iter_mut() {
+                visit_expr_match(arg, visitor)?;
+            }
+        }
+        _ => (),
+    }
+    Ok(())
+}
@@ -18,6 +18,8 @@
 mod bloom_index;
 pub mod filters;
 mod index;
+mod inverted_index;
+mod ngram;
 mod page_index;
 mod range_index;
 
@@ -25,5 +27,11 @@ pub use bloom_index::BloomIndex;
 pub use bloom_index::BloomIndexMeta;
 pub use bloom_index::FilterEvalResult;
 pub use index::Index;
+pub use inverted_index::InvertedIndex;
+pub use inverted_index::InvertedIndexColumn;
+pub use ngram::collect_ngrams;
+pub use ngram::like_literal_segments;
+pub use ngram::regexp_literal_prefix;
+pub use ngram::NGRAM_SIZE;
 pub use page_index::PageIndex;
 pub use range_index::RangeIndex;
@@ -0,0 +1,90 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+/// Number of bytes of the n-grams indexed by the n-gram token filter.
+///
+/// The n-grams are extracted from the raw bytes rather than the characters,
+/// a substring of the bytes is always a substring of the characters.
+pub const NGRAM_SIZE: usize = 3;
+
+/// Collect the distinct n-grams of the values.
+pub fn collect_ngrams<'a>(values: impl Iterator<Item = &'a [u8]>) -> HashSet<&'a [u8]> {
+    let mut ngrams = HashSet::new();
+    for value in values {
+        ngrams.extend(value.windows(NGRAM_SIZE));
+    }
+    ngrams
+}
+
+/// The literal substrings that must be contained by the values matching the `LIKE` pattern.
+///
+/// e.g. `'%time_out%'` requires both `time` and `out`.
+pub fn like_literal_segments(pattern: &[u8]) -> Vec<Vec<u8>> {
+    let mut segments = vec![];
+    let mut segment = vec![];
+    let mut idx = 0;
+    while idx < pattern.len() {
+        match pattern[idx] {
+            b'\\' if idx + 1 < pattern.len() => {
+                idx += 1;
+                segment.push(pattern[idx]);
+            }
+            b'%' | b'_' => segments.push(std::mem::take(&mut segment)),
+            c => segment.push(c),
+        }
+        idx += 1;
+    }
+    segments.push(segment);
+    segments.retain(|s| s.len() >= NGRAM_SIZE);
+    segments
+}
+
+/// The literal prefix of the regular expression, which must be contained by the matched values.
+///
+/// e.g. `'^time(out)?'` and `'timeo*'` both require `time`.
+pub fn regexp_literal_prefix(pattern: &[u8]) -> Option<Vec<u8>> {
+    let pattern = std::str::from_utf8(pattern).ok()?;
+    // The prefix is not required by all the alternatives.
+    if pattern.contains('|') {
+        return None;
+    }
+    let mut chars = pattern
+        .strip_prefix('^')
+        .unwrap_or(pattern)
+        .chars()
+        .peekable();
+    let mut prefix = String::new();
+    while let Some(c) = chars.next() {
+        let literal = match c {
+            '\\' => match chars.next() {
+                Some(c) if c.is_ascii_punctuation() => c,
+                _ => break,
+            },
+            '.' | '[' | ']' | '(' | ')' | '*' | '+' | '?' | '{' | '}' | '^' | '$' => break,
+            c => c,
+        };
+        // The quantifier makes the last literal optional.
+        if matches!(chars.peek(), Some('*' | '?' | '{')) {
+            break;
+        }
+        prefix.push(literal);
+    }
+    if prefix.len() >= NGRAM_SIZE {
+        Some(prefix.into_bytes())
+    } else {
+        None
+    }
+}
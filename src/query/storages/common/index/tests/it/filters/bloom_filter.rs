@@ -118,6 +118,7 @@ fn test_bloom_filter() -> Result<()> {
         LatestBloom::VERSION,
         &blocks_ref,
         bloom_columns,
+        BTreeMap::new(),
     )?
     .unwrap();
 
@@ -251,6 +252,7 @@ fn test_specify_bloom_filter() -> Result<()> {
         LatestBloom::VERSION,
         &blocks_ref,
         bloom_columns,
+        BTreeMap::new(),
     )?
     .unwrap();
 
@@ -291,6 +293,7 @@ fn test_string_bloom_filter() -> Result<()> {
         LatestBloom::VERSION,
         &blocks_ref,
         bloom_columns,
+        BTreeMap::new(),
     )?
     .unwrap();
 
@@ -309,6 +312,134 @@ fn test_string_bloom_filter() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_ngram_filter() -> Result<()> {
+    let schema = Arc::new(TableSchema::new(vec![
+        TableField::new("0", TableDataType::Number(NumberDataType::UInt8)),
+        TableField::new("1", TableDataType::String),
+    ]));
+
+    let blocks = vec![DataBlock::new_from_columns(vec![
+        UInt8Type::from_data(vec![1, 2]),
+        StringType::from_data(vec!["connection timeout", "disk full"]),
+    ])];
+    let blocks_ref = blocks.iter().collect::<Vec<_>>();
+
+    let ngram_columns = BTreeMap::from([(1, schema.field(1).clone())]);
+    let fields = ngram_columns.values().cloned().collect::<Vec<_>>();
+    let index = BloomIndex::try_create(
+        FunctionContext::default(),
+        LatestBloom::VERSION,
+        &blocks_ref,
+        BTreeMap::new(),
+        ngram_columns,
+    )?
+    .unwrap();
+
+    let cases = [
+        ("like", "%timeout%", FilterEvalResult::Uncertain),
+        ("like", "%conn%out", FilterEvalResult::Uncertain),
+        ("like", "%refused%", FilterEvalResult::MustFalse),
+        ("like", "%conn%refused%", FilterEvalResult::MustFalse),
+        // Too short to be checked.
+        ("like", "%xy%", FilterEvalResult::Uncertain),
+        ("regexp", "^disk", FilterEvalResult::Uncertain),
+        ("regexp", "^memory (full)?", FilterEvalResult::MustFalse),
+        ("regexp", "memory|disk", FilterEvalResult::Uncertain),
+    ];
+    for (func, pattern, expected) in cases {
+        let expr = check_function(
+            None,
+            func,
+            &[],
+            &[
+                Expr::ColumnRef {
+                    span: None,
+                    id: "1".to_string(),
+                    data_type: DataType::String,
+                    display_name: "1".to_string(),
+                },
+                Expr::Constant {
+                    span: None,
+                    scalar: Scalar::String(pattern.as_bytes().to_vec()),
+                    data_type: DataType::String,
+                },
+            ],
+            &BUILTIN_FUNCTIONS,
+        )
+        .unwrap();
+        let result = eval_ngram_index(&index, expr, fields.clone(), schema.clone());
+        assert_eq!(expected, result, "{func} '{pattern}'");
+    }
+
+    for (needle, expected) in [
+        ("full", FilterEvalResult::Uncertain),
+        ("empty", FilterEvalResult::MustFalse),
+    ] {
+        let position = check_function(
+            None,
+            "position",
+            &[],
+            &[
+                Expr::Constant {
+                    span: None,
+                    scalar: Scalar::String(needle.as_bytes().to_vec()),
+                    data_type: DataType::String,
+                },
+                Expr::ColumnRef {
+                    span: None,
+                    id: "1".to_string(),
+                    data_type: DataType::String,
+                    display_name: "1".to_string(),
+                },
+            ],
+            &BUILTIN_FUNCTIONS,
+        )
+        .unwrap();
+        let expr = check_function(
+            None,
+            "gt",
+            &[],
+            &[position, Expr::Constant {
+                span: None,
+                scalar: Scalar::Number(NumberScalar::UInt64(0)),
+                data_type: DataType::Number(NumberDataType::UInt64),
+            }],
+            &BUILTIN_FUNCTIONS,
+        )
+        .unwrap();
+        let result = eval_ngram_index(&index, expr, fields.clone(), schema.clone());
+        assert_eq!(expected, result, "position('{needle}')");
+    }
+
+    Ok(())
+}
+
+fn eval_ngram_index(
+    index: &BloomIndex,
+    expr: Expr<String>,
+    fields: Vec<TableField>,
+    schema: Arc<TableSchema>,
+) -> FilterEvalResult {
+    let ngram_cols = BloomIndex::find_ngram_columns(&expr, fields).unwrap();
+
+    let mut scalar_map = HashMap::<Scalar, u64>::new();
+    let func_ctx = FunctionContext::default();
+    for (_, ngrams) in ngram_cols.iter() {
+        for ngram in ngrams {
+            let scalar = Scalar::String(ngram.clone());
+            if !scalar_map.contains_key(&scalar) {
+                let digest =
+                    BloomIndex::calculate_scalar_digest(&func_ctx, &scalar, &DataType::String)
+                        .unwrap();
+                scalar_map.insert(scalar, digest);
+            }
+        }
+    }
+
+    index.apply(expr, &scalar_map, schema).unwrap()
+}
+
 fn eval_index(
     index: &BloomIndex,
     col_name: &str,
@@ -0,0 +1,132 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use common_exception::Result;
+use common_expression::type_check::check_function;
+use common_expression::types::DataType;
+use common_expression::types::StringType;
+use common_expression::DataBlock;
+use common_expression::Expr;
+use common_expression::FromData;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_functions::scalars::Tokenizer;
+use common_functions::BUILTIN_FUNCTIONS;
+use storages_common_index::FilterEvalResult;
+use storages_common_index::InvertedIndex;
+
+#[test]
+fn test_inverted_index() -> Result<()> {
+    let schema = TableSchema::new(vec![
+        TableField::new("title", TableDataType::String),
+        TableField::new("body", TableDataType::String),
+    ]);
+    let block = DataBlock::new_from_columns(vec![
+        StringType::from_data(vec!["Rust", "Databend", "The Book"]),
+        StringType::from_data(vec![
+            "The quick brown fox",
+            "jumps over the lazy dog",
+            "the QUICK dog, the lazy fox",
+        ]),
+    ]);
+    let body_id = schema.field_with_name("body")?.column_id();
+    let columns = BTreeMap::from([(1, (body_id, Tokenizer::English))]);
+    let index = InvertedIndex::try_create(&block, &columns)?.unwrap();
+
+    // round trip.
+    let index = InvertedIndex::from_slice(&index.to_bytes()?)?;
+    assert_eq!(index.columns.len(), 1);
+
+    assert_eq!(index.contains_terms(body_id, b"quick"), Some(true));
+    assert_eq!(index.contains_terms(body_id, b"Lazy Dog"), Some(true));
+    assert_eq!(index.contains_terms(body_id, b"quick, fox!"), Some(true));
+    // the terms in different rows are in the block.
+    assert_eq!(index.contains_terms(body_id, b"brown jumps"), Some(true));
+    assert_eq!(index.contains_terms(body_id, b"quick cat"), Some(false));
+    // stop words are not indexed.
+    assert_eq!(index.contains_terms(body_id, b"the"), None);
+    assert_eq!(index.contains_terms(body_id, b"the fox"), Some(true));
+    // the column is not indexed.
+    let title_id = schema.field_with_name("title")?.column_id();
+    assert_eq!(index.contains_terms(title_id, b"rust"), None);
+
+    let func_ctx = FunctionContext::default();
+    let cases = [
+        ("body", "lazy fox", FilterEvalResult::Uncertain),
+        ("body", "lazy cat", FilterEvalResult::MustFalse),
+        ("body", "the", FilterEvalResult::Uncertain),
+        ("title", "java", FilterEvalResult::Uncertain),
+    ];
+    for (column, query, expected) in cases {
+        let expr = match_expr(column, query);
+        assert_eq!(InvertedIndex::find_match_columns(&expr)?, vec![
+            column.to_string()
+        ]);
+        assert_eq!(index.apply(expr, &schema, &func_ctx)?, expected);
+    }
+
+    // the unknown terms of one side of OR can't prune the block.
+    let expr = check_function(
+        None,
+        "or",
+        &[],
+        &[match_expr("body", "cat"), match_expr("body", "dog")],
+        &BUILTIN_FUNCTIONS,
+    )?;
+    assert_eq!(
+        index.apply(expr, &schema, &func_ctx)?,
+        FilterEvalResult::Uncertain
+    );
+    let expr = check_function(
+        None,
+        "and",
+        &[],
+        &[match_expr("body", "cat"), match_expr("body", "dog")],
+        &BUILTIN_FUNCTIONS,
+    )?;
+    assert_eq!(
+        index.apply(expr, &schema, &func_ctx)?,
+        FilterEvalResult::MustFalse
+    );
+
+    Ok(())
+}
+
+fn match_expr(column: &str, query: &str) -> Expr<String> {
+    check_function(
+        None,
+        "match",
+        &[],
+        &[
+            Expr::ColumnRef {
+                span: None,
+                id: column.to_string(),
+                data_type: DataType::String,
+                display_name: column.to_string(),
+            },
+            Expr::Constant {
+                span: None,
+                scalar: Scalar::String(query.as_bytes().to_vec()),
+                data_type: DataType::String,
+            },
+        ],
+        &BUILTIN_FUNCTIONS,
+    )
+    .unwrap()
+}
@@ -141,6 +141,7 @@ impl LockManager {
                 key: lock.watch_delete_key(reply[position - 1].0),
                 key_end: None,
                 filter_type: FilterType::Delete.into(),
+                start_revision: None,
             };
             let mut watch_stream = meta_api.watch(req).await?;
             // Add a timeout period for watch.
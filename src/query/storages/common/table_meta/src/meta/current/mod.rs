@@ -13,11 +13,15 @@
 // limitations under the License.
 
 pub use v0::ColumnMeta as SingleColumnMeta;
+pub use v1::ColumnHistogram;
+pub use v1::HistogramBucket;
+pub use v1::MostCommonValue;
 pub use v1::TableSnapshotStatistics;
 pub use v2::BlockMeta;
 pub use v2::ClusterStatistics;
 pub use v2::ColumnMeta;
 pub use v2::ColumnStatistics;
+pub use v2::DeletionVectorMeta;
 pub use v2::Statistics;
 pub use v4::CompactSegmentInfo;
 pub use v4::SegmentInfo;
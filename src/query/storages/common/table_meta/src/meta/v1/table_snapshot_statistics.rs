@@ -15,6 +15,7 @@
 use std::collections::HashMap;
 
 use common_expression::ColumnId;
+use common_expression::Scalar;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -31,14 +32,63 @@ pub struct TableSnapshotStatistics {
     pub snapshot_id: SnapshotId,
 
     pub column_distinct_values: HashMap<ColumnId, u64>,
+
+    /// equi-depth histograms of columns, built from the sampled blocks.
+    ///
+    /// absent in the statistics collected by the older versions.
+    #[serde(default)]
+    pub column_histograms: HashMap<ColumnId, ColumnHistogram>,
+}
+
+/// Equi-depth histogram of a column, every bucket holds roughly the same number of rows.
+///
+/// Null values are not counted.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ColumnHistogram {
+    /// lower bound of the first bucket.
+    pub lower_bound: Scalar,
+    /// buckets in ascending order of their upper bounds.
+    pub buckets: Vec<HistogramBucket>,
+    /// most common values in descending order of their frequencies.
+    ///
+    /// they are also counted in the buckets.
+    pub most_common_values: Vec<MostCommonValue>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct HistogramBucket {
+    /// inclusive upper bound of the bucket, also the exclusive lower bound of the next one.
+    pub upper_bound: Scalar,
+    /// estimated number of rows in the bucket.
+    pub num_values: f64,
+    /// estimated number of distinct values in the bucket.
+    pub num_distinct: f64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct MostCommonValue {
+    pub value: Scalar,
+    /// estimated number of rows of the value.
+    pub num_values: f64,
+}
+
+impl ColumnHistogram {
+    /// Estimated number of (non-null) rows covered by the histogram.
+    pub fn num_values(&self) -> f64 {
+        self.buckets.iter().map(|b| b.num_values).sum()
+    }
 }
 
 impl TableSnapshotStatistics {
-    pub fn new(column_distinct_values: HashMap<ColumnId, u64>) -> Self {
+    pub fn new(
+        column_distinct_values: HashMap<ColumnId, u64>,
+        column_histograms: HashMap<ColumnId, ColumnHistogram>,
+    ) -> Self {
         Self {
             format_version: TableSnapshotStatistics::VERSION,
             snapshot_id: SnapshotId::new_v4(),
             column_distinct_values,
+            column_histograms,
         }
     }
 
@@ -49,4 +99,8 @@ impl TableSnapshotStatistics {
     pub fn get_column_distinct_values(&self) -> &HashMap<ColumnId, u64> {
         &self.column_distinct_values
     }
+
+    pub fn get_column_histograms(&self) -> &HashMap<ColumnId, ColumnHistogram> {
+        &self.column_histograms
+    }
 }
@@ -80,6 +80,23 @@ pub struct BlockMeta {
 
     // block create_on
     pub create_on: Option<DateTime<Utc>>,
+
+    /// deletion vector of the block, if some of its rows are deleted by merge-on-read mutations
+    #[serde(default)]
+    pub deletion_vector: Option<DeletionVectorMeta>,
+}
+
+/// Meta information of the deletion vector of a block
+///
+/// The block file is left untouched by a merge-on-read mutation, the offsets
+/// of the deleted rows are kept in a separate deletion vector file instead.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DeletionVectorMeta {
+    /// location of the deletion vector file
+    pub location: Location,
+    pub size: u64,
+    /// number of the rows marked as deleted
+    pub deleted_rows: u64,
 }
 
 impl BlockMeta {
@@ -109,6 +126,7 @@ impl BlockMeta {
             bloom_filter_index_size,
             compression,
             create_on,
+            deletion_vector: None,
         }
     }
 
@@ -116,6 +134,15 @@ impl BlockMeta {
         self.compression
     }
 
+    /// Get the number of rows which are not deleted by the deletion vector.
+    pub fn live_row_count(&self) -> u64 {
+        self.row_count
+            - self
+                .deletion_vector
+                .as_ref()
+                .map_or(0, |deletion_vector| deletion_vector.deleted_rows)
+    }
+
     /// Get the page size of the block.
     /// - If the format is parquet, its page size is its row count.
     /// - If the format is native, its page size is the row count of each page.
@@ -254,6 +281,7 @@ impl BlockMeta {
             bloom_filter_index_size: 0,
             compression: Compression::Lz4,
             create_on: None,
+            deletion_vector: None,
         }
     }
 
@@ -285,6 +313,7 @@ impl BlockMeta {
             bloom_filter_index_size: s.bloom_filter_index_size,
             compression: s.compression,
             create_on: None,
+            deletion_vector: None,
         }
     }
 }
@@ -0,0 +1,62 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use common_exception::Result;
+use common_expression::ColumnId;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::table::OPT_KEY_INVERTED_INDEXES;
+
+/// Definition of an inverted index on a string column of a fuse table.
+///
+/// The index is built for each block written after the index is created,
+/// and kept in a file next to the block.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct InvertedIndexMeta {
+    /// The indexed column, which stays the same if the column is renamed.
+    pub column_id: ColumnId,
+    /// Name of the tokenizer that splits the text into terms.
+    pub tokenizer: String,
+}
+
+/// Inverted indexes of a table by name.
+pub type InvertedIndexes = BTreeMap<String, InvertedIndexMeta>;
+
+/// Reads the inverted indexes kept in the table option `OPT_KEY_INVERTED_INDEXES`.
+pub fn read_inverted_indexes(options: &BTreeMap<String, String>) -> Result<InvertedIndexes> {
+    match options.get(OPT_KEY_INVERTED_INDEXES) {
+        Some(indexes) => Ok(serde_json::from_str(indexes)?),
+        None => Ok(InvertedIndexes::new()),
+    }
+}
+
+/// Writes the inverted indexes into the table option `OPT_KEY_INVERTED_INDEXES`,
+/// the option is removed if there are no indexes left.
+pub fn write_inverted_indexes(
+    options: &mut BTreeMap<String, String>,
+    indexes: &InvertedIndexes,
+) -> Result<()> {
+    if indexes.is_empty() {
+        options.remove(OPT_KEY_INVERTED_INDEXES);
+    } else {
+        options.insert(
+            OPT_KEY_INVERTED_INDEXES.to_owned(),
+            serde_json::to_string(indexes)?,
+        );
+    }
+    Ok(())
+}
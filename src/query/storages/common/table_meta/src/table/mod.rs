@@ -12,10 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod inverted_indexes;
+mod snapshot_refs;
 mod table_compression;
 mod table_keys;
 mod table_prefix;
 
+pub use inverted_indexes::*;
+pub use snapshot_refs::*;
 pub use table_compression::TableCompression;
 pub use table_keys::*;
 pub use table_prefix::*;
@@ -0,0 +1,87 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use chrono::DateTime;
+use chrono::Utc;
+use common_exception::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::table::OPT_KEY_SNAPSHOT_REFS;
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SnapshotRefType {
+    /// A read-only name of a snapshot.
+    Tag,
+    /// A writable snapshot chain, forked from a snapshot of the table.
+    Branch,
+}
+
+impl Display for SnapshotRefType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SnapshotRefType::Tag => write!(f, "tag"),
+            SnapshotRefType::Branch => write!(f, "branch"),
+        }
+    }
+}
+
+/// A named snapshot of a fuse table, the snapshot (and the files it references)
+/// is kept by purge and vacuum until the ref is dropped or expired.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SnapshotRef {
+    pub ref_type: SnapshotRefType,
+    /// Location of the tagged snapshot, or of the head of the branch.
+    pub location: String,
+    pub expire_at: Option<DateTime<Utc>>,
+}
+
+impl SnapshotRef {
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expire_at.map_or(false, |expire_at| expire_at <= now)
+    }
+}
+
+/// Snapshot refs of a table by name, tags and branches share the same namespace.
+pub type SnapshotRefs = BTreeMap<String, SnapshotRef>;
+
+/// Reads the snapshot refs kept in the table option `OPT_KEY_SNAPSHOT_REFS`.
+pub fn read_snapshot_refs(options: &BTreeMap<String, String>) -> Result<SnapshotRefs> {
+    match options.get(OPT_KEY_SNAPSHOT_REFS) {
+        Some(refs) => Ok(serde_json::from_str(refs)?),
+        None => Ok(SnapshotRefs::new()),
+    }
+}
+
+/// Writes the snapshot refs into the table option `OPT_KEY_SNAPSHOT_REFS`,
+/// the option is removed if there are no refs left.
+pub fn write_snapshot_refs(
+    options: &mut BTreeMap<String, String>,
+    refs: &SnapshotRefs,
+) -> Result<()> {
+    if refs.is_empty() {
+        options.remove(OPT_KEY_SNAPSHOT_REFS);
+    } else {
+        options.insert(
+            OPT_KEY_SNAPSHOT_REFS.to_owned(),
+            serde_json::to_string(refs)?,
+        );
+    }
+    Ok(())
+}
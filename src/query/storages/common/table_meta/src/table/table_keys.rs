@@ -23,12 +23,31 @@ pub const OPT_KEY_TABLE_COMPRESSION: &str = "compression";
 pub const OPT_KEY_COMMENT: &str = "comment";
 pub const OPT_KEY_ENGINE: &str = "engine";
 pub const OPT_KEY_BLOOM_INDEX_COLUMNS: &str = "bloom_index_columns";
+pub const OPT_KEY_NGRAM_INDEX_COLUMNS: &str = "ngram_index_columns";
+// Row-level TTL option, the expression of the time after which a row is expired.
+pub const OPT_KEY_TTL: &str = "ttl";
 
 // Attached table options.
 pub const OPT_KEY_TABLE_ATTACHED_DATA_URI: &str = "table_data_uri";
 // Read only attached table options.
 pub const OPT_KEY_TABLE_ATTACHED_READ_ONLY: &str = "read_only_attached";
 
+// Temporary table options, the storage prefix of the session that owns the table.
+pub const OPT_KEY_TEMP_PREFIX: &str = "temp_prefix";
+
+// Cloned table options, comma separated ids of the tables sharing files with the table:
+// the tables it was cloned from, and the tables cloned from it.
+pub const OPT_KEY_CLONED_FROM: &str = "cloned_from";
+pub const OPT_KEY_CLONES: &str = "clones";
+
+// Snapshot refs options, the json encoded tags and branches of the table.
+pub const OPT_KEY_SNAPSHOT_REFS: &str = "snapshot_refs";
+// The branch a table instance is navigated to, never persisted.
+pub const OPT_KEY_BRANCH: &str = "branch";
+
+// Inverted index options, the json encoded inverted indexes of the table.
+pub const OPT_KEY_INVERTED_INDEXES: &str = "inverted_indexes";
+
 /// Legacy table snapshot location key
 ///
 /// # Deprecated
@@ -46,6 +65,12 @@ pub static RESERVED_TABLE_OPTION_KEYS: Lazy<HashSet<&'static str>> = Lazy::new(|
     let mut r = HashSet::new();
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
+    r.insert(OPT_KEY_TEMP_PREFIX);
+    r.insert(OPT_KEY_CLONED_FROM);
+    r.insert(OPT_KEY_CLONES);
+    r.insert(OPT_KEY_SNAPSHOT_REFS);
+    r.insert(OPT_KEY_BRANCH);
+    r.insert(OPT_KEY_INVERTED_INDEXES);
     r
 });
 
@@ -54,6 +79,12 @@ pub static INTERNAL_TABLE_OPTION_KEYS: Lazy<HashSet<&'static str>> = Lazy::new(|
     let mut r = HashSet::new();
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
     r.insert(OPT_KEY_DATABASE_ID);
+    r.insert(OPT_KEY_TEMP_PREFIX);
+    r.insert(OPT_KEY_CLONED_FROM);
+    r.insert(OPT_KEY_CLONES);
+    r.insert(OPT_KEY_SNAPSHOT_REFS);
+    r.insert(OPT_KEY_BRANCH);
+    r.insert(OPT_KEY_INVERTED_INDEXES);
     r
 });
 
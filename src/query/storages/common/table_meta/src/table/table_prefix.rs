@@ -23,3 +23,8 @@ pub fn table_storage_prefix(database_id: impl Display, table_id: impl Display) -
 pub fn database_storage_prefix(database_id: impl Display) -> String {
     format!("{}", database_id)
 }
+
+/// Constructs the prefix path which covers all the data of the temporary tables of a given session
+pub fn temp_table_storage_prefix(session_id: impl Display) -> String {
+    format!("_tmp_tbl/{}", session_id)
+}
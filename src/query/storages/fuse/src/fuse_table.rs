@@ -26,6 +26,7 @@ use common_catalog::plan::PushDownInfo;
 use common_catalog::table::AppendMode;
 use common_catalog::table::ColumnStatisticsProvider;
 use common_catalog::table::NavigationDescriptor;
+use common_catalog::table::SnapshotRefDescriptor;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -55,17 +56,22 @@ use storages_common_table_meta::meta::Statistics as FuseStatistics;
 use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::TableSnapshotStatistics;
 use storages_common_table_meta::meta::Versioned;
+use storages_common_table_meta::table::read_inverted_indexes;
 use storages_common_table_meta::table::table_storage_prefix;
+use storages_common_table_meta::table::InvertedIndexes;
+use storages_common_table_meta::table::SnapshotRefType;
 use storages_common_table_meta::table::TableCompression;
 use storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use storages_common_table_meta::table::OPT_KEY_LEGACY_SNAPSHOT_LOC;
+use storages_common_table_meta::table::OPT_KEY_NGRAM_INDEX_COLUMNS;
 use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_DATA_URI;
 use storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_READ_ONLY;
 use storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
+use storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
 use uuid::Uuid;
 
 use crate::fuse_column::FuseTableColumnStatisticsProvider;
@@ -84,6 +90,7 @@ use crate::DEFAULT_ROW_PER_PAGE;
 use crate::DEFAULT_ROW_PER_PAGE_FOR_BLOCKING;
 use crate::FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
 use crate::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
+use crate::FUSE_OPT_KEY_MERGE_ON_READ;
 use crate::FUSE_OPT_KEY_ROW_PER_BLOCK;
 use crate::FUSE_OPT_KEY_ROW_PER_PAGE;
 use crate::FUSE_TBL_LAST_SNAPSHOT_HINT;
@@ -97,6 +104,8 @@ pub struct FuseTable {
     pub(crate) storage_format: FuseStorageFormat,
     pub(crate) table_compression: TableCompression,
     pub(crate) bloom_index_cols: BloomIndexColumns,
+    pub(crate) ngram_index_cols: BloomIndexColumns,
+    pub(crate) inverted_indexes: InvertedIndexes,
 
     pub(crate) operator: Operator,
     pub(crate) data_metrics: Arc<StorageMetrics>,
@@ -172,6 +181,14 @@ impl FuseTable {
             .and_then(|s| s.parse::<BloomIndexColumns>().ok())
             .unwrap_or(BloomIndexColumns::All);
 
+        let ngram_index_cols = table_info
+            .options()
+            .get(OPT_KEY_NGRAM_INDEX_COLUMNS)
+            .and_then(|s| s.parse::<BloomIndexColumns>().ok())
+            .unwrap_or(BloomIndexColumns::None);
+
+        let inverted_indexes = read_inverted_indexes(table_info.options()).unwrap_or_default();
+
         let part_prefix = table_info.meta.part_prefix.clone();
 
         let meta_location_generator =
@@ -182,6 +199,8 @@ impl FuseTable {
             meta_location_generator,
             cluster_key_meta,
             bloom_index_cols,
+            ngram_index_cols,
+            inverted_indexes,
             operator,
             data_metrics,
             storage_format: FuseStorageFormat::from_str(storage_format.as_str())?,
@@ -233,15 +252,27 @@ impl FuseTable {
         }
     }
 
+    /// Whether a deletion keeps the blocks it touches and writes deletion vectors instead.
+    /// Only the parquet storage format supports it.
+    pub fn merge_on_read(&self) -> bool {
+        matches!(self.storage_format, FuseStorageFormat::Parquet)
+            && self.get_option(FUSE_OPT_KEY_MERGE_ON_READ, false)
+    }
+
     pub fn parse_storage_prefix(table_info: &TableInfo) -> Result<String> {
         // if OPT_KE_STORAGE_PREFIX is specified, use it as storage prefix
         if let Some(prefix) = table_info.options().get(OPT_KEY_STORAGE_PREFIX) {
             return Ok(prefix.clone());
         }
 
-        // otherwise, use database id and table id as storage prefix
-
         let table_id = table_info.ident.table_id;
+
+        // temporary tables are kept under the prefix of the session that owns them
+        if let Some(prefix) = table_info.options().get(OPT_KEY_TEMP_PREFIX) {
+            return Ok(format!("{}/{}", prefix, table_id));
+        }
+
+        // otherwise, use database id and table id as storage prefix
         let db_id = table_info
             .options()
             .get(OPT_KEY_DATABASE_ID)
@@ -392,6 +423,14 @@ impl FuseTable {
         self.bloom_index_cols.clone()
     }
 
+    pub fn ngram_index_cols(&self) -> BloomIndexColumns {
+        self.ngram_index_cols.clone()
+    }
+
+    pub fn inverted_indexes(&self) -> &InvertedIndexes {
+        &self.inverted_indexes
+    }
+
     // Check if table is attached.
     fn is_table_attached(table_meta_options: &BTreeMap<String, String>) -> bool {
         table_meta_options
@@ -684,12 +723,14 @@ impl Table for FuseTable {
                 FuseTableColumnStatisticsProvider::new(
                     stats.clone(),
                     Some(table_statistics.column_distinct_values.clone()),
+                    Some(table_statistics.column_histograms.clone()),
                     snapshot.summary.row_count,
                 )
             } else {
                 FuseTableColumnStatisticsProvider::new(
                     stats.clone(),
                     None,
+                    None,
                     snapshot.summary.row_count,
                 )
             }
@@ -718,6 +759,12 @@ impl Table for FuseTable {
             NavigationPoint::TimePoint(time_point) => Ok(self
                 .navigate_to_time_point(snapshot_location, *time_point)
                 .await?),
+            NavigationPoint::Tag(name) => Ok(self
+                .navigate_to_snapshot_ref(SnapshotRefType::Tag, name)
+                .await?),
+            NavigationPoint::Branch(name) => Ok(self
+                .navigate_to_snapshot_ref(SnapshotRefType::Branch, name)
+                .await?),
         }
     }
 
@@ -782,6 +829,26 @@ impl Table for FuseTable {
         self.do_revert_to(ctx.as_ref(), point).await
     }
 
+    #[async_backtrace::framed]
+    async fn create_snapshot_ref(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        descriptor: SnapshotRefDescriptor,
+    ) -> Result<()> {
+        self.do_create_snapshot_ref(ctx.as_ref(), descriptor).await
+    }
+
+    #[async_backtrace::framed]
+    async fn drop_snapshot_ref(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        ref_type: SnapshotRefType,
+        name: &str,
+    ) -> Result<()> {
+        self.do_drop_snapshot_ref(ctx.as_ref(), ref_type, name)
+            .await
+    }
+
     fn support_prewhere(&self) -> bool {
         matches!(self.storage_format, FuseStorageFormat::Native)
     }
@@ -0,0 +1,113 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use opendal::Operator;
+use storages_common_table_meta::meta::DeletionVectorMeta;
+use storages_common_table_meta::meta::Location;
+
+use crate::io::write_data;
+
+/// The offsets of the rows deleted from a block by merge-on-read mutations.
+///
+/// Serialized as the ascending offsets, each one a little-endian u32.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DeletionVector {
+    rows: BTreeSet<u32>,
+}
+
+impl DeletionVector {
+    pub const VERSION: u64 = 0;
+
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    pub fn contains(&self, row: usize) -> bool {
+        self.rows.contains(&(row as u32))
+    }
+
+    pub fn insert(&mut self, row: usize) {
+        self.rows.insert(row as u32);
+    }
+
+    /// The filter of a block with `num_rows` rows, only the rows not deleted are set.
+    pub fn selection(&self, num_rows: usize) -> Bitmap {
+        let mut bitmap = MutableBitmap::from_len_set(num_rows);
+        for row in self.rows.iter() {
+            bitmap.set(*row as usize, false);
+        }
+        bitmap.into()
+    }
+
+    /// The offsets of the rows not deleted, of a block with `num_rows` rows.
+    pub fn live_rows(&self, num_rows: usize) -> Vec<usize> {
+        (0..num_rows).filter(|row| !self.contains(*row)).collect()
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(self.rows.len() * 4);
+        for row in self.rows.iter() {
+            data.extend_from_slice(&row.to_le_bytes());
+        }
+        data
+    }
+
+    pub fn from_slice(data: &[u8]) -> Result<Self> {
+        if data.len() % 4 != 0 {
+            return Err(ErrorCode::StorageOther(format!(
+                "invalid deletion vector, length {} is not a multiple of 4",
+                data.len()
+            )));
+        }
+        let rows = data
+            .chunks_exact(4)
+            .map(|v| u32::from_le_bytes([v[0], v[1], v[2], v[3]]))
+            .collect();
+        Ok(Self { rows })
+    }
+
+    #[async_backtrace::framed]
+    pub async fn read(dal: &Operator, meta: &DeletionVectorMeta) -> Result<Self> {
+        let data = dal.read(&meta.location.0).await?;
+        Self::from_slice(&data)
+    }
+
+    pub fn sync_read(dal: &Operator, meta: &DeletionVectorMeta) -> Result<Self> {
+        let data = dal.blocking().read(&meta.location.0)?;
+        Self::from_slice(&data)
+    }
+
+    /// Writes the deletion vector to `location`, and returns its meta.
+    #[async_backtrace::framed]
+    pub async fn write(&self, dal: &Operator, location: Location) -> Result<DeletionVectorMeta> {
+        let data = self.to_bytes();
+        let size = data.len() as u64;
+        write_data(data, dal, &location.0).await?;
+        Ok(DeletionVectorMeta {
+            location,
+            size,
+            deleted_rows: self.rows.len() as u64,
+        })
+    }
+}
@@ -26,11 +26,13 @@ use common_expression::DataBlock;
 use common_expression::FieldIndex;
 use common_expression::TableField;
 use common_expression::TableSchemaRef;
+use common_functions::scalars::Tokenizer;
 use common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
 use common_io::constants::DEFAULT_BLOCK_INDEX_BUFFER_SIZE;
 use opendal::Operator;
 use storages_common_blocks::blocks_to_parquet;
 use storages_common_index::BloomIndex;
+use storages_common_index::InvertedIndex;
 use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::ClusterStatistics;
 use storages_common_table_meta::meta::ColumnMeta;
@@ -118,6 +120,7 @@ impl BloomIndexState {
         block: &DataBlock,
         location: Location,
         bloom_columns_map: BTreeMap<FieldIndex, TableField>,
+        ngram_columns_map: BTreeMap<FieldIndex, TableField>,
     ) -> Result<Option<Self>> {
         // write index
         let maybe_bloom_index = BloomIndex::try_create(
@@ -125,6 +128,7 @@ impl BloomIndexState {
             location.1,
             &[block],
             bloom_columns_map,
+            ngram_columns_map,
         )?;
         if let Some(bloom_index) = maybe_bloom_index {
             let index_block = bloom_index.serialize_to_data_block()?;
@@ -150,11 +154,38 @@ impl BloomIndexState {
     }
 }
 
+pub struct InvertedIndexState {
+    pub(crate) data: Vec<u8>,
+    pub(crate) location: String,
+}
+
+impl InvertedIndexState {
+    pub fn try_create(
+        block: &DataBlock,
+        block_location: &str,
+        inverted_index_columns: &BTreeMap<FieldIndex, (ColumnId, Tokenizer)>,
+    ) -> Result<Option<Self>> {
+        if let Some(inverted_index) = InvertedIndex::try_create(block, inverted_index_columns)? {
+            let location =
+                TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(
+                    block_location,
+                );
+            Ok(Some(Self {
+                data: inverted_index.to_bytes()?,
+                location,
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
 pub struct BlockSerialization {
     pub block_raw_data: Vec<u8>,
     pub size: u64, // TODO redundancy
     pub block_meta: BlockMeta,
     pub bloom_index_state: Option<BloomIndexState>,
+    pub inverted_index_state: Option<InvertedIndexState>,
 }
 
 #[derive(Clone)]
@@ -165,6 +196,8 @@ pub struct BlockBuilder {
     pub write_settings: WriteSettings,
     pub cluster_stats_gen: ClusterStatsGenerator,
     pub bloom_columns_map: BTreeMap<FieldIndex, TableField>,
+    pub ngram_columns_map: BTreeMap<FieldIndex, TableField>,
+    pub inverted_index_columns: BTreeMap<FieldIndex, (ColumnId, Tokenizer)>,
 }
 
 impl BlockBuilder {
@@ -180,10 +213,16 @@ impl BlockBuilder {
             &data_block,
             bloom_index_location,
             self.bloom_columns_map.clone(),
+            self.ngram_columns_map.clone(),
         )?;
         let column_distinct_count = bloom_index_state
             .as_ref()
             .map(|i| i.column_distinct_count.clone());
+        let inverted_index_state = InvertedIndexState::try_create(
+            &data_block,
+            &block_location.0,
+            &self.inverted_index_columns,
+        )?;
 
         let row_count = data_block.num_rows() as u64;
         let block_size = data_block.memory_size() as u64;
@@ -213,6 +252,7 @@ impl BlockBuilder {
                 .unwrap_or_default(),
             compression: self.write_settings.table_compression.try_into()?,
             create_on: Some(Utc::now()),
+            deletion_vector: None,
         };
 
         let serialized = BlockSerialization {
@@ -220,6 +260,7 @@ impl BlockBuilder {
             size: file_size,
             block_meta,
             bloom_index_state,
+            inverted_index_state,
         };
         Ok(serialized)
     }
@@ -16,20 +16,36 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Instant;
 
+use common_catalog::plan::Projection;
 use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::ColumnId;
+use common_expression::ScalarRef;
+use common_expression::TableDataType;
 use log::warn;
+use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::ColumnHistogram;
 use storages_common_table_meta::meta::SegmentInfo;
+use storages_common_table_meta::meta::StatisticsOfColumns;
 use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::TableSnapshotStatistics;
 
+use crate::io::DeletionVector;
+use crate::io::ReadSettings;
 use crate::io::SegmentsIO;
+use crate::statistics::build_column_histogram;
 use crate::statistics::reduce_block_statistics;
 use crate::statistics::reduce_cluster_statistics;
+use crate::statistics::HISTOGRAM_BUCKETS;
 use crate::FuseTable;
 
+/// Max number of blocks read to build the histograms.
+const HISTOGRAM_SAMPLE_BLOCKS: usize = 16;
+/// Max number of rows sampled from each block to build the histograms.
+const HISTOGRAM_SAMPLE_ROWS_PER_BLOCK: usize = 8192;
+
 impl FuseTable {
     #[async_backtrace::framed]
     pub async fn do_analyze(&self, ctx: &Arc<dyn TableContext>) -> Result<()> {
@@ -59,6 +75,10 @@ impl FuseTable {
             let mut read_segment_count = 0;
             let mut col_stats = HashMap::new();
             let mut cluster_stats = None;
+            // Blocks evenly picked from the snapshot, to build the histograms.
+            let mut sampled_blocks: Vec<Arc<BlockMeta>> = Vec::new();
+            let sample_stride =
+                (snapshot.summary.block_count as usize / HISTOGRAM_SAMPLE_BLOCKS).max(1);
 
             let start = Instant::now();
             let segments_io = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
@@ -80,11 +100,16 @@ impl FuseTable {
                     stats_of_columns.push(segment.summary.col_stats.clone());
                     blocks_cluster_stats.push(segment.summary.cluster_stats.clone());
                     segment.blocks.iter().for_each(|block| {
-                        let block = block.as_ref();
                         let row_count = block.row_count;
                         if row_count != 0 {
+                            if block_count_sum as usize % sample_stride == 0
+                                && sampled_blocks.len() < HISTOGRAM_SAMPLE_BLOCKS
+                            {
+                                sampled_blocks.push(block.clone());
+                            }
                             block_count_sum += 1;
-                            row_count_sum += row_count;
+                            // The histograms only count the rows not deleted by the deletion vector.
+                            row_count_sum += block.live_row_count();
                             for (i, col_stat) in block.col_stats.iter() {
                                 let density = col_stat
                                     .distinct_of_values
@@ -127,8 +152,13 @@ impl FuseTable {
                 ndv_map.insert(*i, (density_avg * row_count_sum as f64) as u64);
             }
 
-            // 3. Generate new table statistics
-            let table_statistics = TableSnapshotStatistics::new(ndv_map);
+            // 3. Sample blocks to build histograms of columns.
+            let column_histograms = self
+                .build_column_histograms(ctx, &sampled_blocks, &col_stats, row_count_sum, &ndv_map)
+                .await?;
+
+            // 4. Generate new table statistics
+            let table_statistics = TableSnapshotStatistics::new(ndv_map, column_histograms);
             let table_statistics_location = self
                 .meta_location_generator
                 .snapshot_statistics_location_from_uuid(
@@ -136,7 +166,7 @@ impl FuseTable {
                     table_statistics.format_version(),
                 )?;
 
-            // 4. Save table statistics
+            // 5. Save table statistics
             let mut new_snapshot = TableSnapshot::from_previous(&snapshot);
             new_snapshot.summary.col_stats = col_stats;
             new_snapshot.summary.cluster_stats = cluster_stats;
@@ -155,4 +185,81 @@ impl FuseTable {
 
         Ok(())
     }
+
+    #[async_backtrace::framed]
+    async fn build_column_histograms(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        blocks: &[Arc<BlockMeta>],
+        col_stats: &StatisticsOfColumns,
+        row_count: u64,
+        ndv_map: &HashMap<ColumnId, u64>,
+    ) -> Result<HashMap<ColumnId, ColumnHistogram>> {
+        let schema = self.schema();
+        // Only the columns whose values could be compared by the optimizer.
+        let fields = schema
+            .fields()
+            .iter()
+            .enumerate()
+            .filter(|(_, field)| {
+                matches!(
+                    field.data_type().remove_nullable(),
+                    TableDataType::Boolean
+                        | TableDataType::String
+                        | TableDataType::Number(_)
+                        | TableDataType::Timestamp
+                        | TableDataType::Date
+                )
+            })
+            .collect::<Vec<_>>();
+        if blocks.is_empty() || fields.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let projection = Projection::Columns(fields.iter().map(|(idx, _)| *idx).collect());
+        let block_reader = self.create_block_reader(ctx.clone(), projection, false, false)?;
+        let settings = ReadSettings::from_ctx(ctx)?;
+
+        let mut samples = vec![Vec::new(); fields.len()];
+        for block in blocks {
+            let mut data_block = block_reader
+                .read_by_meta(&settings, block, &self.storage_format)
+                .await?;
+            // The deleted rows must not be counted in the histograms.
+            if let Some(meta) = &block.deletion_vector {
+                let deletion_vector = DeletionVector::read(&self.operator, meta).await?;
+                let selection = deletion_vector.selection(data_block.num_rows());
+                data_block = data_block.filter_with_bitmap(&selection)?;
+            }
+            let num_rows = data_block.num_rows();
+            let step = (num_rows / HISTOGRAM_SAMPLE_ROWS_PER_BLOCK).max(1);
+            for (entry, samples) in data_block.columns().iter().zip(samples.iter_mut()) {
+                let column = entry
+                    .value
+                    .convert_to_full_column(&entry.data_type, num_rows);
+                for row in (0..num_rows).step_by(step) {
+                    match column.index(row) {
+                        None | Some(ScalarRef::Null) => {}
+                        Some(value) => samples.push(value.to_owned()),
+                    }
+                }
+            }
+        }
+
+        let mut histograms = HashMap::with_capacity(fields.len());
+        for ((_, field), samples) in fields.into_iter().zip(samples) {
+            let column_id = field.column_id();
+            let null_count = col_stats.get(&column_id).map_or(0, |s| s.null_count);
+            let ndv = ndv_map.get(&column_id).copied().unwrap_or(0);
+            if let Some(histogram) = build_column_histogram(
+                samples,
+                row_count.saturating_sub(null_count),
+                ndv,
+                HISTOGRAM_BUCKETS,
+            ) {
+                histograms.insert(column_id, histogram);
+            }
+        }
+        Ok(histograms)
+    }
 }
@@ -0,0 +1,326 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::hash::Hash;
+use std::sync::Arc;
+
+use common_catalog::plan::Projection;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataBlock;
+use futures::TryStreamExt;
+use storages_common_cache::LoadParams;
+use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::Location;
+use storages_common_table_meta::meta::SegmentInfo;
+use storages_common_table_meta::meta::TableSnapshot;
+
+use crate::io::DeletionVector;
+use crate::io::MetaReaders;
+use crate::io::ReadSettings;
+use crate::io::SegmentsIO;
+use crate::io::SnapshotHistoryReader;
+use crate::io::TableMetaLocationGenerator;
+use crate::FuseTable;
+
+/// The blocks removed and added by a snapshot, compared to its previous snapshot.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Default)]
+pub struct SnapshotChanges {
+    pub removed_blocks: Vec<Arc<BlockMeta>>,
+    pub added_blocks: Vec<Arc<BlockMeta>>,
+    /// The (position in `removed_blocks`, position in `added_blocks`) of the blocks rewritten
+    /// in place by a mutation, e.g. UPDATE, and the blocks they are rewritten to.
+    pub rewritten_blocks: Vec<(usize, usize)>,
+}
+
+impl SnapshotChanges {
+    pub fn is_empty(&self) -> bool {
+        self.removed_blocks.is_empty() && self.added_blocks.is_empty()
+    }
+}
+
+impl FuseTable {
+    /// Collect the changes of the table since the snapshot at `base_location`, one item for
+    /// each snapshot committed after it, in the order of commit. `None` means that the table
+    /// had no snapshot at the base point.
+    ///
+    /// If some snapshots between the base snapshot and the latest one have been purged,
+    /// the two snapshots are compared directly, and only one item is returned.
+    #[async_backtrace::framed]
+    pub async fn collect_changes(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        base_location: Option<String>,
+    ) -> Result<Vec<SnapshotChanges>> {
+        let Some(location) = self.snapshot_loc().await? else {
+            return Ok(vec![]);
+        };
+        if base_location.as_ref() == Some(&location) {
+            return Ok(vec![]);
+        }
+
+        let base_snapshot = match &base_location {
+            Some(base_location) => Some(self.read_base_snapshot(base_location).await?),
+            None => None,
+        };
+        let base_id = base_snapshot.as_ref().map(|snapshot| snapshot.snapshot_id);
+
+        // walk through the history from the latest snapshot, until the base snapshot is found.
+        // snapshots are order by timestamp DESC.
+        let snapshot_version = TableMetaLocationGenerator::snapshot_version(location.as_str());
+        let reader = MetaReaders::table_snapshot_reader(self.get_operator());
+        let mut snapshot_stream = reader.snapshot_history(
+            location,
+            snapshot_version,
+            self.meta_location_generator().clone(),
+        );
+        let mut history = vec![];
+        let mut reached = false;
+        while let Some((snapshot, _)) = snapshot_stream.try_next().await? {
+            if base_id.is_some() && Some(snapshot.snapshot_id) == base_id {
+                reached = true;
+                break;
+            }
+            reached = base_id.is_none() && snapshot.prev_snapshot_id.is_none();
+            history.push(snapshot);
+        }
+
+        let segments_io = SegmentsIO::create(ctx, self.operator.clone(), self.schema());
+        let mut changes = vec![];
+        if reached {
+            let mut prev = base_snapshot;
+            for snapshot in history.into_iter().rev() {
+                let change = diff_snapshots(&segments_io, prev.as_deref(), &snapshot, true).await?;
+                if !change.is_empty() {
+                    changes.push(change);
+                }
+                prev = Some(snapshot);
+            }
+        } else if let Some(latest) = history.first() {
+            // the mutations in between are unknown, so are the blocks rewritten by them.
+            let change =
+                diff_snapshots(&segments_io, base_snapshot.as_deref(), latest, false).await?;
+            if !change.is_empty() {
+                changes.push(change);
+            }
+        }
+        Ok(changes)
+    }
+
+    /// Read the data of the given blocks, with all the columns of the table.
+    #[async_backtrace::framed]
+    pub async fn read_changed_blocks(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        blocks: &[Arc<BlockMeta>],
+    ) -> Result<Vec<DataBlock>> {
+        let projection = Projection::Columns((0..self.schema().fields().len()).collect());
+        let block_reader = self.create_block_reader(ctx.clone(), projection, false, false)?;
+        let settings = ReadSettings::from_ctx(&ctx)?;
+
+        let mut data_blocks = Vec::with_capacity(blocks.len());
+        for block in blocks {
+            let mut data_block = block_reader
+                .read_by_meta(&settings, block, &self.storage_format)
+                .await?;
+            if let Some(meta) = &block.deletion_vector {
+                let deletion_vector = DeletionVector::read(&self.operator, meta).await?;
+                let selection = deletion_vector.selection(data_block.num_rows());
+                data_block = data_block.filter_with_bitmap(&selection)?;
+            }
+            data_blocks.push(data_block);
+        }
+        Ok(data_blocks)
+    }
+
+    #[async_backtrace::framed]
+    async fn read_base_snapshot(&self, location: &str) -> Result<Arc<TableSnapshot>> {
+        let reader = MetaReaders::table_snapshot_reader(self.get_operator());
+        let params = LoadParams {
+            location: location.to_string(),
+            len_hint: None,
+            ver: TableMetaLocationGenerator::snapshot_version(location),
+            put_cache: true,
+        };
+        reader.read(&params).await.map_err(|e| {
+            if e.code() == ErrorCode::STORAGE_NOT_FOUND {
+                ErrorCode::TableHistoricalDataNotFound(format!(
+                    "The base snapshot {} of the changes is not found, it may have been purged",
+                    location
+                ))
+            } else {
+                e
+            }
+        })
+    }
+}
+
+async fn diff_snapshots(
+    segments_io: &SegmentsIO,
+    prev: Option<&TableSnapshot>,
+    snapshot: &TableSnapshot,
+    pair_rewritten: bool,
+) -> Result<SnapshotChanges> {
+    let prev_segments = prev.map_or(&[][..], |prev| prev.segments.as_slice());
+    let prev_set: HashSet<&Location> = prev_segments.iter().collect();
+    let set: HashSet<&Location> = snapshot.segments.iter().collect();
+
+    // only the segments that differ are read, the blocks moved between segments
+    // (e.g. by segment compaction) are not changes.
+    let removed_segments = prev_segments
+        .iter()
+        .filter(|location| !set.contains(location))
+        .cloned()
+        .collect::<Vec<_>>();
+    let added_segments = snapshot
+        .segments
+        .iter()
+        .filter(|location| !prev_set.contains(location))
+        .cloned()
+        .collect::<Vec<_>>();
+    let removed = read_segment_blocks(segments_io, &removed_segments).await?;
+    let added = read_segment_blocks(segments_io, &added_segments).await?;
+
+    // a block whose deletion vector is changed by a merge-on-read mutation is taken as
+    // removed and added again, the rows still alive are cancelled out by the reader.
+    let removed_keys: HashSet<_> = removed.iter().flatten().map(|b| block_key(b)).collect();
+    let added_keys: HashSet<_> = added.iter().flatten().map(|b| block_key(b)).collect();
+    let removed_blocks = changed_blocks(&removed, &added_keys);
+    let added_blocks = changed_blocks(&added, &removed_keys);
+
+    let mut rewritten_blocks = vec![];
+    if pair_rewritten {
+        // the positions of the removed and added segments in the two snapshots.
+        let removed_pos = changed_positions(prev_segments, &set);
+        let added_pos = changed_positions(&snapshot.segments, &prev_set);
+        for (p, c) in pair_replaced(prev_segments, &snapshot.segments) {
+            let (r, a) = (removed_pos[&p], added_pos[&c]);
+            let old = removed[r].iter().map(|b| &b.location).collect::<Vec<_>>();
+            let new = added[a].iter().map(|b| &b.location).collect::<Vec<_>>();
+            for (i, j) in pair_replaced(&old, &new) {
+                if let (Some(i), Some(j)) = (removed_blocks.get(&(r, i)), added_blocks.get(&(a, j)))
+                {
+                    rewritten_blocks.push((*i, *j));
+                }
+            }
+        }
+    }
+
+    Ok(SnapshotChanges {
+        removed_blocks: flatten_changed_blocks(&removed, &removed_blocks),
+        added_blocks: flatten_changed_blocks(&added, &added_blocks),
+        rewritten_blocks,
+    })
+}
+
+/// The positions in the changes of the blocks not in `others`, by (segment, block) positions.
+fn changed_blocks(
+    segments: &[Vec<Arc<BlockMeta>>],
+    others: &HashSet<(&Location, Option<&Location>)>,
+) -> HashMap<(usize, usize), usize> {
+    segments
+        .iter()
+        .enumerate()
+        .flat_map(|(s, blocks)| {
+            blocks
+                .iter()
+                .enumerate()
+                .map(move |(b, block)| (s, b, block))
+        })
+        .filter(|(_, _, block)| !others.contains(&block_key(block)))
+        .enumerate()
+        .map(|(pos, (s, b, _))| ((s, b), pos))
+        .collect()
+}
+
+fn changed_positions(segments: &[Location], others: &HashSet<&Location>) -> HashMap<usize, usize> {
+    segments
+        .iter()
+        .enumerate()
+        .filter(|(_, location)| !others.contains(location))
+        .enumerate()
+        .map(|(pos, (index, _))| (index, pos))
+        .collect()
+}
+
+fn flatten_changed_blocks(
+    segments: &[Vec<Arc<BlockMeta>>],
+    changed: &HashMap<(usize, usize), usize>,
+) -> Vec<Arc<BlockMeta>> {
+    let mut blocks = vec![None; changed.len()];
+    for ((s, b), pos) in changed.iter() {
+        blocks[*pos] = Some(segments[*s][*b].clone());
+    }
+    blocks.into_iter().flatten().collect()
+}
+
+/// Pairs the items of `prev` and `curr` that take the place of each other, as their positions.
+///
+/// A mutation replaces a segment or block in place, drops the ones whose rows are all removed,
+/// and puts the appended segments at the front. The items in both lists split the others into
+/// gaps, and the items of a gap are paired in order only if there are as many of them in `prev`
+/// as in `curr`, otherwise it's unknown which of them are dropped or appended.
+fn pair_replaced<T: Eq + Hash>(prev: &[T], curr: &[T]) -> Vec<(usize, usize)> {
+    let prev_set: HashSet<&T> = prev.iter().collect();
+    let curr_set: HashSet<&T> = curr.iter().collect();
+
+    let mut pairs = vec![];
+    let (mut i, mut j) = (0, 0);
+    loop {
+        let (gap_i, gap_j) = (i, j);
+        while i < prev.len() && !curr_set.contains(&prev[i]) {
+            i += 1;
+        }
+        while j < curr.len() && !prev_set.contains(&curr[j]) {
+            j += 1;
+        }
+        if i - gap_i == j - gap_j {
+            pairs.extend((gap_i..i).zip(gap_j..j));
+        }
+
+        // the kept items are in the same order, unless they are reorganized.
+        if i == prev.len() || j == curr.len() || prev[i] != curr[j] {
+            break;
+        }
+        i += 1;
+        j += 1;
+    }
+    pairs
+}
+
+fn block_key(block: &BlockMeta) -> (&Location, Option<&Location>) {
+    let deletion_vector = block.deletion_vector.as_ref().map(|meta| &meta.location);
+    (&block.location, deletion_vector)
+}
+
+async fn read_segment_blocks(
+    segments_io: &SegmentsIO,
+    locations: &[Location],
+) -> Result<Vec<Vec<Arc<BlockMeta>>>> {
+    let mut blocks = vec![];
+    if locations.is_empty() {
+        return Ok(blocks);
+    }
+    let segments = segments_io
+        .read_segments::<SegmentInfo>(locations, true)
+        .await?;
+    for segment in segments {
+        blocks.push(segment?.blocks);
+    }
+    Ok(blocks)
+}
@@ -0,0 +1,277 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use backoff::backoff::Backoff;
+use common_catalog::catalog::Catalog;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableStatistics;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeq;
+use log::debug;
+use log::info;
+use storages_common_locks::set_backoff;
+use storages_common_table_meta::meta::TableSnapshot;
+use storages_common_table_meta::meta::Versioned;
+use storages_common_table_meta::table::OPT_KEY_CLONED_FROM;
+use storages_common_table_meta::table::OPT_KEY_CLONES;
+use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+use uuid::Uuid;
+
+use crate::FuseTable;
+
+impl FuseTable {
+    /// Makes this newly created table share the files of `source` (navigated to the point
+    /// being cloned): the first snapshot of this table references the segments of `source`,
+    /// and this table is registered as a clone of `source` and of the tables `source`
+    /// was cloned from, so that their purges keep the files this table references.
+    #[async_backtrace::framed]
+    pub async fn do_clone_from(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        source: &FuseTable,
+    ) -> Result<()> {
+        let Some(source_snapshot) = source.read_table_snapshot().await? else {
+            // nothing to share
+            return Ok(());
+        };
+
+        // 1. prepare the first snapshot, it does not link to the history of the source table
+        let new_snapshot = TableSnapshot::new(
+            Uuid::new_v4(),
+            &None,
+            None,
+            source_snapshot.schema.clone(),
+            source_snapshot.summary.clone(),
+            source_snapshot.segments.clone(),
+            self.cluster_key_meta.clone(),
+            // table statistics are not shared
+            None,
+        );
+
+        // 2. write down the snapshot
+        let new_snapshot_loc = self
+            .meta_location_generator
+            .snapshot_location_from_uuid(&new_snapshot.snapshot_id, TableSnapshot::VERSION)?;
+        let bytes = new_snapshot.to_bytes()?;
+        self.operator.write(&new_snapshot_loc, bytes).await?;
+
+        // 3. commit the new table meta, and register the clone on the tables it shares files with
+        let mut ancestors = cloned_table_ids(source.table_info.options(), OPT_KEY_CLONED_FROM);
+        ancestors.push(source.get_id());
+
+        let mut new_table_meta = self.table_info.meta.clone();
+        new_table_meta.options.insert(
+            OPT_KEY_SNAPSHOT_LOCATION.to_owned(),
+            new_snapshot_loc.clone(),
+        );
+        new_table_meta
+            .options
+            .insert(OPT_KEY_CLONED_FROM.to_owned(), join_table_ids(&ancestors));
+        let summary = &new_snapshot.summary;
+        new_table_meta.statistics = TableStatistics {
+            number_of_rows: summary.row_count,
+            data_bytes: summary.uncompressed_byte_size,
+            compressed_data_bytes: summary.compressed_byte_size,
+            index_data_bytes: summary.index_size,
+            number_of_segments: Some(new_snapshot.segments.len() as u64),
+            number_of_blocks: Some(summary.block_count),
+        };
+
+        let catalog = ctx.get_catalog(self.table_info.catalog()).await?;
+        let mut backoff = set_backoff(None, None, None);
+        loop {
+            let mut update_table_metas = vec![UpdateTableMetaReq {
+                table_id: self.get_id(),
+                seq: MatchSeq::Exact(self.table_info.ident.seq),
+                new_table_meta: new_table_meta.clone(),
+                copied_files: None,
+                deduplicated_label: None,
+            }];
+            // the source tables may be updated concurrently, re-read their metas on each retry
+            update_table_metas.extend(
+                Self::update_clones_reqs(catalog.as_ref(), &ancestors, |clones| {
+                    clones.push(self.get_id())
+                })
+                .await?,
+            );
+
+            match catalog
+                .update_multi_table_meta(UpdateMultiTableMetaReq { update_table_metas })
+                .await
+            {
+                Ok(_) => {
+                    // try keep a hit file of last snapshot, unless the update is buffered by an explicit transaction
+                    if !ctx.is_txn_active() {
+                        Self::write_last_snapshot_hint(
+                            &self.operator,
+                            &self.meta_location_generator,
+                            new_snapshot_loc,
+                        )
+                        .await;
+                    }
+                    return Ok(());
+                }
+                Err(e) if e.code() == ErrorCode::TABLE_VERSION_MISMATCHED => {
+                    match backoff.next_backoff() {
+                        Some(d) => {
+                            debug!(
+                                "clone table {}: got error TableVersionMismatched, will be retried {} ms later",
+                                self.table_info.desc,
+                                d.as_millis()
+                            );
+                            common_base::base::tokio::time::sleep(d).await;
+                        }
+                        None => {
+                            let _ = self.operator.delete(&new_snapshot_loc).await;
+                            return Err(ErrorCode::StorageOther(format!(
+                                "clone table {} aborted after retries: {}",
+                                self.table_info.desc, e
+                            )));
+                        }
+                    }
+                }
+                Err(e) => {
+                    if Self::no_side_effects_in_meta_store(&e) {
+                        let _ = self.operator.delete(&new_snapshot_loc).await;
+                    }
+                    return Err(e);
+                }
+            }
+        }
+    }
+
+    /// Returns true if a clone of the dropped table `table_info` still exists, other than
+    /// the tables in `vacuuming`. The files of such a table are shared with its clones,
+    /// so they must be kept until no clone of it remains.
+    #[async_backtrace::framed]
+    pub async fn has_existing_clones(
+        catalog: &dyn Catalog,
+        table_info: &TableInfo,
+        vacuuming: &HashSet<u64>,
+    ) -> Result<bool> {
+        for table_id in cloned_table_ids(table_info.options(), OPT_KEY_CLONES) {
+            if vacuuming.contains(&table_id) {
+                continue;
+            }
+            match catalog.get_table_meta_by_id(table_id).await {
+                // the clone has been dropped and vacuumed
+                Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE_ID => continue,
+                Err(e) => return Err(e),
+                Ok(_) => return Ok(true),
+            }
+        }
+        Ok(false)
+    }
+
+    /// Removes the dropped table `table_info` from the clones registered on the tables
+    /// it was cloned from, once its files have been vacuumed.
+    #[async_backtrace::framed]
+    pub async fn unregister_clone(catalog: &dyn Catalog, table_info: &TableInfo) -> Result<()> {
+        let ancestors = cloned_table_ids(table_info.options(), OPT_KEY_CLONED_FROM);
+        if ancestors.is_empty() {
+            return Ok(());
+        }
+
+        let table_id = table_info.ident.table_id;
+        let mut backoff = set_backoff(None, None, None);
+        loop {
+            let update_table_metas = Self::update_clones_reqs(catalog, &ancestors, |clones| {
+                clones.retain(|id| *id != table_id)
+            })
+            .await?;
+            if update_table_metas.is_empty() {
+                return Ok(());
+            }
+            match catalog
+                .update_multi_table_meta(UpdateMultiTableMetaReq { update_table_metas })
+                .await
+            {
+                Err(e) if e.code() == ErrorCode::TABLE_VERSION_MISMATCHED => {
+                    match backoff.next_backoff() {
+                        Some(d) => common_base::base::tokio::time::sleep(d).await,
+                        None => return Err(e),
+                    }
+                }
+                r => return r.map(|_| ()),
+            }
+        }
+    }
+
+    // Builds the requests that apply `f` to the clones registered on each of `table_ids`,
+    // tables that have been vacuumed are skipped.
+    async fn update_clones_reqs(
+        catalog: &dyn Catalog,
+        table_ids: &[u64],
+        f: impl Fn(&mut Vec<u64>),
+    ) -> Result<Vec<UpdateTableMetaReq>> {
+        let mut reqs = Vec::with_capacity(table_ids.len());
+        for table_id in table_ids {
+            let (ident, meta) = match catalog.get_table_meta_by_id(*table_id).await {
+                Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE_ID => {
+                    // the table has been dropped and vacuumed, nothing left to keep
+                    info!("table {} no longer exists", table_id);
+                    continue;
+                }
+                Err(e) => return Err(e),
+                Ok(v) => v,
+            };
+            let mut meta = meta.as_ref().clone();
+            let mut clones = cloned_table_ids(&meta.options, OPT_KEY_CLONES);
+            f(&mut clones);
+            if clones.is_empty() {
+                meta.options.remove(OPT_KEY_CLONES);
+            } else {
+                meta.options
+                    .insert(OPT_KEY_CLONES.to_owned(), join_table_ids(&clones));
+            }
+            reqs.push(UpdateTableMetaReq {
+                table_id: *table_id,
+                seq: MatchSeq::Exact(ident.seq),
+                new_table_meta: meta,
+                copied_files: None,
+                deduplicated_label: None,
+            });
+        }
+        Ok(reqs)
+    }
+}
+
+/// Parses the comma separated table ids kept in the table option `key`,
+/// which is either `OPT_KEY_CLONED_FROM` or `OPT_KEY_CLONES`.
+pub(crate) fn cloned_table_ids(options: &BTreeMap<String, String>, key: &str) -> Vec<u64> {
+    options
+        .get(key)
+        .map(|ids| {
+            ids.split(',')
+                .filter_map(|id| id.trim().parse::<u64>().ok())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn join_table_ids(ids: &[u64]) -> String {
+    ids.iter()
+        .map(|id| id.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
@@ -25,7 +25,9 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::TableSchemaRef;
 use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
 use common_meta_app::schema::TableStatistics;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpsertTableCopiedFileReq;
 use common_meta_types::MatchSeq;
@@ -48,6 +50,10 @@ use storages_common_table_meta::meta::Statistics;
 use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::TableSnapshotStatistics;
 use storages_common_table_meta::meta::Versioned;
+use storages_common_table_meta::table::read_snapshot_refs;
+use storages_common_table_meta::table::write_snapshot_refs;
+use storages_common_table_meta::table::SnapshotRefType;
+use storages_common_table_meta::table::OPT_KEY_BRANCH;
 use storages_common_table_meta::table::OPT_KEY_LEGACY_SNAPSHOT_LOC;
 use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 
@@ -60,6 +66,7 @@ use crate::operations::common::CommitSink;
 use crate::operations::common::ConflictResolveContext;
 use crate::operations::common::TableMutationAggregator;
 use crate::operations::common::TransformSerializeSegment;
+use crate::operations::iceberg_export::is_iceberg_export;
 use crate::statistics::merge_statistics;
 use crate::FuseTable;
 
@@ -176,25 +183,35 @@ impl FuseTable {
         operator: &Operator,
     ) -> Result<()> {
         // 1. prepare table meta
-        let mut new_table_meta = table_info.meta.clone();
-        // 1.1 set new snapshot location
-        new_table_meta.options.insert(
-            OPT_KEY_SNAPSHOT_LOCATION.to_owned(),
-            snapshot_location.clone(),
-        );
-        // remove legacy options
-        Self::remove_legacy_options(&mut new_table_meta.options);
-
-        // 1.2 setup table statistics
-        let stats = &snapshot.summary;
-        // update statistics
-        new_table_meta.statistics = TableStatistics {
-            number_of_rows: stats.row_count,
-            data_bytes: stats.uncompressed_byte_size,
-            compressed_data_bytes: stats.compressed_byte_size,
-            index_data_bytes: stats.index_size,
-            number_of_segments: Some(snapshot.segments.len() as u64),
-            number_of_blocks: Some(stats.block_count),
+        let branch = table_info.options().get(OPT_KEY_BRANCH);
+        let mut new_table_meta = match branch {
+            // commit to a branch, only the head of the branch is moved
+            Some(branch) => {
+                Self::branch_table_meta(ctx, table_info, branch, &snapshot_location).await?
+            }
+            None => {
+                let mut new_table_meta = table_info.meta.clone();
+                // 1.1 set new snapshot location
+                new_table_meta.options.insert(
+                    OPT_KEY_SNAPSHOT_LOCATION.to_owned(),
+                    snapshot_location.clone(),
+                );
+                // remove legacy options
+                Self::remove_legacy_options(&mut new_table_meta.options);
+
+                // 1.2 setup table statistics
+                let stats = &snapshot.summary;
+                // update statistics
+                new_table_meta.statistics = TableStatistics {
+                    number_of_rows: stats.row_count,
+                    data_bytes: stats.uncompressed_byte_size,
+                    compressed_data_bytes: stats.compressed_byte_size,
+                    index_data_bytes: stats.index_size,
+                    number_of_segments: Some(snapshot.segments.len() as u64),
+                    number_of_blocks: Some(stats.block_count),
+                };
+                new_table_meta
+            }
         };
         new_table_meta.updated_on = Utc::now();
 
@@ -211,14 +228,36 @@ impl FuseTable {
             deduplicated_label: ctx.get_settings().get_deduplicate_label()?,
         };
 
-        // 3. let's roll
-        let reply = catalog.update_table_meta(table_info, req).await;
+        // 3. let's roll, the offsets of the streams consumed by the query are
+        // advanced in the same meta transaction
+        let stream_offsets = ctx.get_stream_offsets();
+        let reply = if stream_offsets.is_empty() {
+            catalog.update_table_meta(table_info, req).await.map(|_| ())
+        } else {
+            let mut update_table_metas = vec![req];
+            update_table_metas.extend(stream_offsets);
+            catalog
+                .update_multi_table_meta(UpdateMultiTableMetaReq { update_table_metas })
+                .await
+                .map(|_| ())
+        };
         match reply {
             Ok(_) => {
-                TableSnapshot::cache().put(snapshot_location.clone(), Arc::new(snapshot));
-                // try keep a hit file of last snapshot
-                Self::write_last_snapshot_hint(operator, location_generator, snapshot_location)
+                ctx.clear_stream_offsets();
+                let snapshot = Arc::new(snapshot);
+                TableSnapshot::cache().put(snapshot_location.clone(), snapshot.clone());
+                // the hint is of the table, not of the branches. Inside an explicit transaction
+                // the update is only buffered, the side effects are done by `COMMIT`
+                if branch.is_none() && !ctx.is_txn_active() {
+                    Self::post_commit(
+                        operator,
+                        location_generator,
+                        table_info,
+                        snapshot_location,
+                        Some(&snapshot),
+                    )
                     .await;
+                }
                 Ok(())
             }
             Err(e) => {
@@ -238,6 +277,82 @@ impl FuseTable {
         }
     }
 
+    // The table meta that moves the head of `branch` to `snapshot_location`, the snapshot location
+    // and statistics of the table are kept. The seq of `table_info` is still matched on commit,
+    // so a concurrent commit to the table or to the branch will be detected.
+    #[async_backtrace::framed]
+    async fn branch_table_meta(
+        ctx: &dyn TableContext,
+        table_info: &TableInfo,
+        branch: &str,
+        snapshot_location: &str,
+    ) -> Result<TableMeta> {
+        let catalog = ctx.get_catalog(table_info.catalog()).await?;
+        let (_, meta) = catalog
+            .get_table_meta_by_id(table_info.ident.table_id)
+            .await?;
+        let mut new_table_meta = meta.as_ref().clone();
+        let mut refs = read_snapshot_refs(&new_table_meta.options)?;
+        match refs.get_mut(branch) {
+            Some(snapshot_ref) if snapshot_ref.ref_type == SnapshotRefType::Branch => {
+                snapshot_ref.location = snapshot_location.to_owned();
+            }
+            _ => {
+                return Err(ErrorCode::UnknownSnapshotRef(format!(
+                    "No branch named '{}' found in table {}",
+                    branch, table_info.desc
+                )));
+            }
+        }
+        write_snapshot_refs(&mut new_table_meta.options, &refs)?;
+        Ok(new_table_meta)
+    }
+
+    /// The side effects of a commit, which are done after the new snapshot is committed
+    /// to meta: the hint file of the last snapshot, and the exported Iceberg metadata.
+    #[async_backtrace::framed]
+    async fn post_commit(
+        operator: &Operator,
+        location_generator: &TableMetaLocationGenerator,
+        table_info: &TableInfo,
+        snapshot_location: String,
+        snapshot: Option<&TableSnapshot>,
+    ) {
+        // try keep a hit file of last snapshot
+        Self::write_last_snapshot_hint(operator, location_generator, snapshot_location).await;
+        // the commit is done, a failed export is fixed by the next one
+        if is_iceberg_export(table_info.options()) {
+            Self::write_iceberg_metadata(operator, location_generator, table_info, snapshot)
+                .await
+                .unwrap_or_else(|e| {
+                    warn!(
+                        "export iceberg metadata of table {} failure. {}",
+                        table_info.desc, e
+                    );
+                });
+        }
+    }
+
+    /// Do the side effects of the commits deferred by an explicit transaction, called
+    /// by `COMMIT` for each table updated in the transaction, after the buffered updates
+    /// are committed to meta.
+    #[async_backtrace::framed]
+    pub async fn post_txn_commit(&self) -> Result<()> {
+        let Some(snapshot_location) = self.snapshot_loc().await? else {
+            return Ok(());
+        };
+        let snapshot = self.read_table_snapshot().await?;
+        Self::post_commit(
+            &self.operator,
+            &self.meta_location_generator,
+            &self.table_info,
+            snapshot_location,
+            snapshot.as_deref(),
+        )
+        .await;
+        Ok(())
+    }
+
     // Left a hint file which indicates the location of the latest snapshot
     #[async_backtrace::framed]
     pub async fn write_last_snapshot_hint(
@@ -20,6 +20,7 @@ use opendal::Operator;
 use storages_common_cache::CacheAccessor;
 use storages_common_cache_manager::CacheManager;
 use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::DeletionVectorMeta;
 
 use crate::io::Files;
 
@@ -28,6 +29,8 @@ pub struct AbortOperation {
     pub segments: Vec<String>,
     pub blocks: Vec<String>,
     pub bloom_filter_indexes: Vec<String>,
+    #[serde(default)]
+    pub deletion_vectors: Vec<String>,
 }
 
 impl AbortOperation {
@@ -35,6 +38,7 @@ impl AbortOperation {
         self.segments.extend(rhs.segments);
         self.blocks.extend(rhs.blocks);
         self.bloom_filter_indexes.extend(rhs.bloom_filter_indexes);
+        self.deletion_vectors.extend(rhs.deletion_vectors);
     }
 
     pub fn add_block(&mut self, block: &BlockMeta) {
@@ -45,6 +49,11 @@ impl AbortOperation {
         }
     }
 
+    pub fn add_deletion_vector(&mut self, deletion_vector: &DeletionVectorMeta) {
+        self.deletion_vectors
+            .push(deletion_vector.location.0.clone());
+    }
+
     pub fn add_segment(&mut self, segment: String) {
         self.segments.push(segment);
     }
@@ -63,6 +72,7 @@ impl AbortOperation {
             .blocks
             .into_iter()
             .chain(self.bloom_filter_indexes.into_iter())
+            .chain(self.deletion_vectors.into_iter())
             .chain(self.segments.into_iter());
         fuse_file.remove_file_in_batch(locations).await
     }
@@ -21,6 +21,7 @@ use common_expression::BlockMetaInfoDowncast;
 use common_expression::DataBlock;
 use common_pipeline_transforms::processors::transforms::AccumulatingTransform;
 use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::DeletionVectorMeta;
 use storages_common_table_meta::meta::FormatVersion;
 use storages_common_table_meta::meta::Statistics;
 
@@ -56,6 +57,10 @@ pub enum MutationLogEntry {
         index: BlockMetaIndex,
         block_meta: Arc<BlockMeta>,
     },
+    DeletionVector {
+        index: BlockMetaIndex,
+        deletion_vector: DeletionVectorMeta,
+    },
     CompactExtras {
         extras: CompactExtraInfo,
     },
@@ -195,6 +200,12 @@ fn merge_commit_meta(
                 .into_iter()
                 .chain(r.abort_operation.bloom_filter_indexes.into_iter())
                 .collect(),
+            deletion_vectors: l
+                .abort_operation
+                .deletion_vectors
+                .into_iter()
+                .chain(r.abort_operation.deletion_vectors.into_iter())
+                .collect(),
         },
     }
 }
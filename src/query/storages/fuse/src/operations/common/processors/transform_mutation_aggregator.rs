@@ -34,6 +34,7 @@ use log::info;
 use log::warn;
 use opendal::Operator;
 use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::DeletionVectorMeta;
 use storages_common_table_meta::meta::Location;
 use storages_common_table_meta::meta::SegmentInfo;
 use storages_common_table_meta::meta::Statistics;
@@ -156,6 +157,24 @@ impl TableMutationAggregator {
                     }
                 }
             }
+            MutationLogEntry::DeletionVector {
+                index,
+                deletion_vector,
+            } => {
+                self.abort_operation.add_deletion_vector(&deletion_vector);
+                match self.mutations.entry(index.segment_idx) {
+                    Entry::Occupied(mut v) => {
+                        v.get_mut()
+                            .push_deletion_vector(index.block_idx, deletion_vector);
+                    }
+                    Entry::Vacant(v) => {
+                        v.insert(BlockMutations::new_deletion_vector(
+                            index.block_idx,
+                            deletion_vector,
+                        ));
+                    }
+                }
+            }
             MutationLogEntry::DeletedBlock { index } => {
                 self.mutations
                     .entry(index.segment_idx)
@@ -197,7 +216,7 @@ impl TableMutationAggregator {
                     Entry::Vacant(v) => {
                         v.insert(BlockMutations {
                             replaced_blocks: extras.unchanged_blocks,
-                            deleted_blocks: vec![],
+                            ..Default::default()
                         });
                     }
                 }
@@ -328,6 +347,14 @@ impl TableMutationAggregator {
                     for idx in segment_mutation.deleted_blocks {
                         block_editor.remove(&idx);
                     }
+                    // the blocks are kept, only their deletion vectors are replaced.
+                    for (idx, deletion_vector) in segment_mutation.deletion_vectors {
+                        if let Some(block_meta) = block_editor.get_mut(&idx) {
+                            let mut new_meta = block_meta.as_ref().clone();
+                            new_meta.deletion_vector = Some(deletion_vector);
+                            *block_meta = Arc::new(new_meta);
+                        }
+                    }
 
                     if block_editor.is_empty() {
                         return Ok(SegmentLite {
@@ -341,8 +368,16 @@ impl TableMutationAggregator {
                     let new_blocks = block_editor.into_values().collect::<Vec<_>>();
                     (new_blocks, Some(segment_info.summary))
                 } else {
-                    // use by compact.
-                    assert!(segment_mutation.deleted_blocks.is_empty());
+                    // use by compact, the deleted blocks are the compact tasks whose rows are
+                    // all expired, they are not in the new segment.
+                    assert!(segment_mutation.deletion_vectors.is_empty());
+                    if segment_mutation.replaced_blocks.is_empty() {
+                        return Ok(SegmentLite {
+                            index,
+                            new_segment_info: None,
+                            origin_summary: None,
+                        });
+                    }
                     // There are more than 1 blocks, means that the blocks can no longer be compacted.
                     // They can be marked as perfect blocks.
                     all_perfect = segment_mutation.replaced_blocks.len() > 1;
@@ -405,20 +440,28 @@ impl TableMutationAggregator {
 struct BlockMutations {
     replaced_blocks: Vec<(BlockIndex, Arc<BlockMeta>)>,
     deleted_blocks: Vec<BlockIndex>,
+    deletion_vectors: Vec<(BlockIndex, DeletionVectorMeta)>,
 }
 
 impl BlockMutations {
     fn new_replacement(block_idx: BlockIndex, block_meta: Arc<BlockMeta>) -> Self {
         BlockMutations {
             replaced_blocks: vec![(block_idx, block_meta)],
-            deleted_blocks: vec![],
+            ..Default::default()
         }
     }
 
     fn new_deletion(block_idx: BlockIndex) -> Self {
         BlockMutations {
-            replaced_blocks: vec![],
             deleted_blocks: vec![block_idx],
+            ..Default::default()
+        }
+    }
+
+    fn new_deletion_vector(block_idx: BlockIndex, deletion_vector: DeletionVectorMeta) -> Self {
+        BlockMutations {
+            deletion_vectors: vec![(block_idx, deletion_vector)],
+            ..Default::default()
         }
     }
 
@@ -429,6 +472,10 @@ impl BlockMutations {
     fn push_deleted(&mut self, block_idx: BlockIndex) {
         self.deleted_blocks.push(block_idx)
     }
+
+    fn push_deletion_vector(&mut self, block_idx: BlockIndex, deletion_vector: DeletionVectorMeta) {
+        self.deletion_vectors.push((block_idx, deletion_vector))
+    }
 }
 
 struct SegmentLite {
@@ -13,6 +13,8 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::BTreeMap;
+use std::str::FromStr;
 use std::sync::Arc;
 use std::time::Instant;
 
@@ -22,6 +24,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::BlockMetaInfoDowncast;
 use common_expression::DataBlock;
+use common_functions::scalars::Tokenizer;
 use common_metrics::storage::*;
 use common_pipeline_core::pipe::PipeItem;
 use common_pipeline_core::processors::port::InputPort;
@@ -78,6 +81,21 @@ impl TransformSerializeBlock {
         let bloom_columns_map = table
             .bloom_index_cols
             .bloom_index_fields(source_schema.clone(), BloomIndex::supported_type)?;
+        let ngram_columns_map = table
+            .ngram_index_cols
+            .bloom_index_fields(source_schema.clone(), BloomIndex::supported_ngram_type)?;
+        let mut inverted_index_columns = BTreeMap::new();
+        for index in table.inverted_indexes().values() {
+            if let Some((field_index, _)) = source_schema
+                .fields()
+                .iter()
+                .enumerate()
+                .find(|(_, f)| f.column_id() == index.column_id)
+            {
+                let tokenizer = Tokenizer::from_str(&index.tokenizer)?;
+                inverted_index_columns.insert(field_index, (index.column_id, tokenizer));
+            }
+        }
         let block_builder = BlockBuilder {
             ctx,
             meta_locations: table.meta_location_generator().clone(),
@@ -85,6 +103,8 @@ impl TransformSerializeBlock {
             write_settings: table.get_write_settings(),
             cluster_stats_gen,
             bloom_columns_map,
+            ngram_columns_map,
+            inverted_index_columns,
         };
         Ok(TransformSerializeBlock {
             state: State::Consume,
@@ -192,6 +212,15 @@ impl Processor for TransformSerializeBlock {
                         Ok(Event::Sync)
                     }
                 }
+                SerializeDataMeta::DeletionVector(deletion_vector) => {
+                    // keep the old block, mark the deleted rows by the deletion vector
+                    let data_block = Self::mutation_logs(MutationLogEntry::DeletionVector {
+                        index: deletion_vector.index,
+                        deletion_vector: deletion_vector.deletion_vector,
+                    });
+                    self.output.push_data(Ok(data_block));
+                    Ok(Event::NeedConsume)
+                }
                 SerializeDataMeta::CompactExtras(compact_extras) => {
                     // compact extras
                     let data_block = Self::mutation_logs(MutationLogEntry::CompactExtras {
@@ -282,6 +311,14 @@ impl Processor for TransformSerializeBlock {
                         );
                     }
                 }
+                if let Some(inverted_index_state) = serialized.inverted_index_state {
+                    write_data(
+                        inverted_index_state.data,
+                        &self.dal,
+                        &inverted_index_state.location,
+                    )
+                    .await?;
+                }
 
                 let data_block = if let Some(index) = index {
                     Self::mutation_logs(MutationLogEntry::ReplacedBlock {
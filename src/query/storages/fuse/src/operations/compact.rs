@@ -95,6 +95,7 @@ impl FuseTable {
             compact_options,
             self.operator.clone(),
             self.cluster_key_id(),
+            self.ttl(ctx.clone())?,
         );
 
         let partitions = mutator.target_select().await?;
@@ -118,9 +119,11 @@ impl FuseTable {
         let is_lazy = parts.is_lazy;
         let thresholds = self.get_block_thresholds();
         let cluster_key_id = self.cluster_key_id();
+        let ttl = self.ttl(ctx.clone())?;
         let mut max_threads = ctx.get_settings().get_max_threads()? as usize;
         if is_lazy {
             let query_ctx = ctx.clone();
+            let ttl = ttl.clone();
 
             let lazy_parts = parts
                 .partitions
@@ -136,12 +139,14 @@ impl FuseTable {
             pipeline.set_on_init(move || {
                 let ctx = query_ctx.clone();
                 let column_ids = column_ids.clone();
+                let ttl = ttl.clone();
                 let partitions = Runtime::with_worker_threads(2, None)?.block_on(async move {
                     let partitions = BlockCompactMutator::build_compact_tasks(
                         ctx.clone(),
                         column_ids,
                         cluster_key_id,
                         thresholds,
+                        ttl,
                         lazy_parts,
                     )
                     .await?;
@@ -161,6 +166,14 @@ impl FuseTable {
         let all_column_indices = self.all_column_indices();
         let projection = Projection::Columns(all_column_indices);
         let block_reader = self.create_block_reader(ctx.clone(), projection, false, false)?;
+        // the expired rows are removed from the compacted blocks.
+        let ttl = match ttl {
+            Some(ttl) => {
+                let offset = block_reader.schema().index_of(ttl.column_name())?;
+                Some((ttl, offset))
+            }
+            None => None,
+        };
         // Add source pipe.
         pipeline.add_source(
             |output| {
@@ -168,6 +181,7 @@ impl FuseTable {
                     ctx.clone(),
                     self.storage_format,
                     block_reader.clone(),
+                    ttl.clone(),
                     output,
                 )
             },
@@ -215,7 +229,11 @@ impl FuseTable {
             return Ok(None);
         };
 
-        if base_snapshot.summary.block_count <= 1 {
+        // A single block is not compacted, unless it has deletion vector (then it is not perfect).
+        let summary = &base_snapshot.summary;
+        if summary.block_count == 0
+            || (summary.block_count == 1 && summary.perfect_block_count == 1)
+        {
             return Ok(None);
         }
 
@@ -227,6 +227,8 @@ impl FuseTable {
                     ops.clone(),
                     self.storage_format,
                     query_row_id_col,
+                    self.merge_on_read(),
+                    self.meta_location_generator().clone(),
                 )
             },
             max_threads,
@@ -272,6 +274,8 @@ impl FuseTable {
             self.table_info.schema(),
             &push_down,
             self.bloom_index_cols(),
+            self.ngram_index_cols(),
+            self.inverted_indexes(),
         )?;
 
         if let Some(inverse) = filters.map(|f| f.inverted_filter) {
@@ -17,12 +17,14 @@ use std::hash::BuildHasher;
 use std::sync::Arc;
 use std::time::Instant;
 
+use chrono::Utc;
 use common_cache::CountableMeter;
 use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::schema::ListIndexesByIdReq;
+use common_meta_app::schema::TableInfo;
 use log::error;
 use log::warn;
 use storages_common_cache::CacheAccessor;
@@ -33,6 +35,9 @@ use storages_common_table_meta::meta::CompactSegmentInfo;
 use storages_common_table_meta::meta::Location;
 use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::TableSnapshotStatistics;
+use storages_common_table_meta::table::read_snapshot_refs;
+use storages_common_table_meta::table::OPT_KEY_CLONED_FROM;
+use storages_common_table_meta::table::OPT_KEY_CLONES;
 
 use crate::io::Files;
 use crate::io::MetaReaders;
@@ -40,6 +45,7 @@ use crate::io::SegmentsIO;
 use crate::io::SnapshotLiteExtended;
 use crate::io::SnapshotsIO;
 use crate::io::TableMetaLocationGenerator;
+use crate::operations::clone::cloned_table_ids;
 use crate::FuseTable;
 use crate::FUSE_TBL_SNAPSHOT_PREFIX;
 
@@ -88,6 +94,10 @@ impl FuseTable {
             })
             .await?;
 
+        // Files shared with the tables this table was cloned from, or cloned to,
+        // and files pinned by the tags and branches.
+        let shared_files = self.read_shared_files(ctx).await?;
+
         // 2. Read snapshot fields by chunk size.
         let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
         for chunk in snapshot_files.chunks(chunk_size).rev() {
@@ -166,6 +176,7 @@ impl FuseTable {
                         ctx,
                         &mut dry_run_purge_files,
                         &root_snapshot_info.referenced_locations,
+                        &shared_files,
                         segments_to_be_purged,
                         ts_to_be_purged,
                         snapshots_to_be_purged,
@@ -181,6 +192,7 @@ impl FuseTable {
                         ctx,
                         &mut counter,
                         &root_snapshot_info.referenced_locations,
+                        &shared_files,
                         segments_to_be_purged,
                         ts_to_be_purged,
                         snapshots_to_be_purged,
@@ -221,6 +233,7 @@ impl FuseTable {
                     ctx,
                     &mut dry_run_purge_files,
                     &root_snapshot_info.referenced_locations,
+                    &shared_files,
                     segments_to_be_purged,
                     ts_to_be_purged,
                     snapshots_to_be_purged,
@@ -232,6 +245,7 @@ impl FuseTable {
                     ctx,
                     &mut counter,
                     &root_snapshot_info.referenced_locations,
+                    &shared_files,
                     segments_to_be_purged,
                     ts_to_be_purged,
                     snapshots_to_be_purged,
@@ -253,6 +267,7 @@ impl FuseTable {
                 root_snapshot_info.snapshot_lite,
                 root_snapshot_info.referenced_locations,
                 root_snapshot_info.snapshot_location,
+                &shared_files,
                 &table_agg_index_ids,
             )
             .await?;
@@ -310,12 +325,113 @@ impl FuseTable {
         }))
     }
 
+    /// Reads the files this table shares with other tables by `CREATE TABLE ... CLONE`,
+    /// and the files pinned by the tags and branches of this table.
+    ///
+    /// - If this table is a clone, the files of the tables it was cloned from are not
+    ///   owned by this table, i.e. they are not under the storage prefix of this table.
+    /// - If this table has been cloned, the files referenced by the snapshots the clones
+    ///   retain are still in use, since a clone may still be navigated to any of them.
+    ///   Clones of clones are registered on this table as well.
+    /// - The snapshots of the unexpired tags and the heads of the unexpired branches are kept,
+    ///   together with the files they reference.
+    #[async_backtrace::framed]
+    pub async fn read_shared_files(&self, ctx: &Arc<dyn TableContext>) -> Result<SharedFiles> {
+        let options = self.table_info.options();
+        let owned_prefix = options
+            .contains_key(OPT_KEY_CLONED_FROM)
+            .then(|| format!("{}/", self.meta_location_generator().prefix()));
+
+        let mut referenced = HashSet::new();
+        let clones = cloned_table_ids(options, OPT_KEY_CLONES);
+        if !clones.is_empty() {
+            let catalog = ctx.get_catalog(self.table_info.catalog()).await?;
+            for table_id in clones {
+                let (ident, meta) = match catalog.get_table_meta_by_id(table_id).await {
+                    // the clone has been dropped and vacuumed
+                    Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE_ID => continue,
+                    Err(e) => return Err(e),
+                    Ok(v) => v,
+                };
+                let table_info = TableInfo {
+                    ident,
+                    desc: format!("clone {} of table {}", table_id, self.table_info.desc),
+                    meta: meta.as_ref().clone(),
+                    tenant: self.table_info.tenant.clone(),
+                    ..Default::default()
+                };
+                let clone = FuseTable::do_create(table_info)?;
+                let mut segments = HashSet::new();
+                let reader = MetaReaders::table_snapshot_reader(clone.get_operator());
+                for location in clone.list_snapshot_files().await? {
+                    let params = LoadParams {
+                        ver: TableMetaLocationGenerator::snapshot_version(&location),
+                        location,
+                        len_hint: None,
+                        put_cache: false,
+                    };
+                    match reader.read(&params).await {
+                        // the snapshot has been purged by the clone concurrently
+                        Err(e) if e.code() == ErrorCode::STORAGE_NOT_FOUND => continue,
+                        Err(e) => return Err(e),
+                        Ok(snapshot) => segments.extend(snapshot.segments.iter().cloned()),
+                    }
+                }
+                if segments.is_empty() {
+                    continue;
+                }
+                let segments = Vec::from_iter(segments);
+                // since we are purging files, the ErrorCode::STORAGE_NOT_FOUND error can be safely ignored.
+                let locations = clone
+                    .get_block_locations(ctx.clone(), &segments, false, true)
+                    .await?;
+                referenced.extend(segments.into_iter().map(|loc| loc.0));
+                referenced.extend(locations.block_location);
+                referenced.extend(locations.bloom_location);
+                referenced.extend(locations.deletion_vector_location);
+            }
+        }
+
+        let now = Utc::now();
+        let refs = read_snapshot_refs(options)?;
+        for snapshot_ref in refs.values().filter(|r| !r.is_expired(now)) {
+            let reader = MetaReaders::table_snapshot_reader(self.get_operator());
+            let params = LoadParams {
+                location: snapshot_ref.location.clone(),
+                len_hint: None,
+                ver: TableMetaLocationGenerator::snapshot_version(&snapshot_ref.location),
+                put_cache: false,
+            };
+            let snapshot = match reader.read(&params).await {
+                // the snapshot has already been collected
+                Err(e) if e.code() == ErrorCode::STORAGE_NOT_FOUND => continue,
+                Err(e) => return Err(e),
+                Ok(v) => v,
+            };
+            let locations = self
+                .get_block_locations(ctx.clone(), &snapshot.segments, false, true)
+                .await?;
+            referenced.insert(snapshot_ref.location.clone());
+            referenced.extend(snapshot.table_statistics_location.clone());
+            referenced.extend(snapshot.segments.iter().map(|loc| loc.0.clone()));
+            referenced.extend(locations.block_location);
+            referenced.extend(locations.bloom_location);
+            referenced.extend(locations.deletion_vector_location);
+        }
+
+        Ok(SharedFiles {
+            owned_prefix,
+            referenced,
+        })
+    }
+
     #[allow(clippy::too_many_arguments)]
     async fn dry_run_purge(
         &self,
         ctx: &Arc<dyn TableContext>,
         purge_files: &mut Vec<String>,
         locations_referenced_by_root: &LocationTuple,
+        shared_files: &SharedFiles,
         segments_to_be_purged: HashSet<Location>,
         ts_to_be_purged: HashSet<String>,
         snapshots_to_be_purged: HashSet<String>,
@@ -331,7 +447,9 @@ impl FuseTable {
                 .await?;
 
             for loc in &locations.block_location {
-                if locations_referenced_by_root.block_location.contains(loc) {
+                if locations_referenced_by_root.block_location.contains(loc)
+                    || shared_files.is_shared(loc)
+                {
                     continue;
                 }
                 purge_files.push(loc.to_string());
@@ -342,19 +460,49 @@ impl FuseTable {
                         ),
                     )
                 }
+                if !self.inverted_indexes.is_empty() {
+                    purge_files.push(
+                        TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(
+                            loc,
+                        ),
+                    )
+                }
             }
 
             for loc in &locations.bloom_location {
-                if locations_referenced_by_root.bloom_location.contains(loc) {
+                if locations_referenced_by_root.bloom_location.contains(loc)
+                    || shared_files.is_shared(loc)
+                {
+                    continue;
+                }
+                purge_files.push(loc.to_string())
+            }
+
+            for loc in &locations.deletion_vector_location {
+                if locations_referenced_by_root
+                    .deletion_vector_location
+                    .contains(loc)
+                    || shared_files.is_shared(loc)
+                {
                     continue;
                 }
                 purge_files.push(loc.to_string())
             }
 
-            purge_files.extend(chunk.iter().map(|loc| loc.0.clone()));
+            purge_files.extend(
+                chunk
+                    .iter()
+                    .filter(|loc| !shared_files.is_shared(&loc.0))
+                    .map(|loc| loc.0.clone()),
+            );
         }
-        purge_files.extend(ts_to_be_purged.iter().map(|loc| loc.to_string()));
-        purge_files.extend(snapshots_to_be_purged.iter().map(|loc| loc.to_string()));
+        purge_files.extend(
+            ts_to_be_purged
+                .iter()
+                .chain(snapshots_to_be_purged.iter())
+                .filter(|loc| !shared_files.is_shared(loc))
+                .map(|loc| loc.to_string()),
+        );
 
         Ok(())
     }
@@ -365,9 +513,10 @@ impl FuseTable {
         ctx: &Arc<dyn TableContext>,
         counter: &mut PurgeCounter,
         locations_referenced_by_root: &LocationTuple,
+        shared_files: &SharedFiles,
         segments_to_be_purged: HashSet<Location>,
-        ts_to_be_purged: HashSet<String>,
-        snapshots_to_be_purged: HashSet<String>,
+        mut ts_to_be_purged: HashSet<String>,
+        mut snapshots_to_be_purged: HashSet<String>,
         table_agg_index_ids: &[u64],
     ) -> Result<()> {
         let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
@@ -382,8 +531,11 @@ impl FuseTable {
 
             let mut blocks_to_be_purged = HashSet::new();
             let mut agg_indexes_to_be_purged = HashSet::new();
+            let mut inverted_indexes_to_be_purged = HashSet::new();
             for loc in &locations.block_location {
-                if locations_referenced_by_root.block_location.contains(loc) {
+                if locations_referenced_by_root.block_location.contains(loc)
+                    || shared_files.is_shared(loc)
+                {
                     continue;
                 }
                 blocks_to_be_purged.insert(loc.to_string());
@@ -394,19 +546,41 @@ impl FuseTable {
                         ),
                     );
                 }
+                if !self.inverted_indexes.is_empty() {
+                    inverted_indexes_to_be_purged.insert(
+                        TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(
+                            loc,
+                        ),
+                    );
+                }
             }
 
             let mut blooms_to_be_purged = HashSet::new();
             for loc in &locations.bloom_location {
-                if locations_referenced_by_root.bloom_location.contains(loc) {
+                if locations_referenced_by_root.bloom_location.contains(loc)
+                    || shared_files.is_shared(loc)
+                {
                     continue;
                 }
                 blooms_to_be_purged.insert(loc.to_string());
             }
 
+            let mut deletion_vectors_to_be_purged = HashSet::new();
+            for loc in &locations.deletion_vector_location {
+                if locations_referenced_by_root
+                    .deletion_vector_location
+                    .contains(loc)
+                    || shared_files.is_shared(loc)
+                {
+                    continue;
+                }
+                deletion_vectors_to_be_purged.insert(loc.to_string());
+            }
+
             let segment_locations_to_be_purged = HashSet::from_iter(
                 chunk
                     .iter()
+                    .filter(|loc| !shared_files.is_shared(&loc.0))
                     .map(|loc| loc.0.clone())
                     .collect::<Vec<String>>(),
             );
@@ -428,32 +602,48 @@ impl FuseTable {
                 counter,
                 blocks_to_be_purged,
                 agg_indexes_to_be_purged,
+                inverted_indexes_to_be_purged,
                 blooms_to_be_purged,
+                deletion_vectors_to_be_purged,
                 segment_locations_to_be_purged,
             )
             .await?;
         }
 
+        ts_to_be_purged.retain(|loc| !shared_files.is_shared(loc));
+        snapshots_to_be_purged.retain(|loc| !shared_files.is_shared(loc));
         self.purge_ts_snapshots(ctx, counter, ts_to_be_purged, snapshots_to_be_purged)
             .await
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn purge_root_snapshot(
         &self,
         ctx: &Arc<dyn TableContext>,
         counter: &mut PurgeCounter,
         root_snapshot: Arc<SnapshotLiteExtended>,
-        root_location_tuple: LocationTuple,
+        mut root_location_tuple: LocationTuple,
         root_snapshot_location: String,
+        shared_files: &SharedFiles,
         table_agg_index_ids: &[u64],
     ) -> Result<()> {
         let segment_locations_to_be_purged = HashSet::from_iter(
             root_snapshot
                 .segments
                 .iter()
+                .filter(|loc| !shared_files.is_shared(&loc.0))
                 .map(|loc| loc.0.clone())
                 .collect::<Vec<_>>(),
         );
+        root_location_tuple
+            .block_location
+            .retain(|loc| !shared_files.is_shared(loc));
+        root_location_tuple
+            .bloom_location
+            .retain(|loc| !shared_files.is_shared(loc));
+        root_location_tuple
+            .deletion_vector_location
+            .retain(|loc| !shared_files.is_shared(loc));
 
         let mut agg_indexes_to_be_purged = HashSet::new();
         for index_id in table_agg_index_ids {
@@ -464,12 +654,23 @@ impl FuseTable {
             }));
         }
 
+        let mut inverted_indexes_to_be_purged = HashSet::new();
+        if !self.inverted_indexes.is_empty() {
+            inverted_indexes_to_be_purged.extend(root_location_tuple.block_location.iter().map(
+                |loc| {
+                    TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(loc)
+                },
+            ));
+        }
+
         self.purge_block_segments(
             ctx,
             counter,
             root_location_tuple.block_location,
             agg_indexes_to_be_purged,
+            inverted_indexes_to_be_purged,
             root_location_tuple.bloom_location,
+            root_location_tuple.deletion_vector_location,
             segment_locations_to_be_purged,
         )
         .await?;
@@ -478,22 +679,23 @@ impl FuseTable {
         if let Some(ts) = root_snapshot.table_statistics_location.clone() {
             ts_to_be_purged.insert(ts);
         }
-        self.purge_ts_snapshots(
-            ctx,
-            counter,
-            ts_to_be_purged,
-            HashSet::from([root_snapshot_location]),
-        )
-        .await
+        ts_to_be_purged.retain(|loc| !shared_files.is_shared(loc));
+        let mut snapshots_to_be_purged = HashSet::from([root_snapshot_location]);
+        snapshots_to_be_purged.retain(|loc| !shared_files.is_shared(loc));
+        self.purge_ts_snapshots(ctx, counter, ts_to_be_purged, snapshots_to_be_purged)
+            .await
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn purge_block_segments(
         &self,
         ctx: &Arc<dyn TableContext>,
         counter: &mut PurgeCounter,
         blocks_to_be_purged: HashSet<String>,
         agg_indexes_to_be_purged: HashSet<String>,
+        inverted_indexes_to_be_purged: HashSet<String>,
         blooms_to_be_purged: HashSet<String>,
+        deletion_vectors_to_be_purged: HashSet<String>,
         segments_to_be_purged: HashSet<String>,
     ) -> Result<()> {
         // 1. Try to purge block file chunks.
@@ -511,6 +713,13 @@ impl FuseTable {
                 .await?;
         }
 
+        let inverted_index_count = inverted_indexes_to_be_purged.len();
+        if inverted_index_count > 0 {
+            counter.inverted_indexes += inverted_index_count;
+            self.try_purge_location_files(ctx.clone(), inverted_indexes_to_be_purged)
+                .await?;
+        }
+
         // 2. Try to purge bloom index file chunks.
         let blooms_count = blooms_to_be_purged.len();
         if blooms_count > 0 {
@@ -522,6 +731,13 @@ impl FuseTable {
             .await?;
         }
 
+        let deletion_vector_count = deletion_vectors_to_be_purged.len();
+        if deletion_vector_count > 0 {
+            counter.deletion_vectors += deletion_vector_count;
+            self.try_purge_location_files(ctx.clone(), deletion_vectors_to_be_purged)
+                .await?;
+        }
+
         // 3. Try to purge segment file chunks.
         let segments_count = segments_to_be_purged.len();
         if segments_count > 0 {
@@ -623,6 +839,7 @@ impl FuseTable {
     ) -> Result<LocationTuple> {
         let mut blocks = HashSet::new();
         let mut blooms = HashSet::new();
+        let mut deletion_vectors = HashSet::new();
 
         let fuse_segments = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
         let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
@@ -646,12 +863,14 @@ impl FuseTable {
                 };
                 blocks.extend(location_tuple.block_location.into_iter());
                 blooms.extend(location_tuple.bloom_location.into_iter());
+                deletion_vectors.extend(location_tuple.deletion_vector_location.into_iter());
             }
         }
 
         Ok(LocationTuple {
             block_location: blocks,
             bloom_location: blooms,
+            deletion_vector_location: deletion_vectors,
         })
     }
 
@@ -671,10 +890,33 @@ struct RootSnapshotInfo {
     snapshot_lite: Arc<SnapshotLiteExtended>,
 }
 
+/// Files that a purge of the table must keep, see `FuseTable::read_shared_files`.
+#[derive(Default)]
+pub struct SharedFiles {
+    /// The storage prefix of the table, if it is a clone.
+    owned_prefix: Option<String>,
+    /// Segments, blocks, bloom indexes and deletion vectors referenced by the clones of the table,
+    /// and the files pinned by the tags and branches of the table.
+    referenced: HashSet<String>,
+}
+
+impl SharedFiles {
+    pub fn is_shared(&self, location: &str) -> bool {
+        !self.is_owned(location) || self.referenced.contains(location)
+    }
+
+    pub fn is_owned(&self, location: &str) -> bool {
+        self.owned_prefix
+            .as_ref()
+            .map_or(true, |prefix| location.starts_with(prefix))
+    }
+}
+
 #[derive(Default)]
 pub struct LocationTuple {
     pub block_location: HashSet<String>,
     pub bloom_location: HashSet<String>,
+    pub deletion_vector_location: HashSet<String>,
 }
 
 impl TryFrom<Arc<CompactSegmentInfo>> for LocationTuple {
@@ -682,16 +924,21 @@ impl TryFrom<Arc<CompactSegmentInfo>> for LocationTuple {
     fn try_from(value: Arc<CompactSegmentInfo>) -> Result<Self> {
         let mut block_location = HashSet::new();
         let mut bloom_location = HashSet::new();
+        let mut deletion_vector_location = HashSet::new();
         let block_metas = value.block_metas()?;
         for block_meta in block_metas.into_iter() {
             block_location.insert(block_meta.location.0.clone());
             if let Some(bloom_loc) = &block_meta.bloom_filter_index_location {
                 bloom_location.insert(bloom_loc.0.clone());
             }
+            if let Some(deletion_vector) = &block_meta.deletion_vector {
+                deletion_vector_location.insert(deletion_vector.location.0.clone());
+            }
         }
         Ok(Self {
             block_location,
             bloom_location,
+            deletion_vector_location,
         })
     }
 }
@@ -700,7 +947,9 @@ struct PurgeCounter {
     start: Instant,
     blocks: usize,
     agg_indexes: usize,
+    inverted_indexes: usize,
     blooms: usize,
+    deletion_vectors: usize,
     segments: usize,
     table_statistics: usize,
     snapshots: usize,
@@ -712,7 +961,9 @@ impl PurgeCounter {
             start: Instant::now(),
             blocks: 0,
             agg_indexes: 0,
+            inverted_indexes: 0,
             blooms: 0,
+            deletion_vectors: 0,
             segments: 0,
             table_statistics: 0,
             snapshots: 0,
@@ -0,0 +1,927 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Export the Iceberg metadata of fuse tables.
+//!
+//! The manifests, manifest lists and table metadata are written here with `apache-avro` and
+//! `serde_json` against the schemas of the Iceberg spec, instead of with icelake, which is
+//! used by `common-storages-iceberg` to read Iceberg tables. The export only points the
+//! Iceberg metadata at the existing block files, there is no icelake table to commit them
+//! through, and the fuse crate would depend on the git pinned icelake for the few records
+//! written here. The output is checked by reading it back in the tests of this module.
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use apache_avro::types::Value as AvroValue;
+use apache_avro::Schema as AvroSchema;
+use apache_avro::Writer as AvroWriter;
+use chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::NumberDataType;
+use common_expression::types::NumberScalar;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRef;
+use common_meta_app::schema::TableInfo;
+use opendal::Operator;
+use opendal::Scheme;
+use serde_json::json;
+use serde_json::Value as JsonValue;
+use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::Location;
+use storages_common_table_meta::meta::TableSnapshot;
+use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
+use uuid::Uuid;
+
+use crate::io::SegmentsIO;
+use crate::io::TableMetaLocationGenerator;
+use crate::FuseStorageFormat;
+use crate::FuseTable;
+use crate::FUSE_OPT_KEY_ICEBERG_EXPORT;
+
+const ICEBERG_METADATA_DIR: &str = "metadata";
+const ICEBERG_VERSION_HINT: &str = "version-hint.text";
+const ICEBERG_FORMAT_VERSION: i32 = 2;
+const ICEBERG_MAX_METADATA_LOG: usize = 100;
+
+const MANIFEST_ENTRY_SCHEMA: &str = r#"{
+  "type": "record",
+  "name": "manifest_entry",
+  "fields": [
+    {"name": "status", "type": "int", "field-id": 0},
+    {"name": "snapshot_id", "type": ["null", "long"], "default": null, "field-id": 1},
+    {"name": "sequence_number", "type": ["null", "long"], "default": null, "field-id": 3},
+    {"name": "file_sequence_number", "type": ["null", "long"], "default": null, "field-id": 4},
+    {"name": "data_file", "field-id": 2, "type": {
+      "type": "record",
+      "name": "r2",
+      "fields": [
+        {"name": "content", "type": "int", "field-id": 134},
+        {"name": "file_path", "type": "string", "field-id": 100},
+        {"name": "file_format", "type": "string", "field-id": 101},
+        {"name": "partition", "field-id": 102, "type": {"type": "record", "name": "r102", "fields": []}},
+        {"name": "record_count", "type": "long", "field-id": 103},
+        {"name": "file_size_in_bytes", "type": "long", "field-id": 104},
+        {"name": "null_value_counts", "default": null, "field-id": 110, "type": ["null", {
+          "type": "array", "logicalType": "map", "items": {
+            "type": "record", "name": "k121_v122", "fields": [
+              {"name": "key", "type": "int", "field-id": 121},
+              {"name": "value", "type": "long", "field-id": 122}
+            ]}}]},
+        {"name": "lower_bounds", "default": null, "field-id": 125, "type": ["null", {
+          "type": "array", "logicalType": "map", "items": {
+            "type": "record", "name": "k126_v127", "fields": [
+              {"name": "key", "type": "int", "field-id": 126},
+              {"name": "value", "type": "bytes", "field-id": 127}
+            ]}}]},
+        {"name": "upper_bounds", "default": null, "field-id": 128, "type": ["null", {
+          "type": "array", "logicalType": "map", "items": {
+            "type": "record", "name": "k129_v130", "fields": [
+              {"name": "key", "type": "int", "field-id": 129},
+              {"name": "value", "type": "bytes", "field-id": 130}
+            ]}}]}
+      ]}}
+  ]
+}"#;
+
+const MANIFEST_FILE_SCHEMA: &str = r#"{
+  "type": "record",
+  "name": "manifest_file",
+  "fields": [
+    {"name": "manifest_path", "type": "string", "field-id": 500},
+    {"name": "manifest_length", "type": "long", "field-id": 501},
+    {"name": "partition_spec_id", "type": "int", "field-id": 502},
+    {"name": "content", "type": "int", "field-id": 517},
+    {"name": "sequence_number", "type": "long", "field-id": 515},
+    {"name": "min_sequence_number", "type": "long", "field-id": 516},
+    {"name": "added_snapshot_id", "type": "long", "field-id": 503},
+    {"name": "added_files_count", "type": "int", "field-id": 504},
+    {"name": "existing_files_count", "type": "int", "field-id": 505},
+    {"name": "deleted_files_count", "type": "int", "field-id": 506},
+    {"name": "added_rows_count", "type": "long", "field-id": 512},
+    {"name": "existing_rows_count", "type": "long", "field-id": 513},
+    {"name": "deleted_rows_count", "type": "long", "field-id": 514}
+  ]
+}"#;
+
+impl FuseTable {
+    /// Whether the Iceberg metadata of the table is kept up to date on each commit.
+    pub fn iceberg_export(&self) -> bool {
+        self.get_option(FUSE_OPT_KEY_ICEBERG_EXPORT, false)
+    }
+
+    /// Write the Iceberg metadata of the current snapshot of the table.
+    #[async_backtrace::framed]
+    pub async fn export_iceberg_metadata(&self) -> Result<()> {
+        let snapshot = self.read_table_snapshot().await?;
+        Self::write_iceberg_metadata(
+            &self.operator,
+            &self.meta_location_generator,
+            &self.table_info,
+            snapshot.as_deref(),
+        )
+        .await
+    }
+
+    /// Write the Iceberg metadata of `snapshot`, so that the table can be read by external
+    /// engines as an Iceberg (format version 2) table located at the storage prefix of it.
+    ///
+    /// The data files of the Iceberg table are the block files of the snapshot, nothing is
+    /// copied. Each segment is exported as one manifest, which is written once and shared by
+    /// the following exports, since segments are immutable. Only the current snapshot is kept
+    /// in the Iceberg metadata, the older ones may have been purged.
+    ///
+    /// The Iceberg field ids of the top level columns are the column ids plus one, the parquet
+    /// files carry no field ids, so the columns are resolved by the name mapping of the table.
+    ///
+    /// Concurrent exports are not coordinated, the metadata written by the last one wins.
+    #[async_backtrace::framed]
+    pub(crate) async fn write_iceberg_metadata(
+        operator: &Operator,
+        location_generator: &TableMetaLocationGenerator,
+        table_info: &TableInfo,
+        snapshot: Option<&TableSnapshot>,
+    ) -> Result<()> {
+        let storage_format = table_info
+            .options()
+            .get(OPT_KEY_STORAGE_FORMAT)
+            .cloned()
+            .unwrap_or_default();
+        if !matches!(
+            FuseStorageFormat::from_str(&storage_format)?,
+            FuseStorageFormat::Parquet
+        ) {
+            return Err(ErrorCode::StorageUnsupported(format!(
+                "iceberg export of table {} is not supported, only the parquet storage format can be exported",
+                table_info.desc
+            )));
+        }
+
+        let schema = table_info.schema();
+        let writer = IcebergMetadataWriter {
+            operator,
+            root_uri: root_uri(operator)?,
+            metadata_dir: format!("{}/{}", location_generator.prefix(), ICEBERG_METADATA_DIR),
+            schema: IcebergSchema::try_create(&schema)?,
+        };
+        writer
+            .write(location_generator.prefix(), &schema, snapshot)
+            .await
+    }
+}
+
+pub(crate) fn is_iceberg_export(options: &BTreeMap<String, String>) -> bool {
+    options
+        .get(FUSE_OPT_KEY_ICEBERG_EXPORT)
+        .and_then(|v| v.parse::<bool>().ok())
+        .unwrap_or(false)
+}
+
+// The uri of the root of the storage, which the paths of the operator are relative to.
+fn root_uri(operator: &Operator) -> Result<String> {
+    let info = operator.info();
+    let scheme = match info.scheme() {
+        Scheme::S3 => "s3",
+        Scheme::Gcs => "gs",
+        Scheme::Oss => "oss",
+        Scheme::Cos => "cosn",
+        Scheme::Fs => return Ok(format!("file://{}", info.root())),
+        other => {
+            return Err(ErrorCode::StorageUnsupported(format!(
+                "iceberg export is not supported on storage {}",
+                other
+            )));
+        }
+    };
+    Ok(format!("{}://{}{}", scheme, info.name(), info.root()))
+}
+
+fn avro_error(e: apache_avro::Error) -> ErrorCode {
+    ErrorCode::Internal(format!("failed to write iceberg manifest: {}", e))
+}
+
+// The Iceberg schema of a table schema, with the name mapping of it.
+struct IcebergSchema {
+    schema: JsonValue,
+    name_mapping: JsonValue,
+    last_column_id: i32,
+}
+
+impl IcebergSchema {
+    fn try_create(schema: &TableSchema) -> Result<Self> {
+        let mut builder = IcebergSchemaBuilder {
+            next_nested_id: schema.next_column_id() as i32 + 1,
+            last_column_id: 0,
+        };
+        let mut fields = Vec::with_capacity(schema.fields().len());
+        let mut mappings = Vec::with_capacity(schema.fields().len());
+        for field in schema.fields() {
+            let id = field.column_id() as i32 + 1;
+            builder.last_column_id = builder.last_column_id.max(id);
+            let (field, mapping) = builder.field(id, field.name(), field.data_type())?;
+            fields.push(field);
+            mappings.push(mapping);
+        }
+        Ok(Self {
+            schema: json!({
+                "type": "struct",
+                "schema-id": 0,
+                "fields": fields,
+            }),
+            name_mapping: JsonValue::Array(mappings),
+            last_column_id: builder.last_column_id,
+        })
+    }
+}
+
+struct IcebergSchemaBuilder {
+    next_nested_id: i32,
+    last_column_id: i32,
+}
+
+impl IcebergSchemaBuilder {
+    fn nested_id(&mut self) -> i32 {
+        let id = self.next_nested_id;
+        self.next_nested_id += 1;
+        self.last_column_id = self.last_column_id.max(id);
+        id
+    }
+
+    // Returns the Iceberg field and the name mapping of it.
+    fn field(
+        &mut self,
+        id: i32,
+        name: &str,
+        data_type: &TableDataType,
+    ) -> Result<(JsonValue, JsonValue)> {
+        let (data_type, required) = unwrap_nullable(data_type);
+        let (field_type, nested) = self.field_type(data_type)?;
+        let field = json!({
+            "id": id,
+            "name": name,
+            "required": required,
+            "type": field_type,
+        });
+        let mut mapping = json!({
+            "field-id": id,
+            "names": [name],
+        });
+        if !nested.is_empty() {
+            mapping["fields"] = JsonValue::Array(nested);
+        }
+        Ok((field, mapping))
+    }
+
+    // Returns the Iceberg type and the name mappings of the nested fields of it.
+    fn field_type(&mut self, data_type: &TableDataType) -> Result<(JsonValue, Vec<JsonValue>)> {
+        let primitive = match data_type {
+            TableDataType::Boolean => "boolean".to_string(),
+            TableDataType::String => "string".to_string(),
+            TableDataType::Number(number) => match number {
+                NumberDataType::Int8
+                | NumberDataType::Int16
+                | NumberDataType::Int32
+                | NumberDataType::UInt8
+                | NumberDataType::UInt16 => "int".to_string(),
+                NumberDataType::Int64 | NumberDataType::UInt32 => "long".to_string(),
+                // The values beyond i64::MAX would overflow `long`, and the blocks store them
+                // as parquet int64, which can not be read as `decimal(20, 0)`.
+                NumberDataType::UInt64 => {
+                    return Err(ErrorCode::UnsupportedDataType(format!(
+                        "iceberg export of {} is not supported, it overflows the iceberg long",
+                        data_type
+                    )));
+                }
+                NumberDataType::Float32 => "float".to_string(),
+                NumberDataType::Float64 => "double".to_string(),
+            },
+            TableDataType::Decimal(decimal) => {
+                let size = decimal.size();
+                if size.precision > 38 {
+                    return Err(ErrorCode::UnsupportedDataType(format!(
+                        "iceberg export of {} is not supported, the max precision of iceberg decimal is 38",
+                        data_type
+                    )));
+                }
+                format!("decimal({}, {})", size.precision, size.scale)
+            }
+            TableDataType::Timestamp => "timestamptz".to_string(),
+            TableDataType::Date => "date".to_string(),
+            TableDataType::Variant | TableDataType::Bitmap => "binary".to_string(),
+            TableDataType::Array(inner) => {
+                let element_id = self.nested_id();
+                let (inner, element_required) = unwrap_nullable(inner);
+                let (element, nested) = self.field_type(inner)?;
+                let list = json!({
+                    "type": "list",
+                    "element-id": element_id,
+                    "element-required": element_required,
+                    "element": element,
+                });
+                let mapping = nested_mapping(element_id, "element", nested);
+                return Ok((list, vec![mapping]));
+            }
+            TableDataType::Map(inner) => {
+                let TableDataType::Tuple { fields_type, .. } = inner.as_ref() else {
+                    return Err(unsupported(data_type));
+                };
+                let key_id = self.nested_id();
+                let value_id = self.nested_id();
+                let (key, key_nested) = self.field_type(unwrap_nullable(&fields_type[0]).0)?;
+                let (value_type, value_required) = unwrap_nullable(&fields_type[1]);
+                let (value, value_nested) = self.field_type(value_type)?;
+                let map = json!({
+                    "type": "map",
+                    "key-id": key_id,
+                    "key": key,
+                    "value-id": value_id,
+                    "value-required": value_required,
+                    "value": value,
+                });
+                let mappings = vec![
+                    nested_mapping(key_id, "key", key_nested),
+                    nested_mapping(value_id, "value", value_nested),
+                ];
+                return Ok((map, mappings));
+            }
+            TableDataType::Tuple {
+                fields_name,
+                fields_type,
+            } => {
+                let mut fields = Vec::with_capacity(fields_type.len());
+                let mut mappings = Vec::with_capacity(fields_type.len());
+                for (name, field_type) in fields_name.iter().zip(fields_type) {
+                    let id = self.nested_id();
+                    let (field, mapping) = self.field(id, name, field_type)?;
+                    fields.push(field);
+                    mappings.push(mapping);
+                }
+                let tuple = json!({
+                    "type": "struct",
+                    "fields": fields,
+                });
+                return Ok((tuple, mappings));
+            }
+            TableDataType::Null
+            | TableDataType::EmptyArray
+            | TableDataType::EmptyMap
+            | TableDataType::Nullable(_) => return Err(unsupported(data_type)),
+        };
+        Ok((JsonValue::String(primitive), vec![]))
+    }
+}
+
+fn unsupported(data_type: &TableDataType) -> ErrorCode {
+    ErrorCode::UnsupportedDataType(format!("iceberg export of {} is not supported", data_type))
+}
+
+fn unwrap_nullable(data_type: &TableDataType) -> (&TableDataType, bool) {
+    match data_type {
+        TableDataType::Nullable(inner) => (inner.as_ref(), false),
+        _ => (data_type, true),
+    }
+}
+
+fn nested_mapping(id: i32, name: &str, nested: Vec<JsonValue>) -> JsonValue {
+    let mut mapping = json!({
+        "field-id": id,
+        "names": [name],
+    });
+    if !nested.is_empty() {
+        mapping["fields"] = JsonValue::Array(nested);
+    }
+    mapping
+}
+
+// The single value serialization of iceberg, used by the bounds of the data files.
+// Strings and floats are skipped, as their min/max statistics may be truncated or NaN.
+fn bound_bytes(value: &Scalar) -> Option<Vec<u8>> {
+    let bytes = match value {
+        Scalar::Boolean(v) => vec![*v as u8],
+        Scalar::Date(v) => v.to_le_bytes().to_vec(),
+        Scalar::Timestamp(v) => v.to_le_bytes().to_vec(),
+        Scalar::Number(number) => match number {
+            NumberScalar::Int8(v) => (*v as i32).to_le_bytes().to_vec(),
+            NumberScalar::Int16(v) => (*v as i32).to_le_bytes().to_vec(),
+            NumberScalar::Int32(v) => v.to_le_bytes().to_vec(),
+            NumberScalar::UInt8(v) => (*v as i32).to_le_bytes().to_vec(),
+            NumberScalar::UInt16(v) => (*v as i32).to_le_bytes().to_vec(),
+            NumberScalar::Int64(v) => v.to_le_bytes().to_vec(),
+            NumberScalar::UInt32(v) => (*v as i64).to_le_bytes().to_vec(),
+            _ => return None,
+        },
+        _ => return None,
+    };
+    Some(bytes)
+}
+
+struct ManifestFile {
+    path: String,
+    length: u64,
+    files_count: u64,
+    rows_count: u64,
+}
+
+struct IcebergMetadataWriter<'a> {
+    operator: &'a Operator,
+    root_uri: String,
+    metadata_dir: String,
+    schema: IcebergSchema,
+}
+
+impl<'a> IcebergMetadataWriter<'a> {
+    #[async_backtrace::framed]
+    async fn write(
+        &self,
+        table_prefix: &str,
+        table_schema: &TableSchemaRef,
+        snapshot: Option<&TableSnapshot>,
+    ) -> Result<()> {
+        let hint_path = format!("{}/{}", self.metadata_dir, ICEBERG_VERSION_HINT);
+        let (prev_version, prev_metadata) = self.read_prev_metadata(&hint_path).await?;
+        let version = prev_version + 1;
+        let now = Utc::now().timestamp_millis();
+
+        let mut snapshots = vec![];
+        let mut snapshot_log = vec![];
+        let mut refs = json!({});
+        let mut current_snapshot_id = JsonValue::Null;
+        if let Some(snapshot) = snapshot {
+            // the sequence numbers of the data files are inherited from the manifest list
+            let snapshot_id = (snapshot.snapshot_id.as_u128() as i64) & i64::MAX;
+            let mut manifests = Vec::with_capacity(snapshot.segments.len());
+            for segment in &snapshot.segments {
+                manifests.push(self.write_manifest(segment, table_schema).await?);
+            }
+            let manifest_list = self
+                .write_manifest_list(snapshot_id, version, &manifests)
+                .await?;
+            let timestamp = snapshot
+                .timestamp
+                .map(|t| t.timestamp_millis())
+                .unwrap_or(now);
+            let summary = &snapshot.summary;
+            snapshots.push(json!({
+                "snapshot-id": snapshot_id,
+                "sequence-number": version,
+                "timestamp-ms": timestamp,
+                "manifest-list": manifest_list,
+                "schema-id": 0,
+                "summary": {
+                    "operation": "overwrite",
+                    "total-records": summary.row_count.to_string(),
+                    "total-data-files": summary.block_count.to_string(),
+                    "total-files-size": summary.compressed_byte_size.to_string(),
+                    "total-delete-files": "0",
+                    "total-position-deletes": "0",
+                    "total-equality-deletes": "0",
+                    "fuse-snapshot-id": snapshot.snapshot_id.simple().to_string(),
+                },
+            }));
+            snapshot_log.push(json!({
+                "timestamp-ms": timestamp,
+                "snapshot-id": snapshot_id,
+            }));
+            refs = json!({
+                "main": {
+                    "snapshot-id": snapshot_id,
+                    "type": "branch",
+                }
+            });
+            current_snapshot_id = json!(snapshot_id);
+        }
+
+        let mut table_uuid = Uuid::new_v4().to_string();
+        let mut metadata_log = vec![];
+        if let Some((prev_path, prev)) = prev_metadata {
+            if let Some(uuid) = prev["table-uuid"].as_str() {
+                table_uuid = uuid.to_string();
+            }
+            if let Some(log) = prev["metadata-log"].as_array() {
+                metadata_log.extend(log.iter().cloned());
+            }
+            metadata_log.push(json!({
+                "timestamp-ms": prev["last-updated-ms"].as_i64().unwrap_or(now),
+                "metadata-file": self.uri(&prev_path),
+            }));
+            let skipped = metadata_log.len().saturating_sub(ICEBERG_MAX_METADATA_LOG);
+            metadata_log.drain(..skipped);
+        }
+
+        let name_mapping = serde_json::to_string(&self.schema.name_mapping)?;
+        let metadata = json!({
+            "format-version": ICEBERG_FORMAT_VERSION,
+            "table-uuid": table_uuid,
+            "location": self.uri(table_prefix),
+            "last-sequence-number": version,
+            "last-updated-ms": now,
+            "last-column-id": self.schema.last_column_id,
+            "current-schema-id": 0,
+            "schemas": [self.schema.schema],
+            "default-spec-id": 0,
+            "partition-specs": [{"spec-id": 0, "fields": []}],
+            "last-partition-id": 999,
+            "default-sort-order-id": 0,
+            "sort-orders": [{"order-id": 0, "fields": []}],
+            "properties": {
+                "schema.name-mapping.default": name_mapping,
+                "write.format.default": "parquet",
+            },
+            "current-snapshot-id": current_snapshot_id,
+            "refs": refs,
+            "snapshots": snapshots,
+            "snapshot-log": snapshot_log,
+            "metadata-log": metadata_log,
+        });
+
+        let metadata_path = self.metadata_path(version);
+        self.operator
+            .write(&metadata_path, serde_json::to_vec_pretty(&metadata)?)
+            .await?;
+        self.operator.write(&hint_path, version.to_string()).await?;
+        Ok(())
+    }
+
+    fn uri(&self, path: &str) -> String {
+        format!("{}{}", self.root_uri, path)
+    }
+
+    fn metadata_path(&self, version: u64) -> String {
+        format!("{}/v{}.metadata.json", self.metadata_dir, version)
+    }
+
+    // Returns the version of the last written metadata, and the path and content of it.
+    #[async_backtrace::framed]
+    async fn read_prev_metadata(
+        &self,
+        hint_path: &str,
+    ) -> Result<(u64, Option<(String, JsonValue)>)> {
+        let hint = match self.operator.read(hint_path).await {
+            Ok(hint) => hint,
+            Err(e) if e.kind() == opendal::ErrorKind::NotFound => return Ok((0, None)),
+            Err(e) => return Err(e.into()),
+        };
+        let version = String::from_utf8(hint)?
+            .trim()
+            .parse::<u64>()
+            .map_err(|e| {
+                ErrorCode::Internal(format!("invalid iceberg version hint {}: {}", hint_path, e))
+            })?;
+        let path = self.metadata_path(version);
+        let metadata = match self.operator.read(&path).await {
+            Ok(content) => Some((path, serde_json::from_slice(&content)?)),
+            Err(e) if e.kind() == opendal::ErrorKind::NotFound => None,
+            Err(e) => return Err(e.into()),
+        };
+        Ok((version, metadata))
+    }
+
+    // Write the manifest of a segment, unless it has been written by a previous export.
+    #[async_backtrace::framed]
+    async fn write_manifest(
+        &self,
+        segment: &Location,
+        table_schema: &TableSchemaRef,
+    ) -> Result<ManifestFile> {
+        let segment_info = SegmentsIO::read_compact_segment(
+            self.operator.clone(),
+            segment.clone(),
+            table_schema.clone(),
+            true,
+        )
+        .await?;
+        let segment_name = segment
+            .0
+            .rsplit('/')
+            .next()
+            .and_then(|name| name.split('.').next())
+            .unwrap_or_default();
+        let path = format!("{}/{}-m0.avro", self.metadata_dir, segment_name);
+        let files_count = segment_info.summary.block_count;
+        let rows_count = segment_info.summary.row_count;
+
+        let length = match self.operator.stat(&path).await {
+            Ok(meta) => meta.content_length(),
+            Err(e) if e.kind() == opendal::ErrorKind::NotFound => {
+                let blocks = segment_info.block_metas()?;
+                if blocks.iter().any(|block| block.deletion_vector.is_some()) {
+                    return Err(ErrorCode::StorageUnsupported(
+                        "iceberg export of blocks with deletion vectors is not supported, compact the table first",
+                    ));
+                }
+                let content = self.manifest_content(table_schema, &blocks)?;
+                let length = content.len() as u64;
+                self.operator.write(&path, content).await?;
+                length
+            }
+            Err(e) => return Err(e.into()),
+        };
+        Ok(ManifestFile {
+            path: self.uri(&path),
+            length,
+            files_count,
+            rows_count,
+        })
+    }
+
+    fn manifest_content(
+        &self,
+        table_schema: &TableSchema,
+        blocks: &[Arc<BlockMeta>],
+    ) -> Result<Vec<u8>> {
+        let avro_schema = AvroSchema::parse_str(MANIFEST_ENTRY_SCHEMA).map_err(avro_error)?;
+        let mut writer = AvroWriter::new(&avro_schema, Vec::new());
+        let metadata = [
+            ("schema", serde_json::to_string(&self.schema.schema)?),
+            ("schema-id", "0".to_string()),
+            ("partition-spec", "[]".to_string()),
+            ("partition-spec-id", "0".to_string()),
+            ("format-version", ICEBERG_FORMAT_VERSION.to_string()),
+            ("content", "data".to_string()),
+        ];
+        for (key, value) in metadata {
+            writer
+                .add_user_metadata(key.to_string(), value)
+                .map_err(avro_error)?;
+        }
+
+        for block in blocks {
+            let mut null_value_counts = vec![];
+            let mut lower_bounds = vec![];
+            let mut upper_bounds = vec![];
+            for field in table_schema.fields() {
+                let Some(stats) = block.col_stats.get(&field.column_id()) else {
+                    continue;
+                };
+                if matches!(
+                    field.data_type().remove_nullable(),
+                    TableDataType::Array(_) | TableDataType::Map(_) | TableDataType::Tuple { .. }
+                ) {
+                    continue;
+                }
+                let id = AvroValue::Int(field.column_id() as i32 + 1);
+                null_value_counts.push(AvroValue::Record(vec![
+                    ("key".to_string(), id.clone()),
+                    (
+                        "value".to_string(),
+                        AvroValue::Long(stats.null_count as i64),
+                    ),
+                ]));
+                if let (Some(min), Some(max)) = (bound_bytes(stats.min()), bound_bytes(stats.max()))
+                {
+                    lower_bounds.push(AvroValue::Record(vec![
+                        ("key".to_string(), id.clone()),
+                        ("value".to_string(), AvroValue::Bytes(min)),
+                    ]));
+                    upper_bounds.push(AvroValue::Record(vec![
+                        ("key".to_string(), id),
+                        ("value".to_string(), AvroValue::Bytes(max)),
+                    ]));
+                }
+            }
+            let map =
+                |entries: Vec<AvroValue>| AvroValue::Union(1, Box::new(AvroValue::Array(entries)));
+            let data_file = AvroValue::Record(vec![
+                ("content".to_string(), AvroValue::Int(0)),
+                (
+                    "file_path".to_string(),
+                    AvroValue::String(self.uri(&block.location.0)),
+                ),
+                (
+                    "file_format".to_string(),
+                    AvroValue::String("PARQUET".to_string()),
+                ),
+                ("partition".to_string(), AvroValue::Record(vec![])),
+                (
+                    "record_count".to_string(),
+                    AvroValue::Long(block.row_count as i64),
+                ),
+                (
+                    "file_size_in_bytes".to_string(),
+                    AvroValue::Long(block.file_size as i64),
+                ),
+                ("null_value_counts".to_string(), map(null_value_counts)),
+                ("lower_bounds".to_string(), map(lower_bounds)),
+                ("upper_bounds".to_string(), map(upper_bounds)),
+            ]);
+            let null = || AvroValue::Union(0, Box::new(AvroValue::Null));
+            // status 1: ADDED, the snapshot id and sequence numbers are inherited
+            let entry = AvroValue::Record(vec![
+                ("status".to_string(), AvroValue::Int(1)),
+                ("snapshot_id".to_string(), null()),
+                ("sequence_number".to_string(), null()),
+                ("file_sequence_number".to_string(), null()),
+                ("data_file".to_string(), data_file),
+            ]);
+            writer.append(entry).map_err(avro_error)?;
+        }
+        writer.into_inner().map_err(avro_error)
+    }
+
+    #[async_backtrace::framed]
+    async fn write_manifest_list(
+        &self,
+        snapshot_id: i64,
+        sequence_number: u64,
+        manifests: &[ManifestFile],
+    ) -> Result<String> {
+        let avro_schema = AvroSchema::parse_str(MANIFEST_FILE_SCHEMA).map_err(avro_error)?;
+        let mut writer = AvroWriter::new(&avro_schema, Vec::new());
+        let metadata = [
+            ("snapshot-id", snapshot_id.to_string()),
+            ("sequence-number", sequence_number.to_string()),
+            ("format-version", ICEBERG_FORMAT_VERSION.to_string()),
+        ];
+        for (key, value) in metadata {
+            writer
+                .add_user_metadata(key.to_string(), value)
+                .map_err(avro_error)?;
+        }
+        for manifest in manifests {
+            let sequence_number = AvroValue::Long(sequence_number as i64);
+            let manifest_file = AvroValue::Record(vec![
+                (
+                    "manifest_path".to_string(),
+                    AvroValue::String(manifest.path.clone()),
+                ),
+                (
+                    "manifest_length".to_string(),
+                    AvroValue::Long(manifest.length as i64),
+                ),
+                ("partition_spec_id".to_string(), AvroValue::Int(0)),
+                ("content".to_string(), AvroValue::Int(0)),
+                ("sequence_number".to_string(), sequence_number.clone()),
+                ("min_sequence_number".to_string(), sequence_number),
+                (
+                    "added_snapshot_id".to_string(),
+                    AvroValue::Long(snapshot_id),
+                ),
+                (
+                    "added_files_count".to_string(),
+                    AvroValue::Int(manifest.files_count as i32),
+                ),
+                ("existing_files_count".to_string(), AvroValue::Int(0)),
+                ("deleted_files_count".to_string(), AvroValue::Int(0)),
+                (
+                    "added_rows_count".to_string(),
+                    AvroValue::Long(manifest.rows_count as i64),
+                ),
+                ("existing_rows_count".to_string(), AvroValue::Long(0)),
+                ("deleted_rows_count".to_string(), AvroValue::Long(0)),
+            ]);
+            writer.append(manifest_file).map_err(avro_error)?;
+        }
+        let content = writer.into_inner().map_err(avro_error)?;
+        let path = format!(
+            "{}/snap-{}-1-{}.avro",
+            self.metadata_dir,
+            snapshot_id,
+            Uuid::new_v4()
+        );
+        self.operator.write(&path, content).await?;
+        Ok(self.uri(&path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use apache_avro::Reader as AvroReader;
+    use common_expression::TableField;
+    use storages_common_table_meta::meta::ColumnStatistics;
+    use storages_common_table_meta::meta::Compression;
+
+    use super::*;
+
+    fn test_writer(operator: &Operator, schema: &TableSchema) -> IcebergMetadataWriter<'_> {
+        IcebergMetadataWriter {
+            operator,
+            root_uri: "s3://bucket".to_string(),
+            metadata_dir: "1/2/metadata".to_string(),
+            schema: IcebergSchema::try_create(schema).unwrap(),
+        }
+    }
+
+    fn record_field<'a>(value: &'a AvroValue, name: &str) -> &'a AvroValue {
+        match value {
+            AvroValue::Record(fields) => &fields.iter().find(|(n, _)| n == name).unwrap().1,
+            _ => panic!("{:?} is not a record", value),
+        }
+    }
+
+    #[test]
+    fn test_manifest_read_back() -> Result<()> {
+        let operator = Operator::new(opendal::services::Memory::default())?.finish();
+        let schema = TableSchema::new(vec![
+            TableField::new("a", TableDataType::Number(NumberDataType::Int32)),
+            TableField::new("b", TableDataType::String),
+        ]);
+        let writer = test_writer(&operator, &schema);
+
+        let col_stats = HashMap::from([
+            (
+                0,
+                ColumnStatistics::new(
+                    Scalar::Number(NumberScalar::Int32(1)),
+                    Scalar::Number(NumberScalar::Int32(9)),
+                    2,
+                    40,
+                    None,
+                ),
+            ),
+            (
+                1,
+                ColumnStatistics::new(
+                    Scalar::String(b"x".to_vec()),
+                    Scalar::String(b"y".to_vec()),
+                    0,
+                    20,
+                    None,
+                ),
+            ),
+        ]);
+        let block = Arc::new(BlockMeta::new(
+            10,
+            60,
+            100,
+            col_stats,
+            HashMap::new(),
+            None,
+            ("1/2/_b/block.parquet".to_string(), 2),
+            None,
+            0,
+            Compression::Zstd,
+            None,
+        ));
+        let content = writer.manifest_content(&schema, &[block])?;
+
+        let reader = AvroReader::new(content.as_slice()).unwrap();
+        let metadata = reader.user_metadata();
+        assert_eq!(metadata["format-version"], b"2".to_vec());
+        assert_eq!(metadata["content"], b"data".to_vec());
+        let entries = reader.collect::<std::result::Result<Vec<_>, _>>().unwrap();
+        assert_eq!(entries.len(), 1);
+
+        let entry = &entries[0];
+        assert_eq!(record_field(entry, "status"), &AvroValue::Int(1));
+        let data_file = record_field(entry, "data_file");
+        assert_eq!(
+            record_field(data_file, "file_path"),
+            &AvroValue::String("s3://bucket1/2/_b/block.parquet".to_string())
+        );
+        assert_eq!(
+            record_field(data_file, "record_count"),
+            &AvroValue::Long(10)
+        );
+        assert_eq!(
+            record_field(data_file, "file_size_in_bytes"),
+            &AvroValue::Long(100)
+        );
+
+        // the field ids are the column ids plus one, the bounds of strings are skipped
+        let bound = |value: Vec<u8>| {
+            AvroValue::Union(
+                1,
+                Box::new(AvroValue::Array(vec![AvroValue::Record(vec![
+                    ("key".to_string(), AvroValue::Int(1)),
+                    ("value".to_string(), AvroValue::Bytes(value)),
+                ])])),
+            )
+        };
+        assert_eq!(
+            record_field(data_file, "lower_bounds"),
+            &bound(1i32.to_le_bytes().to_vec())
+        );
+        assert_eq!(
+            record_field(data_file, "upper_bounds"),
+            &bound(9i32.to_le_bytes().to_vec())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_unsupported_uint64() {
+        let schema = TableSchema::new(vec![TableField::new(
+            "a",
+            TableDataType::Number(NumberDataType::UInt64),
+        )]);
+        let err = IcebergSchema::try_create(&schema).err().unwrap();
+        assert_eq!(err.code(), ErrorCode::UNSUPPORTED_DATA_TYPE);
+    }
+}
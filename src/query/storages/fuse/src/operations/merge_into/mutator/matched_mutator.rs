@@ -49,6 +49,7 @@ use crate::io::write_data;
 use crate::io::BlockBuilder;
 use crate::io::BlockReader;
 use crate::io::CompactSegmentInfoReader;
+use crate::io::DeletionVector;
 use crate::io::MetaReaders;
 use crate::io::ReadSettings;
 use crate::io::WriteSettings;
@@ -295,10 +296,17 @@ impl AggregationContext {
         )
         .await?;
         let origin_num_rows = origin_data_block.num_rows();
+        // the rows deleted by merge-on-read mutations shall not be written back.
+        let deletion_vector = match &block_meta.deletion_vector {
+            Some(meta) => Some(DeletionVector::read(&self.block_reader.operator, meta).await?),
+            None => None,
+        };
         // apply delete
         let mut bitmap = MutableBitmap::new();
         for row in 0..origin_num_rows {
-            if modified_offsets.contains(&row) {
+            if modified_offsets.contains(&row)
+                || deletion_vector.as_ref().is_some_and(|v| v.contains(row))
+            {
                 bitmap.push(false);
             } else {
                 bitmap.push(true);
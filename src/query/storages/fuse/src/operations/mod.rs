@@ -15,11 +15,14 @@
 mod agg_index_sink;
 mod analyze;
 mod append;
+mod changes;
+mod clone;
 mod commit;
 pub mod common;
 mod compact;
 mod delete;
 mod gc;
+mod iceberg_export;
 pub mod merge;
 pub mod merge_into;
 mod mutation;
@@ -31,15 +34,19 @@ mod recluster;
 pub mod replace;
 pub mod replace_into;
 mod revert;
+mod snapshot_ref;
 mod truncate;
+mod ttl;
 mod update;
 pub mod util;
 pub use agg_index_sink::AggIndexSink;
+pub use changes::SnapshotChanges;
 pub use common::BlockMetaIndex;
 pub use common::FillInternalColumnProcessor;
 pub use common::TransformSerializeBlock;
 pub use compact::CompactOptions;
 pub use delete::MutationBlockPruningContext;
+pub use gc::SharedFiles;
 pub use mutation::BlockCompactMutator;
 pub use mutation::CompactPartInfo;
 pub use mutation::DeletedSegmentInfo;
@@ -50,6 +57,7 @@ pub use mutation::SegmentCompactMutator;
 pub use mutation::SegmentCompactionState;
 pub use mutation::SegmentCompactor;
 pub use read::build_row_fetcher_pipeline;
+pub use ttl::TableTtl;
 pub use util::acquire_task_permit;
 pub use util::column_parquet_metas;
 pub use util::read_block;
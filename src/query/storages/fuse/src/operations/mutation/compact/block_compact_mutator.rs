@@ -33,6 +33,7 @@ use opendal::Operator;
 use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::CompactSegmentInfo;
 use storages_common_table_meta::meta::Statistics;
+use storages_common_table_meta::meta::StatisticsOfColumns;
 
 use crate::io::SegmentsIO;
 use crate::operations::acquire_task_permit;
@@ -45,6 +46,7 @@ use crate::operations::mutation::CompactTaskInfo;
 use crate::operations::mutation::SegmentIndex;
 use crate::operations::mutation::MAX_BLOCK_COUNT;
 use crate::operations::CompactOptions;
+use crate::operations::TableTtl;
 use crate::statistics::reducers::merge_statistics_mut;
 use crate::statistics::sort_by_cluster_stats;
 use crate::TableContext;
@@ -57,6 +59,7 @@ pub struct BlockCompactMutator {
     pub thresholds: BlockThresholds,
     pub compact_params: CompactOptions,
     pub cluster_key_id: Option<u32>,
+    pub ttl: Option<TableTtl>,
 }
 
 impl BlockCompactMutator {
@@ -66,6 +69,7 @@ impl BlockCompactMutator {
         compact_params: CompactOptions,
         operator: Operator,
         cluster_key_id: Option<u32>,
+        ttl: Option<TableTtl>,
     ) -> Self {
         Self {
             ctx,
@@ -73,6 +77,7 @@ impl BlockCompactMutator {
             thresholds,
             compact_params,
             cluster_key_id,
+            ttl,
         }
     }
 
@@ -96,6 +101,7 @@ impl BlockCompactMutator {
         let mut checker = SegmentCompactChecker::new(
             self.compact_params.block_per_seg as u64,
             self.cluster_key_id,
+            self.ttl.clone(),
         );
 
         let mut segment_idx = 0;
@@ -133,7 +139,7 @@ impl BlockCompactMutator {
             for (segment_idx, compact_segment) in segment_infos.into_iter() {
                 let segments_vec = checker.add(segment_idx, compact_segment);
                 for segments in segments_vec {
-                    self.generate_part(segments, &mut parts, &mut checker);
+                    self.generate_part(segments, &mut parts, &mut checker)?;
                 }
 
                 if checker.compacted_segment_cnt + checker.segments.len() >= limit
@@ -165,7 +171,7 @@ impl BlockCompactMutator {
             std::mem::take(&mut checker.segments),
             &mut parts,
             &mut checker,
-        );
+        )?;
 
         // Status.
         self.ctx.set_status_info(&format!(
@@ -199,6 +205,7 @@ impl BlockCompactMutator {
                     column_ids,
                     self.cluster_key_id,
                     self.thresholds,
+                    self.ttl.clone(),
                     lazy_parts,
                 )
                 .await?,
@@ -215,6 +222,7 @@ impl BlockCompactMutator {
         column_ids: HashSet<ColumnId>,
         cluster_key_id: Option<u32>,
         thresholds: BlockThresholds,
+        ttl: Option<TableTtl>,
         mut lazy_parts: Vec<CompactLazyPartInfo>,
     ) -> Result<Vec<PartInfoPtr>> {
         let start = Instant::now();
@@ -232,6 +240,7 @@ impl BlockCompactMutator {
             remain -= gap_size;
 
             let column_ids = column_ids.clone();
+            let ttl = ttl.clone();
             let semaphore = semaphore.clone();
 
             let batch = lazy_parts.drain(0..batch_size).collect::<Vec<_>>();
@@ -240,8 +249,12 @@ impl BlockCompactMutator {
                 async move {
                     let mut res = vec![];
                     for lazy_part in batch {
-                        let mut builder =
-                            CompactTaskBuilder::new(column_ids.clone(), cluster_key_id, thresholds);
+                        let mut builder = CompactTaskBuilder::new(
+                            column_ids.clone(),
+                            cluster_key_id,
+                            thresholds,
+                            ttl.clone(),
+                        );
                         let parts = builder
                             .build_tasks(
                                 ctx.clone(),
@@ -284,8 +297,8 @@ impl BlockCompactMutator {
         segments: Vec<(SegmentIndex, Arc<CompactSegmentInfo>)>,
         parts: &mut Vec<PartInfoPtr>,
         checker: &mut SegmentCompactChecker,
-    ) {
-        if !segments.is_empty() && checker.check_for_compact(&segments) {
+    ) -> Result<()> {
+        if !segments.is_empty() && checker.check_for_compact(&segments)? {
             let mut segment_indices = Vec::with_capacity(segments.len());
             let mut compact_segments = Vec::with_capacity(segments.len());
             for (idx, segment) in segments.into_iter() {
@@ -296,6 +309,7 @@ impl BlockCompactMutator {
             let lazy_part = CompactLazyPartInfo::create(segment_indices, compact_segments);
             parts.push(lazy_part);
         }
+        Ok(())
     }
 }
 
@@ -304,18 +318,20 @@ struct SegmentCompactChecker {
     total_block_count: u64,
     block_threshold: u64,
     cluster_key_id: Option<u32>,
+    ttl: Option<TableTtl>,
 
     compacted_segment_cnt: usize,
     compacted_block_cnt: u64,
 }
 
 impl SegmentCompactChecker {
-    fn new(block_threshold: u64, cluster_key_id: Option<u32>) -> Self {
+    fn new(block_threshold: u64, cluster_key_id: Option<u32>, ttl: Option<TableTtl>) -> Self {
         Self {
             segments: vec![],
             total_block_count: 0,
             block_threshold,
             cluster_key_id,
+            ttl,
             compacted_block_cnt: 0,
             compacted_segment_cnt: 0,
         }
@@ -324,19 +340,28 @@ impl SegmentCompactChecker {
     fn check_for_compact(
         &mut self,
         segments: &Vec<(SegmentIndex, Arc<CompactSegmentInfo>)>,
-    ) -> bool {
+    ) -> Result<bool> {
         if segments.is_empty() {
-            return false;
+            return Ok(false);
         }
 
         if segments.len() == 1 {
             let summary = &segments[0].1.summary;
-            if (summary.block_count == 1 || summary.perfect_block_count == summary.block_count)
+            // A block with deletion vector is never perfect, it shall be compacted even if
+            // it is the only block of the segment.
+            let no_need_compact = (summary.perfect_block_count == summary.block_count
+                || (summary.block_count == 1
+                    && segments[0].1.block_metas().map_or(true, |blocks| {
+                        blocks.iter().all(|block| block.deletion_vector.is_none())
+                    })))
+                // The expired rows need to be removed.
+                && !has_expired_rows(&self.ttl, &summary.col_stats)?;
+            if no_need_compact
                 && (self.cluster_key_id.is_none()
                     || self.cluster_key_id
                         == summary.cluster_stats.as_ref().map(|v| v.cluster_key_id))
             {
-                return false;
+                return Ok(false);
             }
         }
 
@@ -344,7 +369,7 @@ impl SegmentCompactChecker {
         self.compacted_block_cnt += segments
             .iter()
             .fold(0, |acc, x| acc + x.1.summary.block_count);
-        true
+        Ok(true)
     }
 
     fn add(
@@ -378,6 +403,7 @@ struct CompactTaskBuilder {
     column_ids: HashSet<ColumnId>,
     cluster_key_id: Option<u32>,
     thresholds: BlockThresholds,
+    ttl: Option<TableTtl>,
 
     blocks: Vec<Arc<BlockMeta>>,
     total_rows: usize,
@@ -389,11 +415,13 @@ impl CompactTaskBuilder {
         column_ids: HashSet<ColumnId>,
         cluster_key_id: Option<u32>,
         thresholds: BlockThresholds,
+        ttl: Option<TableTtl>,
     ) -> Self {
         Self {
             column_ids,
             cluster_key_id,
             thresholds,
+            ttl,
             blocks: vec![],
             total_rows: 0,
             total_size: 0,
@@ -421,7 +449,7 @@ impl CompactTaskBuilder {
             }
         }
 
-        let total_rows = self.total_rows + block.row_count as usize;
+        let total_rows = self.total_rows + block.live_row_count() as usize;
         let total_size = self.total_size + block.block_size as usize;
         if !thresholds.check_large_enough(total_rows, total_size) {
             // blocks < N
@@ -445,29 +473,34 @@ impl CompactTaskBuilder {
         unchanged_blocks: &mut Vec<(BlockIndex, Arc<BlockMeta>)>,
         block_idx: BlockIndex,
         blocks: Vec<Arc<BlockMeta>>,
-    ) -> bool {
+    ) -> Result<bool> {
         let mut flag = false;
-        if blocks.len() == 1 && !self.check_compact(&blocks[0]) {
+        if blocks.len() == 1 && !self.check_compact(&blocks[0])? {
             unchanged_blocks.push((block_idx, blocks[0].clone()));
             flag = true;
         } else {
             tasks.push_back((block_idx, blocks));
         }
-        flag
+        Ok(flag)
     }
 
-    fn check_compact(&self, block: &Arc<BlockMeta>) -> bool {
+    fn check_compact(&self, block: &Arc<BlockMeta>) -> Result<bool> {
+        // The deleted rows and the expired rows need to be removed.
+        if block.deletion_vector.is_some() || has_expired_rows(&self.ttl, &block.col_stats)? {
+            return Ok(true);
+        }
+
         let column_ids: HashSet<ColumnId> = block.col_metas.keys().cloned().collect();
         if self.column_ids == column_ids {
             // Check if the block needs to be resort.
-            self.cluster_key_id.is_some_and(|key| {
+            Ok(self.cluster_key_id.is_some_and(|key| {
                 block
                     .cluster_stats
                     .as_ref()
                     .map_or(true, |v| v.cluster_key_id != key)
-            })
+            }))
         } else {
-            true
+            Ok(true)
         }
     }
 
@@ -525,12 +558,14 @@ impl CompactTaskBuilder {
             let (unchanged, need_take) = self.add(block, self.thresholds);
             if need_take {
                 let blocks = self.take_blocks();
-                latest_flag = self.build_task(&mut tasks, &mut unchanged_blocks, block_idx, blocks);
+                latest_flag =
+                    self.build_task(&mut tasks, &mut unchanged_blocks, block_idx, blocks)?;
                 block_idx += 1;
             }
             if unchanged {
                 let blocks = vec![block.clone()];
-                latest_flag = self.build_task(&mut tasks, &mut unchanged_blocks, block_idx, blocks);
+                latest_flag =
+                    self.build_task(&mut tasks, &mut unchanged_blocks, block_idx, blocks)?;
                 block_idx += 1;
             }
         }
@@ -539,7 +574,7 @@ impl CompactTaskBuilder {
             let tail = self.take_blocks();
             if self.cluster_key_id.is_some() && latest_flag {
                 // The clustering table cannot compact different level blocks.
-                self.build_task(&mut tasks, &mut unchanged_blocks, block_idx, tail);
+                self.build_task(&mut tasks, &mut unchanged_blocks, block_idx, tail)?;
             } else {
                 let mut blocks = if latest_flag {
                     unchanged_blocks.pop().map_or(vec![], |(_, v)| vec![v])
@@ -549,17 +584,17 @@ impl CompactTaskBuilder {
 
                 let (total_rows, total_size) =
                     blocks.iter().chain(tail.iter()).fold((0, 0), |mut acc, x| {
-                        acc.0 += x.row_count as usize;
+                        acc.0 += x.live_row_count() as usize;
                         acc.1 += x.block_size as usize;
                         acc
                     });
                 if self.thresholds.check_for_compact(total_rows, total_size) {
                     blocks.extend(tail);
-                    self.build_task(&mut tasks, &mut unchanged_blocks, block_idx, blocks);
+                    self.build_task(&mut tasks, &mut unchanged_blocks, block_idx, blocks)?;
                 } else {
                     // blocks > 2N
-                    self.build_task(&mut tasks, &mut unchanged_blocks, block_idx, blocks);
-                    self.build_task(&mut tasks, &mut unchanged_blocks, block_idx + 1, tail);
+                    self.build_task(&mut tasks, &mut unchanged_blocks, block_idx, blocks)?;
+                    self.build_task(&mut tasks, &mut unchanged_blocks, block_idx + 1, tail)?;
                 }
             }
         }
@@ -587,3 +622,10 @@ impl CompactTaskBuilder {
         Ok(partitions)
     }
 }
+
+fn has_expired_rows(ttl: &Option<TableTtl>, col_stats: &StatisticsOfColumns) -> Result<bool> {
+    match ttl {
+        Some(ttl) => ttl.any_expired(col_stats),
+        None => Ok(false),
+    }
+}
@@ -27,12 +27,14 @@ use common_pipeline_core::processors::processor::ProcessorPtr;
 use storages_common_table_meta::meta::BlockMeta;
 
 use crate::io::BlockReader;
+use crate::io::DeletionVector;
 use crate::io::ReadSettings;
 use crate::operations::mutation::compact::compact_part::CompactPartInfo;
 use crate::operations::mutation::mutation_meta::ClusterStatsGenType;
 use crate::operations::mutation::mutation_meta::SerializeBlock;
 use crate::operations::mutation::SerializeDataMeta;
 use crate::operations::BlockMetaIndex;
+use crate::operations::TableTtl;
 use crate::pipelines::processors::port::OutputPort;
 use crate::pipelines::processors::processor::Event;
 use crate::pipelines::processors::Processor;
@@ -42,7 +44,7 @@ use crate::MergeIOReadResult;
 enum State {
     ReadData(Option<PartInfoPtr>),
     Concat {
-        read_res: Vec<MergeIOReadResult>,
+        read_res: Vec<(MergeIOReadResult, Option<DeletionVector>)>,
         metas: Vec<Arc<BlockMeta>>,
         index: BlockMetaIndex,
     },
@@ -55,6 +57,8 @@ pub struct CompactSource {
     ctx: Arc<dyn TableContext>,
     block_reader: Arc<BlockReader>,
     storage_format: FuseStorageFormat,
+    // the ttl of the table, and the offset of the ttl column in the blocks.
+    ttl: Option<(TableTtl, usize)>,
     output: Arc<OutputPort>,
 }
 
@@ -63,6 +67,7 @@ impl CompactSource {
         ctx: Arc<dyn TableContext>,
         storage_format: FuseStorageFormat,
         block_reader: Arc<BlockReader>,
+        ttl: Option<(TableTtl, usize)>,
         output: Arc<OutputPort>,
     ) -> Result<ProcessorPtr> {
         Ok(ProcessorPtr::create(Box::new(CompactSource {
@@ -70,6 +75,7 @@ impl CompactSource {
             ctx,
             block_reader,
             storage_format,
+            ttl,
             output,
         })))
     }
@@ -133,12 +139,28 @@ impl Processor for CompactSource {
                 let blocks = read_res
                     .into_iter()
                     .zip(metas.into_iter())
-                    .map(|(data, meta)| {
-                        self.block_reader.deserialize_chunks_with_meta(
+                    .map(|((data, deletion_vector), meta)| {
+                        let block = self.block_reader.deserialize_chunks_with_meta(
                             &meta,
                             &self.storage_format,
                             data,
-                        )
+                        )?;
+                        // fold the deletion vector into the compacted block.
+                        let block = match deletion_vector {
+                            Some(deletion_vector) => {
+                                let selection = deletion_vector.selection(block.num_rows());
+                                block.filter_with_bitmap(&selection)?
+                            }
+                            None => block,
+                        };
+                        // remove the expired rows.
+                        match &self.ttl {
+                            Some((ttl, offset)) => {
+                                let live_rows = ttl.live_rows(&block, *offset)?;
+                                block.filter_with_bitmap(&live_rows)
+                            }
+                            None => Ok(block),
+                        }
                     })
                     .collect::<Result<Vec<_>>>()?;
 
@@ -148,11 +170,17 @@ impl Processor for CompactSource {
                 } else {
                     DataBlock::concat(&blocks)?
                 };
-
                 let meta = Box::new(SerializeDataMeta::SerializeBlock(SerializeBlock::create(
                     index,
                     ClusterStatsGenType::Generally,
                 )));
+                if block.is_empty() {
+                    // all the rows are expired, the blocks are dropped.
+                    let block = DataBlock::empty_with_meta(meta);
+                    self.state = State::Output(self.ctx.get_partition(), block);
+                    return Ok(());
+                }
+
                 let new_block = block.add_meta(Some(meta))?;
 
                 let progress_values = ProgressValues {
@@ -194,14 +222,21 @@ impl Processor for CompactSource {
                                     metrics_inc_compact_block_read_bytes(block.block_size);
                                 }
 
-                                block_reader
+                                let data = block_reader
                                     .read_columns_data_by_merge_io(
                                         &settings,
                                         &block.location.0,
                                         &block.col_metas,
                                         &None,
                                     )
-                                    .await
+                                    .await?;
+                                let deletion_vector = match &block.deletion_vector {
+                                    Some(meta) => Some(
+                                        DeletionVector::read(&block_reader.operator, meta).await?,
+                                    ),
+                                    None => None,
+                                };
+                                Ok::<_, ErrorCode>((data, deletion_vector))
                             });
                         }
 
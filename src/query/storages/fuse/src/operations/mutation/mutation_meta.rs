@@ -15,6 +15,7 @@
 use common_expression::BlockMetaInfo;
 use common_expression::BlockMetaInfoDowncast;
 use storages_common_table_meta::meta::ClusterStatistics;
+use storages_common_table_meta::meta::DeletionVectorMeta;
 
 use crate::operations::common::BlockMetaIndex;
 use crate::operations::mutation::compact::CompactExtraInfo;
@@ -25,6 +26,7 @@ pub enum SerializeDataMeta {
     SerializeBlock(SerializeBlock),
     DeletedSegment(DeletedSegmentInfo),
     CompactExtras(CompactExtraInfo),
+    DeletionVector(DeletionVectorInfo),
 }
 
 #[typetag::serde(name = "serialize_data_meta")]
@@ -55,3 +57,19 @@ impl SerializeBlock {
         SerializeBlock { index, stats_type }
     }
 }
+
+/// The deletion vector written by a merge-on-read deletion, the block itself is kept.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct DeletionVectorInfo {
+    pub index: BlockMetaIndex,
+    pub deletion_vector: DeletionVectorMeta,
+}
+
+impl DeletionVectorInfo {
+    pub fn create(index: BlockMetaIndex, deletion_vector: DeletionVectorMeta) -> Self {
+        DeletionVectorInfo {
+            index,
+            deletion_vector,
+        }
+    }
+}
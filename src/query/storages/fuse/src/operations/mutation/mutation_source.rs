@@ -38,9 +38,12 @@ use common_sql::evaluator::BlockOperator;
 use super::mutation_meta::SerializeBlock;
 use crate::fuse_part::FusePartInfo;
 use crate::io::BlockReader;
+use crate::io::DeletionVector;
 use crate::io::ReadSettings;
+use crate::io::TableMetaLocationGenerator;
 use crate::operations::common::BlockMetaIndex;
 use crate::operations::mutation::mutation_meta::ClusterStatsGenType;
+use crate::operations::mutation::DeletionVectorInfo;
 use crate::operations::mutation::Mutation;
 use crate::operations::mutation::SerializeDataMeta;
 use crate::pipelines::processors::port::OutputPort;
@@ -70,6 +73,7 @@ enum State {
         filter: Option<Value<BooleanType>>,
     },
     PerformOperator(DataBlock),
+    WriteDeletionVector(DeletionVector),
     Output(Option<PartInfoPtr>, DataBlock),
     Finish,
 }
@@ -86,9 +90,13 @@ pub struct MutationSource {
     storage_format: FuseStorageFormat,
     action: MutationAction,
     query_row_id_col: bool,
+    merge_on_read: bool,
+    location_gen: TableMetaLocationGenerator,
 
     index: BlockMetaIndex,
     stats_type: ClusterStatsGenType,
+    // The rows of the current block deleted by the former merge-on-read mutations.
+    deletion_vector: Option<DeletionVector>,
 }
 
 impl MutationSource {
@@ -103,6 +111,8 @@ impl MutationSource {
         operators: Vec<BlockOperator>,
         storage_format: FuseStorageFormat,
         query_row_id_col: bool,
+        merge_on_read: bool,
+        location_gen: TableMetaLocationGenerator,
     ) -> Result<ProcessorPtr> {
         Ok(ProcessorPtr::create(Box::new(MutationSource {
             state: State::ReadData(None),
@@ -115,8 +125,11 @@ impl MutationSource {
             storage_format,
             action,
             query_row_id_col,
+            merge_on_read,
+            location_gen,
             index: BlockMetaIndex::default(),
             stats_type: ClusterStatsGenType::Generally,
+            deletion_vector: None,
         })))
     }
 }
@@ -163,7 +176,10 @@ impl Processor for MutationSource {
             }
         }
 
-        if matches!(self.state, State::ReadData(_) | State::ReadRemain { .. }) {
+        if matches!(
+            self.state,
+            State::ReadData(_) | State::ReadRemain { .. } | State::WriteDeletionVector(_)
+        ) {
             Ok(Event::Async)
         } else {
             Ok(Event::Sync)
@@ -179,6 +195,11 @@ impl Processor for MutationSource {
                     chunks,
                     &self.storage_format,
                 )?;
+                if let Some(deletion_vector) = &self.deletion_vector {
+                    // The rows deleted by the former merge-on-read mutations are skipped.
+                    let selection = deletion_vector.selection(data_block.num_rows());
+                    data_block = data_block.filter_with_bitmap(&selection)?;
+                }
                 let num_rows = data_block.num_rows();
 
                 if let Some(filter) = self.filter.as_ref() {
@@ -192,7 +213,10 @@ impl Processor for MutationSource {
                             block_location: block_meta.block_location.clone(),
                             segment_location: block_meta.segment_location.clone(),
                             snapshot_location: None,
-                            offsets: None,
+                            offsets: self
+                                .deletion_vector
+                                .as_ref()
+                                .map(|v| v.live_rows(fuse_part.nums_rows)),
                         };
                         let internal_col = InternalColumn {
                             column_name: ROW_ID_COL_NAME.to_string(),
@@ -250,6 +274,21 @@ impl Processor for MutationSource {
                                         self.ctx.get_partition(),
                                         DataBlock::empty_with_meta(meta),
                                     );
+                                } else if self.merge_on_read {
+                                    // keep the block, mark the deleted rows in the deletion vector.
+                                    let predicate_col = predicates.into_column().unwrap();
+                                    let fuse_part = FusePartInfo::from_part(&part)?;
+                                    let mut deletion_vector =
+                                        self.deletion_vector.take().unwrap_or_default();
+                                    let live_rows = deletion_vector.live_rows(fuse_part.nums_rows);
+                                    for (row, deleted) in
+                                        live_rows.into_iter().zip(predicate_col.iter())
+                                    {
+                                        if deleted {
+                                            deletion_vector.insert(row);
+                                        }
+                                    }
+                                    self.state = State::WriteDeletionVector(deletion_vector);
                                 } else {
                                     let predicate_col = predicates.into_column().unwrap();
                                     let filter = predicate_col.not();
@@ -304,11 +343,15 @@ impl Processor for MutationSource {
             } => {
                 if let Some(remain_reader) = self.remain_reader.as_ref() {
                     let chunks = merged_io_read_result.columns_chunks()?;
-                    let remain_block = remain_reader.deserialize_chunks_with_part_info(
+                    let mut remain_block = remain_reader.deserialize_chunks_with_part_info(
                         part,
                         chunks,
                         &self.storage_format,
                     )?;
+                    if let Some(deletion_vector) = &self.deletion_vector {
+                        let selection = deletion_vector.selection(remain_block.num_rows());
+                        remain_block = remain_block.filter_with_bitmap(&selection)?;
+                    }
 
                     let remain_block = if let Some(filter) = filter {
                         // for deletion.
@@ -379,8 +422,12 @@ impl Processor for MutationSource {
                             && matches!(self.action, MutationAction::Deletion)
                         {
                             // whole block deletion.
+                            let deleted_rows = fuse_part
+                                .deletion_vector
+                                .as_ref()
+                                .map_or(0, |v| v.deleted_rows as usize);
                             let progress_values = ProgressValues {
-                                rows: fuse_part.nums_rows,
+                                rows: fuse_part.nums_rows - deleted_rows,
                                 bytes: 0,
                             };
                             self.ctx.get_write_progress().incr(&progress_values);
@@ -401,6 +448,12 @@ impl Processor for MutationSource {
                                     &None,
                                 )
                                 .await?;
+                            self.deletion_vector = match &fuse_part.deletion_vector {
+                                Some(meta) => Some(
+                                    DeletionVector::read(&self.block_reader.operator, meta).await?,
+                                ),
+                                None => None,
+                            };
                             self.state = State::FilterData(inner_part, read_res);
                         }
                     }
@@ -433,6 +486,17 @@ impl Processor for MutationSource {
                     return Err(ErrorCode::Internal("It's a bug. No remain reader"));
                 }
             }
+            State::WriteDeletionVector(deletion_vector) => {
+                let location = self.location_gen.gen_deletion_vector_location();
+                let deletion_vector = deletion_vector
+                    .write(&self.block_reader.operator, location)
+                    .await?;
+                let meta = Box::new(SerializeDataMeta::DeletionVector(
+                    DeletionVectorInfo::create(self.index.clone(), deletion_vector),
+                ));
+                self.state =
+                    State::Output(self.ctx.get_partition(), DataBlock::empty_with_meta(meta));
+            }
             _ => return Err(ErrorCode::Internal("It's a bug.")),
         }
         Ok(())
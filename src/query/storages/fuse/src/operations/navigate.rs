@@ -28,6 +28,9 @@ use opendal::EntryMode;
 use opendal::Metakey;
 use storages_common_cache::LoadParams;
 use storages_common_table_meta::meta::TableSnapshot;
+use storages_common_table_meta::table::read_snapshot_refs;
+use storages_common_table_meta::table::SnapshotRefType;
+use storages_common_table_meta::table::OPT_KEY_BRANCH;
 use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 
 use crate::io::MetaReaders;
@@ -93,43 +96,10 @@ impl FuseTable {
         }
 
         if let Some((snapshot, format_version)) = instant {
-            // Load the table instance by the snapshot
-
-            // The `seq` of ident that we cloned here is JUST a place holder
-            // we should NOT use it other than a pure place holder.
-            let mut table_info = self.table_info.clone();
-
-            // There are more to be kept in snapshot, like engine_options, ordering keys...
-            // or we could just keep a clone of TableMeta in the snapshot.
-            //
-            // currently, here are what we can recovery from the snapshot:
-
-            // 1. the table schema
-            table_info.meta.schema = Arc::new(snapshot.schema.clone());
-
-            // 2. the table option `snapshot_location`
             let loc = self
                 .meta_location_generator
                 .snapshot_location_from_uuid(&snapshot.snapshot_id, format_version)?;
-            table_info
-                .meta
-                .options
-                .insert(OPT_KEY_SNAPSHOT_LOCATION.to_owned(), loc);
-
-            // 3. The statistics
-            let summary = &snapshot.summary;
-            table_info.meta.statistics = TableStatistics {
-                number_of_rows: summary.row_count,
-                data_bytes: summary.uncompressed_byte_size,
-                compressed_data_bytes: summary.compressed_byte_size,
-                index_data_bytes: summary.index_size,
-                number_of_segments: Some(snapshot.segments.len() as u64),
-                number_of_blocks: Some(summary.block_count),
-            };
-
-            // let's instantiate it
-            let table = FuseTable::do_create(table_info)?;
-            Ok(table.into())
+            self.load_table_at(&snapshot, loc)
         } else {
             Err(ErrorCode::TableHistoricalDataNotFound(
                 "No historical data found at given point",
@@ -137,6 +107,93 @@ impl FuseTable {
         }
     }
 
+    /// Navigates to the snapshot of a tag, or to the head of a branch.
+    ///
+    /// The table navigated to a branch is writable: it is marked by the table option
+    /// `OPT_KEY_BRANCH`, and commits of it advance the head of the branch instead of
+    /// the snapshot location of the table.
+    #[async_backtrace::framed]
+    pub async fn navigate_to_snapshot_ref(
+        &self,
+        ref_type: SnapshotRefType,
+        name: &str,
+    ) -> Result<Arc<FuseTable>> {
+        let refs = read_snapshot_refs(self.table_info.options())?;
+        let snapshot_ref = match refs.get(name) {
+            Some(snapshot_ref) if snapshot_ref.ref_type == ref_type => snapshot_ref,
+            _ => {
+                return Err(ErrorCode::UnknownSnapshotRef(format!(
+                    "No {} named '{}' found in table {}",
+                    ref_type, name, self.table_info.desc
+                )));
+            }
+        };
+        if snapshot_ref.is_expired(Utc::now()) {
+            return Err(ErrorCode::TableHistoricalDataNotFound(format!(
+                "The {} '{}' of table {} has expired",
+                ref_type, name, self.table_info.desc
+            )));
+        }
+
+        let location = snapshot_ref.location.clone();
+        let reader = MetaReaders::table_snapshot_reader(self.get_operator());
+        let params = LoadParams {
+            location: location.clone(),
+            len_hint: None,
+            ver: TableMetaLocationGenerator::snapshot_version(location.as_str()),
+            put_cache: true,
+        };
+        let snapshot = reader.read(&params).await?;
+        let table = self.load_table_at(&snapshot, location)?;
+        if ref_type == SnapshotRefType::Tag {
+            return Ok(table);
+        }
+
+        let mut table_info = table.table_info.clone();
+        table_info
+            .meta
+            .options
+            .insert(OPT_KEY_BRANCH.to_owned(), name.to_owned());
+        let table = FuseTable::do_create(table_info)?;
+        Ok(table.into())
+    }
+
+    // Load the table instance by the snapshot
+    fn load_table_at(&self, snapshot: &TableSnapshot, location: String) -> Result<Arc<FuseTable>> {
+        // The `seq` of ident that we cloned here is JUST a place holder
+        // we should NOT use it other than a pure place holder.
+        let mut table_info = self.table_info.clone();
+
+        // There are more to be kept in snapshot, like engine_options, ordering keys...
+        // or we could just keep a clone of TableMeta in the snapshot.
+        //
+        // currently, here are what we can recovery from the snapshot:
+
+        // 1. the table schema
+        table_info.meta.schema = Arc::new(snapshot.schema.clone());
+
+        // 2. the table option `snapshot_location`
+        table_info
+            .meta
+            .options
+            .insert(OPT_KEY_SNAPSHOT_LOCATION.to_owned(), location);
+
+        // 3. The statistics
+        let summary = &snapshot.summary;
+        table_info.meta.statistics = TableStatistics {
+            number_of_rows: summary.row_count,
+            data_bytes: summary.uncompressed_byte_size,
+            compressed_data_bytes: summary.compressed_byte_size,
+            index_data_bytes: summary.index_size,
+            number_of_segments: Some(snapshot.segments.len() as u64),
+            number_of_blocks: Some(summary.block_count),
+        };
+
+        // let's instantiate it
+        let table = FuseTable::do_create(table_info)?;
+        Ok(table.into())
+    }
+
     #[async_backtrace::framed]
     pub async fn navigate_for_purge(
         &self,
@@ -164,6 +221,11 @@ impl FuseTable {
                 self.list_by_snapshot_id(snapshot_id.as_str(), time_point)
                     .await
             }
+            Some(NavigationPoint::Tag(_)) | Some(NavigationPoint::Branch(_)) => {
+                Err(ErrorCode::InvalidArgument(
+                    "Purge before a tag or branch is not supported, drop it instead",
+                ))
+            }
             None => self.list_by_time_point(time_point).await,
         }?;
 
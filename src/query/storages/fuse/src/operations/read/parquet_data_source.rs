@@ -22,12 +22,19 @@ use common_expression::BlockMetaInfoPtr;
 use serde::Deserializer;
 use serde::Serializer;
 
+use crate::io::DeletionVector;
 use crate::io::MergeIOReadResult;
 use crate::io::VirtualMergeIOReadResult;
 
 pub enum DataSource {
     AggIndex((PartInfoPtr, MergeIOReadResult)),
-    Normal((MergeIOReadResult, Option<VirtualMergeIOReadResult>)),
+    Normal(
+        (
+            MergeIOReadResult,
+            Option<VirtualMergeIOReadResult>,
+            Option<DeletionVector>,
+        ),
+    ),
 }
 
 pub struct DataSourceMeta {
@@ -188,7 +188,7 @@ impl Processor for DeserializeDataTransform {
 
                     self.output_data = Some(block);
                 }
-                DataSource::Normal((data, virtual_data)) => {
+                DataSource::Normal((data, virtual_data, deletion_vector)) => {
                     let start = Instant::now();
                     let columns_chunks = data.columns_chunks()?;
                     let part = FusePartInfo::from_part(&part)?;
@@ -211,6 +211,17 @@ impl Processor for DeserializeDataTransform {
                         )?;
                     }
 
+                    // Filter out the rows deleted by merge-on-read mutations, the offsets of
+                    // the remaining rows are kept to generate the internal columns.
+                    let mut offsets = None;
+                    if let Some(deletion_vector) = deletion_vector {
+                        let selection = deletion_vector.selection(part.nums_rows);
+                        data_block = data_block.filter_with_bitmap(&selection)?;
+                        if self.block_reader.query_internal_columns() {
+                            offsets = Some(deletion_vector.live_rows(part.nums_rows));
+                        }
+                    }
+
                     // Perf.
                     {
                         metrics_inc_remote_io_deserialize_milliseconds(
@@ -229,7 +240,7 @@ impl Processor for DeserializeDataTransform {
                     // Fill `BlockMetaIndex` as `DataBlock.meta` if query internal columns,
                     // `FillInternalColumnProcessor` will generate internal columns using `BlockMetaIndex` in next pipeline.
                     if self.block_reader.query_internal_columns() {
-                        let data_block = fill_internal_column_meta(data_block, part, None)?;
+                        let data_block = fill_internal_column_meta(data_block, part, offsets)?;
                         self.output_data = Some(data_block);
                     } else {
                         self.output_data = Some(data_block);
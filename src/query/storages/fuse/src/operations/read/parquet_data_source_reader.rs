@@ -33,6 +33,7 @@ use super::parquet_data_source::DataSource;
 use crate::fuse_part::FusePartInfo;
 use crate::io::AggIndexReader;
 use crate::io::BlockReader;
+use crate::io::DeletionVector;
 use crate::io::ReadSettings;
 use crate::io::TableMetaLocationGenerator;
 use crate::io::VirtualColumnReader;
@@ -101,8 +102,11 @@ impl SyncSource for ReadParquetDataSource<true> {
         match self.partitions.steal_one(self.id) {
             None => Ok(None),
             Some(part) => {
-                if let Some(index_reader) = self.index_reader.as_ref() {
-                    let fuse_part = FusePartInfo::from_part(&part)?;
+                let fuse_part = FusePartInfo::from_part(&part)?;
+                // The aggregating index is built before the deletion, skip it if the block has deleted rows.
+                if let (Some(index_reader), None) =
+                    (self.index_reader.as_ref(), &fuse_part.deletion_vector)
+                {
                     let loc =
                         TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
                             &fuse_part.location,
@@ -122,7 +126,6 @@ impl SyncSource for ReadParquetDataSource<true> {
 
                 // If virtual column file exists, read the data from the virtual columns directly.
                 let virtual_source = if let Some(virtual_reader) = self.virtual_reader.as_ref() {
-                    let fuse_part = FusePartInfo::from_part(&part)?;
                     let loc =
                         TableMetaLocationGenerator::gen_virtual_block_location(&fuse_part.location);
 
@@ -146,9 +149,21 @@ impl SyncSource for ReadParquetDataSource<true> {
                     ignore_column_ids,
                 )?;
 
+                let deletion_vector = match &fuse_part.deletion_vector {
+                    Some(meta) => Some(DeletionVector::sync_read(
+                        &self.block_reader.operator,
+                        meta,
+                    )?),
+                    None => None,
+                };
+
                 Ok(Some(DataBlock::empty_with_meta(DataSourceMeta::create(
                     vec![part],
-                    vec![DataSource::Normal((source, virtual_source))],
+                    vec![DataSource::Normal((
+                        source,
+                        virtual_source,
+                        deletion_vector,
+                    ))],
                 ))))
             }
         }
@@ -206,7 +221,10 @@ impl Processor for ReadParquetDataSource<false> {
                     tokio::spawn(async_backtrace::location!().frame(async move {
                         let part = FusePartInfo::from_part(&part)?;
 
-                        if let Some(index_reader) = index_reader.as_ref() {
+                        // The aggregating index is built before the deletion, skip it if the block has deleted rows.
+                        if let (Some(index_reader), None) =
+                            (index_reader.as_ref(), &part.deletion_vector)
+                        {
                             let loc =
                         TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
                             &part.location,
@@ -249,7 +267,18 @@ impl Processor for ReadParquetDataSource<false> {
                             )
                             .await?;
 
-                        Ok(DataSource::Normal((source, virtual_source)))
+                        let deletion_vector = match &part.deletion_vector {
+                            Some(meta) => {
+                                Some(DeletionVector::read(&block_reader.operator, meta).await?)
+                            }
+                            None => None,
+                        };
+
+                        Ok(DataSource::Normal((
+                            source,
+                            virtual_source,
+                            deletion_vector,
+                        )))
                     }))
                     .await
                     .unwrap()
@@ -39,6 +39,7 @@ use common_sql::BloomIndexColumns;
 use log::warn;
 use opendal::Operator;
 use storages_common_table_meta::meta::CompactSegmentInfo;
+use storages_common_table_meta::table::InvertedIndexes;
 
 use crate::operations::common::CommitSink;
 use crate::operations::common::MutationGenerator;
@@ -57,6 +58,17 @@ use crate::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
 use crate::FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD;
 
 impl FuseTable {
+    /// The average depth of the blocks, above which the table needs to be reclustered.
+    pub fn recluster_depth_threshold(&self, block_count: u64) -> f64 {
+        let avg_depth_threshold = self.get_option(
+            FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD,
+            DEFAULT_AVG_DEPTH_THRESHOLD,
+        );
+        (block_count as f64 * avg_depth_threshold)
+            .max(1.0)
+            .min(64.0)
+    }
+
     /// The flow of Pipeline is as follows:
     // ┌──────────┐     ┌───────────────┐     ┌─────────┐
     // │FuseSource├────►│CompoundBlockOp├────►│SortMerge├────┐
@@ -108,14 +120,7 @@ impl FuseTable {
         let block_thresholds = self.get_block_thresholds();
         let block_per_seg =
             self.get_option(FUSE_OPT_KEY_BLOCK_PER_SEGMENT, DEFAULT_BLOCK_PER_SEGMENT);
-        let avg_depth_threshold = self.get_option(
-            FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD,
-            DEFAULT_AVG_DEPTH_THRESHOLD,
-        );
-        let block_count = snapshot.summary.block_count;
-        let threshold = (block_count as f64 * avg_depth_threshold)
-            .max(1.0)
-            .min(64.0);
+        let threshold = self.recluster_depth_threshold(snapshot.summary.block_count);
         let mut mutator = ReclusterMutator::try_create(
             ctx.clone(),
             snapshot.clone(),
@@ -357,6 +362,8 @@ impl FuseTable {
             None,
             vec![],
             BloomIndexColumns::None,
+            BloomIndexColumns::None,
+            &InvertedIndexes::new(),
             max_concurrency,
         )?;
 
@@ -53,6 +53,7 @@ use crate::io::write_data;
 use crate::io::BlockBuilder;
 use crate::io::BlockReader;
 use crate::io::CompactSegmentInfoReader;
+use crate::io::DeletionVector;
 use crate::io::MetaReaders;
 use crate::io::ReadSettings;
 use crate::io::WriteSettings;
@@ -411,9 +412,18 @@ impl AggregationContext {
                 .value);
         }
 
+        // the rows deleted by merge-on-read mutations are dropped along with the conflicting ones.
+        let deletion_vector = match &block_meta.deletion_vector {
+            Some(meta) => Some(DeletionVector::read(&self.data_accessor, meta).await?),
+            None => None,
+        };
+        let already_deleted = deletion_vector.as_ref().map_or(0, |v| v.len());
+
         let mut bitmap = MutableBitmap::new();
         for row in 0..num_rows {
-            if let Some(hash) = row_hash_of_columns(&columns, row)? {
+            if deletion_vector.as_ref().is_some_and(|v| v.contains(row)) {
+                bitmap.push(false);
+            } else if let Some(hash) = row_hash_of_columns(&columns, row)? {
                 // some row hash means on-conflict columns of this row contains non-null values
                 // let's check it out
                 bitmap.push(!deleted_key_hashes.contains(&hash));
@@ -423,7 +433,7 @@ impl AggregationContext {
             }
         }
 
-        let delete_nums = bitmap.unset_bits();
+        let delete_nums = bitmap.unset_bits() - already_deleted;
         info!("number of row deleted: {}", delete_nums);
 
         // shortcut: nothing to be deleted
@@ -446,12 +456,11 @@ impl AggregationContext {
             .incr(&progress_values);
 
         // shortcut: whole block deletion
-        if delete_nums == block_meta.row_count as usize {
+        if delete_nums == block_meta.live_row_count() as usize {
             info!("whole block deletion");
             metrics_inc_replace_whole_block_deletion(1);
             metrics_inc_replace_deleted_blocks_rows(num_rows as u64);
-            // whole block deletion
-            // NOTE that if deletion marker is enabled, check the real meaning of `row_count`
+            // whole block deletion, the rows deleted by the deletion vector are not counted
             let mutation = MutationLogEntry::DeletedBlock {
                 index: BlockMetaIndex {
                     segment_idx: segment_index,
@@ -526,6 +535,9 @@ impl AggregationContext {
         if let Some(index_state) = serialized.bloom_index_state {
             write_data(index_state.data, &data_accessor, &index_state.location.0).await?;
         }
+        if let Some(index_state) = serialized.inverted_index_state {
+            write_data(index_state.data, &data_accessor, &index_state.location).await?;
+        }
 
         // generate log
         let mutation = MutationLogEntry::ReplacedBlock {
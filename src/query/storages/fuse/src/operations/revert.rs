@@ -19,6 +19,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_types::MatchSeq;
+use storages_common_table_meta::table::OPT_KEY_BRANCH;
 
 use crate::FuseTable;
 
@@ -40,7 +41,9 @@ impl FuseTable {
         }
 
         // 2. prepare table meta which being reverted to
-        let table_meta_to_be_committed = table_reverting_to.table_info.meta.clone();
+        let mut table_meta_to_be_committed = table_reverting_to.table_info.meta.clone();
+        // reverting to a branch publishes the head of the branch, the branch itself is kept
+        table_meta_to_be_committed.options.remove(OPT_KEY_BRANCH);
 
         // 3. prepare the request
         //  using the CURRENT version as the base table version
@@ -57,8 +60,8 @@ impl FuseTable {
 
         // 4. let's roll
         let reply = catalog.update_table_meta(&self.table_info, req).await;
-        if reply.is_ok() {
-            // try keep the snapshot hit
+        if reply.is_ok() && !ctx.is_txn_active() {
+            // try keep the snapshot hit, unless the update is buffered by an explicit transaction
             let snapshot_location = table_reverting_to.snapshot_loc().await?.ok_or_else(|| {
                     ErrorCode::Internal("internal error, fuse table which navigated to given point has no snapshot location")
                 })?;
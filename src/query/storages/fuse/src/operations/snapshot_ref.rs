@@ -0,0 +1,123 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::Duration;
+use chrono::Utc;
+use common_catalog::table::SnapshotRefDescriptor;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeq;
+use storages_common_table_meta::table::read_snapshot_refs;
+use storages_common_table_meta::table::write_snapshot_refs;
+use storages_common_table_meta::table::SnapshotRef;
+use storages_common_table_meta::table::SnapshotRefType;
+use storages_common_table_meta::table::SnapshotRefs;
+
+use crate::FuseTable;
+
+impl FuseTable {
+    #[async_backtrace::framed]
+    pub async fn do_create_snapshot_ref(
+        &self,
+        ctx: &dyn TableContext,
+        descriptor: SnapshotRefDescriptor,
+    ) -> Result<()> {
+        let SnapshotRefDescriptor {
+            ref_type,
+            name,
+            point,
+            retain_days,
+        } = descriptor;
+
+        let mut refs = read_snapshot_refs(self.table_info.options())?;
+        if refs.contains_key(&name) {
+            return Err(ErrorCode::SnapshotRefAlreadyExists(format!(
+                "Tag or branch '{}' already exists in table {}",
+                name, self.table_info.desc
+            )));
+        }
+
+        // 1. locate the snapshot being referenced
+        let location = match &point {
+            Some(point) => {
+                let table = self.navigate_to(point).await?;
+                FuseTable::try_from_table(table.as_ref())?
+                    .snapshot_loc()
+                    .await?
+            }
+            None => self.snapshot_loc().await?,
+        };
+        let location = location.ok_or_else(|| {
+            ErrorCode::TableHistoricalDataNotFound(format!(
+                "Can not create {} '{}', table {} has no snapshot",
+                ref_type, name, self.table_info.desc
+            ))
+        })?;
+
+        // 2. register the ref
+        let expire_at = retain_days.map(|days| Utc::now() + Duration::days(days as i64));
+        refs.insert(name, SnapshotRef {
+            ref_type,
+            location,
+            expire_at,
+        });
+        self.commit_snapshot_refs(ctx, &refs).await
+    }
+
+    #[async_backtrace::framed]
+    pub async fn do_drop_snapshot_ref(
+        &self,
+        ctx: &dyn TableContext,
+        ref_type: SnapshotRefType,
+        name: &str,
+    ) -> Result<()> {
+        let mut refs = read_snapshot_refs(self.table_info.options())?;
+        match refs.get(name) {
+            Some(snapshot_ref) if snapshot_ref.ref_type == ref_type => {
+                refs.remove(name);
+            }
+            _ => {
+                return Err(ErrorCode::UnknownSnapshotRef(format!(
+                    "No {} named '{}' found in table {}",
+                    ref_type, name, self.table_info.desc
+                )));
+            }
+        }
+        // the snapshots and files no longer referenced are left to purge and vacuum
+        self.commit_snapshot_refs(ctx, &refs).await
+    }
+
+    async fn commit_snapshot_refs(
+        &self,
+        ctx: &dyn TableContext,
+        refs: &SnapshotRefs,
+    ) -> Result<()> {
+        let mut new_table_meta = self.table_info.meta.clone();
+        write_snapshot_refs(&mut new_table_meta.options, refs)?;
+
+        let catalog = ctx.get_catalog(self.table_info.catalog()).await?;
+        let req = UpdateTableMetaReq {
+            table_id: self.table_info.ident.table_id,
+            seq: MatchSeq::Exact(self.table_info.ident.seq),
+            new_table_meta,
+            copied_files: None,
+            deduplicated_label: None,
+        };
+        catalog.update_table_meta(&self.table_info, req).await?;
+        Ok(())
+    }
+}
@@ -89,13 +89,15 @@ impl FuseTable {
                 })
                 .await?;
 
-            // try keep a hit file of last snapshot
-            Self::write_last_snapshot_hint(
-                &self.operator,
-                &self.meta_location_generator,
-                new_snapshot_loc,
-            )
-            .await;
+            // try keep a hit file of last snapshot, unless the update is buffered by an explicit transaction
+            if !ctx.is_txn_active() {
+                Self::write_last_snapshot_hint(
+                    &self.operator,
+                    &self.meta_location_generator,
+                    new_snapshot_loc,
+                )
+                .await;
+            }
 
             // best effort to remove historical data. if failed, let `vacuum` to do the job.
             // TODO: consider remove the `purge` option from `truncate`
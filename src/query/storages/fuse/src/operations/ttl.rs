@@ -0,0 +1,254 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ops::Not;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_catalog::lock::Lock;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::BooleanType;
+use common_expression::types::DataType;
+use common_expression::BlockEntry;
+use common_expression::ColumnId;
+use common_expression::DataBlock;
+use common_expression::DataSchema;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_expression::TableSchemaRef;
+use common_expression::Value;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_sql::parse_computed_expr;
+use log::info;
+use storages_common_locks::LockManager;
+use storages_common_table_meta::meta::SegmentInfo;
+use storages_common_table_meta::meta::Statistics;
+use storages_common_table_meta::meta::StatisticsOfColumns;
+use storages_common_table_meta::table::OPT_KEY_TTL;
+
+use crate::io::SegmentWriter;
+use crate::io::SegmentsIO;
+use crate::operations::common::AbortOperation;
+use crate::statistics::reducers::merge_statistics_mut;
+use crate::statistics::reducers::reduce_block_metas;
+use crate::FuseTable;
+
+/// The row-level TTL of a table, declared by `TTL = <expr>`: a row is expired once the value
+/// of `<expr>` is not later than now.
+///
+/// `<expr>` must refer to one Date or Timestamp column, and is assumed to be non-decreasing
+/// with it, e.g. `ts + INTERVAL 90 DAY`. So whether some or all of the rows of a block are
+/// expired can be told by the min and max values of the column.
+#[derive(Clone)]
+pub struct TableTtl {
+    column_name: String,
+    column_id: ColumnId,
+    data_type: DataType,
+    // `is_true((<expr>) <= now())`, evaluated on the ttl column only.
+    expired: Expr,
+    func_ctx: FunctionContext,
+}
+
+impl TableTtl {
+    pub fn try_create(
+        ctx: Arc<dyn TableContext>,
+        ttl: &str,
+        schema: TableSchemaRef,
+    ) -> Result<Self> {
+        let invalid = |reason: &str| {
+            ErrorCode::TableOptionInvalid(format!("invalid ttl expression `{ttl}`, {reason}"))
+        };
+        let is_datetime = |data_type: &DataType| {
+            matches!(
+                data_type.remove_nullable(),
+                DataType::Date | DataType::Timestamp
+            )
+        };
+
+        let data_schema = Arc::new(DataSchema::from(schema.clone()));
+        let expr = parse_computed_expr(ctx.clone(), data_schema.clone(), ttl)?;
+        if !is_datetime(expr.data_type()) {
+            return Err(invalid("it must be of type Date or Timestamp"));
+        }
+        let columns = expr.column_refs();
+        if columns.len() != 1 {
+            return Err(invalid("it must refer to exactly one column"));
+        }
+        let (index, data_type) = columns.into_iter().next().unwrap();
+        let field = schema.field(index);
+        if !is_datetime(&data_type) || field.computed_expr().is_some() {
+            return Err(invalid("the column must be a Date or Timestamp column"));
+        }
+
+        let expired = parse_computed_expr(
+            ctx.clone(),
+            data_schema,
+            &format!("is_true(({ttl}) <= now())"),
+        )?
+        .project_column_ref(|_| 0);
+        Ok(Self {
+            column_name: field.name().clone(),
+            column_id: field.column_id(),
+            data_type,
+            expired,
+            func_ctx: ctx.get_function_context()?,
+        })
+    }
+
+    pub fn column_name(&self) -> &str {
+        &self.column_name
+    }
+
+    /// Whether all the rows are expired, judged by the max value of the ttl column.
+    ///
+    /// The rows whose ttl column is NULL never expire, a block with NULLs is never all expired.
+    pub fn all_expired(&self, col_stats: &StatisticsOfColumns) -> Result<bool> {
+        match col_stats.get(&self.column_id) {
+            Some(stats) if stats.null_count == 0 => self.is_expired(&stats.max),
+            _ => Ok(false),
+        }
+    }
+
+    /// Whether some of the rows are expired, judged by the min value of the ttl column.
+    pub fn any_expired(&self, col_stats: &StatisticsOfColumns) -> Result<bool> {
+        match col_stats.get(&self.column_id) {
+            Some(stats) => self.is_expired(&stats.min),
+            None => Ok(false),
+        }
+    }
+
+    /// The filter of the rows not expired, `offset` is the offset of the ttl column in the block.
+    pub fn live_rows(&self, block: &DataBlock, offset: usize) -> Result<Bitmap> {
+        let num_rows = block.num_rows();
+        let block = DataBlock::new(vec![block.get_by_offset(offset).clone()], num_rows);
+        match self.eval(&block)? {
+            Value::Scalar(expired) => Ok(Bitmap::new_constant(!expired, num_rows)),
+            Value::Column(expired) => Ok(expired.not()),
+        }
+    }
+
+    fn is_expired(&self, value: &Scalar) -> Result<bool> {
+        if matches!(value, Scalar::Null) {
+            return Ok(false);
+        }
+        let entry = BlockEntry::new(self.data_type.clone(), Value::Scalar(value.clone()));
+        let block = DataBlock::new(vec![entry], 1);
+        match self.eval(&block)? {
+            Value::Scalar(expired) => Ok(expired),
+            Value::Column(expired) => Ok(expired.get_bit(0)),
+        }
+    }
+
+    fn eval(&self, block: &DataBlock) -> Result<Value<BooleanType>> {
+        let evaluator = Evaluator::new(block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        let value = evaluator.run(&self.expired)?;
+        value.try_downcast::<BooleanType>().ok_or_else(|| {
+            ErrorCode::Internal(format!("ttl of column {} is not boolean", self.column_name))
+        })
+    }
+}
+
+impl FuseTable {
+    /// The row-level TTL of the table, if any.
+    pub fn ttl(&self, ctx: Arc<dyn TableContext>) -> Result<Option<TableTtl>> {
+        self.table_info
+            .options()
+            .get(OPT_KEY_TTL)
+            .map(|ttl| TableTtl::try_create(ctx, ttl, self.schema()))
+            .transpose()
+    }
+
+    /// Drop the blocks whose rows are all expired by the ttl of the table. Only the segments
+    /// are rewritten, the blocks partially expired are left to the compaction.
+    ///
+    /// Returns true if some blocks are dropped.
+    #[async_backtrace::framed]
+    pub async fn drop_expired_blocks(&self, ctx: Arc<dyn TableContext>) -> Result<bool> {
+        let Some(ttl) = self.ttl(ctx.clone())? else {
+            return Ok(false);
+        };
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            return Ok(false);
+        };
+
+        let segments_io = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
+        let segment_writer = SegmentWriter::new(&self.operator, &self.meta_location_generator);
+        let thresholds = self.get_block_thresholds();
+        let default_cluster_key_id = self.cluster_key_id();
+        let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
+
+        let mut segments = Vec::with_capacity(snapshot.segments.len());
+        let mut summary = Statistics::default();
+        let mut abort_operation = AbortOperation::default();
+        let mut num_expired_blocks = 0;
+        for chunk in snapshot.segments.chunks(chunk_size) {
+            let segment_infos = segments_io
+                .read_segments::<SegmentInfo>(chunk, false)
+                .await?;
+            for (location, segment_info) in chunk.iter().zip(segment_infos) {
+                let segment_info = segment_info?;
+                let mut blocks = segment_info.blocks.clone();
+                if ttl.any_expired(&segment_info.summary.col_stats)? {
+                    blocks.clear();
+                    for block in segment_info.blocks.iter() {
+                        if !ttl.all_expired(&block.col_stats)? {
+                            blocks.push(block.clone());
+                        }
+                    }
+                }
+                if blocks.len() == segment_info.blocks.len() {
+                    merge_statistics_mut(
+                        &mut summary,
+                        &segment_info.summary,
+                        default_cluster_key_id,
+                    );
+                    segments.push(location.clone());
+                    continue;
+                }
+
+                num_expired_blocks += segment_info.blocks.len() - blocks.len();
+                if blocks.is_empty() {
+                    continue;
+                }
+                let new_summary = reduce_block_metas(&blocks, thresholds, default_cluster_key_id);
+                merge_statistics_mut(&mut summary, &new_summary, default_cluster_key_id);
+                let location = segment_writer
+                    .write_segment(SegmentInfo::new(blocks, new_summary))
+                    .await?;
+                abort_operation.add_segment(location.0.clone());
+                segments.push(location);
+            }
+        }
+
+        if num_expired_blocks == 0 {
+            return Ok(false);
+        }
+        info!(
+            "drop {} expired blocks of table {}",
+            num_expired_blocks,
+            self.name()
+        );
+
+        let table_lock = LockManager::create_table_lock(self.table_info.clone())?;
+        let _guard = table_lock.try_lock(ctx.clone()).await?;
+        self.commit_mutation(&ctx, snapshot, &segments, summary, abort_operation, None)
+            .await?;
+        Ok(true)
+    }
+}
@@ -306,6 +306,8 @@ impl FuseTable {
                         ops.clone(),
                         self.storage_format,
                         true,
+                        false,
+                        self.meta_location_generator().clone(),
                     )
                 },
                 max_threads,
@@ -0,0 +1,119 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::TableSchemaRef;
+use log::warn;
+use opendal::Operator;
+use storages_common_index::FilterEvalResult;
+use storages_common_index::InvertedIndex;
+use storages_common_table_meta::table::InvertedIndexes;
+
+use crate::io::TableMetaLocationGenerator;
+
+#[async_trait::async_trait]
+pub trait InvertedIndexPruner {
+    // returns true, if target should NOT be pruned (false positive allowed)
+    async fn should_keep(&self, block_location: &str) -> bool;
+}
+
+pub struct InvertedIndexPrunerCreator {
+    func_ctx: FunctionContext,
+
+    /// the expression that would be evaluate
+    filter_expression: Expr<String>,
+
+    /// the data accessor
+    dal: Operator,
+
+    /// the schema of data being indexed
+    data_schema: TableSchemaRef,
+}
+
+impl InvertedIndexPrunerCreator {
+    pub fn create(
+        func_ctx: FunctionContext,
+        schema: &TableSchemaRef,
+        dal: Operator,
+        filter_expr: Option<&Expr<String>>,
+        inverted_indexes: &InvertedIndexes,
+    ) -> Result<Option<Arc<dyn InvertedIndexPruner + Send + Sync>>> {
+        if let Some(expr) = filter_expr {
+            let match_columns = InvertedIndex::find_match_columns(expr)?;
+            let indexed = match_columns.iter().any(|name| {
+                schema.field_with_name(name).map_or(false, |field| {
+                    inverted_indexes
+                        .values()
+                        .any(|index| index.column_id == field.column_id())
+                })
+            });
+            if indexed {
+                let creator = InvertedIndexPrunerCreator {
+                    func_ctx,
+                    filter_expression: expr.clone(),
+                    dal,
+                    data_schema: schema.clone(),
+                };
+                return Ok(Some(Arc::new(creator)));
+            }
+        }
+        Ok(None)
+    }
+
+    // Check a block is hit or not by the inverted index.
+    #[async_backtrace::framed]
+    pub async fn apply(&self, block_location: &str) -> Result<bool> {
+        let index_location =
+            TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(
+                block_location,
+            );
+        let data = match self.dal.read(&index_location).await {
+            Ok(data) => data,
+            Err(e) if e.kind() == opendal::ErrorKind::NotFound => {
+                // blocks written before the index was created have no index file
+                return Ok(true);
+            }
+            Err(e) => return Err(ErrorCode::from(e)),
+        };
+        let index = InvertedIndex::from_slice(&data)?;
+        Ok(index.apply(
+            self.filter_expression.clone(),
+            &self.data_schema,
+            &self.func_ctx,
+        )? != FilterEvalResult::MustFalse)
+    }
+}
+
+#[async_trait::async_trait]
+impl InvertedIndexPruner for InvertedIndexPrunerCreator {
+    #[async_backtrace::framed]
+    async fn should_keep(&self, block_location: &str) -> bool {
+        match self.apply(block_location).await {
+            Ok(v) => v,
+            Err(e) => {
+                // swallow exceptions intentionally, corrupted index should not prevent execution
+                warn!(
+                    "failed to apply inverted index pruner, returning true. {}",
+                    e
+                );
+                true
+            }
+        }
+    }
+}
@@ -0,0 +1,99 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_expression::Scalar;
+use storages_common_table_meta::meta::ColumnHistogram;
+use storages_common_table_meta::meta::HistogramBucket;
+use storages_common_table_meta::meta::MostCommonValue;
+
+use crate::statistics::Trim;
+use crate::statistics::STATS_STRING_PREFIX_LEN;
+
+pub const HISTOGRAM_BUCKETS: usize = 100;
+pub const HISTOGRAM_MOST_COMMON_VALUES: usize = 10;
+
+/// Build an equi-depth histogram from the sampled (non-null) values of a column.
+///
+/// # Arguments
+///  * `samples` - sampled values of the column, nulls excluded
+///  * `num_values` - total number of non-null values of the column
+///  * `ndv` - estimated number of distinct values of the column
+///  * `num_buckets` - max number of buckets
+pub fn build_column_histogram(
+    mut samples: Vec<Scalar>,
+    num_values: u64,
+    ndv: u64,
+    num_buckets: usize,
+) -> Option<ColumnHistogram> {
+    if samples.is_empty() || num_buckets == 0 {
+        return None;
+    }
+    samples.sort();
+
+    // (value, count) of each distinct sampled value, in ascending order.
+    let mut groups: Vec<(Scalar, usize)> = Vec::new();
+    for value in samples.iter() {
+        match groups.last_mut() {
+            Some((last, count)) if last == value => *count += 1,
+            _ => groups.push((value.clone(), 1)),
+        }
+    }
+
+    let num_samples = samples.len();
+    let value_scale = num_values.max(num_samples as u64) as f64 / num_samples as f64;
+    // the sample always underestimates the NDV, spread the estimated one over the buckets.
+    let ndv_scale = ndv.max(groups.len() as u64) as f64 / groups.len() as f64;
+
+    let depth = (num_samples + num_buckets - 1) / num_buckets;
+    let mut buckets = Vec::with_capacity(num_buckets);
+    let (mut bucket_values, mut bucket_distinct) = (0, 0);
+    for (idx, (value, count)) in groups.iter().enumerate() {
+        bucket_values += count;
+        bucket_distinct += 1;
+        if bucket_values >= depth || idx == groups.len() - 1 {
+            buckets.push(HistogramBucket {
+                upper_bound: value.clone().trim_max(STATS_STRING_PREFIX_LEN)?,
+                num_values: bucket_values as f64 * value_scale,
+                num_distinct: bucket_distinct as f64 * ndv_scale,
+            });
+            bucket_values = 0;
+            bucket_distinct = 0;
+        }
+    }
+
+    // a value is worth remembering only if it's more common than the average.
+    let avg_count = num_samples as f64 / groups.len() as f64;
+    let mut most_common_values: Vec<&(Scalar, usize)> = groups
+        .iter()
+        .filter(|(value, count)| {
+            *count > 1
+                && *count as f64 > avg_count
+                && !matches!(value, Scalar::String(s) if s.len() > STATS_STRING_PREFIX_LEN)
+        })
+        .collect();
+    most_common_values.sort_by(|a, b| b.1.cmp(&a.1));
+    let most_common_values = most_common_values
+        .into_iter()
+        .take(HISTOGRAM_MOST_COMMON_VALUES)
+        .map(|(value, count)| MostCommonValue {
+            value: value.clone(),
+            num_values: *count as f64 * value_scale,
+        })
+        .collect();
+
+    Some(ColumnHistogram {
+        lower_bound: groups[0].0.clone().trim_min(STATS_STRING_PREFIX_LEN)?,
+        buckets,
+        most_common_values,
+    })
+}
@@ -48,13 +48,13 @@ pub struct ClusteringInformation<'a> {
     pub table: &'a FuseTable,
 }
 
-struct ClusteringStatistics {
-    total_block_count: u64,
-    constant_block_count: u64,
-    unclustered_block_count: u64,
-    average_overlaps: f64,
-    average_depth: f64,
-    block_depth_histogram: JsonValue,
+pub struct ClusteringStatistics {
+    pub total_block_count: u64,
+    pub constant_block_count: u64,
+    pub unclustered_block_count: u64,
+    pub average_overlaps: f64,
+    pub average_depth: f64,
+    pub block_depth_histogram: JsonValue,
 }
 
 impl Default for ClusteringStatistics {
@@ -77,6 +77,12 @@ impl<'a> ClusteringInformation<'a> {
 
     #[async_backtrace::framed]
     pub async fn get_clustering_info(&self) -> Result<DataBlock> {
+        let info = self.get_clustering_statistics().await?;
+        self.build_block(info)
+    }
+
+    #[async_backtrace::framed]
+    pub async fn get_clustering_statistics(&self) -> Result<ClusteringStatistics> {
         if self.table.cluster_key_meta.is_none() {
             return Err(ErrorCode::UnclusteredTable(format!(
                 "Unclustered table {}",
@@ -86,7 +92,7 @@ impl<'a> ClusteringInformation<'a> {
 
         let snapshot = self.table.read_table_snapshot().await?;
         if snapshot.is_none() {
-            return self.build_block(ClusteringStatistics::default());
+            return Ok(ClusteringStatistics::default());
         }
         let snapshot = snapshot.unwrap();
 
@@ -205,8 +211,7 @@ impl<'a> ClusteringInformation<'a> {
             average_depth,
             block_depth_histogram,
         };
-
-        self.build_block(info)
+        Ok(info)
     }
 
     fn build_block(&self, info: ClusteringStatistics) -> Result<DataBlock> {
@@ -17,4 +17,5 @@ mod clustering_information;
 mod clustering_information_table;
 
 pub use clustering_information::ClusteringInformation;
+pub use clustering_information::ClusteringStatistics;
 pub use clustering_information_table::ClusteringInformationTable;
@@ -23,6 +23,7 @@ mod table_args;
 
 pub use clustering_information::ClusteringInformation;
 pub use clustering_information::ClusteringInformationTable;
+pub use clustering_information::ClusteringStatistics;
 use common_catalog::table_args::TableArgs;
 use common_catalog::table_function::TableFunction;
 pub use fuse_blocks::FuseBlock;
@@ -53,6 +53,7 @@ impl ResultCacheMetaManager {
                 value_meta: Some(KVMeta {
                     expire_at: Some(expire_at),
                 }),
+                lease_id: None,
             })
             .await?;
         Ok(())
@@ -162,6 +162,11 @@ impl Table for StageTable {
         } else {
             None
         };
+        let filter = plan
+            .push_downs
+            .as_ref()
+            .and_then(|p| p.filters.as_ref())
+            .map(|f| f.filter.clone());
         let stage_table_info =
             if let DataSourceInfo::StageSource(stage_table_info) = &plan.source_info {
                 stage_table_info
@@ -216,6 +221,7 @@ impl Table for StageTable {
             on_error_map,
             self.table_info.is_select,
             projection,
+            filter,
             self.table_info.default_values.clone(),
         )?);
         debug!("start copy splits feeder in {}", ctx.get_cluster().local_id);
@@ -0,0 +1,66 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_catalog::plan::PartInfo;
+use common_catalog::plan::PartInfoPtr;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::TableInfo;
+use common_storages_fuse::operations::SnapshotChanges;
+
+/// The changes of the base table since the offset of a stream.
+#[derive(serde::Serialize, serde::Deserialize, PartialEq)]
+pub struct StreamPartInfo {
+    /// The base table, at the snapshot the changes are collected to.
+    pub table_info: TableInfo,
+    /// The changes of each snapshot committed after the offset, in the order of commit.
+    pub changes: Vec<SnapshotChanges>,
+}
+
+#[typetag::serde(name = "stream")]
+impl PartInfo for StreamPartInfo {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn equals(&self, info: &Box<dyn PartInfo>) -> bool {
+        info.as_any()
+            .downcast_ref::<StreamPartInfo>()
+            .is_some_and(|other| self == other)
+    }
+
+    fn hash(&self) -> u64 {
+        0
+    }
+}
+
+impl StreamPartInfo {
+    pub fn create(table_info: TableInfo, changes: Vec<SnapshotChanges>) -> PartInfoPtr {
+        Arc::new(Box::new(StreamPartInfo {
+            table_info,
+            changes,
+        }))
+    }
+
+    pub fn from_part(info: &PartInfoPtr) -> Result<&StreamPartInfo> {
+        info.as_any()
+            .downcast_ref::<StreamPartInfo>()
+            .ok_or(ErrorCode::Internal(
+                "Cannot downcast from PartInfo to StreamPartInfo.",
+            ))
+    }
+}
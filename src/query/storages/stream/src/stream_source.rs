@@ -0,0 +1,281 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::BooleanType;
+use common_expression::types::DataType;
+use common_expression::BlockEntry;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::Scalar;
+use common_expression::Value;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_sources::AsyncSource;
+use common_pipeline_sources::AsyncSourcer;
+use common_storages_fuse::operations::SnapshotChanges;
+use common_storages_fuse::FuseTable;
+use storages_common_table_meta::meta::BlockMeta;
+
+const ACTION_INSERT: &str = "INSERT";
+const ACTION_DELETE: &str = "DELETE";
+
+/// The hash of the values of a row, the rows are compared by it instead of the values,
+/// so that the changed blocks need not be kept in memory.
+type RowKey = (u64, u64);
+
+fn row_key(block: &DataBlock, row: usize) -> RowKey {
+    let mut hashers = [DefaultHasher::new(), DefaultHasher::new()];
+    for (seed, hasher) in hashers.iter_mut().enumerate() {
+        seed.hash(hasher);
+        for entry in block.columns() {
+            entry.value.index(row).unwrap().hash(hasher);
+        }
+    }
+    (hashers[0].finish(), hashers[1].finish())
+}
+
+/// The rows of the changed blocks of a snapshot.
+struct ChangedRows {
+    // the keys of the rows of the removed blocks, followed by the added blocks.
+    keys: Vec<Vec<RowKey>>,
+    // (block index, row index) of the deleted rows, `None` if it's cancelled.
+    deleted: Vec<Option<(usize, usize)>>,
+    // (block index, row index) of the inserted rows, `None` if it's cancelled.
+    inserted: Vec<Option<(usize, usize)>>,
+}
+
+impl ChangedRows {
+    fn row_key(&self, (block, row): (usize, usize)) -> RowKey {
+        self.keys[block][row]
+    }
+}
+
+/// The changed rows of a block, with whether each of them is a part of an update.
+struct ChangedBlock {
+    meta: Arc<BlockMeta>,
+    action: &'static str,
+    rows: Vec<(u32, bool)>,
+}
+
+/// Read the changes of the base table of a stream.
+///
+/// A block rewritten by a mutation shows up as removed and added as a whole, so the rows
+/// appear in both sides are not changed and cancelled out. Besides, a row inserted after
+/// the offset and deleted later is not a change either.
+///
+/// The changed blocks are read twice: first to find out the changed rows by the hashes of
+/// the rows, then one block at a time to output its changed rows.
+pub struct StreamSource {
+    ctx: Arc<dyn TableContext>,
+    table: Arc<FuseTable>,
+    changes: Vec<SnapshotChanges>,
+
+    initialized: bool,
+    changed_blocks: VecDeque<ChangedBlock>,
+}
+
+impl StreamSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        table: Arc<FuseTable>,
+        changes: Vec<SnapshotChanges>,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx.clone(), output, StreamSource {
+            ctx,
+            table,
+            changes,
+            initialized: false,
+            changed_blocks: VecDeque::new(),
+        })
+    }
+
+    #[async_backtrace::framed]
+    async fn collect_rows(&self) -> Result<Vec<ChangedRows>> {
+        let mut steps = Vec::with_capacity(self.changes.len());
+        for change in self.changes.iter() {
+            let mut step = ChangedRows {
+                keys: vec![],
+                deleted: vec![],
+                inserted: vec![],
+            };
+            let blocks = change.removed_blocks.iter().chain(&change.added_blocks);
+            for (block_idx, meta) in blocks.enumerate() {
+                let block = self
+                    .table
+                    .read_changed_blocks(self.ctx.clone(), &[meta.clone()])
+                    .await?
+                    .remove(0);
+                let rows = (0..block.num_rows()).map(|row| Some((block_idx, row)));
+                if block_idx < change.removed_blocks.len() {
+                    step.deleted.extend(rows);
+                } else {
+                    step.inserted.extend(rows);
+                }
+                step.keys.push(
+                    (0..block.num_rows())
+                        .map(|row| row_key(&block, row))
+                        .collect(),
+                );
+            }
+            steps.push(step);
+        }
+        Ok(steps)
+    }
+
+    fn cancel_unchanged_rows(steps: &mut [ChangedRows]) {
+        // key -> (step index, position in `inserted`) of the rows inserted by the previous
+        // steps which are still alive.
+        let mut alive: HashMap<RowKey, Vec<(usize, usize)>> = HashMap::new();
+        for step_idx in 0..steps.len() {
+            let step = &mut steps[step_idx];
+
+            // the rows rewritten by the same snapshot.
+            let mut inserted: HashMap<RowKey, Vec<usize>> = HashMap::new();
+            for (pos, row) in step.inserted.iter().enumerate() {
+                let key = step.row_key(row.unwrap());
+                inserted.entry(key).or_default().push(pos);
+            }
+            let mut deleted = Vec::with_capacity(step.deleted.len());
+            for pos in 0..step.deleted.len() {
+                let key = step.row_key(step.deleted[pos].unwrap());
+                if let Some(pos_inserted) = inserted.get_mut(&key).and_then(|v| v.pop()) {
+                    step.inserted[pos_inserted] = None;
+                    step.deleted[pos] = None;
+                } else {
+                    deleted.push((pos, key));
+                }
+            }
+
+            // the rows inserted by the previous snapshots and deleted by this one.
+            for (pos, key) in deleted {
+                if let Some((prev_step, prev_pos)) = alive.get_mut(&key).and_then(|v| v.pop()) {
+                    steps[prev_step].inserted[prev_pos] = None;
+                    steps[step_idx].deleted[pos] = None;
+                }
+            }
+
+            for (key, positions) in inserted {
+                alive
+                    .entry(key)
+                    .or_default()
+                    .extend(positions.into_iter().map(|pos| (step_idx, pos)));
+            }
+        }
+    }
+
+    /// Collect the rows not cancelled of each changed block.
+    ///
+    /// A deleted row and an inserted row are a part of an update, if they are at the same
+    /// place of a block and the block it is rewritten to with the same number of rows.
+    fn build_changed_blocks(
+        change: &SnapshotChanges,
+        step: &ChangedRows,
+        output: &mut VecDeque<ChangedBlock>,
+    ) {
+        let num_removed = change.removed_blocks.len();
+        let deleted = step.deleted.iter().flatten().collect::<HashSet<_>>();
+        let inserted = step.inserted.iter().flatten().collect::<HashSet<_>>();
+
+        let mut updated = HashSet::new();
+        for (removed, added) in change.rewritten_blocks.iter() {
+            let (removed, added) = (*removed, num_removed + *added);
+            if step.keys[removed].len() != step.keys[added].len() {
+                continue;
+            }
+            for row in 0..step.keys[removed].len() {
+                if deleted.contains(&(removed, row)) && inserted.contains(&(added, row)) {
+                    updated.insert((removed, row));
+                    updated.insert((added, row));
+                }
+            }
+        }
+
+        let blocks = change.removed_blocks.iter().chain(&change.added_blocks);
+        for (block_idx, meta) in blocks.enumerate() {
+            let (action, changed) = if block_idx < num_removed {
+                (ACTION_DELETE, &deleted)
+            } else {
+                (ACTION_INSERT, &inserted)
+            };
+            let rows = (0..step.keys[block_idx].len())
+                .filter(|row| changed.contains(&(block_idx, *row)))
+                .map(|row| (row as u32, updated.contains(&(block_idx, row))))
+                .collect::<Vec<_>>();
+            if !rows.is_empty() {
+                output.push_back(ChangedBlock {
+                    meta: meta.clone(),
+                    action,
+                    rows,
+                });
+            }
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn read_changed_block(&self, changed: ChangedBlock) -> Result<DataBlock> {
+        let block = self
+            .table
+            .read_changed_blocks(self.ctx.clone(), &[changed.meta])
+            .await?;
+        let indices = changed
+            .rows
+            .iter()
+            .map(|(row, _)| (0, *row, 1))
+            .collect::<Vec<_>>();
+        let mut block = DataBlock::take_blocks(&block, &indices, indices.len());
+        block.add_column(BlockEntry::new(
+            DataType::String,
+            Value::Scalar(Scalar::String(changed.action.as_bytes().to_vec())),
+        ));
+        let is_update = changed.rows.iter().map(|(_, is_update)| *is_update);
+        block.add_column(BlockEntry::new(
+            DataType::Boolean,
+            Value::Column(BooleanType::from_data(is_update.collect::<Vec<_>>())),
+        ));
+        Ok(block)
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for StreamSource {
+    const NAME: &'static str = "StreamSource";
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if !self.initialized {
+            self.initialized = true;
+            let mut steps = self.collect_rows().await?;
+            Self::cancel_unchanged_rows(&mut steps);
+            for (change, step) in self.changes.iter().zip(steps.iter()) {
+                Self::build_changed_blocks(change, step, &mut self.changed_blocks);
+            }
+        }
+        match self.changed_blocks.pop_front() {
+            Some(changed) => Ok(Some(self.read_changed_block(changed).await?)),
+            None => Ok(None),
+        }
+    }
+}
@@ -0,0 +1,233 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_catalog::catalog::StorageDescription;
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::PartStatistics;
+use common_catalog::plan::Partitions;
+use common_catalog::plan::PartitionsShuffleKind;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::query_kind::QueryKind;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::TableSchema;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeq;
+use common_pipeline_core::Pipeline;
+use common_pipeline_sources::EmptySource;
+use common_storages_fuse::FuseTable;
+use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+
+use crate::StreamPartInfo;
+use crate::StreamSource;
+
+pub const STREAM_ENGINE: &str = "STREAM";
+
+pub const OPT_KEY_TABLE_ID: &str = "table_id";
+pub const OPT_KEY_TABLE_NAME: &str = "table_name";
+pub const OPT_KEY_TABLE_DATABASE: &str = "table_database";
+
+pub const CHANGE_ACTION_COL_NAME: &str = "change$action";
+pub const CHANGE_IS_UPDATE_COL_NAME: &str = "change$is_update";
+
+/// A stream records the changes of a fuse table since an offset.
+///
+/// The offset is a snapshot of the base table, kept in the option `snapshot_location`
+/// of the stream (absent if the base table had no snapshot). Reading the stream returns
+/// the rows inserted and deleted since the offset, an updated row is returned as a
+/// deleted row and an inserted row with `change$is_update` set. If the stream is read by
+/// a DML statement, the offset is moved to the snapshot read, along with the commit of
+/// the statement.
+pub struct StreamTable {
+    table_info: TableInfo,
+    table_id: u64,
+    table_name: String,
+    table_database: String,
+    offset_location: Option<String>,
+}
+
+impl StreamTable {
+    pub fn try_create(table_info: TableInfo) -> Result<Box<dyn Table>> {
+        let options = table_info.options();
+        let table_id = options
+            .get(OPT_KEY_TABLE_ID)
+            .and_then(|id| id.parse::<u64>().ok())
+            .ok_or_else(|| ErrorCode::Internal("Need `table_id` when creating StreamTable"))?;
+        let table_name = options.get(OPT_KEY_TABLE_NAME).cloned().unwrap_or_default();
+        let table_database = options
+            .get(OPT_KEY_TABLE_DATABASE)
+            .cloned()
+            .unwrap_or_default();
+        let offset_location = options.get(OPT_KEY_SNAPSHOT_LOCATION).cloned();
+        Ok(Box::new(StreamTable {
+            table_info,
+            table_id,
+            table_name,
+            table_database,
+            offset_location,
+        }))
+    }
+
+    pub fn description() -> StorageDescription {
+        StorageDescription {
+            engine_name: STREAM_ENGINE.to_string(),
+            comment: "STREAM Storage Engine".to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn base_table_id(&self) -> u64 {
+        self.table_id
+    }
+
+    pub fn offset_location(&self) -> Option<String> {
+        self.offset_location.clone()
+    }
+
+    /// Load the base table of the stream, in its latest state.
+    #[async_backtrace::framed]
+    pub async fn source_table(&self, ctx: Arc<dyn TableContext>) -> Result<Arc<dyn Table>> {
+        let catalog = ctx.get_catalog(self.table_info.catalog()).await?;
+        let (ident, meta) = catalog.get_table_meta_by_id(self.table_id).await?;
+        if meta.drop_on.is_some() {
+            return Err(ErrorCode::UnknownTable(format!(
+                "The base table '{}'.'{}' of stream {} has been dropped",
+                self.table_database, self.table_name, self.table_info.desc
+            )));
+        }
+        let table_info = TableInfo {
+            ident,
+            desc: format!("'{}'.'{}'", self.table_database, self.table_name),
+            name: self.table_name.clone(),
+            meta: meta.as_ref().clone(),
+            tenant: self.table_info.tenant.clone(),
+            ..Default::default()
+        };
+        catalog.get_table_by_info(&table_info)
+    }
+
+    fn check_source_schema(&self, source_schema: &TableSchema) -> Result<()> {
+        let schema = self.schema();
+        let num_fields = schema.fields().len() - 2;
+        let fields = &schema.fields()[..num_fields];
+        let source_fields = source_schema.fields();
+        let matched = fields.len() == source_fields.len()
+            && fields
+                .iter()
+                .zip(source_fields)
+                .all(|(field, source_field)| {
+                    field.name() == source_field.name()
+                        && field.data_type() == source_field.data_type()
+                });
+        if !matched {
+            return Err(ErrorCode::TableSchemaMismatch(format!(
+                "The schema of the base table '{}'.'{}' has been changed since stream {} is created, please recreate the stream",
+                self.table_database, self.table_name, self.table_info.desc
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for StreamTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn is_read_only(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+        dry_run: bool,
+    ) -> Result<(PartStatistics, Partitions)> {
+        let table = self.source_table(ctx.clone()).await?;
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        self.check_source_schema(&fuse_table.schema())?;
+
+        let changes = fuse_table
+            .collect_changes(ctx.clone(), self.offset_location.clone())
+            .await?;
+
+        // The stream is consumed by a DML statement, move the offset to the
+        // snapshot the changes are collected to.
+        let location = fuse_table.snapshot_loc().await?;
+        if !dry_run
+            && location != self.offset_location
+            && matches!(ctx.get_query_kind(), QueryKind::Insert | QueryKind::Update)
+        {
+            let mut new_table_meta = self.table_info.meta.clone();
+            if let Some(location) = &location {
+                new_table_meta
+                    .options
+                    .insert(OPT_KEY_SNAPSHOT_LOCATION.to_owned(), location.clone());
+            }
+            ctx.add_stream_offset(UpdateTableMetaReq {
+                table_id: self.table_info.ident.table_id,
+                seq: MatchSeq::Exact(self.table_info.ident.seq),
+                new_table_meta,
+                copied_files: None,
+                deduplicated_label: None,
+            })?;
+        }
+
+        let (mut read_rows, mut read_bytes) = (0, 0);
+        for change in changes.iter() {
+            for block in change.removed_blocks.iter().chain(&change.added_blocks) {
+                read_rows += block.row_count as usize;
+                read_bytes += block.block_size as usize;
+            }
+        }
+        let part = StreamPartInfo::create(fuse_table.get_table_info().clone(), changes);
+        Ok((
+            PartStatistics::new_exact(read_rows, read_bytes, 1, 1),
+            Partitions::create_nolazy(PartitionsShuffleKind::Seq, vec![part]),
+        ))
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+        _put_cache: bool,
+    ) -> Result<()> {
+        if plan.parts.is_empty() {
+            return pipeline.add_source(EmptySource::create, 1);
+        }
+
+        let part = StreamPartInfo::from_part(&plan.parts.partitions[0])?;
+        let fuse_table: Arc<FuseTable> = FuseTable::do_create(part.table_info.clone())?.into();
+        let changes = part.changes.clone();
+        pipeline.add_source(
+            |output| StreamSource::create(ctx.clone(), output, fuse_table.clone(), changes.clone()),
+            1,
+        )
+    }
+}
@@ -0,0 +1,119 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::NumberDataType;
+use common_expression::ColumnBuilder;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRef;
+use common_expression::TableSchemaRefExt;
+
+use crate::SystemLogElement;
+use crate::SystemLogQueue;
+use crate::SystemLogTable;
+
+#[derive(Clone)]
+pub struct AutoCompactionHistoryLogElement {
+    pub start_time: i64,
+    pub end_time: i64,
+    pub database: String,
+    pub table: String,
+    // the write operation whose commit triggered the compaction.
+    pub trigger: String,
+    // one of `compact_segments`, `compact_blocks` and `recluster`.
+    pub action: String,
+    pub segment_count_before: u64,
+    pub block_count_before: u64,
+    pub segment_count_after: u64,
+    pub block_count_after: u64,
+    pub status: String,
+    pub error_message: String,
+}
+
+impl SystemLogElement for AutoCompactionHistoryLogElement {
+    const TABLE_NAME: &'static str = "auto_compaction_history";
+
+    fn schema() -> TableSchemaRef {
+        TableSchemaRefExt::create(vec![
+            TableField::new("start_time", TableDataType::Timestamp),
+            TableField::new("end_time", TableDataType::Timestamp),
+            TableField::new("database", TableDataType::String),
+            TableField::new("table", TableDataType::String),
+            TableField::new("trigger", TableDataType::String),
+            TableField::new("action", TableDataType::String),
+            TableField::new(
+                "segment_count_before",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "block_count_before",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "segment_count_after",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "block_count_after",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new("status", TableDataType::String),
+            TableField::new("error_message", TableDataType::String),
+        ])
+    }
+
+    fn fill_to_data_block(&self, columns: &mut Vec<ColumnBuilder>) -> Result<()> {
+        let mut columns = columns.iter_mut();
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Timestamp(self.start_time).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Timestamp(self.end_time).as_ref());
+        for value in [&self.database, &self.table, &self.trigger, &self.action] {
+            columns
+                .next()
+                .unwrap()
+                .push(Scalar::String(value.as_bytes().to_vec()).as_ref());
+        }
+        for value in [
+            self.segment_count_before,
+            self.block_count_before,
+            self.segment_count_after,
+            self.block_count_after,
+        ] {
+            columns
+                .next()
+                .unwrap()
+                .push(Scalar::Number(NumberScalar::UInt64(value)).as_ref());
+        }
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.status.as_bytes().to_vec()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.error_message.as_bytes().to_vec()).as_ref());
+        Ok(())
+    }
+}
+
+pub type AutoCompactionHistoryQueue = SystemLogQueue<AutoCompactionHistoryLogElement>;
+pub type AutoCompactionHistoryTable = SystemLogTable<AutoCompactionHistoryLogElement>;
@@ -18,6 +18,7 @@
 
 extern crate core;
 
+mod auto_compaction_history_table;
 mod background_jobs_table;
 mod background_tasks_table;
 mod backtrace_table;
@@ -57,6 +58,9 @@ mod tracing_table;
 mod users_table;
 mod util;
 
+pub use auto_compaction_history_table::AutoCompactionHistoryLogElement;
+pub use auto_compaction_history_table::AutoCompactionHistoryQueue;
+pub use auto_compaction_history_table::AutoCompactionHistoryTable;
 pub use background_jobs_table::BackgroundJobTable;
 pub use background_tasks_table::BackgroundTaskTable;
 pub use backtrace_table::BacktraceTable;
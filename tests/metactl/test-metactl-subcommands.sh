@@ -0,0 +1,130 @@
+#!/bin/sh
+
+set -o errexit
+
+BUILD_PROFILE="${BUILD_PROFILE:-debug}"
+
+metactl="./target/${BUILD_PROFILE}/databend-metactl"
+
+chmod +x ./target/${BUILD_PROFILE}/databend-metactl
+chmod +x ./target/${BUILD_PROFILE}/databend-meta
+
+rm -fr .databend/
+
+# Fail the test if the output of a command does not contain the expected string.
+expect_contains () {
+    local output="$1"
+    local want="$2"
+
+    echo "$output"
+    if echo "$output" | grep -Fq "$want"; then
+        echo " === Found '$want', good!"
+    else
+        echo " === Expect '$want' in the output!!!"
+        exit 1
+    fi
+}
+
+
+echo " ==="
+echo " === 1. Start a single meta node"
+echo " ==="
+
+nohup ./target/${BUILD_PROFILE}/databend-meta --config-file=./tests/metactl/config/databend-meta-node-1.toml &
+python3 scripts/ci/wait_tcp.py --timeout 10 --port 9191
+
+sleep 1
+
+
+echo " ==="
+echo " === 2. Test kv subcommands through the grpc api"
+echo " ==="
+
+grpc="--grpc-api-address 127.0.0.1:9191"
+
+echo " === put"
+output="$($metactl $grpc put metactl_test/a foo)"
+expect_contains "$output" '"data":"foo"'
+
+echo " === get"
+output="$($metactl $grpc get metactl_test/a)"
+expect_contains "$output" '"data":"foo"'
+
+echo " === txn"
+output="$(echo '{"condition":[],"if_then":[{"request":{"Put":{"key":"metactl_test/b","value":[98,97,114],"prev_value":false}}}],"else_then":[]}' |
+    $metactl $grpc txn -)"
+expect_contains "$output" '"success":true'
+
+echo " === list"
+output="$($metactl $grpc list --prefix metactl_test/)"
+expect_contains "$output" '"key":"metactl_test/a"'
+expect_contains "$output" '"key":"metactl_test/b"'
+expect_contains "$output" '"data":"bar"'
+
+echo " === del"
+output="$($metactl $grpc del metactl_test/a)"
+expect_contains "$output" '"data":"foo"'
+
+output="$($metactl $grpc get metactl_test/a)"
+expect_contains "$output" '"value":null'
+
+echo " === status"
+output="$($metactl $grpc status)"
+expect_contains "$output" 'State: Leader'
+
+
+echo " ==="
+echo " === 3. Test cluster subcommands through the admin api"
+echo " ==="
+
+nohup ./target/${BUILD_PROFILE}/databend-meta --config-file=./tests/metactl/config/databend-meta-node-2.toml &
+python3 scripts/ci/wait_tcp.py --timeout 10 --port 28202
+
+sleep 1
+
+nohup ./target/${BUILD_PROFILE}/databend-meta --config-file=./tests/metactl/config/databend-meta-node-3.toml &
+python3 scripts/ci/wait_tcp.py --timeout 10 --port 28302
+
+sleep 3
+
+echo " === member list"
+output="$($metactl --admin-api-address 127.0.0.1:28101 member list)"
+expect_contains "$output" '"port":28103'
+expect_contains "$output" '"port":28203'
+expect_contains "$output" '"port":28303'
+
+echo " === trigger-snapshot"
+output="$($metactl --admin-api-address 127.0.0.1:28101 trigger-snapshot)"
+expect_contains "$output" 'Triggered snapshot on 127.0.0.1:28101'
+
+echo " === trigger-elect does not replace an active leader"
+$metactl --admin-api-address 127.0.0.1:28201 trigger-elect
+sleep 3
+
+output="$($metactl $grpc status)"
+expect_contains "$output" 'State: Leader'
+
+echo " === trigger-elect elects a new leader once the leader is gone"
+kill $(pgrep -f databend-meta-node-1.toml)
+sleep 1
+
+# The followers refuse to vote until the lease of the stopped leader expires, retry until then.
+for i in $(seq 1 20); do
+    $metactl --admin-api-address 127.0.0.1:28201 trigger-elect
+    sleep 1
+
+    output="$($metactl --grpc-api-address 127.0.0.1:28202 status)"
+    if echo "$output" | grep -Eq 'Leader: id=(2|3) '; then
+        break
+    fi
+done
+expect_contains "$output" 'Leader: id='
+if echo "$output" | grep -q 'Leader: id=1 '; then
+    echo " === Expect a new leader other than node 1!!!"
+    exit 1
+fi
+
+killall databend-meta
+sleep 2
+
+echo " === Done"